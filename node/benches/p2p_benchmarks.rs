@@ -88,6 +88,7 @@ fn bench_message_batch_serialization(c: &mut Criterion) {
                 success: i % 2 == 0,
                 amount: (i * 1000) as u64,
                 timestamp: 1704067200 + i as u64,
+                skill: None,
             })
             .collect();
 
@@ -198,6 +199,7 @@ fn bench_trust_cache(c: &mut Criterion) {
                 successful_transactions: 100,
                 failed_transactions: 5,
                 endorsement_count: 3,
+                skill: None,
             };
             runtime.block_on(async {
                 cache.insert(&did, info).await;
@@ -219,6 +221,7 @@ fn bench_trust_cache(c: &mut Criterion) {
                 successful_transactions: 100,
                 failed_transactions: 5,
                 endorsement_count: 3,
+                skill: None,
             };
             cache.insert(&did, info).await;
         }
@@ -0,0 +1,105 @@
+//! Trust Propagation Benchmarks
+//!
+//! Benchmarks for EigenTrust-style propagation over synthetic endorsement
+//! and transaction graphs of up to 100k agents.
+//!
+//! ## Running Benchmarks
+//!
+//! ```bash
+//! cargo bench --bench trust_propagation
+//! cargo bench --bench trust_propagation -- eigentrust/100000
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use agoramesh_node::eigentrust::{EigenTrustConfig, LocalTrustGraph};
+
+/// Average outgoing endorsements per agent.
+const ENDORSEMENTS_PER_AGENT: usize = 5;
+
+/// Average outgoing transaction relationships per agent.
+const COUNTERPARTIES_PER_AGENT: usize = 3;
+
+/// Number of pre-trusted seeds.
+const SEED_COUNT: usize = 10;
+
+// ============================================================================
+// Graph Generation
+// ============================================================================
+
+fn agent_did(i: usize) -> String {
+    format!("did:agoramesh:base:agent-{}", i)
+}
+
+/// Random graph with a 10% Sybil ring endorsing itself.
+fn create_graph(agents: usize) -> (LocalTrustGraph, Vec<String>) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut graph = LocalTrustGraph::new();
+    let honest = agents - agents / 10;
+
+    for i in 0..honest {
+        let from = agent_did(i);
+        for _ in 0..ENDORSEMENTS_PER_AGENT {
+            graph.add_local_trust(&from, &agent_did(rng.gen_range(0..honest)), 1.0);
+        }
+        for _ in 0..COUNTERPARTIES_PER_AGENT {
+            let transactions = rng.gen_range(1..20) as f64;
+            graph.add_local_trust(
+                &from,
+                &agent_did(rng.gen_range(0..honest)),
+                transactions * 0.1,
+            );
+        }
+    }
+    for i in honest..agents {
+        let from = agent_did(i);
+        for _ in 0..ENDORSEMENTS_PER_AGENT {
+            graph.add_local_trust(&from, &agent_did(rng.gen_range(honest..agents)), 1.0);
+        }
+    }
+
+    let seeds = (0..SEED_COUNT).map(agent_did).collect();
+    (graph, seeds)
+}
+
+// ============================================================================
+// EigenTrust Benchmarks
+// ============================================================================
+
+fn bench_eigentrust(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eigentrust");
+    group.sample_size(10);
+
+    let config = EigenTrustConfig::default();
+
+    for agents in [1_000, 10_000, 100_000] {
+        let (mut graph, seeds) = create_graph(agents);
+
+        group.throughput(Throughput::Elements(agents as u64));
+        group.bench_with_input(BenchmarkId::new("propagate", agents), &agents, |b, _| {
+            b.iter(|| {
+                let trust = graph.propagate(black_box(&seeds), &config);
+                black_box(trust);
+            });
+        });
+    }
+
+    group.bench_function("build_graph/100000", |b| {
+        b.iter(|| {
+            let graph = create_graph(black_box(100_000));
+            black_box(graph);
+        });
+    });
+
+    group.finish();
+}
+
+// ============================================================================
+// Criterion Configuration
+// ============================================================================
+
+criterion_group!(benches, bench_eigentrust);
+
+criterion_main!(benches);
@@ -4,13 +4,14 @@
 //! - Health check endpoint
 //! - Agent discovery endpoints
 //! - Trust query endpoints
+//! - DID resolution endpoint
 //! - A2A protocol endpoints
 
 use axum::{
     extract::{Path, Query, State},
     http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
@@ -22,12 +23,19 @@ use tokio::sync::RwLock;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 
 use crate::config::ApiConfig;
-use crate::discovery::{CapabilityCard, DiscoveryService};
-use crate::error::Result;
+use crate::did_resolver::UniversalResolver;
+use crate::discovery::{CapabilityCard, DiscoveryService, PricingModel};
+use crate::error::{Error, Result};
 use crate::metrics::{MetricsConfig, MetricsService};
+use crate::multichain::AggregatedTrust;
+use crate::network::{topics, EnvelopeVerifier, GossipPublisher, SignedEnvelope, TrustMessage};
 use crate::rate_limit::{RateLimitConfig, RateLimitLayer, RateLimitService};
-use crate::search::HybridSearch;
-use crate::trust::{TrustInfo, TrustService};
+use crate::search::{
+    base_price, HybridSearch, Page, SchemaQuery, SearchFilter, SkillFit, SortOrder,
+};
+use crate::trust::{EndorsementPath, TrustExplanation, TrustInfo, TrustService};
+use crate::trust_history::{parse_resolution, TrustHistory};
+use crate::trust_reconcile::ReconciledTrust;
 
 /// Health check response.
 #[derive(Debug, Serialize, Deserialize)]
@@ -77,8 +85,20 @@ pub struct AppState {
     pub metrics: Arc<MetricsService>,
     /// Optional semantic search service.
     pub hybrid_search: Option<Arc<RwLock<HybridSearch>>>,
+    /// Optional DID resolver for `GET /did/{did}`.
+    pub did_resolver: Option<Arc<UniversalResolver>>,
     /// Optional admin token for agent registration.
     pub api_token: Option<String>,
+    /// Verifies DID-signed agent registrations; `None` accepts plain cards
+    /// (`trust.authenticated_writes`).
+    pub write_verifier: Option<Arc<EnvelopeVerifier>>,
+    /// Verifies requests that must be DID-signed whatever
+    /// `trust.authenticated_writes` says (endorsement revocations);
+    /// `None` rejects them.
+    pub envelope_verifier: Option<Arc<EnvelopeVerifier>>,
+    /// Signed GossipSub publisher for propagating trust changes to peers;
+    /// `None` keeps them local.
+    pub gossip: Option<GossipPublisher>,
 }
 
 /// Semantic search result with scores.
@@ -86,8 +106,11 @@ pub struct AppState {
 pub struct SemanticSearchResult {
     /// Agent DID.
     pub did: String,
-    /// Combined hybrid score (0.0 - 1.0).
+    /// Final score: relevance blended with node-computed trust (0.0 - 1.0).
     pub score: f32,
+    /// Combined vector and keyword score before trust (0.0 - 1.0).
+    #[serde(default)]
+    pub relevance: f32,
     /// Vector similarity score.
     pub vector_score: f32,
     /// Keyword match score.
@@ -99,6 +122,21 @@ pub struct SemanticSearchResult {
     pub trust: Option<TrustInfo>,
 }
 
+/// Schema match result: an agent and its best-fitting skill.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SchemaMatchResult {
+    /// Agent DID (empty for cards without one).
+    pub did: String,
+    /// How well the agent's best skill fits the posted schemas.
+    #[serde(flatten)]
+    pub fit: SkillFit,
+    /// The capability card.
+    pub card: CapabilityCard,
+    /// Live trust data from TrustService (enriched at query time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust: Option<TrustInfo>,
+}
+
 /// API server.
 pub struct ApiServer {
     /// API configuration.
@@ -112,8 +150,88 @@ pub struct ApiServer {
 pub struct SearchQuery {
     /// Search query string.
     pub q: Option<String>,
+    /// Only return agents offering this skill ID, ranked by their trust
+    /// in it.
+    pub skill: Option<String>,
+    /// Only return agents whose trust score (in `skill`, if given) is at
+    /// least this (0.0 - 1.0).
+    pub min_trust: Option<f64>,
+    /// Only return agents with at least this much stake in USDC (6 decimals).
+    pub min_stake: Option<u64>,
+    /// Only return agents using this pricing model.
+    pub pricing_model: Option<PricingModel>,
+    /// Only return agents whose base price is at most this, in USDC
+    /// (6 decimals).
+    pub max_price: Option<u64>,
+    /// Only return agents accepting this payment method (e.g. "x402").
+    pub payment_method: Option<String>,
+    /// Only return agents supporting this authentication scheme.
+    pub auth_scheme: Option<String>,
+    /// Only return agents from this provider organization.
+    pub provider: Option<String>,
+    /// Result order (default: relevance).
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Maximum number of results (1 - 100, default: all).
+    pub limit: Option<usize>,
+    /// Offset of the first result, as returned in `X-Next-Cursor` by the
+    /// previous page.
+    pub cursor: Option<String>,
+}
+
+impl SearchQuery {
+    /// The validated filter and page requested.
+    fn parse(&self) -> std::result::Result<(SearchFilter, Page), (StatusCode, Json<ApiError>)> {
+        let filter = SearchFilter {
+            pricing_model: self.pricing_model.clone(),
+            max_price: self.max_price,
+            payment_method: self.payment_method.clone(),
+            auth_scheme: self.auth_scheme.clone(),
+            provider: self.provider.clone(),
+            skill: self.skill.clone(),
+            min_trust: self.min_trust,
+            min_stake: self.min_stake,
+        };
+        filter.validate().map_err(|e| bad_request(e.to_string()))?;
+        let page = Page::parse(self.limit, self.cursor.as_deref())
+            .map_err(|e| bad_request(e.to_string()))?;
+        Ok((filter, page))
+    }
+}
+
+/// Response header carrying the cursor of the next page of search results.
+pub const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+/// Headers announcing the `next` page cursor, if any.
+fn next_cursor_headers(next: Option<String>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(value) = next.and_then(|cursor| HeaderValue::from_str(&cursor).ok()) {
+        headers.insert(HeaderName::from_static(NEXT_CURSOR_HEADER), value);
+    }
+    headers
+}
+
+/// Query parameters for trust lookups.
+#[derive(Debug, Deserialize)]
+pub struct TrustQuery {
+    /// Skill ID to scope the reputation component to.
+    pub skill: Option<String>,
+}
+
+/// Query parameters for trust history.
+#[derive(Debug, Deserialize)]
+pub struct TrustHistoryQuery {
+    /// Start of the range in Unix seconds (default: 30 days before `to`).
+    pub from: Option<u64>,
+    /// End of the range in Unix seconds (default: now).
+    pub to: Option<u64>,
+    /// Bucket size such as `15m`, `1h` or `1d` (default: raw snapshots).
+    pub resolution: Option<String>,
 }
 
+/// Default trust history range when `from` is omitted.
+const DEFAULT_HISTORY_RANGE_SECS: u64 = 30 * 86_400;
+
 /// API error response.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApiError {
@@ -121,23 +239,33 @@ pub struct ApiError {
     pub error: String,
 }
 
+/// A `400 Bad Request` carrying `error`.
+fn bad_request(error: String) -> (StatusCode, Json<ApiError>) {
+    (StatusCode::BAD_REQUEST, Json(ApiError { error }))
+}
+
 impl ApiServer {
     /// Create a new API server.
     pub fn new(config: ApiConfig) -> Self {
         let api_token = normalize_api_token(config.admin_token.clone());
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
         let state = AppState {
-            discovery: Arc::new(DiscoveryService::new()),
-            trust: Arc::new(TrustService::new(
-                "https://sepolia.base.org".to_string(),
-                None,
-            )),
+            discovery: Arc::new(DiscoveryService::new().with_trust(trust.clone())),
+            trust,
             start_time: Instant::now(),
             peer_count: Arc::new(AtomicU64::new(0)),
             node_info: None,
             rate_limiter: Arc::new(RateLimitService::new(RateLimitConfig::default())),
             metrics: Arc::new(MetricsService::new(MetricsConfig::default())),
             hybrid_search: None,
+            did_resolver: None,
             api_token,
+            write_verifier: None,
+            envelope_verifier: None,
+            gossip: None,
         };
         Self { config, state }
     }
@@ -165,8 +293,18 @@ impl ApiServer {
                 get(search_agents_handler).post(register_agent_handler),
             )
             .route("/agents/semantic", get(semantic_search_handler))
+            .route("/agents/match", post(match_agents_handler))
             .route("/agents/{did}", get(get_agent_handler))
             .route("/trust/{did}", get(get_trust_handler))
+            .route("/trust/{did}/path", get(get_trust_path_handler))
+            .route("/trust/{did}/explain", get(explain_trust_handler))
+            .route("/trust/{did}/history", get(get_trust_history_handler))
+            .route("/trust/{did}/chains", get(get_chain_trust_handler))
+            .route(
+                "/trust/{did}/endorsements/revoke",
+                post(revoke_endorsement_handler),
+            )
+            .route("/did/{did}", get(resolve_did_handler))
             .layer(rate_limit_layer);
 
         // Routes that are NOT rate limited (health checks, metadata, metrics)
@@ -247,6 +385,8 @@ fn build_cors_layer(config: &ApiConfig) -> Option<CorsLayer> {
         HeaderName::from_static("x-api-key"),
     ]);
 
+    cors = cors.expose_headers([HeaderName::from_static(NEXT_CURSOR_HEADER)]);
+
     Some(cors)
 }
 
@@ -354,15 +494,81 @@ async fn agent_card_handler(State(state): State<AppState>) -> Json<CapabilityCar
     })
 }
 
+/// Pair each of the `items` with its agent's trust, in `skill` if given,
+/// keeping their order. Only cached TrustRegistry values are used.
+async fn with_trust<T>(
+    trust: &TrustService,
+    items: Vec<T>,
+    skill: Option<&str>,
+    card_of: impl Fn(&T) -> &CapabilityCard,
+) -> Vec<(T, Option<TrustInfo>)> {
+    let mut paired = Vec::with_capacity(items.len());
+    for item in items {
+        let info = match card_of(&item).agoramesh.as_ref() {
+            Some(ext) => trust.get_cached_skill_trust(&ext.did, skill).await.ok(),
+            None => None,
+        };
+        paired.push((item, info));
+    }
+    paired
+}
+
+/// Order search results by `sort`, pairing each with the live trust it
+/// was judged by (in `filter.skill` if set). The constraints of `filter`
+/// were already applied by the search, whose relevance order already
+/// weighs that trust, so only `sort=trust` reorders by it.
+///
+/// Trust is looked up when a trust ordering needs it, or when `enrich`
+/// asks for it regardless.
+async fn refine_results<T>(
+    trust: &TrustService,
+    items: Vec<T>,
+    filter: &SearchFilter,
+    sort: SortOrder,
+    enrich: bool,
+    card_of: impl Fn(&T) -> &CapabilityCard,
+) -> Vec<(T, Option<TrustInfo>)> {
+    let mut items = if enrich || sort == SortOrder::Trust {
+        with_trust(trust, items, filter.skill.as_deref(), &card_of).await
+    } else {
+        items.into_iter().map(|item| (item, None)).collect()
+    };
+
+    // Stable sorts, so equal agents keep their relevance order
+    match sort {
+        SortOrder::Relevance => {}
+        SortOrder::Trust => {
+            let score = |info: &Option<TrustInfo>| info.as_ref().map_or(0.0, |i| i.score);
+            items.sort_by(|a, b| score(&b.1).total_cmp(&score(&a.1)));
+        }
+        SortOrder::Price => {
+            items.sort_by_key(|(item, _)| base_price(card_of(item)).unwrap_or(u64::MAX));
+        }
+    }
+    items
+}
+
 /// Search agents handler.
 async fn search_agents_handler(
     State(state): State<AppState>,
     Query(params): Query<SearchQuery>,
-) -> std::result::Result<Json<Vec<CapabilityCard>>, (StatusCode, Json<ApiError>)> {
-    let query = params.q.unwrap_or_default();
-
-    match state.discovery.search(&query).await {
-        Ok(agents) => Ok(Json(agents)),
+) -> std::result::Result<(HeaderMap, Json<Vec<CapabilityCard>>), (StatusCode, Json<ApiError>)> {
+    let (filter, page) = params.parse()?;
+    let query = params.q.as_deref().unwrap_or_default();
+
+    match state.discovery.search(query, &filter).await {
+        Ok(agents) => {
+            let agents =
+                refine_results(&state.trust, agents, &filter, params.sort, false, |card| {
+                    card
+                })
+                .await;
+            let (agents, next) = page.apply(agents);
+            Ok((
+                next_cursor_headers(next),
+                Json(agents.into_iter().map(|(card, _)| card).collect()),
+            ))
+        }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiError {
@@ -376,7 +582,8 @@ async fn search_agents_handler(
 async fn semantic_search_handler(
     State(state): State<AppState>,
     Query(params): Query<SearchQuery>,
-) -> std::result::Result<Json<Vec<SemanticSearchResult>>, (StatusCode, Json<ApiError>)> {
+) -> std::result::Result<(HeaderMap, Json<Vec<SemanticSearchResult>>), (StatusCode, Json<ApiError>)>
+{
     // Check if HybridSearch is configured
     let hybrid = match &state.hybrid_search {
         Some(h) => h,
@@ -391,20 +598,22 @@ async fn semantic_search_handler(
         }
     };
 
-    let query = params.q.unwrap_or_default();
+    let (filter, page) = params.parse()?;
+    let query = params.q.as_deref().unwrap_or_default();
     if query.is_empty() {
-        return Ok(Json(vec![]));
+        return Ok((HeaderMap::new(), Json(vec![])));
     }
 
     // Perform semantic search
     let hybrid_guard = hybrid.read().await;
-    match hybrid_guard.search(&query).await {
+    match hybrid_guard.rank(query, &filter).await {
         Ok(results) => {
-            let mut response: Vec<SemanticSearchResult> = results
+            let response: Vec<SemanticSearchResult> = results
                 .into_iter()
                 .map(|r| SemanticSearchResult {
                     did: r.did,
                     score: r.score,
+                    relevance: r.relevance,
                     vector_score: r.vector_score,
                     keyword_score: r.keyword_score,
                     card: r.card,
@@ -412,14 +621,76 @@ async fn semantic_search_handler(
                 })
                 .collect();
 
-            // Enrich each result with live trust data from TrustService
-            for result in &mut response {
-                if let Ok(trust_info) = state.trust.get_trust(&result.did).await {
-                    result.trust = Some(trust_info);
-                }
-            }
+            // The relevance ranking stands unless sorting by trust; either
+            // way each result is enriched with live trust data from
+            // TrustService
+            let response =
+                refine_results(&state.trust, response, &filter, params.sort, true, |r| {
+                    &r.card
+                })
+                .await;
+            let (response, next) = page.apply(response);
+
+            Ok((
+                next_cursor_headers(next),
+                Json(
+                    response
+                        .into_iter()
+                        .map(|(result, trust)| SemanticSearchResult { trust, ..result })
+                        .collect(),
+                ),
+            ))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError {
+                error: e.to_string(),
+            }),
+        )),
+    }
+}
+
+/// Schema match handler: agents whose skills accept the posted input
+/// schema and produce the posted output schema, best fit first.
+async fn match_agents_handler(
+    State(state): State<AppState>,
+    Query(params): Query<SearchQuery>,
+    Json(query): Json<SchemaQuery>,
+) -> std::result::Result<(HeaderMap, Json<Vec<SchemaMatchResult>>), (StatusCode, Json<ApiError>)> {
+    let (filter, page) = params.parse()?;
+    query.validate().map_err(|e| bad_request(e.to_string()))?;
+
+    match state.discovery.match_schema(&query, &filter).await {
+        Ok(matches) => {
+            let results: Vec<SchemaMatchResult> = matches
+                .into_iter()
+                .map(|(card, fit)| SchemaMatchResult {
+                    did: card
+                        .agoramesh
+                        .as_ref()
+                        .map(|ext| ext.did.clone())
+                        .unwrap_or_default(),
+                    fit,
+                    card,
+                    trust: None,
+                })
+                .collect();
 
-            Ok(Json(response))
+            let results = refine_results(&state.trust, results, &filter, params.sort, true, |r| {
+                &r.card
+            })
+            .await;
+            let (results, next) = page.apply(results);
+
+            Ok((
+                next_cursor_headers(next),
+                Json(
+                    results
+                        .into_iter()
+                        .map(|(result, trust)| SchemaMatchResult { trust, ..result })
+                        .collect(),
+                ),
+            ))
         }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -457,11 +728,22 @@ async fn get_agent_handler(
     }
 }
 
+/// Envelope topic that signed `POST /agents` bodies are sealed for.
+pub const REGISTER_AGENT_TOPIC: &str = "/agoramesh/api/agents";
+
+/// Envelope topic that signed endorsement revocations are sealed for: the
+/// trust gossip topic, so the node can relay them to peers unchanged.
+pub const REVOKE_ENDORSEMENT_TOPIC: &str = topics::TRUST;
+
 /// Register agent handler.
+///
+/// With a [`AppState::write_verifier`], the body must be a
+/// [`SignedEnvelope`] sealed for [`REGISTER_AGENT_TOPIC`] by the card's own
+/// DID, and that DID must meet the node's trust requirements.
 async fn register_agent_handler(
     State(state): State<AppState>,
     headers: HeaderMap,
-    Json(card): Json<CapabilityCard>,
+    Json(body): Json<serde_json::Value>,
 ) -> std::result::Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<ApiError>)> {
     if let Some(token) = state.api_token.as_deref() {
         if !is_admin_request(&headers, token) {
@@ -474,7 +756,30 @@ async fn register_agent_handler(
         }
     }
 
-    match state.discovery.register(&card).await {
+    let (card, registration) = match state.write_verifier.as_deref() {
+        Some(verifier) => {
+            let (card, envelope) = authenticate_registration(&state.trust, verifier, body).await?;
+            (card, Some(envelope))
+        }
+        None => {
+            let card = serde_json::from_value::<CapabilityCard>(body).map_err(|e| {
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(ApiError {
+                        error: format!("Invalid capability card: {}", e),
+                    }),
+                )
+            })?;
+            (card, None)
+        }
+    };
+
+    // Peers can check a signed registration themselves when it is relayed
+    let registered = match registration {
+        Some(ref envelope) => state.discovery.register_signed(&card, envelope).await,
+        None => state.discovery.register(&card).await,
+    };
+    match registered {
         Ok(()) => {
             let did = card
                 .agoramesh
@@ -498,18 +803,340 @@ async fn register_agent_handler(
     }
 }
 
+/// Verify a signed registration and return the card it carries, with the
+/// envelope it was sealed in.
+///
+/// Bad or missing signatures are 401; a card for another DID, or a DID
+/// below the trust requirements, is 403.
+async fn authenticate_registration(
+    trust: &TrustService,
+    verifier: &EnvelopeVerifier,
+    body: serde_json::Value,
+) -> std::result::Result<(CapabilityCard, SignedEnvelope), (StatusCode, Json<ApiError>)> {
+    let reject = |status: StatusCode, error: String| (status, Json(ApiError { error }));
+
+    let (card, envelope): (CapabilityCard, _) =
+        open_signed_write(verifier, REGISTER_AGENT_TOPIC, "Agent registration", body).await?;
+    let sender = envelope.sender_did.as_str();
+    let did = card.agoramesh.as_ref().map(|ext| ext.did.as_str());
+    if did != Some(sender) {
+        return Err(reject(
+            StatusCode::FORBIDDEN,
+            format!(
+                "Card DID {} does not match envelope sender {}",
+                did.unwrap_or("(none)"),
+                sender
+            ),
+        ));
+    }
+
+    match trust.meets_requirements(sender).await {
+        Ok(true) => Ok((card, envelope)),
+        Ok(false) => Err(reject(
+            StatusCode::FORBIDDEN,
+            format!("{} does not meet the trust requirements", sender),
+        )),
+        Err(e) => Err(reject(StatusCode::BAD_REQUEST, e.to_string())),
+    }
+}
+
+/// Verify a signed write sealed for `topic` and return its payload and
+/// the envelope (whose `sender_did` is the authenticated writer).
+///
+/// A body that is not a valid signed envelope is 401; a payload that does
+/// not parse is 422.
+async fn open_signed_write<T: serde::de::DeserializeOwned>(
+    verifier: &EnvelopeVerifier,
+    topic: &str,
+    what: &str,
+    body: serde_json::Value,
+) -> std::result::Result<(T, SignedEnvelope), (StatusCode, Json<ApiError>)> {
+    let reject = |status: StatusCode, error: String| (status, Json(ApiError { error }));
+
+    let envelope: SignedEnvelope = serde_json::from_value(body).map_err(|e| {
+        reject(
+            StatusCode::UNAUTHORIZED,
+            format!("{} must be a signed envelope: {}", what, e),
+        )
+    })?;
+    verifier
+        .verify(topic, &envelope)
+        .await
+        .map_err(|rejection| reject(StatusCode::UNAUTHORIZED, rejection.to_string()))?;
+
+    let payload = serde_json::from_str(&envelope.payload).map_err(|e| {
+        reject(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Invalid {}: {}", what.to_lowercase(), e),
+        )
+    })?;
+    Ok((payload, envelope))
+}
+
+/// Content type of W3C DID resolution results.
+const DID_RESOLUTION_CONTENT_TYPE: &str =
+    "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+
+/// DID resolution handler.
+///
+/// Returns the W3C resolution result; the HTTP status mirrors the
+/// resolution error (or 410 Gone for deactivated DIDs).
+async fn resolve_did_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+) -> std::result::Result<impl IntoResponse, (StatusCode, Json<ApiError>)> {
+    let Some(resolver) = state.did_resolver.as_ref() else {
+        return Err((
+            StatusCode::NOT_IMPLEMENTED,
+            Json(ApiError {
+                error: "DID resolution not available (resolver not configured)".to_string(),
+            }),
+        ));
+    };
+
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    let result = resolver.resolve_did(&did).await;
+    let status = match result.error_code() {
+        None if result.is_deactivated() => StatusCode::GONE,
+        None => StatusCode::OK,
+        Some("invalidDid") => StatusCode::BAD_REQUEST,
+        Some("notFound") => StatusCode::NOT_FOUND,
+        Some("methodNotSupported") => StatusCode::NOT_IMPLEMENTED,
+        Some(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    Ok((
+        status,
+        [(header::CONTENT_TYPE, DID_RESOLUTION_CONTENT_TYPE)],
+        Json(result),
+    ))
+}
+
 /// Get trust info handler.
 async fn get_trust_handler(
     State(state): State<AppState>,
     Path(did): Path<String>,
+    Query(params): Query<TrustQuery>,
+) -> std::result::Result<Json<ReconciledTrust>, (StatusCode, Json<ApiError>)> {
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    match state
+        .trust
+        .get_reconciled_trust(&did, params.skill.as_deref())
+        .await
+    {
+        Ok(trust) => Ok(Json(trust)),
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: e.to_string(),
+            }),
+        )),
+    }
+}
+
+/// Explain trust score handler.
+async fn explain_trust_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+    Query(params): Query<TrustQuery>,
+) -> std::result::Result<Json<TrustExplanation>, (StatusCode, Json<ApiError>)> {
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    match state.trust.explain(&did, params.skill.as_deref()).await {
+        Ok(explanation) => Ok(Json(explanation)),
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: e.to_string(),
+            }),
+        )),
+    }
+}
+
+/// Get the endorsement chain from the nearest trust root handler.
+async fn get_trust_path_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+) -> std::result::Result<Json<EndorsementPath>, (StatusCode, Json<ApiError>)> {
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    match state.trust.endorsement_path(&did) {
+        Ok(Some(path)) => Ok(Json(path)),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError {
+                error: format!("No endorsement path from a trust root to {}", did),
+            }),
+        )),
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError {
+                error: e.to_string(),
+            }),
+        )),
+    }
+}
+
+/// Revoke an endorsement of an agent handler.
+///
+/// Requires the admin token if one is set. Whatever
+/// `trust.authenticated_writes` says, the body must be a [`SignedEnvelope`]
+/// sealed for [`REVOKE_ENDORSEMENT_TOPIC`] by the endorser itself, carrying
+/// a [`TrustMessage::EndorsementRevocation`] of the agent in the path. The
+/// envelope is relayed to peers as is. Returns the agent's trust after the
+/// revocation.
+async fn revoke_endorsement_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
 ) -> std::result::Result<Json<TrustInfo>, (StatusCode, Json<ApiError>)> {
+    let reject = |status: StatusCode, error: String| (status, Json(ApiError { error }));
+
+    if let Some(token) = state.api_token.as_deref() {
+        if !is_admin_request(&headers, token) {
+            return Err(reject(StatusCode::UNAUTHORIZED, "Unauthorized".to_string()));
+        }
+    }
+
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    let Some(verifier) = state.envelope_verifier.as_deref() else {
+        return Err(reject(
+            StatusCode::UNAUTHORIZED,
+            "This node cannot verify signed endorsement revocations".to_string(),
+        ));
+    };
+    let (message, envelope): (TrustMessage, _) = open_signed_write(
+        verifier,
+        REVOKE_ENDORSEMENT_TOPIC,
+        "Endorsement revocation",
+        body,
+    )
+    .await?;
+    let TrustMessage::EndorsementRevocation {
+        endorser_did,
+        target_did,
+        ..
+    } = message
+    else {
+        return Err(reject(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Invalid endorsement revocation: expected an endorsement_revocation message"
+                .to_string(),
+        ));
+    };
+    if endorser_did != envelope.sender_did {
+        return Err(reject(
+            StatusCode::FORBIDDEN,
+            format!(
+                "Only {} may revoke its endorsements, not {}",
+                endorser_did, envelope.sender_did
+            ),
+        ));
+    }
+    if target_did != did {
+        return Err(reject(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Revocation is for {}, not {}", target_did, did),
+        ));
+    }
+
+    match state.trust.revoke_endorsement(&endorser_did, &did).await {
+        Ok(true) => {
+            publish_revocation(&state, &envelope).await;
+            state
+                .trust
+                .get_trust(&did)
+                .await
+                .map(Json)
+                .map_err(|e| reject(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+        Ok(false) => Err(reject(
+            StatusCode::NOT_FOUND,
+            format!("{} has no endorsement from {}", did, endorser_did),
+        )),
+        Err(e) => Err(reject(StatusCode::BAD_REQUEST, e.to_string())),
+    }
+}
+
+/// Relay the endorser's signed revocation so peers drop the endorsement too.
+async fn publish_revocation(state: &AppState, envelope: &SignedEnvelope) {
+    let Some(ref gossip) = state.gossip else {
+        return;
+    };
+    if let Err(e) = gossip.forward(envelope).await {
+        tracing::warn!("Failed to gossip endorsement revocation: {}", e);
+    }
+}
+
+/// Get trust score history handler.
+async fn get_trust_history_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+    Query(params): Query<TrustHistoryQuery>,
+) -> std::result::Result<Json<TrustHistory>, (StatusCode, Json<ApiError>)> {
+    // URL decode the DID (colons are encoded)
+    let did = urlencoding::decode(&did)
+        .map(|s| s.into_owned())
+        .unwrap_or(did);
+
+    let to = params.to.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default()
+    });
+    let from = params
+        .from
+        .unwrap_or_else(|| to.saturating_sub(DEFAULT_HISTORY_RANGE_SECS));
+    let resolution = match params.resolution {
+        Some(ref value) => parse_resolution(value).map_err(|e| bad_request(e.to_string()))?,
+        None => 0,
+    };
+
+    match state.trust.history(&did, from, to, resolution) {
+        Ok(Some(history)) => Ok(Json(history)),
+        Ok(None) => Err((
+            StatusCode::NOT_IMPLEMENTED,
+            Json(ApiError {
+                error: "Trust history is not enabled on this node".to_string(),
+            }),
+        )),
+        Err(e) => Err(bad_request(e.to_string())),
+    }
+}
+
+/// Per-chain trust handler.
+async fn get_chain_trust_handler(
+    State(state): State<AppState>,
+    Path(did): Path<String>,
+) -> std::result::Result<Json<AggregatedTrust>, (StatusCode, Json<ApiError>)> {
     // URL decode the DID (colons are encoded)
     let did = urlencoding::decode(&did)
         .map(|s| s.into_owned())
         .unwrap_or(did);
 
-    match state.trust.get_trust(&did).await {
-        Ok(trust_info) => Ok(Json(trust_info)),
+    match state.trust.get_chain_trust(&did).await {
+        Ok(trust) => Ok(Json(trust)),
+        Err(Error::Config(e)) => Err((StatusCode::NOT_IMPLEMENTED, Json(ApiError { error: e }))),
         Err(e) => Err((
             StatusCode::BAD_REQUEST,
             Json(ApiError {
@@ -522,16 +1149,21 @@ async fn get_trust_handler(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::discovery::{AgoraMeshExtension, PricingInfo, PricingModel, ProviderInfo, Skill};
+    use crate::discovery::{
+        AgoraMeshExtension, AuthenticationInfo, PricingInfo, PricingModel, ProviderInfo, Skill,
+    };
+    use crate::network::EnvelopeSigner;
+    use crate::trust_reconcile::{ReconcilePolicy, TrustSource};
     use axum_test::TestServer;
 
     fn test_state() -> AppState {
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
         AppState {
-            discovery: Arc::new(DiscoveryService::new()),
-            trust: Arc::new(TrustService::new(
-                "https://sepolia.base.org".to_string(),
-                None,
-            )),
+            discovery: Arc::new(DiscoveryService::new().with_trust(trust.clone())),
+            trust,
             start_time: Instant::now(),
             peer_count: Arc::new(AtomicU64::new(0)),
             node_info: None,
@@ -541,7 +1173,11 @@ mod tests {
             metrics: Arc::new(MetricsService::disabled()),
             // No hybrid search by default
             hybrid_search: None,
+            did_resolver: None,
             api_token: None,
+            write_verifier: None,
+            envelope_verifier: None,
+            gossip: None,
         }
     }
 
@@ -756,35 +1392,566 @@ mod tests {
         assert_eq!(agents.len(), 2);
     }
 
-    // ========== TDD Tests: GET /agents/:did ==========
-
     #[tokio::test]
-    async fn test_get_agent_returns_404_for_unknown_did() {
-        let server = test_server(test_state());
+    async fn test_search_agents_for_skill_ranks_by_skill_trust() {
+        // Arrange: the generalist has more transactions overall, the
+        // specialist is better at translation
+        let state = test_state();
+        let generalist = "did:agoramesh:base:generalist";
+        let specialist = "did:agoramesh:base:specialist";
+        let mut reviewer = sample_capability_card("did:agoramesh:base:reviewer");
+        reviewer.skills[0].id = "code-review".to_string();
+        for card in [
+            sample_capability_card(generalist),
+            sample_capability_card(specialist),
+            reviewer,
+        ] {
+            state.discovery.register(&card).await.unwrap();
+        }
+        for _ in 0..50 {
+            state
+                .trust
+                .record_success(generalist, 1_000_000, Some("code-review"))
+                .await
+                .unwrap();
+        }
+        for _ in 0..5 {
+            state
+                .trust
+                .record_failure(generalist, "error", Some("translate"))
+                .await
+                .unwrap();
+            state
+                .trust
+                .record_success(specialist, 1_000_000, Some("translate"))
+                .await
+                .unwrap();
+        }
+        let server = test_server(state);
 
-        let response = server.get("/agents/did%3Aagoramesh%3Abase%3Aunknown").await;
+        // Act
+        let response = server.get("/agents?q=&skill=translate").await;
 
-        response.assert_status_not_found();
-        let error: ApiError = response.json();
-        assert!(error.error.contains("not found"));
+        // Assert
+        response.assert_status_ok();
+        let agents: Vec<CapabilityCard> = response.json();
+        let dids: Vec<&str> = agents
+            .iter()
+            .filter_map(|c| c.agoramesh.as_ref().map(|e| e.did.as_str()))
+            .collect();
+        assert_eq!(dids, vec![specialist, generalist]);
     }
 
     #[tokio::test]
-    async fn test_get_agent_returns_registered_agent() {
+    async fn test_search_agents_min_trust_filters_out_low_trust_agents() {
+        // Arrange: only the veteran has any track record
         let state = test_state();
-        let did = "did:agoramesh:base:my-agent";
-        let card = sample_capability_card(did);
-        state.discovery.register(&card).await.unwrap();
-
+        let veteran = "did:agoramesh:base:veteran";
+        let newcomer = "did:agoramesh:base:newcomer";
+        for did in [veteran, newcomer] {
+            state
+                .discovery
+                .register(&sample_capability_card(did))
+                .await
+                .unwrap();
+        }
+        state.trust.set_trust_data(veteran, 0, 100, 0, 0);
         let server = test_server(state);
 
-        // URL encode the DID
-        let encoded_did = urlencoding::encode(did);
-        let response = server.get(&format!("/agents/{}", encoded_did)).await;
+        // Act
+        let response = server.get("/agents?q=&min_trust=0.3").await;
 
+        // Assert
         response.assert_status_ok();
-        let returned_card: CapabilityCard = response.json();
-        assert_eq!(returned_card.name, "Test Agent");
+        let agents: Vec<CapabilityCard> = response.json();
+        assert_eq!(agents.len(), 1);
+        assert_eq!(agents[0].agoramesh.as_ref().unwrap().did, veteran);
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_min_stake_filters_by_staked_amount() {
+        let state = test_state();
+        let staked = "did:agoramesh:base:staked";
+        let unstaked = "did:agoramesh:base:unstaked";
+        for did in [staked, unstaked] {
+            state
+                .discovery
+                .register(&sample_capability_card(did))
+                .await
+                .unwrap();
+        }
+        state.trust.set_trust_data(staked, 5_000_000_000, 0, 0, 0);
+        let server = test_server(state);
+
+        let response = server.get("/agents?q=&min_stake=1000000000").await;
+
+        response.assert_status_ok();
+        let agents: Vec<CapabilityCard> = response.json();
+        assert_eq!(agents.len(), 1);
+        assert_eq!(agents[0].agoramesh.as_ref().unwrap().did, staked);
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_min_trust_applies_to_skill_trust() {
+        // Arrange: trusted overall, but failing at translation
+        let state = test_state();
+        let did = "did:agoramesh:base:reviewer";
+        state
+            .discovery
+            .register(&sample_capability_card(did))
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            state
+                .trust
+                .record_success(did, 1_000_000, Some("code-review"))
+                .await
+                .unwrap();
+        }
+        for _ in 0..3 {
+            state
+                .trust
+                .record_failure(did, "error", Some("translate"))
+                .await
+                .unwrap();
+        }
+        let server = test_server(state);
+
+        // Act
+        let overall = server.get("/agents?q=&min_trust=0.3").await;
+        let translate = server.get("/agents?q=&skill=translate&min_trust=0.3").await;
+
+        // Assert
+        assert_eq!(overall.json::<Vec<CapabilityCard>>().len(), 1);
+        assert!(translate.json::<Vec<CapabilityCard>>().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_rejects_out_of_range_min_trust() {
+        let server = test_server(test_state());
+
+        let response = server.get("/agents?min_trust=1.5").await;
+
+        response.assert_status_bad_request();
+        let error: ApiError = response.json();
+        assert!(error.error.contains("min_trust"));
+    }
+
+    // ========== TDD Tests: GET /agents filters, sort and pagination ==========
+
+    /// Register a card for `did` priced at `base_price`, tweaked by `edit`.
+    async fn register_priced(
+        state: &AppState,
+        did: &str,
+        base_price: u64,
+        edit: impl FnOnce(&mut CapabilityCard),
+    ) {
+        let mut card = sample_capability_card(did);
+        card.agoramesh
+            .as_mut()
+            .unwrap()
+            .pricing
+            .as_mut()
+            .unwrap()
+            .base_price = base_price;
+        edit(&mut card);
+        state.discovery.register(&card).await.unwrap();
+    }
+
+    fn dids(agents: &[CapabilityCard]) -> Vec<&str> {
+        agents
+            .iter()
+            .filter_map(|c| c.agoramesh.as_ref().map(|e| e.did.as_str()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_filters_by_card_attributes() {
+        // Arrange
+        let state = test_state();
+        let cheap = "did:agoramesh:base:cheap";
+        let pricey = "did:agoramesh:base:pricey";
+        let streamed = "did:agoramesh:base:streamed";
+        let acme = "did:agoramesh:base:acme";
+        register_priced(&state, cheap, 10_000, |card| {
+            card.authentication = Some(AuthenticationInfo {
+                schemes: vec!["did".to_string()],
+            });
+        })
+        .await;
+        register_priced(&state, pricey, 900_000, |_| {}).await;
+        register_priced(&state, streamed, 10_000, |card| {
+            let ext = card.agoramesh.as_mut().unwrap();
+            ext.pricing.as_mut().unwrap().model = PricingModel::PerSecond;
+            ext.payment_methods = vec!["stripe".to_string()];
+        })
+        .await;
+        register_priced(&state, acme, 10_000, |card| {
+            card.provider.as_mut().unwrap().organization = "Acme".to_string();
+            card.skills[0].id = "summarize".to_string();
+        })
+        .await;
+        let server = test_server(state);
+
+        for (query, expected) in [
+            ("max_price=50000", vec![acme, cheap, streamed]),
+            ("pricing_model=per_second", vec![streamed]),
+            ("payment_method=stripe", vec![streamed]),
+            ("auth_scheme=did", vec![cheap]),
+            ("provider=acme", vec![acme]),
+            ("skill=summarize", vec![acme]),
+            (
+                "max_price=50000&payment_method=x402&provider=Test+Org",
+                vec![cheap],
+            ),
+        ] {
+            // Act
+            let response = server.get(&format!("/agents?q=&{}", query)).await;
+
+            // Assert
+            response.assert_status_ok();
+            let agents: Vec<CapabilityCard> = response.json();
+            let mut found = dids(&agents);
+            found.sort();
+            assert_eq!(found, expected, "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_sorts_by_price_and_trust() {
+        // Arrange
+        let state = test_state();
+        let budget = "did:agoramesh:base:budget";
+        let premium = "did:agoramesh:base:premium";
+        register_priced(&state, budget, 1_000, |_| {}).await;
+        register_priced(&state, premium, 500_000, |_| {}).await;
+        state.trust.set_trust_data(premium, 0, 100, 0, 0);
+        let server = test_server(state);
+
+        // Act
+        let by_price = server.get("/agents?q=&sort=price").await;
+        let by_trust = server.get("/agents?q=&sort=trust").await;
+
+        // Assert
+        assert_eq!(dids(&by_price.json::<Vec<_>>()), vec![budget, premium]);
+        assert_eq!(dids(&by_trust.json::<Vec<_>>()), vec![premium, budget]);
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_ignores_self_declared_trust() {
+        // Arrange: the newcomer claims perfect trust, the veteran earned it
+        let state = test_state();
+        let boaster = "did:agoramesh:base:boaster";
+        let veteran = "did:agoramesh:base:veteran";
+        register_priced(&state, boaster, 1_000, |card| {
+            card.agoramesh.as_mut().unwrap().trust_score = Some(1.0);
+        })
+        .await;
+        register_priced(&state, veteran, 1_000, |card| {
+            card.agoramesh.as_mut().unwrap().trust_score = Some(0.0);
+        })
+        .await;
+        state.trust.set_trust_data(veteran, 0, 100, 0, 0);
+        let expected = state.trust.get_trust(veteran).await.unwrap().score;
+        let server = test_server(state);
+
+        // Act
+        let response = server.get("/agents?q=Test").await;
+
+        // Assert: ranked and labelled by the node's own trust scores
+        let agents: Vec<CapabilityCard> = response.json();
+        assert_eq!(dids(&agents), vec![veteran, boaster]);
+        let declared = |i: usize| agents[i].agoramesh.as_ref().unwrap().trust_score;
+        assert_eq!(declared(0), Some(expected));
+        assert!(declared(1).unwrap() < expected);
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_paginates_with_cursor() {
+        // Arrange: five agents, cheapest first when sorted by price
+        let state = test_state();
+        let all: Vec<String> = (1..=5)
+            .map(|i| format!("did:agoramesh:base:agent{}", i))
+            .collect();
+        for (i, did) in all.iter().enumerate() {
+            register_priced(&state, did, 1_000 * (i as u64 + 1), |_| {}).await;
+        }
+        let server = test_server(state);
+
+        // Act: walk the pages
+        let mut seen = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let url = match &cursor {
+                Some(c) => format!("/agents?q=&sort=price&limit=2&cursor={}", c),
+                None => "/agents?q=&sort=price&limit=2".to_string(),
+            };
+            let response = server.get(&url).await;
+            response.assert_status_ok();
+            let agents: Vec<CapabilityCard> = response.json();
+            assert!(agents.len() <= 2);
+            seen.extend(dids(&agents).into_iter().map(str::to_string));
+            cursor = response
+                .maybe_header(NEXT_CURSOR_HEADER)
+                .map(|v| v.to_str().unwrap().to_string());
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        // Assert
+        assert_eq!(seen, all);
+    }
+
+    #[tokio::test]
+    async fn test_search_agents_rejects_bad_pagination() {
+        let server = test_server(test_state());
+
+        let zero = server.get("/agents?limit=0").await;
+        let huge = server.get("/agents?limit=1000").await;
+        let cursor = server.get("/agents?cursor=abc").await;
+
+        zero.assert_status_bad_request();
+        huge.assert_status_bad_request();
+        cursor.assert_status_bad_request();
+        assert!(cursor.json::<ApiError>().error.contains("cursor"));
+    }
+
+    // ========== TDD Tests: POST /agents/match ==========
+
+    /// Register an agent whose only skill takes `input` and returns `output`.
+    async fn register_with_schemas(
+        state: &AppState,
+        did: &str,
+        input: serde_json::Value,
+        output: serde_json::Value,
+    ) {
+        let mut card = sample_capability_card(did);
+        card.skills[0].input_schema = Some(input);
+        card.skills[0].output_schema = Some(output);
+        state.discovery.register(&card).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_match_agents_ranks_compatible_agents_by_fit() {
+        // Arrange: both take text; only "full" also uses the language, and
+        // "numbers" returns a count instead of a translation
+        let state = test_state();
+        let text_only = serde_json::json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" }, "hint": { "type": "string" } },
+            "required": ["text"]
+        });
+        let with_language = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "language": { "type": "string" }
+            },
+            "required": ["text"]
+        });
+        let translation = serde_json::json!({
+            "type": "object",
+            "properties": { "translation": { "type": "string" } },
+            "required": ["translation"]
+        });
+        let count = serde_json::json!({
+            "type": "object",
+            "properties": { "count": { "type": "integer" } }
+        });
+        register_with_schemas(
+            &state,
+            "did:agoramesh:base:partial",
+            text_only.clone(),
+            translation.clone(),
+        )
+        .await;
+        register_with_schemas(
+            &state,
+            "did:agoramesh:base:full",
+            with_language,
+            translation,
+        )
+        .await;
+        register_with_schemas(&state, "did:agoramesh:base:numbers", text_only, count).await;
+        let server = test_server(state);
+
+        // Act
+        let response = server
+            .post("/agents/match")
+            .json(&serde_json::json!({
+                "input": {
+                    "type": "object",
+                    "properties": {
+                        "text": { "type": "string" },
+                        "language": { "type": "string" }
+                    }
+                },
+                "output": {
+                    "type": "object",
+                    "properties": { "translation": { "type": "string" } },
+                    "required": ["translation"]
+                }
+            }))
+            .await;
+
+        // Assert
+        response.assert_status_ok();
+        let results: Vec<SchemaMatchResult> = response.json();
+        let dids: Vec<&str> = results.iter().map(|r| r.did.as_str()).collect();
+        assert_eq!(
+            dids,
+            vec!["did:agoramesh:base:full", "did:agoramesh:base:partial"]
+        );
+        assert_eq!(results[0].fit.skill_id, "translate");
+        assert_eq!(results[0].fit.score, 1.0);
+        assert!(results[1].fit.score < 1.0);
+        assert!(results[0].trust.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_match_agents_for_skill_keeps_fit_order_unless_sorting_by_trust() {
+        // Arrange: "full" fits the query best, "partial" is better trusted
+        // in the skill
+        let state = test_state();
+        let text_only = serde_json::json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" }, "hint": { "type": "string" } }
+        });
+        let with_language = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "language": { "type": "string" }
+            }
+        });
+        let translation = serde_json::json!({
+            "type": "object",
+            "properties": { "translation": { "type": "string" } }
+        });
+        register_with_schemas(
+            &state,
+            "did:agoramesh:base:partial",
+            text_only,
+            translation.clone(),
+        )
+        .await;
+        register_with_schemas(
+            &state,
+            "did:agoramesh:base:full",
+            with_language.clone(),
+            translation,
+        )
+        .await;
+        for _ in 0..5 {
+            state
+                .trust
+                .record_success("did:agoramesh:base:partial", 1_000_000, Some("translate"))
+                .await
+                .unwrap();
+        }
+        let server = test_server(state);
+        let query = serde_json::json!({ "input": with_language });
+
+        // Act
+        let by_fit = server
+            .post("/agents/match?skill=translate")
+            .json(&query)
+            .await;
+        let by_trust = server
+            .post("/agents/match?skill=translate&sort=trust")
+            .json(&query)
+            .await;
+
+        // Assert
+        let dids = |response: axum_test::TestResponse| -> Vec<String> {
+            response
+                .json::<Vec<SchemaMatchResult>>()
+                .into_iter()
+                .map(|r| r.did)
+                .collect()
+        };
+        assert_eq!(
+            dids(by_fit),
+            vec!["did:agoramesh:base:full", "did:agoramesh:base:partial"]
+        );
+        assert_eq!(
+            dids(by_trust),
+            vec!["did:agoramesh:base:partial", "did:agoramesh:base:full"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_match_agents_applies_filters() {
+        let state = test_state();
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" } }
+        });
+        register_with_schemas(
+            &state,
+            "did:agoramesh:base:a",
+            schema.clone(),
+            schema.clone(),
+        )
+        .await;
+        let server = test_server(state);
+
+        let matching = server
+            .post("/agents/match?payment_method=x402")
+            .json(&serde_json::json!({ "input": schema }))
+            .await;
+        let filtered = server
+            .post("/agents/match?payment_method=stripe")
+            .json(&serde_json::json!({ "input": schema }))
+            .await;
+
+        assert_eq!(matching.json::<Vec<SchemaMatchResult>>().len(), 1);
+        assert!(filtered.json::<Vec<SchemaMatchResult>>().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_match_agents_requires_a_schema() {
+        let server = test_server(test_state());
+
+        let response = server
+            .post("/agents/match")
+            .json(&serde_json::json!({}))
+            .await;
+
+        response.assert_status_bad_request();
+        assert!(response.json::<ApiError>().error.contains("schema"));
+    }
+
+    // ========== TDD Tests: GET /agents/:did ==========
+
+    #[tokio::test]
+    async fn test_get_agent_returns_404_for_unknown_did() {
+        let server = test_server(test_state());
+
+        let response = server.get("/agents/did%3Aagoramesh%3Abase%3Aunknown").await;
+
+        response.assert_status_not_found();
+        let error: ApiError = response.json();
+        assert!(error.error.contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_get_agent_returns_registered_agent() {
+        let state = test_state();
+        let did = "did:agoramesh:base:my-agent";
+        let card = sample_capability_card(did);
+        state.discovery.register(&card).await.unwrap();
+
+        let server = test_server(state);
+
+        // URL encode the DID
+        let encoded_did = urlencoding::encode(did);
+        let response = server.get(&format!("/agents/{}", encoded_did)).await;
+
+        response.assert_status_ok();
+        let returned_card: CapabilityCard = response.json();
+        assert_eq!(returned_card.name, "Test Agent");
     }
 
     // ========== TDD Tests: POST /agents ==========
@@ -826,6 +1993,194 @@ mod tests {
         assert!(error.error.contains("agoramesh") || error.error.contains("DID"));
     }
 
+    // ========== TDD Tests: POST /agents (DID-authenticated) ==========
+
+    /// Signer for `did:agoramesh:base:{identifier}` and a registry holding its document.
+    async fn test_did_signer(
+        identifier: &str,
+    ) -> (EnvelopeSigner, crate::network::DidDocumentRegistry) {
+        use crate::did::DIDDocumentBuilder;
+        use crate::network::envelope::encode_ed25519_multibase;
+        use crate::network::DidDocumentRegistry;
+        use libp2p::identity::ed25519;
+
+        let keypair = ed25519::Keypair::generate();
+        let document = DIDDocumentBuilder::new("base", identifier)
+            .add_ed25519_key("key-1", &encode_ed25519_multibase(&keypair.public()))
+            .build()
+            .unwrap();
+        let signer = EnvelopeSigner::ed25519(&document.id, "key-1", keypair);
+        let documents = DidDocumentRegistry::new();
+        documents.register(document).await.unwrap();
+        (signer, documents)
+    }
+
+    /// State that only accepts registrations signed by DIDs with a trust
+    /// score of at least 0.3, and a signer whose key `did:agoramesh:base:
+    /// {identifier}` publishes.
+    async fn authenticated_write_state(identifier: &str) -> (AppState, EnvelopeSigner) {
+        use crate::config::TrustConfig;
+
+        let (signer, documents) = test_did_signer(identifier).await;
+
+        let trust = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_requirements(TrustConfig {
+                min_trust_score: 0.3,
+                authenticated_writes: true,
+                ..Default::default()
+            });
+        let verifier = Arc::new(EnvelopeVerifier::new(Arc::new(documents)));
+        let state = AppState {
+            trust: Arc::new(trust),
+            write_verifier: Some(verifier.clone()),
+            envelope_verifier: Some(verifier),
+            ..test_state()
+        };
+        (state, signer)
+    }
+
+    #[tokio::test]
+    async fn test_signed_registration_from_trusted_did_succeeds() {
+        // Arrange
+        let (state, signer) = authenticated_write_state("trusted").await;
+        state.trust.set_trust_data(signer.did(), 0, 100, 0, 0);
+        let card = sample_capability_card(signer.did());
+        let envelope = SignedEnvelope::seal(REGISTER_AGENT_TOPIC, &card, &signer).unwrap();
+        let server = test_server(state);
+
+        // Act
+        let response = server.post("/agents").json(&envelope).await;
+
+        // Assert
+        response.assert_status(StatusCode::CREATED);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["did"], signer.did());
+    }
+
+    #[tokio::test]
+    async fn test_signed_registration_is_relayed_for_peers_to_verify() {
+        use crate::config::TrustConfig;
+        use crate::network::{DidDocumentSource, MessageHandler, NetworkEvent, SwarmCommand};
+
+        // Arrange: the node relays as its own DID, the agent signs its card
+        let (node, node_documents) = test_did_signer("relaying-node").await;
+        let (agent, documents) = test_did_signer("agent").await;
+        let node_document = node_documents.document(node.did()).await.unwrap().unwrap();
+        documents.register(node_document).await.unwrap();
+        let documents = Arc::new(documents);
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let trust = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_requirements(TrustConfig {
+                min_trust_score: 0.3,
+                authenticated_writes: true,
+                ..Default::default()
+            });
+        trust.set_trust_data(agent.did(), 0, 100, 0, 0);
+        let state = AppState {
+            discovery: Arc::new(
+                DiscoveryService::with_network(tx.clone())
+                    .with_publisher(GossipPublisher::new(tx, node)),
+            ),
+            trust: Arc::new(trust),
+            write_verifier: Some(Arc::new(EnvelopeVerifier::new(documents.clone()))),
+            ..test_state()
+        };
+        let card = sample_capability_card(agent.did());
+        let envelope = SignedEnvelope::seal(REGISTER_AGENT_TOPIC, &card, &agent).unwrap();
+        let server = test_server(state);
+
+        let peer_discovery = Arc::new(DiscoveryService::new());
+        let peer = MessageHandler::new(peer_discovery.clone()).with_did_documents(documents);
+
+        // Act: register on the node and deliver its gossip to the peer
+        server
+            .post("/agents")
+            .json(&envelope)
+            .await
+            .assert_status(StatusCode::CREATED);
+        let data = loop {
+            match rx.recv().await {
+                Some(SwarmCommand::Publish { data, .. }) => break data,
+                Some(_) => continue,
+                None => panic!("Expected the card to be relayed"),
+            }
+        };
+        peer.handle_event(&NetworkEvent::Message {
+            topic: topics::DISCOVERY.to_string(),
+            source: None,
+            data,
+            message_id: libp2p::gossipsub::MessageId::new(b"relay"),
+        })
+        .await
+        .expect("Peer should accept the agent's signed registration");
+
+        // Assert
+        assert!(peer_discovery.get(agent.did()).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_registration_rejected_when_authenticated() {
+        let (state, signer) = authenticated_write_state("trusted").await;
+        state.trust.set_trust_data(signer.did(), 0, 100, 0, 0);
+        let server = test_server(state);
+
+        let response = server
+            .post("/agents")
+            .json(&sample_capability_card(signer.did()))
+            .await;
+
+        response.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_signed_registration_from_low_trust_did_forbidden() {
+        // Arrange: valid signature, but no track record
+        let (state, signer) = authenticated_write_state("newcomer").await;
+        let card = sample_capability_card(signer.did());
+        let envelope = SignedEnvelope::seal(REGISTER_AGENT_TOPIC, &card, &signer).unwrap();
+        let server = test_server(state);
+
+        // Act
+        let response = server.post("/agents").json(&envelope).await;
+
+        // Assert
+        response.assert_status(StatusCode::FORBIDDEN);
+        let error: ApiError = response.json();
+        assert!(error.error.contains("trust requirements"));
+    }
+
+    #[tokio::test]
+    async fn test_signed_registration_for_another_did_forbidden() {
+        let (state, signer) = authenticated_write_state("trusted").await;
+        state.trust.set_trust_data(signer.did(), 0, 100, 0, 0);
+        let card = sample_capability_card("did:agoramesh:base:someone-else");
+        let envelope = SignedEnvelope::seal(REGISTER_AGENT_TOPIC, &card, &signer).unwrap();
+        let server = test_server(state);
+
+        let response = server.post("/agents").json(&envelope).await;
+
+        response.assert_status(StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_signed_registration_cannot_be_replayed() {
+        let (state, signer) = authenticated_write_state("trusted").await;
+        state.trust.set_trust_data(signer.did(), 0, 100, 0, 0);
+        let card = sample_capability_card(signer.did());
+        let envelope = SignedEnvelope::seal(REGISTER_AGENT_TOPIC, &card, &signer).unwrap();
+        let server = test_server(state);
+
+        server
+            .post("/agents")
+            .json(&envelope)
+            .await
+            .assert_status(StatusCode::CREATED);
+        let replay = server.post("/agents").json(&envelope).await;
+
+        replay.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
     // ========== TDD Tests: GET /trust/:did ==========
 
     #[tokio::test]
@@ -841,6 +2196,55 @@ mod tests {
         assert_eq!(trust_info.reputation, 0.0);
     }
 
+    #[tokio::test]
+    async fn test_get_trust_scopes_reputation_to_skill() {
+        // Arrange
+        let state = test_state();
+        let did = "did:agoramesh:base:polyglot";
+        for _ in 0..5 {
+            state
+                .trust
+                .record_success(did, 1_000_000, Some("translate"))
+                .await
+                .unwrap();
+            state
+                .trust
+                .record_failure(did, "error", Some("code-review"))
+                .await
+                .unwrap();
+        }
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode(did);
+        let translate: TrustInfo = server
+            .get(&format!("/trust/{}?skill=translate", encoded_did))
+            .await
+            .json();
+        let global: TrustInfo = server.get(&format!("/trust/{}", encoded_did)).await.json();
+
+        // Assert
+        assert_eq!(translate.skill.as_deref(), Some("translate"));
+        assert_eq!(translate.failed_transactions, 0);
+        assert_eq!(global.skill, None);
+        assert!(translate.reputation > global.reputation);
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_reports_component_provenance() {
+        let server = test_server(test_state());
+
+        let encoded_did = urlencoding::encode("did:agoramesh:base:unknown");
+        let response = server.get(&format!("/trust/{}", encoded_did)).await;
+
+        response.assert_status_ok();
+        let trust: ReconciledTrust = response.json();
+        assert_eq!(trust.info.did, "did:agoramesh:base:unknown");
+        assert_eq!(trust.provenance.policy, ReconcilePolicy::Local);
+        assert_eq!(trust.provenance.reputation.source, TrustSource::Local);
+        assert_eq!(trust.provenance.endorsements.age_secs, 0);
+    }
+
     #[tokio::test]
     async fn test_get_trust_fails_with_invalid_did() {
         let server = test_server(test_state());
@@ -852,6 +2256,610 @@ mod tests {
         assert!(error.error.contains("DID"));
     }
 
+    // ========== TDD Tests: GET /trust/:did/path ==========
+
+    #[tokio::test]
+    async fn test_get_trust_path_returns_chain_from_root() {
+        // Arrange
+        let root = "did:agoramesh:base:root";
+        let endorser = "did:agoramesh:base:endorser";
+        let target = "did:agoramesh:base:target";
+        let trust = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_trust_roots(vec![root.to_string()]);
+        trust.add_endorsement(root, endorser).await.unwrap();
+        trust.add_endorsement(endorser, target).await.unwrap();
+        let mut state = test_state();
+        state.trust = Arc::new(trust);
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode(target);
+        let response = server.get(&format!("/trust/{}/path", encoded_did)).await;
+
+        // Assert
+        response.assert_status_ok();
+        let path: EndorsementPath = response.json();
+        assert_eq!(path.root, root);
+        assert_eq!(path.hops, 2);
+        assert_eq!(path.chain, vec![root, endorser, target]);
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_path_returns_404_when_unreachable() {
+        let server = test_server(test_state());
+
+        let encoded_did = urlencoding::encode("did:agoramesh:base:unknown");
+        let response = server.get(&format!("/trust/{}/path", encoded_did)).await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_path_fails_with_invalid_did() {
+        let server = test_server(test_state());
+
+        let response = server.get("/trust/invalid-did/path").await;
+
+        response.assert_status_bad_request();
+    }
+
+    // ========== TDD Tests: GET /trust/:did/explain ==========
+
+    #[tokio::test]
+    async fn test_explain_trust_lists_components() {
+        // Arrange
+        let state = test_state();
+        let did = "did:agoramesh:base:explained";
+        state.trust.set_trust_data(did, 0, 80, 20, 0);
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode(did);
+        let response = server.get(&format!("/trust/{}/explain", encoded_did)).await;
+
+        // Assert
+        response.assert_status_ok();
+        let explanation: TrustExplanation = response.json();
+        assert_eq!(explanation.did, did);
+        assert_eq!(explanation.reputation.successful_transactions, 80);
+        assert!((explanation.reputation.success_rate - 0.8).abs() < 1e-9);
+        assert_eq!(explanation.reputation.volume_factor, 1.0);
+        assert!(explanation.weights.reputation > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_explain_trust_scopes_to_skill() {
+        // Arrange
+        let state = test_state();
+        let did = "did:agoramesh:base:explained-skill";
+        state
+            .trust
+            .record_success(did, 1_000_000, Some("translate"))
+            .await
+            .unwrap();
+        state
+            .trust
+            .record_failure(did, "timeout", Some("summarize"))
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode(did);
+        let response = server
+            .get(&format!("/trust/{}/explain?skill=translate", encoded_did))
+            .await;
+
+        // Assert
+        response.assert_status_ok();
+        let explanation: TrustExplanation = response.json();
+        assert_eq!(explanation.skill.as_deref(), Some("translate"));
+        assert_eq!(explanation.reputation.successful_transactions, 1);
+        assert_eq!(explanation.reputation.failed_transactions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_explain_trust_fails_with_invalid_did() {
+        let server = test_server(test_state());
+
+        let response = server.get("/trust/invalid-did/explain").await;
+
+        response.assert_status_bad_request();
+    }
+
+    // ========== TDD Tests: GET /trust/:did/history ==========
+
+    fn test_state_with_history() -> (AppState, crate::persistence::TrustHistoryStore) {
+        use crate::persistence::{MemoryStore, TrustHistoryStore};
+
+        let history = TrustHistoryStore::new(Arc::new(MemoryStore::new()));
+        let mut state = test_state();
+        state.trust = Arc::new(
+            TrustService::new("https://sepolia.base.org".to_string(), None)
+                .with_history(history.clone()),
+        );
+        (state, history)
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_history_downsamples_range() {
+        use crate::trust_history::TrustSnapshot;
+
+        // Arrange: a snapshot every 30 minutes for 4 hours
+        let (state, history) = test_state_with_history();
+        let did = "did:agoramesh:base:trending";
+        for i in 0..8u64 {
+            let snapshot = TrustSnapshot {
+                timestamp: 1_000_000 + i * 1_800,
+                score: i as f64 / 10.0,
+                reputation: 0.0,
+                stake_score: 0.0,
+                endorsement_score: 0.0,
+                stake_amount: 0,
+                successful_transactions: i,
+                failed_transactions: 0,
+                endorsement_count: 0,
+            };
+            history.put_snapshot(did, &snapshot).unwrap();
+        }
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode(did);
+        let response = server
+            .get(&format!(
+                "/trust/{}/history?from=1000000&to=1010000&resolution=1h",
+                encoded_did
+            ))
+            .await;
+
+        // Assert
+        response.assert_status_ok();
+        let body: TrustHistory = response.json();
+        assert_eq!(body.resolution, 3_600);
+        assert!(body.snapshots.len() <= 5);
+        assert!(body
+            .snapshots
+            .windows(2)
+            .all(|w| w[0].timestamp < w[1].timestamp));
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_history_includes_events() {
+        let (state, _) = test_state_with_history();
+        let did = "did:agoramesh:base:active";
+        state
+            .trust
+            .record_success(did, 1_000_000, None)
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        let encoded_did = urlencoding::encode(did);
+        let response = server.get(&format!("/trust/{}/history", encoded_did)).await;
+
+        response.assert_status_ok();
+        let body: TrustHistory = response.json();
+        assert_eq!(body.events.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_history_rejects_bad_resolution() {
+        let (state, _) = test_state_with_history();
+        let server = test_server(state);
+
+        let encoded_did = urlencoding::encode("did:agoramesh:base:any");
+        let response = server
+            .get(&format!("/trust/{}/history?resolution=1y", encoded_did))
+            .await;
+
+        response.assert_status_bad_request();
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_history_without_store_returns_501() {
+        let server = test_server(test_state());
+
+        let encoded_did = urlencoding::encode("did:agoramesh:base:any");
+        let response = server.get(&format!("/trust/{}/history", encoded_did)).await;
+
+        response.assert_status(StatusCode::NOT_IMPLEMENTED);
+    }
+
+    // ========== TDD Tests: GET /trust/:did/chains ==========
+
+    #[tokio::test]
+    async fn test_get_chain_trust_reports_per_chain_errors() {
+        use crate::multichain::{
+            ChainConfig, MultiChainClient, MultiChainConfig, TrustAggregation,
+        };
+
+        // Arrange: two chains whose RPC endpoints refuse connections
+        let mut config = MultiChainConfig {
+            aggregation: TrustAggregation::StakeWeighted,
+            ..Default::default()
+        };
+        for chain_id in [8453, 137] {
+            let mut chain = ChainConfig::new(chain_id);
+            chain.rpc_url = Some("http://127.0.0.1:1".to_string());
+            chain.trust_registry_address =
+                Some("0x1111111111111111111111111111111111111111".to_string());
+            config.add_chain(chain);
+        }
+        let mut state = test_state();
+        state.trust = Arc::new(
+            TrustService::new("https://sepolia.base.org".to_string(), None)
+                .with_multichain(Arc::new(MultiChainClient::new(config).unwrap())),
+        );
+        let server = test_server(state);
+
+        // Act
+        let encoded_did = urlencoding::encode("did:agoramesh:base:bridged");
+        let response = server.get(&format!("/trust/{}/chains", encoded_did)).await;
+
+        // Assert
+        response.assert_status_ok();
+        let trust: AggregatedTrust = response.json();
+        assert_eq!(trust.aggregation, TrustAggregation::StakeWeighted);
+        assert!(trust.details.is_none());
+        assert_eq!(trust.chains.len(), 2);
+        assert!(trust.chains.iter().all(|c| c.error.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_trust_not_configured() {
+        let server = test_server(test_state());
+
+        let encoded_did = urlencoding::encode("did:agoramesh:base:any");
+        let response = server.get(&format!("/trust/{}/chains", encoded_did)).await;
+
+        response.assert_status(StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_trust_fails_with_invalid_did() {
+        let server = test_server(test_state());
+
+        let response = server.get("/trust/invalid-did/chains").await;
+
+        response.assert_status_bad_request();
+    }
+
+    // ========== TDD Tests: POST /trust/:did/endorsements/revoke ==========
+
+    fn revoke_path(did: &str) -> String {
+        format!("/trust/{}/endorsements/revoke", urlencoding::encode(did))
+    }
+
+    /// Default state (no `authenticated_writes`) that verifies signed
+    /// requests, and a signer whose key `did:agoramesh:base:{identifier}`
+    /// publishes.
+    async fn signed_revocation_state(
+        identifier: &str,
+    ) -> (
+        AppState,
+        EnvelopeSigner,
+        Arc<crate::network::DidDocumentRegistry>,
+    ) {
+        let (signer, documents) = test_did_signer(identifier).await;
+        let documents = Arc::new(documents);
+        let state = AppState {
+            envelope_verifier: Some(Arc::new(EnvelopeVerifier::new(documents.clone()))),
+            ..test_state()
+        };
+        (state, signer, documents)
+    }
+
+    /// `signer`'s revocation of `endorser_did`'s endorsement of `target_did`.
+    fn sealed_revocation(
+        signer: &EnvelopeSigner,
+        endorser_did: &str,
+        target_did: &str,
+    ) -> SignedEnvelope {
+        let revocation = TrustMessage::EndorsementRevocation {
+            endorser_did: endorser_did.to_string(),
+            target_did: target_did.to_string(),
+            timestamp: 1_700_000_000,
+        };
+        SignedEnvelope::seal(REVOKE_ENDORSEMENT_TOPIC, &revocation, signer).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_revoke_endorsement_updates_trust() {
+        // Arrange
+        let (state, signer, _) = signed_revocation_state("endorser").await;
+        let target = "did:agoramesh:base:endorsed";
+        state
+            .trust
+            .add_endorsement(signer.did(), target)
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        // Act
+        let response = server
+            .post(&revoke_path(target))
+            .json(&sealed_revocation(&signer, signer.did(), target))
+            .await;
+
+        // Assert
+        response.assert_status_ok();
+        let trust: TrustInfo = response.json();
+        assert_eq!(trust.endorsement_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_unknown_endorsement_returns_404() {
+        let (state, signer, _) = signed_revocation_state("stranger").await;
+        let target = "did:agoramesh:base:endorsed";
+        let server = test_server(state);
+
+        let response = server
+            .post(&revoke_path(target))
+            .json(&sealed_revocation(&signer, signer.did(), target))
+            .await;
+
+        response.assert_status_not_found();
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_revocation_is_rejected_without_authenticated_writes() {
+        // Arrange: anyone could name the endorser in a plain body
+        let (state, _, _) = signed_revocation_state("node").await;
+        let endorser = "did:agoramesh:base:endorser";
+        let target = "did:agoramesh:base:endorsed";
+        state.trust.add_endorsement(endorser, target).await.unwrap();
+        let trust = state.trust.clone();
+        let server = test_server(state);
+
+        // Act
+        let response = server
+            .post(&revoke_path(target))
+            .json(&serde_json::json!({ "endorser_did": endorser }))
+            .await;
+
+        // Assert
+        response.assert_status_unauthorized();
+        assert!(trust.has_endorsement(endorser, target));
+    }
+
+    #[tokio::test]
+    async fn test_revocation_without_verifier_is_rejected() {
+        let (signer, _) = test_did_signer("endorser").await;
+        let target = "did:agoramesh:base:endorsed";
+        let state = test_state();
+        state
+            .trust
+            .add_endorsement(signer.did(), target)
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        let response = server
+            .post(&revoke_path(target))
+            .json(&sealed_revocation(&signer, signer.did(), target))
+            .await;
+
+        response.assert_status_unauthorized();
+    }
+
+    #[tokio::test]
+    async fn test_signed_revocation_from_endorser_succeeds() {
+        // Arrange
+        let (state, signer) = authenticated_write_state("endorser").await;
+        let target = "did:agoramesh:base:endorsed";
+        state
+            .trust
+            .add_endorsement(signer.did(), target)
+            .await
+            .unwrap();
+        let envelope = sealed_revocation(&signer, signer.did(), target);
+        let server = test_server(state);
+
+        // Act
+        let response = server.post(&revoke_path(target)).json(&envelope).await;
+
+        // Assert
+        response.assert_status_ok();
+        assert_eq!(response.json::<TrustInfo>().endorsement_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_signed_revocation_of_another_endorser_forbidden() {
+        // Arrange: the signer tries to drop someone else's endorsement
+        let (state, signer, _) = signed_revocation_state("endorsed").await;
+        let endorser = "did:agoramesh:base:endorser";
+        state
+            .trust
+            .add_endorsement(endorser, signer.did())
+            .await
+            .unwrap();
+        let envelope = sealed_revocation(&signer, endorser, signer.did());
+        let trust = state.trust.clone();
+        let server = test_server(state);
+
+        // Act
+        let response = server
+            .post(&revoke_path(signer.did()))
+            .json(&envelope)
+            .await;
+
+        // Assert
+        response.assert_status(StatusCode::FORBIDDEN);
+        assert!(trust.has_endorsement(endorser, signer.did()));
+    }
+
+    #[tokio::test]
+    async fn test_signed_revocation_of_another_agent_rejected() {
+        // Arrange: a revocation of `other` replayed against `target`
+        let (state, signer, _) = signed_revocation_state("endorser").await;
+        let target = "did:agoramesh:base:endorsed";
+        let other = "did:agoramesh:base:other";
+        state
+            .trust
+            .add_endorsement(signer.did(), target)
+            .await
+            .unwrap();
+        let envelope = sealed_revocation(&signer, signer.did(), other);
+        let trust = state.trust.clone();
+        let server = test_server(state);
+
+        // Act
+        let response = server.post(&revoke_path(target)).json(&envelope).await;
+
+        // Assert
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(trust.has_endorsement(signer.did(), target));
+    }
+
+    #[tokio::test]
+    async fn test_signed_revocation_is_relayed_to_peers() {
+        use crate::network::{DidDocumentSource, MessageHandler, NetworkEvent, SwarmCommand};
+
+        // Arrange: node A gossips as its own DID, the endorser is an agent
+        let (state, endorser, documents) = signed_revocation_state("endorser").await;
+        let (node, node_documents) = test_did_signer("relaying-node").await;
+        let node_document = node_documents.document(node.did()).await.unwrap().unwrap();
+        documents.register(node_document).await.unwrap();
+        let target = "did:agoramesh:base:endorsed";
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let state = AppState {
+            gossip: Some(GossipPublisher::new(tx, node)),
+            ..state
+        };
+        state
+            .trust
+            .add_endorsement(endorser.did(), target)
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        // Node B learned the same endorsement and trusts the endorser as a root
+        let peer_trust = Arc::new(
+            TrustService::new("https://sepolia.base.org".to_string(), None)
+                .with_trust_roots(vec![endorser.did().to_string()]),
+        );
+        peer_trust.set_trust_data(endorser.did(), 0, 100, 0, 0);
+        peer_trust
+            .add_endorsement(endorser.did(), target)
+            .await
+            .unwrap();
+        let score_before = peer_trust.get_trust(target).await.unwrap().score;
+        let peer = MessageHandler::with_services(
+            Arc::new(DiscoveryService::new()),
+            Some(peer_trust.clone()),
+            None,
+        )
+        .with_did_documents(documents);
+
+        // Act: revoke on A and deliver its gossip to B
+        server
+            .post(&revoke_path(target))
+            .json(&sealed_revocation(&endorser, endorser.did(), target))
+            .await
+            .assert_status_ok();
+        let Some(SwarmCommand::Publish { topic, data }) = rx.recv().await else {
+            panic!("Expected the revocation to be published");
+        };
+        assert_eq!(topic, topics::TRUST);
+        peer.handle_event(&NetworkEvent::Message {
+            topic,
+            source: None,
+            data,
+            message_id: libp2p::gossipsub::MessageId::new(b"revocation"),
+        })
+        .await
+        .expect("Peer should accept the endorser's revocation");
+
+        // Assert
+        assert!(!peer_trust.has_endorsement(endorser.did(), target));
+        assert!(peer_trust.get_trust(target).await.unwrap().score < score_before);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_revocation_is_not_gossiped() {
+        let (state, signer, _) = signed_revocation_state("endorsed").await;
+        let (node, _) = test_did_signer("this-node").await;
+        let endorser = "did:agoramesh:base:endorser";
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let state = AppState {
+            gossip: Some(GossipPublisher::new(tx, node)),
+            ..state
+        };
+        state
+            .trust
+            .add_endorsement(endorser, signer.did())
+            .await
+            .unwrap();
+        let server = test_server(state);
+
+        server
+            .post(&revoke_path(signer.did()))
+            .json(&sealed_revocation(&signer, endorser, signer.did()))
+            .await
+            .assert_status(StatusCode::FORBIDDEN);
+
+        assert!(rx.try_recv().is_err(), "Peers would reject this revocation");
+    }
+
+    // ========== TDD Tests: GET /did/:did ==========
+
+    fn test_state_with_did_resolver() -> AppState {
+        use crate::did_resolver::KeyDidResolver;
+
+        let mut state = test_state();
+        state.did_resolver = Some(Arc::new(
+            UniversalResolver::new().with_method("key", Arc::new(KeyDidResolver)),
+        ));
+        state
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_returns_resolution_result() {
+        use crate::did::DIDResolutionResult;
+        use crate::did_resolver::KeyDidResolver;
+
+        // Arrange
+        let server = test_server(test_state_with_did_resolver());
+        let did = KeyDidResolver::did_for(&libp2p::identity::ed25519::Keypair::generate().public());
+
+        // Act
+        let response = server
+            .get(&format!("/did/{}", urlencoding::encode(&did)))
+            .await;
+
+        // Assert
+        response.assert_status_ok();
+        response.assert_header(
+            "content-type",
+            "application/ld+json;profile=\"https://w3id.org/did-resolution\"",
+        );
+        let result: DIDResolutionResult = response.json();
+        assert_eq!(result.did_document.unwrap().id, did);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_maps_errors_to_status() {
+        let server = test_server(test_state_with_did_resolver());
+
+        let response = server.get("/did/not-a-did").await;
+        response.assert_status_bad_request();
+
+        let response = server.get("/did/did%3Aion%3Aabc").await;
+        assert_eq!(response.status_code(), StatusCode::NOT_IMPLEMENTED);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["didResolutionMetadata"]["error"], "methodNotSupported");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_returns_501_without_resolver() {
+        let server = test_server(test_state());
+
+        let response = server.get("/did/did%3Akey%3Az6Mk").await;
+
+        assert_eq!(response.status_code(), StatusCode::NOT_IMPLEMENTED);
+    }
+
     // ========== TDD Tests: Rate Limiting ==========
 
     fn test_state_with_rate_limit(requests_per_second: u32, burst_size: u32) -> AppState {
@@ -871,7 +2879,11 @@ mod tests {
             })),
             metrics: Arc::new(MetricsService::disabled()),
             hybrid_search: None,
+            did_resolver: None,
             api_token: None,
+            write_verifier: None,
+            envelope_verifier: None,
+            gossip: None,
         }
     }
 
@@ -1062,27 +3074,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_semantic_search_applies_min_trust() {
+        let state = test_state_with_hybrid_search();
+        let Some(state) = state else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+
+        let card = sample_capability_card("did:agoramesh:base:untrusted");
+        if let Some(ref hybrid) = state.hybrid_search {
+            hybrid.write().await.index_card(&card).await.unwrap();
+        }
+
+        let server = test_server(state);
+
+        let response = server
+            .get("/agents/semantic?q=Test+Agent&min_trust=0.3")
+            .await;
+
+        response.assert_status_ok();
+        let results: Vec<SemanticSearchResult> = response.json();
+        assert!(
+            results.is_empty(),
+            "Agents without trust should be filtered"
+        );
+    }
+
     /// Helper to create test state with HybridSearch if model is available
     fn test_state_with_hybrid_search() -> Option<AppState> {
         use crate::search::{EmbeddingService, HybridSearch};
         use tokio::sync::RwLock;
 
         let embedding = EmbeddingService::new().ok()?;
-        let hybrid = HybridSearch::new(embedding);
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
+        let hybrid = HybridSearch::new(embedding).with_trust(trust.clone());
 
         Some(AppState {
-            discovery: Arc::new(DiscoveryService::new()),
-            trust: Arc::new(TrustService::new(
-                "https://sepolia.base.org".to_string(),
-                None,
-            )),
+            discovery: Arc::new(DiscoveryService::new().with_trust(trust.clone())),
+            trust,
             start_time: Instant::now(),
             peer_count: Arc::new(AtomicU64::new(0)),
             node_info: None,
             rate_limiter: Arc::new(RateLimitService::disabled()),
             metrics: Arc::new(MetricsService::disabled()),
             hybrid_search: Some(Arc::new(RwLock::new(hybrid))),
+            did_resolver: None,
             api_token: None,
+            write_verifier: None,
+            envelope_verifier: None,
+            gossip: None,
         })
     }
 }
@@ -58,6 +58,8 @@
 //! let config = KlerosConfig {
 //!     rpc_url: "https://sepolia.base.org".to_string(),
 //!     arbitrator_address: "0x...".to_string(),
+//!     // Only needed for `create_dispute`; view calls work without a signer
+//!     signer_key: Some(std::env::var("KLEROS_SIGNER_KEY")?),
 //!     ..Default::default()
 //! };
 //!
@@ -73,7 +75,10 @@
 //! let status = client.get_dispute_status(dispute_id).await?;
 //! ```
 
-use alloy::primitives::{Address, U256};
+use alloy::network::EthereumWallet;
+use alloy::primitives::{keccak256, Address, B256, U256};
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::signers::local::PrivateKeySigner;
 use alloy::sol;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -211,6 +216,15 @@ sol!(
             ],
             "outputs": [{"name": "ruling", "type": "uint256"}],
             "stateMutability": "view"
+        },
+        {
+            "type": "event",
+            "name": "DisputeCreation",
+            "inputs": [
+                {"name": "_disputeID", "type": "uint256", "indexed": true},
+                {"name": "_arbitrable", "type": "address", "indexed": true}
+            ],
+            "anonymous": false
         }
     ]"#
 );
@@ -358,7 +372,7 @@ pub struct KlerosDispute {
 // ========== Configuration ==========
 
 /// Configuration for Kleros client.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct KlerosConfig {
     /// Ethereum RPC URL.
     pub rpc_url: String,
@@ -374,6 +388,27 @@ pub struct KlerosConfig {
 
     /// Timeout for RPC calls.
     pub timeout: Duration,
+
+    /// Hex-encoded private key used to sign `createDispute` transactions.
+    ///
+    /// Read-only calls work without it.
+    pub signer_key: Option<String>,
+}
+
+impl std::fmt::Debug for KlerosConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KlerosConfig")
+            .field("rpc_url", &self.rpc_url)
+            .field("arbitrator_address", &self.arbitrator_address)
+            .field("court_id", &self.court_id)
+            .field("initial_jurors", &self.initial_jurors)
+            .field("timeout", &self.timeout)
+            .field(
+                "signer_key",
+                &self.signer_key.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
 }
 
 impl Default for KlerosConfig {
@@ -385,6 +420,7 @@ impl Default for KlerosConfig {
             court_id: 0, // General court
             initial_jurors: 3,
             timeout: Duration::from_secs(30),
+            signer_key: None,
         }
     }
 }
@@ -411,6 +447,18 @@ impl KlerosConfig {
         self
     }
 
+    /// Set the private key used to sign dispute creation transactions.
+    pub fn with_signer_key(mut self, key: impl Into<String>) -> Self {
+        self.signer_key = Some(key.into());
+        self
+    }
+
+    /// Set the timeout for RPC calls.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Encode extra data for Kleros (court ID + jurors).
     pub fn encode_extra_data(&self) -> Vec<u8> {
         // Kleros extraData format: abi.encode(courtId, minJurors)
@@ -470,9 +518,12 @@ impl KlerosStats {
 /// Client for interacting with Kleros arbitrator contract.
 ///
 /// Provides methods for creating disputes, checking status, and filing appeals.
+/// View calls go straight to the ERC-792 `IArbitrator` contract over HTTP;
+/// `create_dispute` additionally needs a signer (see [`KlerosConfig::with_signer_key`]).
 pub struct KlerosClient {
     config: KlerosConfig,
     arbitrator_address: Address,
+    signer: Option<PrivateKeySigner>,
     stats: Arc<KlerosStats>,
 }
 
@@ -484,9 +535,19 @@ impl KlerosClient {
             .parse::<Address>()
             .map_err(|e| Error::Config(format!("Invalid arbitrator address: {}", e)))?;
 
+        let signer = config
+            .signer_key
+            .as_deref()
+            .map(|key| {
+                key.parse::<PrivateKeySigner>()
+                    .map_err(|e| Error::Config(format!("Invalid Kleros signer key: {}", e)))
+            })
+            .transpose()?;
+
         Ok(Self {
             config,
             arbitrator_address,
+            signer,
             stats: Arc::new(KlerosStats::default()),
         })
     }
@@ -496,6 +557,7 @@ impl KlerosClient {
         Self {
             config: KlerosConfig::default(),
             arbitrator_address: Address::ZERO,
+            signer: None,
             stats: Arc::new(KlerosStats::default()),
         }
     }
@@ -515,32 +577,76 @@ impl KlerosClient {
         self.arbitrator_address
     }
 
+    /// Get the address transactions are signed with, if a signer is configured.
+    pub fn signer_address(&self) -> Option<Address> {
+        self.signer.as_ref().map(|s| s.address())
+    }
+
     /// Check if the client is properly configured.
     pub fn is_configured(&self) -> bool {
         self.arbitrator_address != Address::ZERO
     }
 
+    /// Ensure the client points at a real arbitrator before touching the chain.
+    fn ensure_configured(&self) -> Result<()> {
+        if !self.is_configured() {
+            return Err(Error::Config("Kleros client not configured".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Build a read-only contract instance over HTTP.
+    fn arbitrator(&self) -> Result<IArbitrator::IArbitratorInstance<impl Provider>> {
+        let provider = ProviderBuilder::new().connect_http(
+            self.config
+                .rpc_url
+                .parse()
+                .map_err(|e| Error::Network(format!("Invalid RPC URL: {}", e)))?,
+        );
+
+        Ok(IArbitrator::new(self.arbitrator_address, provider))
+    }
+
+    /// Run an RPC future under the configured timeout, mapping failures to
+    /// `Error::Contract` and counting them in the stats.
+    async fn rpc<T, E, F>(&self, what: &str, fut: F) -> Result<T>
+    where
+        F: std::future::IntoFuture<Output = std::result::Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let result = match tokio::time::timeout(self.config.timeout, fut.into_future()).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(e)) => Err(Error::Contract(format!("Failed to {}: {}", what, e))),
+            Err(_) => Err(Error::Contract(format!(
+                "Failed to {}: timed out after {:?}",
+                what, self.config.timeout
+            ))),
+        };
+
+        if result.is_err() {
+            self.stats.record_error();
+        }
+        result
+    }
+
     /// Get the arbitration cost for a dispute with given number of choices.
     ///
+    /// Kleros prices disputes by court and juror count (both carried in
+    /// `extraData`), not by the number of choices.
+    ///
     /// # Arguments
     /// * `choices` - Number of possible rulings (typically 3 for AgoraMesh)
     ///
     /// # Returns
     /// Cost in wei (ETH for gas, not USDC)
     pub async fn get_arbitration_cost(&self, _choices: u64) -> Result<U256> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
-        }
+        self.ensure_configured()?;
 
-        // In a real implementation, we would call the contract:
-        // let provider = ProviderBuilder::new().connect_http(self.config.rpc_url.parse()?);
-        // let contract = IArbitrator::new(self.arbitrator_address, provider);
-        // let extra_data = self.config.encode_extra_data();
-        // let cost = contract.arbitrationCost(extra_data.into()).call().await?;
+        let contract = self.arbitrator()?;
+        let extra_data = self.config.encode_extra_data();
+        let call = contract.arbitrationCost(extra_data.into());
 
-        // For now, return a placeholder cost (0.01 ETH = 10^16 wei)
-        // Real cost depends on court and juror count
-        Ok(U256::from(10_000_000_000_000_000u64))
+        self.rpc("get arbitration cost", call.call()).await
     }
 
     /// Get the appeal cost for an existing dispute.
@@ -550,14 +656,14 @@ impl KlerosClient {
     ///
     /// # Returns
     /// Cost in wei for filing an appeal
-    pub async fn get_appeal_cost(&self, _dispute_id: U256) -> Result<U256> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
-        }
+    pub async fn get_appeal_cost(&self, dispute_id: U256) -> Result<U256> {
+        self.ensure_configured()?;
 
-        // Appeal cost is typically 2x the previous round
-        // Real implementation would call appealCost()
-        Ok(U256::from(20_000_000_000_000_000u64))
+        let contract = self.arbitrator()?;
+        let extra_data = self.config.encode_extra_data();
+        let call = contract.appealCost(dispute_id, extra_data.into());
+
+        self.rpc("get appeal cost", call.call()).await
     }
 
     /// Get the current status of a dispute.
@@ -567,13 +673,14 @@ impl KlerosClient {
     ///
     /// # Returns
     /// Current dispute status
-    pub async fn get_dispute_status(&self, _dispute_id: U256) -> Result<DisputeStatus> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
-        }
+    pub async fn get_dispute_status(&self, dispute_id: U256) -> Result<DisputeStatus> {
+        self.ensure_configured()?;
+
+        let contract = self.arbitrator()?;
+        let call = contract.disputeStatus(dispute_id);
+        let status = self.rpc("get dispute status", call.call()).await?;
 
-        // Real implementation would call disputeStatus()
-        Ok(DisputeStatus::Waiting)
+        DisputeStatus::try_from(status)
     }
 
     /// Get the current ruling for a dispute.
@@ -585,15 +692,18 @@ impl KlerosClient {
     ///
     /// # Returns
     /// Current ruling
-    pub async fn get_current_ruling(&self, _dispute_id: U256) -> Result<Ruling> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
-        }
+    pub async fn get_current_ruling(&self, dispute_id: U256) -> Result<Ruling> {
+        self.ensure_configured()?;
+
+        let contract = self.arbitrator()?;
+        let call = contract.currentRuling(dispute_id);
+        let ruling = self.rpc("get current ruling", call.call()).await?;
 
         self.stats.record_ruling();
 
-        // Real implementation would call currentRuling()
-        Ok(Ruling::None)
+        let ruling = u64::try_from(ruling)
+            .map_err(|_| Error::Contract(format!("Invalid ruling: {}", ruling)))?;
+        Ruling::try_from(ruling)
     }
 
     /// Get the appeal period for a dispute.
@@ -603,14 +713,22 @@ impl KlerosClient {
     ///
     /// # Returns
     /// Appeal period start and end timestamps, or None if not appealable
-    pub async fn get_appeal_period(&self, _dispute_id: U256) -> Result<Option<AppealPeriod>> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
+    pub async fn get_appeal_period(&self, dispute_id: U256) -> Result<Option<AppealPeriod>> {
+        self.ensure_configured()?;
+
+        let contract = self.arbitrator()?;
+        let call = contract.appealPeriod(dispute_id);
+        let period = self.rpc("get appeal period", call.call()).await?;
+
+        // The arbitrator returns (0, 0) outside of an appeal window
+        if period.start.is_zero() && period.end.is_zero() {
+            return Ok(None);
         }
 
-        // Real implementation would call appealPeriod()
-        // Returns (0, 0) if not in appeal period
-        Ok(None)
+        Ok(Some(AppealPeriod {
+            start: period.start.try_into().unwrap_or(u64::MAX),
+            end: period.end.try_into().unwrap_or(u64::MAX),
+        }))
     }
 
     /// Get full dispute information.
@@ -676,10 +794,12 @@ impl KlerosClient {
 
     /// Create a new dispute on Kleros.
     ///
-    /// In production, this would:
-    /// 1. Call `createDispute` on the Kleros Arbitrator contract
-    /// 2. Pay the arbitration cost in ETH
-    /// 3. Return the on-chain dispute ID
+    /// Sends `createDispute{value: arbitration_cost}(choices, extraData)` to the
+    /// arbitrator, waits for the receipt and reads the dispute ID from the
+    /// `DisputeCreation` event. Requires a signer key in the config.
+    ///
+    /// The evidence bundle itself is published through the arbitrable
+    /// contract (ERC-1497), so `evidence_uri` is only validated and logged here.
     ///
     /// # Arguments
     /// * `evidence_uri` - IPFS URI containing the evidence bundle
@@ -692,11 +812,9 @@ impl KlerosClient {
         &self,
         evidence_uri: &str,
         choices: u64,
-        _arbitration_cost: U256,
+        arbitration_cost: U256,
     ) -> Result<U256> {
-        if !self.is_configured() {
-            return Err(Error::Config("Kleros client not configured".to_string()));
-        }
+        self.ensure_configured()?;
 
         // Validate inputs
         if choices == 0 {
@@ -711,32 +829,61 @@ impl KlerosClient {
             ));
         }
 
-        // In production, this would:
-        // 1. Connect to the RPC provider
-        // 2. Call the createDispute function on the Kleros Arbitrator contract:
-        //    arbitrator.createDispute{value: arbitration_cost}(choices, extra_data)
-        // 3. Listen for the DisputeCreation event to get the dispute ID
-        //
-        // For now, generate a mock dispute ID based on timestamp
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map_err(|e| Error::Internal(format!("System clock error: {}", e)))?
-            .as_secs();
+        let signer = self.signer.clone().ok_or_else(|| {
+            Error::Config("Kleros signer key not configured for createDispute".to_string())
+        })?;
+
+        let provider = ProviderBuilder::new()
+            .wallet(EthereumWallet::from(signer))
+            .connect_http(
+                self.config
+                    .rpc_url
+                    .parse()
+                    .map_err(|e| Error::Network(format!("Invalid RPC URL: {}", e)))?,
+            );
+        let contract = IArbitrator::new(self.arbitrator_address, provider);
+
+        let extra_data = self.config.encode_extra_data();
+        let call = contract
+            .createDispute(U256::from(choices), extra_data.into())
+            .value(arbitration_cost);
+
+        let pending = self.rpc("send createDispute", call.send()).await?;
+        let receipt = self
+            .rpc("get createDispute receipt", pending.get_receipt())
+            .await?;
+
+        if !receipt.status() {
+            self.stats.record_error();
+            return Err(Error::Contract(format!(
+                "createDispute reverted in transaction {}",
+                receipt.transaction_hash
+            )));
+        }
 
-        // Use timestamp + hash of evidence URI as mock dispute ID
-        let evidence_hash = evidence_uri
-            .bytes()
-            .fold(0u64, |acc, b| acc.wrapping_add(b as u64));
-        let dispute_id = U256::from(timestamp.wrapping_add(evidence_hash));
+        let dispute_id = receipt
+            .logs()
+            .iter()
+            .filter(|log| log.address() == self.arbitrator_address)
+            .find_map(|log| log.log_decode::<IArbitrator::DisputeCreation>().ok())
+            .map(|log| log.inner.data._disputeID)
+            .ok_or_else(|| {
+                self.stats.record_error();
+                Error::Contract(format!(
+                    "No DisputeCreation event in transaction {}",
+                    receipt.transaction_hash
+                ))
+            })?;
 
         // Record stats
-        self.stats.disputes_created.fetch_add(1, Ordering::Relaxed);
+        self.stats.record_dispute_created();
 
         tracing::info!(
             dispute_id = %dispute_id,
             evidence_uri = %evidence_uri,
             choices = choices,
-            "Created Kleros dispute (mock)"
+            tx_hash = %receipt.transaction_hash,
+            "Created Kleros dispute"
         );
 
         Ok(dispute_id)
@@ -1753,6 +1900,41 @@ impl JurorVote {
         }
     }
 
+    /// Compute the commitment a juror submits during the commit phase.
+    ///
+    /// The commitment is `keccak256(abi.encode(disputeId, jurorDid, ruling, salt))`,
+    /// rendered as `0x`-prefixed lowercase hex. Binding the dispute ID and juror
+    /// DID prevents a commitment from being replayed in another session or by
+    /// another juror; the secret salt keeps the ruling hidden until reveal.
+    pub fn compute_commitment(
+        dispute_id: &str,
+        juror_did: &str,
+        ruling: Ruling,
+        salt: B256,
+    ) -> String {
+        use alloy::sol_types::SolValue;
+
+        let encoded = (
+            dispute_id.to_string(),
+            juror_did.to_string(),
+            U256::from(ruling as u8),
+            salt,
+        )
+            .abi_encode();
+        format!("{}", keccak256(encoded))
+    }
+
+    /// Check whether a reveal matches this vote's commitment.
+    pub fn matches_commitment(&self, dispute_id: &str, ruling: Ruling, salt: B256) -> bool {
+        match &self.commitment {
+            Some(commitment) => {
+                let expected = Self::compute_commitment(dispute_id, &self.juror_did, ruling, salt);
+                commitment.eq_ignore_ascii_case(&expected)
+            }
+            None => false,
+        }
+    }
+
     /// Create a revealed vote.
     pub fn reveal(
         juror_did: impl Into<String>,
@@ -1894,27 +2076,41 @@ impl VotingSession {
             .map(|(ruling, _)| ruling)
     }
 
+    /// Jurors that did not reveal a vote (never committed or failed to reveal).
+    pub fn non_revealers(&self) -> Vec<String> {
+        self.jurors
+            .iter()
+            .filter(|juror| {
+                !self
+                    .votes
+                    .iter()
+                    .any(|v| &v.juror_did == *juror && v.revealed)
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Calculate coherence results (Schelling point redistribution).
     ///
     /// Based on Kleros whitepaper:
     /// - Coherent jurors (voted with majority) receive rewards
     /// - Incoherent jurors lose portion of stake
+    /// - Jurors that did not reveal are treated as incoherent
     /// - Returns map of juror DID -> amount (positive = reward, negative = slash)
     pub fn calculate_coherence(&mut self, stake_at_risk_percent: u64) -> HashMap<String, i64> {
-        let majority = match self.determine_majority() {
-            Some(r) => r,
-            None => return HashMap::new(),
-        };
-
+        let majority = self.determine_majority();
         let revealed: Vec<_> = self.votes.iter().filter(|v| v.revealed).collect();
-        let total_votes = revealed.len() as i64;
-        if total_votes == 0 {
-            return HashMap::new();
-        }
+        let non_revealers = self.non_revealers();
 
         // Calculate coherent/incoherent counts
-        let coherent_count = revealed.iter().filter(|v| v.choice == majority).count() as i64;
-        let incoherent_count = total_votes - coherent_count;
+        let coherent_count = revealed
+            .iter()
+            .filter(|v| Some(v.choice) == majority)
+            .count() as i64;
+        let incoherent_count = revealed.len() as i64 - coherent_count + non_revealers.len() as i64;
+        if coherent_count + incoherent_count == 0 {
+            return HashMap::new();
+        }
 
         // Base stake at risk (in basis points, e.g., 1000 = 10%)
         let risk_bps = stake_at_risk_percent as i64;
@@ -1931,13 +2127,16 @@ impl VotingSession {
 
         let mut results = HashMap::new();
         for vote in &revealed {
-            let amount = if vote.choice == majority {
+            let amount = if Some(vote.choice) == majority {
                 reward_per_coherent
             } else {
                 -slash_per_incoherent
             };
             results.insert(vote.juror_did.clone(), amount);
         }
+        for juror_did in non_revealers {
+            results.insert(juror_did, -slash_per_incoherent);
+        }
 
         self.coherence_results = results.clone();
         results
@@ -2363,11 +2562,16 @@ impl JurorPool {
     }
 
     /// Reveal a vote (reveal phase).
+    ///
+    /// The `choice` and `salt` must hash to the commitment submitted during
+    /// the commit phase (see [`JurorVote::compute_commitment`]); otherwise
+    /// the reveal is rejected and the vote stays hidden.
     pub fn reveal_vote(
         &self,
         dispute_id: &str,
         juror_did: &str,
         choice: Ruling,
+        salt: B256,
         justification: &str,
     ) -> Result<()> {
         let mut sessions = self
@@ -2400,8 +2604,13 @@ impl JurorPool {
             )));
         }
 
-        // In production, verify commitment matches reveal
-        // For now, just reveal the vote
+        if !vote.matches_commitment(dispute_id, choice, salt) {
+            return Err(Error::Contract(format!(
+                "Reveal does not match commitment for juror {}",
+                juror_did
+            )));
+        }
+
         vote.choice = choice;
         vote.justification = justification.to_string();
         vote.revealed = true;
@@ -2452,6 +2661,14 @@ impl JurorPool {
                     self.stats.record_vote(was_coherent);
                 }
             }
+
+            // Jurors who never revealed count as incoherent
+            for juror_did in session.non_revealers() {
+                if let Some(juror) = jurors.get_mut(&juror_did) {
+                    juror.record_case(false);
+                    juror.status = JurorStatus::Active; // Release from serving
+                }
+            }
         }
 
         Ok(coherence_results)
@@ -2490,6 +2707,281 @@ impl JurorPool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy::consensus::transaction::SignerRecoverable;
+    use alloy::consensus::{Transaction as _, TxEnvelope};
+    use alloy::eips::Decodable2718;
+    use alloy::sol_types::{SolCall, SolEvent, SolValue};
+    use serde_json::{json, Value};
+
+    // ========== Local ERC-792 Arbitrator Stand-in ==========
+    //
+    // Anvil-style JSON-RPC endpoint that executes IArbitrator calls against
+    // in-memory dispute state, so KlerosClient is exercised over real HTTP.
+
+    /// Anvil's first dev account.
+    const TEST_SIGNER_KEY: &str =
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const TEST_ARBITRATOR: &str = "0x1234567890123456789012345678901234567890";
+    const MOCK_ARBITRATION_COST: u64 = 10_000_000_000_000_000; // 0.01 ETH
+
+    #[derive(Debug, Clone, Copy, Default)]
+    struct MockDispute {
+        status: u8,
+        ruling: u64,
+        appeal_start: u64,
+        appeal_end: u64,
+    }
+
+    #[derive(Default)]
+    struct MockChainState {
+        disputes: HashMap<u64, MockDispute>,
+        next_dispute_id: u64,
+        receipts: HashMap<B256, Value>,
+        nonce: u64,
+        last_extra_data: Vec<u8>,
+    }
+
+    #[derive(Clone)]
+    struct MockArbitrator {
+        address: Address,
+        state: Arc<std::sync::Mutex<MockChainState>>,
+    }
+
+    impl MockArbitrator {
+        /// Serve the stand-in on an ephemeral port and return its RPC URL.
+        async fn start() -> (String, Self) {
+            let mock = Self {
+                address: TEST_ARBITRATOR.parse().unwrap(),
+                state: Arc::new(std::sync::Mutex::new(MockChainState {
+                    next_dispute_id: 1,
+                    ..Default::default()
+                })),
+            };
+
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(Self::handle))
+                .with_state(mock.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+            (url, mock)
+        }
+
+        fn set_dispute(&self, id: u64, dispute: MockDispute) {
+            self.state.lock().unwrap().disputes.insert(id, dispute);
+        }
+
+        fn dispute(&self, id: u64) -> Option<MockDispute> {
+            self.state.lock().unwrap().disputes.get(&id).copied()
+        }
+
+        fn dispute_count(&self) -> usize {
+            self.state.lock().unwrap().disputes.len()
+        }
+
+        fn last_extra_data(&self) -> Vec<u8> {
+            self.state.lock().unwrap().last_extra_data.clone()
+        }
+
+        async fn handle(
+            axum::extract::State(mock): axum::extract::State<Self>,
+            axum::Json(request): axum::Json<Value>,
+        ) -> axum::Json<Value> {
+            match request {
+                Value::Array(batch) => axum::Json(Value::Array(
+                    batch.iter().map(|r| mock.respond(r)).collect(),
+                )),
+                single => axum::Json(mock.respond(&single)),
+            }
+        }
+
+        fn respond(&self, request: &Value) -> Value {
+            let params = &request["params"];
+            let result = match request["method"].as_str().unwrap_or_default() {
+                "eth_chainId" => Ok(json!("0x7a69")),
+                "eth_blockNumber" => Ok(json!("0x1")),
+                "eth_getTransactionCount" => {
+                    Ok(json!(format!("{:#x}", self.state.lock().unwrap().nonce)))
+                }
+                "eth_feeHistory" => Ok(json!({
+                    "oldestBlock": "0x1",
+                    "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00"],
+                    "gasUsedRatio": [0.5],
+                    "reward": [["0x3b9aca00"]]
+                })),
+                "eth_call" => self
+                    .call(&params[0])
+                    .map(|out| json!(alloy::hex::encode_prefixed(out))),
+                "eth_estimateGas" => self.call(&params[0]).map(|_| json!("0x30d40")),
+                "eth_sendRawTransaction" => self.send_raw(params[0].as_str().unwrap_or_default()),
+                "eth_getTransactionReceipt" => {
+                    let hash: B256 = params[0].as_str().unwrap_or_default().parse().unwrap();
+                    let state = self.state.lock().unwrap();
+                    Ok(state.receipts.get(&hash).cloned().unwrap_or(Value::Null))
+                }
+                other => Err(format!("method not supported: {}", other)),
+            };
+
+            match result {
+                Ok(result) => json!({"jsonrpc": "2.0", "id": request["id"], "result": result}),
+                Err(message) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": {"code": 3, "message": message}
+                }),
+            }
+        }
+
+        /// Dry-run a call (eth_call / eth_estimateGas).
+        fn call(&self, tx: &Value) -> std::result::Result<Vec<u8>, String> {
+            let input = tx["input"].as_str().or(tx["data"].as_str()).unwrap_or("0x");
+            let input = alloy::hex::decode(input).map_err(|e| e.to_string())?;
+            let value = tx["value"]
+                .as_str()
+                .map(|v| v.parse::<U256>().unwrap())
+                .unwrap_or_default();
+
+            self.execute(&input, value, false).map(|(out, _)| out)
+        }
+
+        fn send_raw(&self, raw: &str) -> std::result::Result<Value, String> {
+            let raw = alloy::hex::decode(raw).map_err(|e| e.to_string())?;
+            let tx = TxEnvelope::decode_2718(&mut raw.as_slice()).map_err(|e| e.to_string())?;
+            let sender = tx.recover_signer().map_err(|e| e.to_string())?;
+            let hash = *tx.tx_hash();
+
+            if tx.to() != Some(self.address) {
+                return Err("transaction not addressed to arbitrator".to_string());
+            }
+
+            let (_, created) = self.execute(tx.input(), tx.value(), true)?;
+
+            let logs: Vec<Value> = created
+                .into_iter()
+                .map(|id| {
+                    json!({
+                        "address": self.address,
+                        "topics": [
+                            IArbitrator::DisputeCreation::SIGNATURE_HASH,
+                            B256::from(U256::from(id)),
+                            sender.into_word(),
+                        ],
+                        "data": "0x",
+                        "blockNumber": "0x2",
+                        "blockHash": B256::repeat_byte(0xbb),
+                        "transactionHash": hash,
+                        "transactionIndex": "0x0",
+                        "logIndex": "0x0",
+                        "removed": false
+                    })
+                })
+                .collect();
+
+            let receipt = json!({
+                "transactionHash": hash,
+                "transactionIndex": "0x0",
+                "blockHash": B256::repeat_byte(0xbb),
+                "blockNumber": "0x2",
+                "from": sender,
+                "to": self.address,
+                "cumulativeGasUsed": "0x30d40",
+                "gasUsed": "0x30d40",
+                "effectiveGasPrice": "0x3b9aca00",
+                "contractAddress": null,
+                "logs": logs,
+                "logsBloom": alloy::hex::encode_prefixed([0u8; 256]),
+                "type": "0x2",
+                "status": "0x1"
+            });
+
+            let mut state = self.state.lock().unwrap();
+            state.nonce += 1;
+            state.receipts.insert(hash, receipt);
+
+            Ok(json!(hash))
+        }
+
+        /// Execute an IArbitrator call, returning ABI-encoded output and the
+        /// ID of any dispute created.
+        fn execute(
+            &self,
+            input: &[u8],
+            value: U256,
+            commit: bool,
+        ) -> std::result::Result<(Vec<u8>, Option<u64>), String> {
+            let selector: [u8; 4] = input
+                .get(..4)
+                .and_then(|s| s.try_into().ok())
+                .ok_or("execution reverted: missing selector")?;
+            let cost = U256::from(MOCK_ARBITRATION_COST);
+            let mut state = self.state.lock().unwrap();
+
+            let lookup = |state: &MockChainState, id: U256| {
+                let id: u64 = id.try_into().map_err(|_| "invalid dispute id")?;
+                state
+                    .disputes
+                    .get(&id)
+                    .copied()
+                    .ok_or_else(|| "execution reverted: unknown dispute".to_string())
+            };
+
+            if selector == IArbitrator::arbitrationCostCall::SELECTOR {
+                Ok((cost.abi_encode(), None))
+            } else if selector == IArbitrator::appealCostCall::SELECTOR {
+                let call =
+                    IArbitrator::appealCostCall::abi_decode(input).map_err(|e| e.to_string())?;
+                lookup(&state, call._disputeID)?;
+                Ok(((cost * U256::from(2)).abi_encode(), None))
+            } else if selector == IArbitrator::disputeStatusCall::SELECTOR {
+                let call =
+                    IArbitrator::disputeStatusCall::abi_decode(input).map_err(|e| e.to_string())?;
+                let dispute = lookup(&state, call._disputeID)?;
+                Ok((U256::from(dispute.status).abi_encode(), None))
+            } else if selector == IArbitrator::currentRulingCall::SELECTOR {
+                let call =
+                    IArbitrator::currentRulingCall::abi_decode(input).map_err(|e| e.to_string())?;
+                let dispute = lookup(&state, call._disputeID)?;
+                Ok((U256::from(dispute.ruling).abi_encode(), None))
+            } else if selector == IArbitrator::appealPeriodCall::SELECTOR {
+                let call =
+                    IArbitrator::appealPeriodCall::abi_decode(input).map_err(|e| e.to_string())?;
+                let dispute = lookup(&state, call._disputeID)?;
+                let period = (
+                    U256::from(dispute.appeal_start),
+                    U256::from(dispute.appeal_end),
+                );
+                Ok((period.abi_encode(), None))
+            } else if selector == IArbitrator::createDisputeCall::SELECTOR {
+                let call =
+                    IArbitrator::createDisputeCall::abi_decode(input).map_err(|e| e.to_string())?;
+                if value < cost {
+                    return Err(
+                        "execution reverted: Not enough ETH to cover arbitration costs."
+                            .to_string(),
+                    );
+                }
+
+                let id = state.next_dispute_id;
+                if commit {
+                    state.next_dispute_id += 1;
+                    state.disputes.insert(id, MockDispute::default());
+                    state.last_extra_data = call._extraData.to_vec();
+                }
+                Ok((U256::from(id).abi_encode(), Some(id)))
+            } else {
+                Err("execution reverted: unknown selector".to_string())
+            }
+        }
+    }
+
+    /// Kleros client wired to a running stand-in, with a signer.
+    fn mock_kleros_client(rpc_url: &str) -> KlerosClient {
+        let config = KlerosConfig::new(rpc_url, TEST_ARBITRATOR)
+            .with_signer_key(TEST_SIGNER_KEY)
+            .with_timeout(Duration::from_secs(10));
+        KlerosClient::new(config).unwrap()
+    }
 
     // ========== RED Phase: DisputeStatus Tests ==========
 
@@ -2749,6 +3241,44 @@ mod tests {
         assert_eq!(client.arbitrator_address(), Address::ZERO);
     }
 
+    #[test]
+    fn test_client_new_parses_signer_key() {
+        let config = KlerosConfig::new("https://sepolia.base.org", TEST_ARBITRATOR)
+            .with_signer_key(TEST_SIGNER_KEY);
+
+        let client = KlerosClient::new(config).unwrap();
+
+        assert_eq!(
+            client.signer_address(),
+            Some(
+                "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                    .parse::<Address>()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_client_new_invalid_signer_key() {
+        let config = KlerosConfig::new("https://sepolia.base.org", TEST_ARBITRATOR)
+            .with_signer_key("not-a-key");
+
+        let result = KlerosClient::new(config);
+
+        assert!(result.is_err());
+        assert!(result.err().unwrap().to_string().contains("signer key"));
+    }
+
+    #[test]
+    fn test_config_debug_redacts_signer_key() {
+        let config = KlerosConfig::default().with_signer_key(TEST_SIGNER_KEY);
+
+        let debug = format!("{:?}", config);
+
+        assert!(!debug.contains("ac0974bec39a17e3"));
+        assert!(debug.contains("<redacted>"));
+    }
+
     #[test]
     fn test_client_exposes_config() {
         let config = KlerosConfig::default().with_court_id(5);
@@ -2847,17 +3377,51 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_arbitration_cost_configured() {
-        let config = KlerosConfig::new(
-            "https://sepolia.base.org",
-            "0x1234567890123456789012345678901234567890",
-        );
+        let (rpc_url, _mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
+
+        let result = client.get_arbitration_cost(3).await;
+
+        assert_eq!(result.unwrap(), U256::from(MOCK_ARBITRATION_COST));
+    }
+
+    #[tokio::test]
+    async fn test_get_arbitration_cost_rpc_failure_records_error() {
+        // Nothing listens on port 1
+        let config = KlerosConfig::new("http://127.0.0.1:1", TEST_ARBITRATOR)
+            .with_timeout(Duration::from_secs(5));
         let client = KlerosClient::new(config).unwrap();
 
         let result = client.get_arbitration_cost(3).await;
 
-        // Should return a placeholder cost since we're not actually calling the contract
-        assert!(result.is_ok());
-        assert!(result.unwrap() > U256::ZERO);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to get arbitration cost"));
+        assert_eq!(client.stats().rpc_errors.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_appeal_cost_configured() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        mock.set_dispute(7, MockDispute::default());
+        let client = mock_kleros_client(&rpc_url);
+
+        let cost = client.get_appeal_cost(U256::from(7)).await.unwrap();
+
+        assert_eq!(cost, U256::from(2 * MOCK_ARBITRATION_COST));
+    }
+
+    #[tokio::test]
+    async fn test_get_appeal_cost_unknown_dispute_reverts() {
+        let (rpc_url, _mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
+
+        let result = client.get_appeal_cost(U256::from(99)).await;
+
+        assert!(result.is_err());
+        assert_eq!(client.stats().rpc_errors.load(Ordering::Relaxed), 1);
     }
 
     #[tokio::test]
@@ -2898,18 +3462,83 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_dispute_configured() {
-        let config = KlerosConfig::new(
-            "https://sepolia.base.org",
-            "0x1234567890123456789012345678901234567890",
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        mock.set_dispute(
+            1,
+            MockDispute {
+                status: 1,
+                ruling: 2,
+                appeal_start: 1_700_000_000,
+                appeal_end: 1_700_432_000,
+            },
         );
-        let client = KlerosClient::new(config).unwrap();
+        let client = mock_kleros_client(&rpc_url);
 
         let result = client.get_dispute(U256::from(1), 3).await;
 
-        assert!(result.is_ok());
+        assert!(result.is_ok(), "{:?}", result.err());
         let dispute = result.unwrap();
         assert_eq!(dispute.dispute_id, U256::from(1));
         assert_eq!(dispute.choices, 3);
+        assert_eq!(dispute.status, DisputeStatus::Appealable);
+        assert_eq!(dispute.ruling, Ruling::FavorProvider);
+        let period = dispute.appeal_period.unwrap();
+        assert_eq!(period.start, 1_700_000_000);
+        assert_eq!(period.end, 1_700_432_000);
+        assert_eq!(client.stats().rulings_received.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_dispute_status_tracks_contract_state() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        mock.set_dispute(4, MockDispute::default());
+        let client = mock_kleros_client(&rpc_url);
+
+        let status = client.get_dispute_status(U256::from(4)).await.unwrap();
+        assert_eq!(status, DisputeStatus::Waiting);
+
+        mock.set_dispute(
+            4,
+            MockDispute {
+                status: 2,
+                ruling: 1,
+                ..Default::default()
+            },
+        );
+
+        let status = client.get_dispute_status(U256::from(4)).await.unwrap();
+        let ruling = client.get_current_ruling(U256::from(4)).await.unwrap();
+        assert_eq!(status, DisputeStatus::Solved);
+        assert_eq!(ruling, Ruling::FavorClient);
+    }
+
+    #[tokio::test]
+    async fn test_get_current_ruling_rejects_unknown_ruling() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        mock.set_dispute(
+            5,
+            MockDispute {
+                ruling: 42,
+                ..Default::default()
+            },
+        );
+        let client = mock_kleros_client(&rpc_url);
+
+        let result = client.get_current_ruling(U256::from(5)).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid ruling"));
+    }
+
+    #[tokio::test]
+    async fn test_get_appeal_period_none_outside_window() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        mock.set_dispute(3, MockDispute::default());
+        let client = mock_kleros_client(&rpc_url);
+
+        let period = client.get_appeal_period(U256::from(3)).await.unwrap();
+
+        assert!(period.is_none());
     }
 
     // ========== TDD Tests: KlerosClient.create_dispute() (Task #67) ==========
@@ -2936,11 +3565,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_kleros_create_dispute_configured_client_returns_id() {
-        let config = KlerosConfig::new(
-            "https://sepolia.base.org",
-            "0x1234567890123456789012345678901234567890",
-        );
-        let client = KlerosClient::new(config).unwrap();
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
 
         let result = client
             .create_dispute(
@@ -2950,33 +3576,90 @@ mod tests {
             )
             .await;
 
-        // Should return a dispute ID (placeholder in test mode)
+        // Should return the ID emitted in DisputeCreation
         assert!(
             result.is_ok(),
             "Configured client should create dispute: {:?}",
             result.err()
         );
-        assert!(
-            result.unwrap() > U256::ZERO,
-            "Dispute ID should be non-zero"
-        );
+        let dispute_id = result.unwrap();
+        assert_eq!(dispute_id, U256::from(1));
+        assert!(mock.dispute(1).is_some(), "Dispute should exist on-chain");
+    }
+
+    #[tokio::test]
+    async fn test_kleros_create_dispute_sends_court_extra_data() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let config = KlerosConfig::new(rpc_url, TEST_ARBITRATOR)
+            .with_signer_key(TEST_SIGNER_KEY)
+            .with_court_id(4)
+            .with_jurors(5);
+        let client = KlerosClient::new(config.clone()).unwrap();
+
+        client
+            .create_dispute("ipfs://QmTest", 3, U256::from(MOCK_ARBITRATION_COST))
+            .await
+            .unwrap();
+
+        assert_eq!(mock.last_extra_data(), config.encode_extra_data());
+    }
+
+    #[tokio::test]
+    async fn test_kleros_create_dispute_assigns_sequential_ids() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
+        let cost = U256::from(MOCK_ARBITRATION_COST);
+
+        let first = client.create_dispute("ipfs://QmA", 3, cost).await.unwrap();
+        let second = client.create_dispute("ipfs://QmB", 3, cost).await.unwrap();
+
+        assert_eq!(second, first + U256::from(1));
+        assert_eq!(mock.dispute_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_kleros_create_dispute_underpaid_reverts() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
+
+        let result = client
+            .create_dispute("ipfs://QmTest", 3, U256::from(1u64))
+            .await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Not enough ETH"));
+        assert_eq!(mock.dispute_count(), 0);
+        assert_eq!(client.stats().disputes_created.load(Ordering::Relaxed), 0);
+        assert_eq!(client.stats().rpc_errors.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_kleros_create_dispute_requires_signer() {
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let client = KlerosClient::new(KlerosConfig::new(rpc_url, TEST_ARBITRATOR)).unwrap();
+
+        let result = client
+            .create_dispute("ipfs://QmTest", 3, U256::from(MOCK_ARBITRATION_COST))
+            .await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("signer key"));
+        assert_eq!(mock.dispute_count(), 0);
     }
 
     #[tokio::test]
     async fn test_kleros_create_dispute_records_stats() {
-        let config = KlerosConfig::new(
-            "https://sepolia.base.org",
-            "0x1234567890123456789012345678901234567890",
-        );
-        let client = KlerosClient::new(config).unwrap();
+        let (rpc_url, _mock) = MockArbitrator::start().await;
+        let client = mock_kleros_client(&rpc_url);
 
         // Initial count
         let initial_count = client.stats().disputes_created.load(Ordering::Relaxed);
 
         // Create dispute
-        let _ = client
-            .create_dispute("ipfs://QmTest", 3, U256::from(10_000_000_000_000_000u64))
-            .await;
+        client
+            .create_dispute("ipfs://QmTest", 3, U256::from(MOCK_ARBITRATION_COST))
+            .await
+            .unwrap();
 
         // Should increment count
         let final_count = client.stats().disputes_created.load(Ordering::Relaxed);
@@ -3752,10 +4435,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_ai_arbitrator_full_appeal_flow() {
-        let kleros_config = KlerosConfig::new(
-            "https://sepolia.base.org",
-            "0x1234567890123456789012345678901234567890",
-        );
+        let (rpc_url, mock) = MockArbitrator::start().await;
+        let kleros_config =
+            KlerosConfig::new(rpc_url, TEST_ARBITRATOR).with_signer_key(TEST_SIGNER_KEY);
         let config = AIArbitrationConfig::default().with_kleros(kleros_config);
         let arbitrator = AIArbitrator::new(config).unwrap();
 
@@ -3781,6 +4463,10 @@ mod tests {
         let kleros_id = arbitrator.appeal_to_kleros(&dispute_id).await.unwrap();
         assert!(kleros_id > U256::ZERO);
 
+        // The Kleros dispute exists on the arbitrator and is awaiting jurors
+        let on_chain: u64 = kleros_id.try_into().unwrap();
+        assert!(mock.dispute(on_chain).is_some());
+
         // Verify dispute state
         let dispute = arbitrator.get_dispute(&dispute_id).unwrap();
         assert_eq!(dispute.state, AIDisputeState::Appealed);
@@ -4247,7 +4933,10 @@ mod tests {
         pool.advance_session_state("dispute-123").unwrap();
 
         // Commit vote
-        pool.commit_vote("dispute-123", &juror_did, "hash123")
+        let salt = B256::repeat_byte(0x42);
+        let commitment =
+            JurorVote::compute_commitment("dispute-123", &juror_did, Ruling::FavorClient, salt);
+        pool.commit_vote("dispute-123", &juror_did, &commitment)
             .unwrap();
 
         // Advance to Reveal phase
@@ -4258,6 +4947,7 @@ mod tests {
             "dispute-123",
             &juror_did,
             Ruling::FavorClient,
+            salt,
             "Client evidence was stronger",
         )
         .unwrap();
@@ -4266,6 +4956,132 @@ mod tests {
         assert_eq!(session.revealed_vote_count(), 1);
     }
 
+    #[test]
+    fn test_compute_commitment_binds_all_inputs() {
+        let salt = B256::repeat_byte(0x01);
+        let base = JurorVote::compute_commitment("d-1", "did:juror1", Ruling::FavorClient, salt);
+
+        assert!(base.starts_with("0x"));
+        assert_eq!(base.len(), 66);
+        assert_eq!(
+            base,
+            JurorVote::compute_commitment("d-1", "did:juror1", Ruling::FavorClient, salt)
+        );
+        assert_ne!(
+            base,
+            JurorVote::compute_commitment("d-2", "did:juror1", Ruling::FavorClient, salt)
+        );
+        assert_ne!(
+            base,
+            JurorVote::compute_commitment("d-1", "did:juror2", Ruling::FavorClient, salt)
+        );
+        assert_ne!(
+            base,
+            JurorVote::compute_commitment("d-1", "did:juror1", Ruling::FavorProvider, salt)
+        );
+        assert_ne!(
+            base,
+            JurorVote::compute_commitment(
+                "d-1",
+                "did:juror1",
+                Ruling::FavorClient,
+                B256::repeat_byte(0x02)
+            )
+        );
+    }
+
+    #[test]
+    fn test_juror_pool_reveal_rejects_mismatched_ruling() {
+        let pool = JurorPool::disabled();
+
+        for i in 1..=5 {
+            pool.register_juror(format!("did:juror{}", i), 500_000_000, vec![0])
+                .unwrap();
+        }
+
+        pool.create_session("dispute-123", 0).unwrap();
+        let juror_did = pool.get_session("dispute-123").unwrap().jurors[0].clone();
+        pool.advance_session_state("dispute-123").unwrap(); // Commit
+
+        let salt = B256::repeat_byte(0x07);
+        let commitment =
+            JurorVote::compute_commitment("dispute-123", &juror_did, Ruling::FavorClient, salt);
+        pool.commit_vote("dispute-123", &juror_did, &commitment)
+            .unwrap();
+        pool.advance_session_state("dispute-123").unwrap(); // Reveal
+
+        // Revealing a different ruling than committed must fail
+        let result = pool.reveal_vote(
+            "dispute-123",
+            &juror_did,
+            Ruling::FavorProvider,
+            salt,
+            "changed my mind",
+        );
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("does not match commitment"));
+
+        // Wrong salt must fail as well
+        let result = pool.reveal_vote(
+            "dispute-123",
+            &juror_did,
+            Ruling::FavorClient,
+            B256::ZERO,
+            "reason",
+        );
+        assert!(result.is_err());
+
+        let session = pool.get_session("dispute-123").unwrap();
+        assert_eq!(session.revealed_vote_count(), 0);
+
+        // The honest reveal still succeeds
+        pool.reveal_vote(
+            "dispute-123",
+            &juror_did,
+            Ruling::FavorClient,
+            salt,
+            "reason",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_juror_pool_reveal_rejects_copied_commitment() {
+        let pool = JurorPool::disabled();
+
+        for i in 1..=5 {
+            pool.register_juror(format!("did:juror{}", i), 500_000_000, vec![0])
+                .unwrap();
+        }
+
+        pool.create_session("dispute-123", 0).unwrap();
+        let jurors = pool.get_session("dispute-123").unwrap().jurors;
+        pool.advance_session_state("dispute-123").unwrap(); // Commit
+
+        // Juror 1 copies juror 0's commitment verbatim
+        let salt = B256::repeat_byte(0x09);
+        let commitment =
+            JurorVote::compute_commitment("dispute-123", &jurors[0], Ruling::FavorClient, salt);
+        pool.commit_vote("dispute-123", &jurors[0], &commitment)
+            .unwrap();
+        pool.commit_vote("dispute-123", &jurors[1], &commitment)
+            .unwrap();
+        pool.advance_session_state("dispute-123").unwrap(); // Reveal
+
+        // Even knowing the ruling and salt, the copy is bound to juror 0's DID
+        let result = pool.reveal_vote(
+            "dispute-123",
+            &jurors[1],
+            Ruling::FavorClient,
+            salt,
+            "copied",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_juror_pool_commit_vote_wrong_state() {
         let pool = JurorPool::disabled();
@@ -4325,19 +5141,25 @@ mod tests {
         // Go through voting phases
         pool.advance_session_state("dispute-123").unwrap(); // Commit
 
-        for juror_did in &jurors {
-            pool.commit_vote("dispute-123", juror_did, "hash").unwrap();
+        // 2 vote FavorClient, 1 votes FavorProvider
+        let choices = [
+            Ruling::FavorClient,
+            Ruling::FavorClient,
+            Ruling::FavorProvider,
+        ];
+        let salt = B256::repeat_byte(0x11);
+        for (juror_did, choice) in jurors.iter().zip(choices) {
+            let commitment = JurorVote::compute_commitment("dispute-123", juror_did, choice, salt);
+            pool.commit_vote("dispute-123", juror_did, &commitment)
+                .unwrap();
         }
 
         pool.advance_session_state("dispute-123").unwrap(); // Reveal
 
-        // 2 vote FavorClient, 1 votes FavorProvider
-        pool.reveal_vote("dispute-123", &jurors[0], Ruling::FavorClient, "reason")
-            .unwrap();
-        pool.reveal_vote("dispute-123", &jurors[1], Ruling::FavorClient, "reason")
-            .unwrap();
-        pool.reveal_vote("dispute-123", &jurors[2], Ruling::FavorProvider, "reason")
-            .unwrap();
+        for (juror_did, choice) in jurors.iter().zip(choices) {
+            pool.reveal_vote("dispute-123", juror_did, choice, salt, "reason")
+                .unwrap();
+        }
 
         pool.advance_session_state("dispute-123").unwrap(); // Completed
 
@@ -4354,6 +5176,56 @@ mod tests {
         assert!(results.get(&jurors[2]).unwrap() < &0);
     }
 
+    #[test]
+    fn test_juror_pool_finalize_penalizes_non_revealers() {
+        let pool = JurorPool::disabled();
+
+        for i in 1..=5 {
+            pool.register_juror(format!("did:juror{}", i), 500_000_000, vec![0])
+                .unwrap();
+        }
+
+        pool.create_session("dispute-123", 0).unwrap();
+        let jurors = pool.get_session("dispute-123").unwrap().jurors;
+        pool.advance_session_state("dispute-123").unwrap(); // Commit
+
+        // All three commit, but only two reveal
+        let salt = B256::repeat_byte(0x22);
+        for juror_did in &jurors {
+            let commitment =
+                JurorVote::compute_commitment("dispute-123", juror_did, Ruling::FavorClient, salt);
+            pool.commit_vote("dispute-123", juror_did, &commitment)
+                .unwrap();
+        }
+        pool.advance_session_state("dispute-123").unwrap(); // Reveal
+        for juror_did in &jurors[..2] {
+            pool.reveal_vote(
+                "dispute-123",
+                juror_did,
+                Ruling::FavorClient,
+                salt,
+                "reason",
+            )
+            .unwrap();
+        }
+        pool.advance_session_state("dispute-123").unwrap(); // Completed
+
+        let results = pool.finalize_session("dispute-123").unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results[&jurors[0]] > 0);
+        assert!(results[&jurors[1]] > 0);
+        assert!(
+            results[&jurors[2]] < 0,
+            "Non-revealing juror should be slashed"
+        );
+
+        let silent = pool.get_juror(&jurors[2]).unwrap();
+        assert_eq!(silent.cases_total, 1);
+        assert_eq!(silent.cases_coherent, 0);
+        assert!(silent.reputation < 1.0);
+        assert_eq!(silent.status, JurorStatus::Active);
+    }
+
     #[test]
     fn test_juror_pool_get_jurors_by_court() {
         let pool = JurorPool::disabled();
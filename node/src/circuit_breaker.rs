@@ -20,10 +20,17 @@
 //! }).await;
 //! ```
 
+use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use std::time::{Duration, Instant};
 
+use lru::LruCache;
+
+/// Default number of keys a [`ResilientCircuitBreaker`] keeps a last
+/// known good value for.
+pub const DEFAULT_MAX_KEYS: usize = 10_000;
+
 /// Circuit breaker state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CircuitState {
@@ -461,6 +468,10 @@ pub struct ResilientCircuitBreaker<T: Clone> {
     breaker: CircuitBreaker,
     strategy: DegradationStrategy<T>,
     last_good: RwLock<Option<T>>,
+    /// Least recently used keys are evicted first; entries also expire
+    /// after `last_good_ttl`.
+    last_good_by_key: Mutex<LruCache<String, (T, Instant)>>,
+    last_good_ttl: Option<Duration>,
 }
 
 impl<T: Clone> ResilientCircuitBreaker<T> {
@@ -470,9 +481,23 @@ impl<T: Clone> ResilientCircuitBreaker<T> {
             breaker: CircuitBreaker::new(config),
             strategy,
             last_good: RwLock::new(None),
+            last_good_by_key: Mutex::new(LruCache::new(
+                NonZeroUsize::new(DEFAULT_MAX_KEYS).unwrap_or(NonZeroUsize::MIN),
+            )),
+            last_good_ttl: None,
         }
     }
 
+    /// Bound the per-key last known good values to `max_keys` entries,
+    /// each kept for at most `ttl` (`None` keeps them until evicted).
+    pub fn with_key_limits(mut self, max_keys: usize, ttl: Option<Duration>) -> Self {
+        self.last_good_by_key = Mutex::new(LruCache::new(
+            NonZeroUsize::new(max_keys).unwrap_or(NonZeroUsize::MIN),
+        ));
+        self.last_good_ttl = ttl;
+        self
+    }
+
     /// Create with static fallback value.
     pub fn with_fallback(config: CircuitBreakerConfig, fallback: T) -> Self {
         Self::new(config, DegradationStrategy::StaticFallback(fallback))
@@ -525,17 +550,54 @@ impl<T: Clone> ResilientCircuitBreaker<T> {
         }
     }
 
+    /// Execute with graceful degradation, keeping a last known good value
+    /// per `key`.
+    ///
+    /// Use this when one breaker protects a service queried for many
+    /// different keys (e.g. one RPC endpoint queried per DID), so an open
+    /// circuit falls back to the value last returned for the same key.
+    pub async fn call_with_fallback_for<F, Fut, E>(
+        &self,
+        key: &str,
+        f: F,
+    ) -> Result<DegradedResult<T>, CircuitError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        match self.breaker.call(f).await {
+            Ok(value) => {
+                self.set_last_known_good_for(key, value.clone());
+                Ok(DegradedResult::ok(value))
+            }
+            Err(CircuitError::Open(open_err)) => self.apply_degradation_with(
+                &format!("Circuit open: {}", open_err),
+                self.get_last_known_good_for(key),
+            ),
+            Err(CircuitError::Service(e)) => Err(CircuitError::Service(e)),
+        }
+    }
+
     /// Apply the degradation strategy.
     fn apply_degradation<E>(&self, reason: &str) -> Result<DegradedResult<T>, CircuitError<E>> {
+        self.apply_degradation_with(reason, self.get_last_known_good())
+    }
+
+    /// Apply the degradation strategy with `last_good` as the last known
+    /// good value.
+    fn apply_degradation_with<E>(
+        &self,
+        reason: &str,
+        last_good: Option<T>,
+    ) -> Result<DegradedResult<T>, CircuitError<E>> {
         match &self.strategy {
             DegradationStrategy::StaticFallback(value) => {
                 Ok(DegradedResult::degraded(value.clone(), reason))
             }
             DegradationStrategy::LastKnownGood => {
-                if let Ok(guard) = self.last_good.read() {
-                    if let Some(value) = guard.as_ref() {
-                        return Ok(DegradedResult::degraded(value.clone(), reason));
-                    }
+                if let Some(value) = last_good {
+                    return Ok(DegradedResult::degraded(value, reason));
                 }
                 // No last known good - cannot degrade
                 Err(CircuitError::Open(CircuitOpenError {
@@ -569,6 +631,27 @@ impl<T: Clone> ResilientCircuitBreaker<T> {
     pub fn get_last_known_good(&self) -> Option<T> {
         self.last_good.read().ok().and_then(|g| g.clone())
     }
+
+    /// Manually set the last known good value for `key`.
+    pub fn set_last_known_good_for(&self, key: &str, value: T) {
+        if let Ok(mut guard) = self.last_good_by_key.lock() {
+            guard.put(key.to_string(), (value, Instant::now()));
+        }
+    }
+
+    /// Get the last known good value for `key` (if any and not expired).
+    pub fn get_last_known_good_for(&self, key: &str) -> Option<T> {
+        let mut guard = self.last_good_by_key.lock().ok()?;
+        let (value, stored_at) = guard.get(key)?;
+        if self
+            .last_good_ttl
+            .is_some_and(|ttl| stored_at.elapsed() > ttl)
+        {
+            guard.pop(key);
+            return None;
+        }
+        Some(value.clone())
+    }
 }
 
 // ========== TDD Tests ==========
@@ -1087,6 +1170,66 @@ mod tests {
         assert!(matches!(result, Err(CircuitError::Open(_))));
     }
 
+    #[tokio::test]
+    async fn test_resilient_breaker_keeps_last_known_good_per_key() {
+        let rb: ResilientCircuitBreaker<i32> =
+            ResilientCircuitBreaker::with_last_known_good(CircuitBreakerConfig::default());
+
+        // Successful calls store a value per key
+        let _ = rb
+            .call_with_fallback_for("a", || async { Ok::<_, &str>(1) })
+            .await;
+        let _ = rb
+            .call_with_fallback_for("b", || async { Ok::<_, &str>(2) })
+            .await;
+
+        // Force open
+        rb.breaker().force_open();
+
+        let a = rb
+            .call_with_fallback_for("a", || async { Ok::<_, &str>(42) })
+            .await
+            .unwrap();
+        assert_eq!(a.value, 1, "Should return the value last seen for 'a'");
+        assert!(a.degraded);
+
+        let c = rb
+            .call_with_fallback_for("c", || async { Ok::<_, &str>(42) })
+            .await;
+        assert!(matches!(c, Err(CircuitError::Open(_))));
+        assert!(rb.get_last_known_good().is_none());
+    }
+
+    #[test]
+    fn test_per_key_last_known_good_is_bounded() {
+        let rb: ResilientCircuitBreaker<i32> =
+            ResilientCircuitBreaker::with_last_known_good(CircuitBreakerConfig::default())
+                .with_key_limits(2, None);
+
+        rb.set_last_known_good_for("a", 1);
+        rb.set_last_known_good_for("b", 2);
+        assert_eq!(rb.get_last_known_good_for("a"), Some(1));
+        rb.set_last_known_good_for("c", 3);
+
+        // "b" was the least recently used
+        assert_eq!(rb.get_last_known_good_for("b"), None);
+        assert_eq!(rb.get_last_known_good_for("a"), Some(1));
+        assert_eq!(rb.get_last_known_good_for("c"), Some(3));
+    }
+
+    #[test]
+    fn test_per_key_last_known_good_expires() {
+        let rb: ResilientCircuitBreaker<i32> =
+            ResilientCircuitBreaker::with_last_known_good(CircuitBreakerConfig::default())
+                .with_key_limits(10, Some(Duration::from_millis(20)));
+
+        rb.set_last_known_good_for("a", 1);
+        assert_eq!(rb.get_last_known_good_for("a"), Some(1));
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(rb.get_last_known_good_for("a"), None);
+    }
+
     #[test]
     fn test_set_and_get_last_known_good() {
         let rb: ResilientCircuitBreaker<String> =
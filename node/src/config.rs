@@ -4,7 +4,15 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 use crate::error::{Error, Result};
+use crate::metrics::MetricsConfig;
+use crate::multichain::MultiChainConfig;
 use crate::persistence::PersistenceConfig;
+use crate::rate_limit::RateLimitConfig;
+use crate::reputation::ReputationMode;
+use crate::search::{HybridSearchConfig, VectorBackend, VectorIndexConfig};
+use crate::trust::EndorsementMode;
+use crate::trust_history::TrustHistoryConfig;
+use crate::trust_reconcile::{ReconcileConfig, ReconcilePolicy};
 
 /// Main configuration for an AgoraMesh node.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +39,22 @@ pub struct NodeConfig {
     /// Node info for capability card (optional).
     #[serde(default)]
     pub node_info: NodeInfoConfig,
+
+    /// API rate limiting.
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
+    /// Prometheus metrics.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Agent search.
+    #[serde(default)]
+    pub search: SearchConfig,
+
+    /// Additional chains consulted for trust.
+    #[serde(default)]
+    pub multichain: MultiChainConfig,
 }
 
 /// Identity configuration.
@@ -88,6 +112,74 @@ pub struct TrustConfig {
 
     /// Minimum stake amount in USDC (6 decimals).
     pub min_stake: u64,
+
+    /// Drop gossiped capability cards from DIDs that do not meet
+    /// `min_trust_score` (and `min_stake` when `require_stake` is set).
+    #[serde(default)]
+    pub gate_gossip: bool,
+
+    /// Require `POST /agents` to be a signed envelope from the registering
+    /// DID, which must meet the same requirements.
+    #[serde(default)]
+    pub authenticated_writes: bool,
+
+    /// DIDs endorsement hop distances are measured from.
+    ///
+    /// Empty means no endorser is reachable, so endorsements add nothing to
+    /// trust scores.
+    #[serde(default)]
+    pub roots: Vec<String>,
+
+    /// How the endorsements component of the trust score is computed
+    /// (`hopdecay` or `eigentrust`).
+    #[serde(default)]
+    pub endorsement_mode: EndorsementMode,
+
+    /// How the reputation component of the trust score is computed
+    /// (`weighted` or `count`).
+    #[serde(default)]
+    pub reputation_mode: ReputationMode,
+
+    /// Trust score history recording and retention.
+    #[serde(default)]
+    pub history: TrustHistoryConfig,
+
+    /// Reconciliation with the on-chain TrustRegistry.
+    #[serde(default)]
+    pub reconcile: ReconcileConfig,
+}
+
+impl Default for TrustConfig {
+    fn default() -> Self {
+        Self {
+            min_trust_score: 0.5,
+            require_stake: false,
+            min_stake: 0,
+            gate_gossip: false,
+            authenticated_writes: false,
+            roots: Vec::new(),
+            endorsement_mode: EndorsementMode::default(),
+            reputation_mode: ReputationMode::default(),
+            history: TrustHistoryConfig::default(),
+            reconcile: ReconcileConfig::default(),
+        }
+    }
+}
+
+/// Agent search configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// Enable semantic search (downloads ~90MB embedding model on first use).
+    #[serde(default)]
+    pub semantic: bool,
+
+    /// Hybrid (vector + keyword) ranking parameters.
+    #[serde(default)]
+    pub hybrid: HybridSearchConfig,
+
+    /// Vector index backend for card embeddings.
+    #[serde(default)]
+    pub index: VectorIndexConfig,
 }
 
 /// Node info configuration for capability card.
@@ -117,6 +209,33 @@ pub struct BlockchainConfig {
 
     /// Escrow contract address.
     pub escrow_address: Option<String>,
+
+    /// Follow TrustRegistry/escrow events and apply them to local state.
+    ///
+    /// Has no effect unless a contract address is configured.
+    #[serde(default = "default_listen_events")]
+    pub listen_events: bool,
+
+    /// Block the contracts were deployed at. Without a persisted event
+    /// cursor, contract history is backfilled from here; unset starts at the head.
+    #[serde(default)]
+    pub deploy_block: Option<u64>,
+
+    /// Blocks after which a contract event is considered final (0 = no reorg tracking).
+    #[serde(default = "default_confirmations")]
+    pub confirmations: u64,
+}
+
+/// Upper bound on `blockchain.confirmations`; every unconfirmed block's logs
+/// are kept in memory for reorg detection.
+pub const MAX_CONFIRMATIONS: u64 = 1_000;
+
+fn default_listen_events() -> bool {
+    true
+}
+
+fn default_confirmations() -> u64 {
+    12
 }
 
 impl Default for NodeConfig {
@@ -138,19 +257,22 @@ impl Default for NodeConfig {
                 trust_proxy: false,
                 admin_token: None,
             },
-            trust: TrustConfig {
-                min_trust_score: 0.5,
-                require_stake: false,
-                min_stake: 0,
-            },
+            trust: TrustConfig::default(),
             blockchain: BlockchainConfig {
                 chain_id: 84532, // Base Sepolia
                 rpc_url: "https://sepolia.base.org".to_string(),
                 trust_registry_address: None,
                 escrow_address: None,
+                listen_events: true,
+                deploy_block: None,
+                confirmations: default_confirmations(),
             },
             persistence: PersistenceConfig::default(),
             node_info: NodeInfoConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            metrics: MetricsConfig::default(),
+            search: SearchConfig::default(),
+            multichain: MultiChainConfig::default(),
         }
     }
 }
@@ -192,6 +314,369 @@ impl NodeConfig {
         let did = self.identity.did.as_ref()?;
         self.node_info.to_node_info(did)
     }
+
+    /// Validate the configuration, failing with every problem found.
+    pub fn validate(&self) -> Result<()> {
+        let problems = self.problems();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Config(problems.join("; ")))
+        }
+    }
+
+    /// Check every section and list all problems (empty if valid).
+    ///
+    /// Each entry names the offending key, e.g. `api.listen_address: ...`.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut report =
+            |key: &str, problem: String| problems.push(format!("{}: {}", key, problem));
+
+        // [identity]
+        if self.identity.key_file.trim().is_empty() {
+            report("identity.key_file", "must not be empty".to_string());
+        }
+        if let Some(ref did) = self.identity.did {
+            if let Err(e) = crate::did::DIDDocument::parse_did(did) {
+                report("identity.did", e.to_string());
+            }
+        }
+
+        // [network]
+        if self.network.listen_addresses.is_empty() {
+            report(
+                "network.listen_addresses",
+                "at least one address is required".to_string(),
+            );
+        }
+        for addr in &self.network.listen_addresses {
+            if addr.parse::<libp2p::Multiaddr>().is_err() {
+                report(
+                    "network.listen_addresses",
+                    format!("'{}' is not a multiaddr", addr),
+                );
+            }
+        }
+        for peer in &self.network.bootstrap_peers {
+            match peer.parse::<libp2p::Multiaddr>() {
+                Ok(addr)
+                    if !addr
+                        .iter()
+                        .any(|p| matches!(p, libp2p::multiaddr::Protocol::P2p(_))) =>
+                {
+                    report(
+                        "network.bootstrap_peers",
+                        format!("'{}' has no /p2p/ peer ID", peer),
+                    )
+                }
+                Ok(_) => {}
+                Err(_) => report(
+                    "network.bootstrap_peers",
+                    format!("'{}' is not a multiaddr", peer),
+                ),
+            }
+        }
+        if let Err(e) = crate::network::validate_network_config(&self.network) {
+            report("network", e.to_string());
+        }
+
+        // [api]
+        if self
+            .api
+            .listen_address
+            .parse::<std::net::SocketAddr>()
+            .is_err()
+        {
+            report(
+                "api.listen_address",
+                format!(
+                    "'{}' is not a host:port socket address",
+                    self.api.listen_address
+                ),
+            );
+        }
+        for origin in &self.api.cors_origins {
+            if origin != "*" && !is_http_url(origin) {
+                report(
+                    "api.cors_origins",
+                    format!("'{}' is not '*' or an http(s) origin", origin),
+                );
+            }
+        }
+
+        // [trust]
+        if !(0.0..=1.0).contains(&self.trust.min_trust_score) {
+            report(
+                "trust.min_trust_score",
+                format!("{} is outside 0.0-1.0", self.trust.min_trust_score),
+            );
+        }
+        if self.trust.require_stake && self.trust.min_stake == 0 {
+            report(
+                "trust.min_stake",
+                "must be greater than 0 when require_stake is set".to_string(),
+            );
+        }
+        if self.trust.history.enabled && self.trust.history.snapshot_interval_secs == 0 {
+            report(
+                "trust.history.snapshot_interval_secs",
+                "must be greater than 0".to_string(),
+            );
+        }
+        for root in &self.trust.roots {
+            if !root.starts_with("did:") {
+                report("trust.roots", format!("'{}' is not a DID", root));
+            }
+        }
+        if self.trust.reconcile.policy != ReconcilePolicy::Local {
+            let aggregated = self.multichain.aggregate_trust
+                && !self.multichain.chains_with_trust_registry().is_empty();
+            if self.blockchain.trust_registry_address.is_none() && !aggregated {
+                report(
+                    "trust.reconcile.policy",
+                    "requires blockchain.trust_registry_address or multichain.aggregate_trust"
+                        .to_string(),
+                );
+            }
+            if self.trust.reconcile.cache_ttl_secs == 0 {
+                report(
+                    "trust.reconcile.cache_ttl_secs",
+                    "must be greater than 0".to_string(),
+                );
+            }
+        }
+
+        // [blockchain]
+        if self.blockchain.chain_id == 0 {
+            report("blockchain.chain_id", "must not be 0".to_string());
+        }
+        if !is_rpc_url(&self.blockchain.rpc_url) {
+            report(
+                "blockchain.rpc_url",
+                format!(
+                    "'{}' is not an http(s) or ws(s) URL",
+                    self.blockchain.rpc_url
+                ),
+            );
+        }
+        for (key, address) in [
+            (
+                "blockchain.trust_registry_address",
+                &self.blockchain.trust_registry_address,
+            ),
+            ("blockchain.escrow_address", &self.blockchain.escrow_address),
+        ] {
+            if let Some(address) = address {
+                if address.parse::<alloy::primitives::Address>().is_err() {
+                    report(key, format!("'{}' is not an address", address));
+                }
+            }
+        }
+        if self.blockchain.deploy_block.is_some()
+            && self.blockchain.trust_registry_address.is_none()
+            && self.blockchain.escrow_address.is_none()
+        {
+            report(
+                "blockchain.deploy_block",
+                "is set but no contract address is configured".to_string(),
+            );
+        }
+        if self.blockchain.confirmations > MAX_CONFIRMATIONS {
+            report(
+                "blockchain.confirmations",
+                format!(
+                    "{} exceeds the maximum of {}",
+                    self.blockchain.confirmations, MAX_CONFIRMATIONS
+                ),
+            );
+        }
+
+        // [persistence]
+        if self.persistence.enabled && self.persistence.data_dir.trim().is_empty() {
+            report(
+                "persistence.data_dir",
+                "must not be empty when persistence is enabled".to_string(),
+            );
+        }
+
+        // [node_info]
+        if let Some(ref url) = self.node_info.url {
+            if !is_http_url(url) {
+                report("node_info.url", format!("'{}' is not an http(s) URL", url));
+            }
+        }
+
+        // [rate_limit]
+        if self.rate_limit.enabled {
+            if self.rate_limit.requests_per_second == 0 {
+                report(
+                    "rate_limit.requests_per_second",
+                    "must be greater than 0 when enabled".to_string(),
+                );
+            }
+            if self.rate_limit.burst_size == 0 {
+                report(
+                    "rate_limit.burst_size",
+                    "must be greater than 0 when enabled".to_string(),
+                );
+            }
+        }
+
+        // [metrics]
+        let prefix = &self.metrics.prefix;
+        let valid_prefix = prefix
+            .chars()
+            .enumerate()
+            .all(|(i, c)| c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()));
+        if prefix.is_empty() || !valid_prefix {
+            report(
+                "metrics.prefix",
+                format!("'{}' is not a valid Prometheus metric name prefix", prefix),
+            );
+        }
+
+        // [search]
+        let hybrid = &self.search.hybrid;
+        for (key, value) in [
+            ("search.hybrid.vector_weight", hybrid.vector_weight),
+            ("search.hybrid.keyword_weight", hybrid.keyword_weight),
+            ("search.hybrid.min_score", hybrid.min_score),
+            ("search.hybrid.trust_weight", hybrid.trust_weight),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                report(key, format!("{} is outside 0.0-1.0", value));
+            }
+        }
+        if hybrid.vector_weight + hybrid.keyword_weight <= 0.0 {
+            report(
+                "search.hybrid",
+                "vector_weight and keyword_weight are both 0".to_string(),
+            );
+        }
+        if hybrid.rrf_k <= 0.0 {
+            report("search.hybrid.rrf_k", "must be greater than 0".to_string());
+        }
+        if hybrid.max_results == 0 {
+            report(
+                "search.hybrid.max_results",
+                "must be greater than 0".to_string(),
+            );
+        }
+        if hybrid.vector_candidates == 0 {
+            report(
+                "search.hybrid.vector_candidates",
+                "must be greater than 0".to_string(),
+            );
+        }
+        let bm25 = &hybrid.bm25;
+        if bm25.k1 < 0.0 {
+            report("search.hybrid.bm25.k1", "must not be negative".to_string());
+        }
+        if !(0.0..=1.0).contains(&bm25.b) {
+            report(
+                "search.hybrid.bm25.b",
+                format!("{} is outside 0.0-1.0", bm25.b),
+            );
+        }
+        for (key, value) in [
+            ("search.hybrid.bm25.name_boost", bm25.name_boost),
+            ("search.hybrid.bm25.skill_boost", bm25.skill_boost),
+            (
+                "search.hybrid.bm25.description_boost",
+                bm25.description_boost,
+            ),
+        ] {
+            if value < 0.0 {
+                report(key, "must not be negative".to_string());
+            }
+        }
+        if bm25.name_boost + bm25.skill_boost + bm25.description_boost <= 0.0 {
+            report(
+                "search.hybrid.bm25",
+                "name_boost, skill_boost and description_boost are all 0".to_string(),
+            );
+        }
+        let index = &self.search.index;
+        match index.backend {
+            VectorBackend::Hnsw => {
+                if index.hnsw.m < 2 {
+                    report("search.index.hnsw.m", "must be at least 2".to_string());
+                }
+                for (key, value) in [
+                    (
+                        "search.index.hnsw.ef_construction",
+                        index.hnsw.ef_construction,
+                    ),
+                    ("search.index.hnsw.ef_search", index.hnsw.ef_search),
+                ] {
+                    if value == 0 {
+                        report(key, "must be greater than 0".to_string());
+                    }
+                }
+            }
+            VectorBackend::Qdrant => {
+                let url = &index.qdrant.url;
+                if !url.starts_with("http://") && !url.starts_with("https://") {
+                    report(
+                        "search.index.qdrant.url",
+                        format!("'{}' is not an http(s) URL", url),
+                    );
+                }
+                if index.qdrant.collection.trim().is_empty() {
+                    report(
+                        "search.index.qdrant.collection",
+                        "must not be empty".to_string(),
+                    );
+                }
+            }
+        }
+
+        // [multichain]
+        let mut seen = std::collections::HashSet::new();
+        for chain in &self.multichain.chains {
+            let key = format!("multichain.chains[{}]", chain.chain_id);
+            if !seen.insert(chain.chain_id) {
+                report(&key, "configured more than once".to_string());
+            }
+            match chain.effective_rpc_url() {
+                Some(url) if !is_rpc_url(&url) => report(
+                    &key,
+                    format!("rpc_url '{}' is not an http(s) or ws(s) URL", url),
+                ),
+                Some(_) => {}
+                None => report(&key, "unknown chain requires an rpc_url".to_string()),
+            }
+            for (field, address) in [
+                ("trust_registry_address", &chain.trust_registry_address),
+                ("escrow_address", &chain.escrow_address),
+            ] {
+                if let Some(address) = address {
+                    if address.parse::<alloy::primitives::Address>().is_err() {
+                        report(&key, format!("{} '{}' is not an address", field, address));
+                    }
+                }
+            }
+        }
+        if let Some(primary) = self.multichain.primary_chain_id {
+            if !seen.contains(&primary) {
+                report(
+                    "multichain.primary_chain_id",
+                    format!("chain {} is not listed in multichain.chains", primary),
+                );
+            }
+        }
+
+        problems
+    }
+}
+
+fn is_http_url(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+fn is_rpc_url(url: &str) -> bool {
+    is_http_url(url) || url.starts_with("ws://") || url.starts_with("wss://")
 }
 
 #[cfg(test)]
@@ -336,4 +821,283 @@ url = "https://loaded.example.com"
         assert!(config.node_info.description.is_none());
         assert!(config.node_info.url.is_none());
     }
+
+    // ========== TDD Tests: Service Sections ==========
+
+    #[test]
+    fn test_load_config_with_service_sections() {
+        // Arrange: only some keys set, the rest should take defaults
+        let toml_content = r#"
+[identity]
+key_file = "node.key"
+
+[network]
+listen_addresses = ["/ip4/0.0.0.0/tcp/9000"]
+bootstrap_peers = []
+max_connections = 50
+
+[api]
+listen_address = "0.0.0.0:8080"
+cors_enabled = true
+cors_origins = ["*"]
+
+[trust]
+min_trust_score = 0.7
+require_stake = true
+min_stake = 1000000
+endorsement_mode = "eigentrust"
+reputation_mode = "count"
+
+[blockchain]
+chain_id = 8453
+rpc_url = "https://mainnet.base.org"
+
+[rate_limit]
+requests_per_second = 10
+
+[metrics]
+prefix = "mesh"
+
+[search]
+semantic = true
+
+[search.hybrid]
+vector_weight = 0.5
+
+[[multichain.chains]]
+chain_id = 137
+"#;
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(toml_content.as_bytes()).unwrap();
+
+        // Act
+        let config = NodeConfig::load(temp_file.path()).unwrap();
+
+        // Assert
+        assert_eq!(config.rate_limit.requests_per_second, 10);
+        assert_eq!(
+            config.rate_limit.burst_size,
+            RateLimitConfig::default().burst_size
+        );
+        assert_eq!(config.trust.endorsement_mode, EndorsementMode::EigenTrust);
+        assert_eq!(config.trust.reputation_mode, ReputationMode::Count);
+        assert_eq!(config.metrics.prefix, "mesh");
+        assert!(config.search.semantic);
+        assert_eq!(config.search.hybrid.vector_weight, 0.5);
+        assert_eq!(config.search.hybrid.max_results, 20);
+        assert_eq!(config.multichain.chains[0].chain_id, 137);
+        assert!(config.multichain.chains[0].enabled);
+        assert!(config.problems().is_empty(), "{:?}", config.problems());
+    }
+
+    #[test]
+    fn test_config_round_trips_through_toml() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let mut config = NodeConfig::default();
+        config.search.semantic = true;
+        config.rate_limit.enabled = false;
+
+        config.save(temp_file.path()).unwrap();
+        let loaded = NodeConfig::load(temp_file.path()).unwrap();
+
+        assert!(loaded.search.semantic);
+        assert!(!loaded.rate_limit.enabled);
+    }
+
+    // ========== TDD Tests: Validation ==========
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert!(NodeConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        // Arrange
+        let mut config = NodeConfig::default();
+        config.api.listen_address = "not-an-address".to_string();
+        config.trust.min_trust_score = 1.5;
+        config.trust.roots = vec!["agoramesh-root".to_string()];
+        config.trust.history.snapshot_interval_secs = 0;
+        config.trust.reconcile.policy = ReconcilePolicy::OnChain;
+        config.trust.reconcile.cache_ttl_secs = 0;
+        config.blockchain.trust_registry_address = Some("0x123".to_string());
+        config.rate_limit.requests_per_second = 0;
+        config.search.hybrid.max_results = 0;
+        config.multichain.primary_chain_id = Some(137);
+
+        // Act
+        let problems = config.problems();
+
+        // Assert
+        for key in [
+            "api.listen_address",
+            "trust.min_trust_score",
+            "trust.roots",
+            "trust.history.snapshot_interval_secs",
+            "trust.reconcile.cache_ttl_secs",
+            "blockchain.trust_registry_address",
+            "rate_limit.requests_per_second",
+            "search.hybrid.max_results",
+            "multichain.primary_chain_id",
+        ] {
+            assert!(
+                problems.iter().any(|p| p.starts_with(key)),
+                "Expected a problem for {} in {:?}",
+                key,
+                problems
+            );
+        }
+        assert!(matches!(config.validate(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_search_index_defaults_to_hnsw_and_parses_qdrant() {
+        // Arrange
+        let toml_content = r#"
+[index]
+backend = "qdrant"
+
+[index.qdrant]
+url = "http://qdrant:6334"
+"#;
+
+        // Act
+        let config = NodeConfig {
+            search: toml::from_str(toml_content).unwrap(),
+            ..Default::default()
+        };
+
+        // Assert
+        assert_eq!(
+            NodeConfig::default().search.index.backend,
+            VectorBackend::Hnsw
+        );
+        assert_eq!(config.search.index.backend, VectorBackend::Qdrant);
+        assert_eq!(config.search.index.qdrant.url, "http://qdrant:6334");
+        assert_eq!(config.search.index.qdrant.collection, "agoramesh_agents");
+        assert!(config.problems().is_empty(), "{:?}", config.problems());
+    }
+
+    #[test]
+    fn test_blockchain_listen_events_defaults_on() {
+        let toml_content = r#"
+chain_id = 8453
+rpc_url = "https://mainnet.base.org"
+trust_registry_address = "0x1234567890123456789012345678901234567890"
+"#;
+
+        let blockchain: BlockchainConfig = toml::from_str(toml_content).unwrap();
+        assert!(blockchain.listen_events);
+        assert_eq!(blockchain.deploy_block, None);
+        assert_eq!(blockchain.confirmations, 12);
+        assert!(NodeConfig::default().blockchain.listen_events);
+
+        let blockchain: BlockchainConfig =
+            toml::from_str(&format!("{}listen_events = false\n", toml_content)).unwrap();
+        assert!(!blockchain.listen_events);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_event_settings() {
+        let mut config = NodeConfig::default();
+        config.blockchain.deploy_block = Some(12_000_000);
+        config.blockchain.confirmations = MAX_CONFIRMATIONS + 1;
+
+        let problems = config.problems();
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("blockchain.deploy_block")));
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("blockchain.confirmations")));
+
+        config.blockchain.trust_registry_address =
+            Some("0x1234567890123456789012345678901234567890".to_string());
+        config.blockchain.confirmations = 64;
+        assert!(config.problems().is_empty(), "{:?}", config.problems());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_bm25_parameters() {
+        let mut config = NodeConfig::default();
+        config.search.hybrid.bm25.k1 = -1.0;
+        config.search.hybrid.bm25.b = 1.5;
+        config.search.hybrid.bm25.skill_boost = -2.0;
+
+        let problems = config.problems();
+
+        for key in [
+            "search.hybrid.bm25.k1",
+            "search.hybrid.bm25.b",
+            "search.hybrid.bm25.skill_boost",
+        ] {
+            assert!(
+                problems.iter().any(|p| p.starts_with(key)),
+                "{:?}",
+                problems
+            );
+        }
+
+        let mut config = NodeConfig::default();
+        config.search.hybrid.bm25.name_boost = 0.0;
+        config.search.hybrid.bm25.skill_boost = 0.0;
+        config.search.hybrid.bm25.description_boost = 0.0;
+
+        assert!(config
+            .problems()
+            .iter()
+            .any(|p| p.starts_with("search.hybrid.bm25:")));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_search_index() {
+        let mut config = NodeConfig::default();
+        config.search.hybrid.vector_candidates = 0;
+        config.search.index.hnsw.m = 1;
+        config.search.index.hnsw.ef_search = 0;
+
+        let problems = config.problems();
+
+        for key in [
+            "search.hybrid.vector_candidates",
+            "search.index.hnsw.m",
+            "search.index.hnsw.ef_search",
+        ] {
+            assert!(
+                problems.iter().any(|p| p.starts_with(key)),
+                "{:?}",
+                problems
+            );
+        }
+
+        config.search.index.backend = VectorBackend::Qdrant;
+        config.search.index.qdrant.url = "qdrant:6334".to_string();
+        config.search.index.qdrant.collection = String::new();
+        let problems = config.problems();
+
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("search.index.qdrant.url")));
+        assert!(problems
+            .iter()
+            .any(|p| p.starts_with("search.index.qdrant.collection")));
+        assert!(!problems.iter().any(|p| p.starts_with("search.index.hnsw")));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_multichain_entries() {
+        let mut config = NodeConfig::default();
+        let mut unknown = crate::multichain::ChainConfig::new(999_999);
+        unknown.trust_registry_address = Some("nope".to_string());
+        config.multichain.chains = vec![unknown.clone(), unknown];
+
+        let problems = config.problems();
+
+        assert!(problems.iter().any(|p| p.contains("more than once")));
+        assert!(problems.iter().any(|p| p.contains("requires an rpc_url")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("trust_registry_address 'nope'")));
+    }
 }
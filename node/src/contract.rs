@@ -6,6 +6,7 @@
 use alloy::primitives::{Address, FixedBytes};
 use alloy::providers::ProviderBuilder;
 use alloy::sol;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
 
@@ -61,6 +62,52 @@ sol!(
             ],
             "stateMutability": "view"
         },
+        {
+            "type": "function",
+            "name": "getAgent",
+            "inputs": [
+                {"name": "didHash", "type": "bytes32"}
+            ],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "tuple",
+                    "internalType": "struct ITrustRegistry.AgentInfo",
+                    "components": [
+                        {"name": "didHash", "type": "bytes32"},
+                        {"name": "owner", "type": "address"},
+                        {"name": "capabilityCardCID", "type": "string"},
+                        {"name": "registeredAt", "type": "uint256"},
+                        {"name": "isActive", "type": "bool"}
+                    ]
+                }
+            ],
+            "stateMutability": "view"
+        },
+        {
+            "type": "function",
+            "name": "getTrustData",
+            "inputs": [
+                {"name": "didHash", "type": "bytes32"}
+            ],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "tuple",
+                    "internalType": "struct ITrustRegistry.TrustData",
+                    "components": [
+                        {"name": "reputationScore", "type": "uint256"},
+                        {"name": "totalTransactions", "type": "uint256"},
+                        {"name": "successfulTransactions", "type": "uint256"},
+                        {"name": "totalVolumeUsd", "type": "uint256"},
+                        {"name": "lastActivityTimestamp", "type": "uint256"},
+                        {"name": "stakedAmount", "type": "uint256"},
+                        {"name": "stakeUnlockTime", "type": "uint256"}
+                    ]
+                }
+            ],
+            "stateMutability": "view"
+        },
         {
             "type": "function",
             "name": "getReputation",
@@ -78,7 +125,7 @@ sol!(
 );
 
 /// Trust score details from the contract.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OnChainTrustDetails {
     /// Reputation component (0-10000).
     pub reputation_score: u64,
@@ -90,6 +137,38 @@ pub struct OnChainTrustDetails {
     pub composite_score: u64,
 }
 
+/// Raw trust data stored for an agent by the contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnChainTrustData {
+    /// Reputation score (0-10000).
+    pub reputation_score: u64,
+    /// Total number of transactions.
+    pub total_transactions: u64,
+    /// Number of successful transactions.
+    pub successful_transactions: u64,
+    /// Total transaction volume in cents.
+    pub total_volume_usd: u64,
+    /// Last activity (Unix seconds, 0 if never active).
+    pub last_activity_timestamp: u64,
+    /// Staked USDC (6 decimals).
+    pub staked_amount: u64,
+    /// When stake can be withdrawn (0 if no withdrawal was requested).
+    pub stake_unlock_time: u64,
+}
+
+/// Agent registration record from the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnChainAgent {
+    /// Address that owns and manages the agent.
+    pub owner: Address,
+    /// IPFS CID of the agent's capability card.
+    pub capability_card_cid: String,
+    /// Registration time (Unix seconds).
+    pub registered_at: u64,
+    /// Whether the agent is currently active.
+    pub is_active: bool,
+}
+
 /// Client for interacting with TrustRegistry contract.
 pub struct TrustRegistryClient {
     rpc_url: String,
@@ -185,6 +264,81 @@ impl TrustRegistryClient {
         })
     }
 
+    /// Get the raw trust data for an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `did` - Agent's DID string
+    ///
+    /// # Returns
+    ///
+    /// Transaction counts, volume, stake and last activity (all zero if the
+    /// DID was never registered).
+    pub async fn get_trust_data(&self, did: &str) -> Result<OnChainTrustData> {
+        let provider = ProviderBuilder::new().connect_http(
+            self.rpc_url
+                .parse()
+                .map_err(|e| Error::Network(format!("Invalid RPC URL: {}", e)))?,
+        );
+
+        let contract = TrustRegistry::new(self.contract_address, provider);
+        let did_hash = Self::did_to_hash(did);
+
+        let data = contract
+            .getTrustData(did_hash)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(format!("Failed to get trust data: {}", e)))?;
+
+        Ok(OnChainTrustData {
+            reputation_score: data.reputationScore.try_into().unwrap_or(0),
+            total_transactions: data.totalTransactions.try_into().unwrap_or(0),
+            successful_transactions: data.successfulTransactions.try_into().unwrap_or(0),
+            total_volume_usd: data.totalVolumeUsd.try_into().unwrap_or(u64::MAX),
+            last_activity_timestamp: data.lastActivityTimestamp.try_into().unwrap_or(0),
+            staked_amount: data.stakedAmount.try_into().unwrap_or(u64::MAX),
+            stake_unlock_time: data.stakeUnlockTime.try_into().unwrap_or(0),
+        })
+    }
+
+    /// Get the registration record for an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `did` - Agent's DID string
+    ///
+    /// # Returns
+    ///
+    /// The record, or `None` if the DID was never registered.
+    pub async fn get_agent(&self, did: &str) -> Result<Option<OnChainAgent>> {
+        let provider = ProviderBuilder::new().connect_http(
+            self.rpc_url
+                .parse()
+                .map_err(|e| Error::Network(format!("Invalid RPC URL: {}", e)))?,
+        );
+
+        let contract = TrustRegistry::new(self.contract_address, provider);
+        let did_hash = Self::did_to_hash(did);
+
+        let info = contract
+            .getAgent(did_hash)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(format!("Failed to get agent: {}", e)))?;
+
+        // Unregistered DIDs come back as a zeroed struct
+        if info.registeredAt.is_zero() {
+            return Ok(None);
+        }
+
+        Ok(Some(OnChainAgent {
+            owner: info.owner,
+            capability_card_cid: info.capabilityCardCID,
+            registered_at: info.registeredAt.try_into().unwrap_or(u64::MAX),
+            is_active: info.isActive,
+        }))
+    }
+
     /// Get reputation data for an agent.
     ///
     /// # Arguments
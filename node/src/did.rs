@@ -2,7 +2,7 @@
 //!
 //! This module provides:
 //! - DID Document creation and validation
-//! - DID resolution via DHT
+//! - DID resolution results (resolvers live in [`crate::did_resolver`])
 //! - Verification method management
 
 use serde::{Deserialize, Serialize};
@@ -495,24 +495,55 @@ impl DIDResolutionResult {
 
     /// Create a not found error result.
     pub fn not_found(did: &str) -> Self {
-        Self {
-            did_document: None,
-            did_resolution_metadata: DIDResolutionMetadata {
-                content_type: None,
-                error: Some("notFound".to_string()),
-                message: Some(format!("DID '{}' not found", did)),
-            },
-            did_document_metadata: None,
-        }
+        Self::error("notFound", &format!("DID '{}' not found", did))
     }
 
     /// Create an invalid DID error result.
     pub fn invalid_did(message: &str) -> Self {
+        Self::error("invalidDid", message)
+    }
+
+    /// Create a result for a DID that resolved but has been deactivated.
+    pub fn deactivated(document: DIDDocument) -> Self {
+        let mut result = Self::success(document);
+        if let Some(ref mut metadata) = result.did_document_metadata {
+            metadata.deactivated = Some(true);
+        }
+        result
+    }
+
+    /// Create an error result for a DID method this node cannot resolve.
+    pub fn method_not_supported(method: &str) -> Self {
+        Self::error(
+            "methodNotSupported",
+            &format!("DID method '{}' is not supported", method),
+        )
+    }
+
+    /// Create an error result for a resolver failure (network, contract, ...).
+    pub fn internal_error(message: &str) -> Self {
+        Self::error("internalError", message)
+    }
+
+    /// The resolution error code, if resolution failed.
+    pub fn error_code(&self) -> Option<&str> {
+        self.did_resolution_metadata.error.as_deref()
+    }
+
+    /// Whether the resolved DID has been deactivated.
+    pub fn is_deactivated(&self) -> bool {
+        self.did_document_metadata
+            .as_ref()
+            .and_then(|m| m.deactivated)
+            .unwrap_or(false)
+    }
+
+    fn error(code: &str, message: &str) -> Self {
         Self {
             did_document: None,
             did_resolution_metadata: DIDResolutionMetadata {
                 content_type: None,
-                error: Some("invalidDid".to_string()),
+                error: Some(code.to_string()),
                 message: Some(message.to_string()),
             },
             did_document_metadata: None,
@@ -846,4 +877,22 @@ mod tests {
             Some("invalidDid".to_string())
         );
     }
+
+    #[test]
+    fn test_resolution_deactivated() {
+        let doc = DIDDocumentBuilder::new("base", "agent123").build().unwrap();
+        let result = DIDResolutionResult::deactivated(doc);
+
+        assert!(result.did_document.is_some());
+        assert!(result.is_deactivated());
+        assert_eq!(result.error_code(), None);
+    }
+
+    #[test]
+    fn test_resolution_method_not_supported() {
+        let result = DIDResolutionResult::method_not_supported("ion");
+
+        assert!(result.did_document.is_none());
+        assert_eq!(result.error_code(), Some("methodNotSupported"));
+    }
 }
@@ -0,0 +1,1042 @@
+//! DID resolution for `did:agoramesh`, `did:key` and `did:web`.
+//!
+//! Each DID method has a [`DidResolver`]. [`UniversalResolver`] dispatches on
+//! the method name and caches successful results for a TTL.
+//!
+//! - `did:agoramesh` documents are published to the DHT as a
+//!   [`SignedDidRecord`] and only accepted when signed by the owner the
+//!   TrustRegistry has on record for the DID
+//! - `did:agoramesh` node DIDs (`did:agoramesh:{chain}:{peer_id}`) are
+//!   derived from the Ed25519 key embedded in the PeerId
+//! - `did:key` documents are derived from the identifier (Ed25519 only)
+//! - `did:web` documents are fetched over HTTPS through a [`DidWebFetcher`]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use alloy::primitives::{Address, Signature};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::SignerSync;
+use alloy::transports::http::reqwest;
+use async_trait::async_trait;
+use libp2p::identity::{ed25519, PublicKey};
+use libp2p::PeerId;
+use moka::future::Cache;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::contract::{OnChainAgent, TrustRegistryClient};
+use crate::did::{DIDDocument, DIDDocumentBuilder, DIDResolutionResult, VerificationMethod};
+use crate::error::{Error, Result};
+use crate::identity::NODE_KEY_FRAGMENT;
+use crate::network::envelope::{
+    decode_ed25519_multibase, encode_ed25519_multibase, DidDocumentSource, ED25519_METHOD_TYPE,
+};
+use crate::network::SwarmCommand;
+
+/// Default time successful resolutions stay cached.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Default maximum number of cached resolutions.
+pub const DEFAULT_CACHE_CAPACITY: u64 = 10_000;
+
+/// Default timeout for DHT lookups.
+pub const DEFAULT_DHT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default timeout for `did:web` fetches.
+pub const DEFAULT_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum accepted size of a fetched DID document (64KB).
+pub const MAX_DID_DOCUMENT_BYTES: usize = 64 * 1024;
+
+/// DHT key prefix for `did:agoramesh` document records.
+pub const DID_RECORD_PREFIX: &str = "/agoramesh/did/";
+
+const DID_CORE_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const ED25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2020/v1";
+
+/// Resolves DIDs of one method to W3C resolution results.
+#[async_trait]
+pub trait DidResolver: Send + Sync {
+    /// Resolve `did`.
+    ///
+    /// Resolution outcomes (`notFound`, `invalidDid`, ...) are reported in
+    /// the result; `Err` is reserved for failures reaching the source.
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult>;
+}
+
+/// Extract the method name from a DID (`did:{method}:{id}`).
+pub fn did_method(did: &str) -> Option<&str> {
+    let (method, id) = did.strip_prefix("did:")?.split_once(':')?;
+    (!method.is_empty() && !id.is_empty()).then_some(method)
+}
+
+// ========== did:key ==========
+
+/// Resolver for `did:key` identifiers holding an Ed25519 public key.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyDidResolver;
+
+impl KeyDidResolver {
+    /// The `did:key` identifier for an Ed25519 public key.
+    pub fn did_for(public_key: &ed25519::PublicKey) -> String {
+        format!("did:key:{}", encode_ed25519_multibase(public_key))
+    }
+
+    /// Expand a `did:key` identifier into its DID document.
+    pub fn document(did: &str) -> Result<DIDDocument> {
+        let multibase = did
+            .strip_prefix("did:key:")
+            .ok_or_else(|| Error::Did(format!("Not a did:key identifier: {}", did)))?;
+
+        // Only multicodec-prefixed Ed25519 keys (z6Mk...) are supported
+        let bytes = multibase
+            .strip_prefix('z')
+            .and_then(|encoded| bs58::decode(encoded).into_vec().ok())
+            .unwrap_or_default();
+        if bytes.len() != 34 || bytes[..2] != [0xed, 0x01] {
+            return Err(Error::Did(format!(
+                "Unsupported did:key {}: only Ed25519 keys are supported",
+                did
+            )));
+        }
+        decode_ed25519_multibase(multibase)?;
+
+        let key_id = format!("{}#{}", did, multibase);
+        Ok(DIDDocument {
+            context: vec![
+                DID_CORE_CONTEXT.to_string(),
+                ED25519_2020_CONTEXT.to_string(),
+            ],
+            id: did.to_string(),
+            controller: None,
+            verification_method: Some(vec![VerificationMethod {
+                id: key_id.clone(),
+                method_type: ED25519_METHOD_TYPE.to_string(),
+                controller: did.to_string(),
+                public_key_multibase: Some(multibase.to_string()),
+                public_key_jwk: None,
+                blockchain_account_id: None,
+            }]),
+            authentication: Some(vec![key_id.clone()]),
+            assertion_method: Some(vec![key_id]),
+            service: None,
+            metadata: None,
+        })
+    }
+}
+
+#[async_trait]
+impl DidResolver for KeyDidResolver {
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult> {
+        Ok(match Self::document(did) {
+            Ok(document) => DIDResolutionResult::success(document),
+            Err(e) => DIDResolutionResult::invalid_did(&e.to_string()),
+        })
+    }
+}
+
+// ========== did:web ==========
+
+/// HTTP transport for `did:web` documents.
+#[async_trait]
+pub trait DidWebFetcher: Send + Sync {
+    /// GET `url`, returning `None` on 404 Not Found.
+    async fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>>;
+}
+
+/// [`DidWebFetcher`] backed by an HTTP client.
+pub struct HttpDidWebFetcher {
+    client: reqwest::Client,
+}
+
+impl HttpDidWebFetcher {
+    /// Create a fetcher with the default timeout.
+    pub fn new() -> Result<Self> {
+        Self::with_timeout(DEFAULT_FETCH_TIMEOUT)
+    }
+
+    /// Create a fetcher with a custom request timeout.
+    pub fn with_timeout(timeout: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| Error::Network(format!("Failed to build HTTP client: {}", e)))?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl DidWebFetcher for HttpDidWebFetcher {
+    async fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>> {
+        let mut response = self
+            .client
+            .get(url)
+            .header("Accept", "application/did+json, application/json")
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to fetch {}: {}", url, e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(Error::Network(format!(
+                "Fetching {} returned {}",
+                url, status
+            )));
+        }
+        if response
+            .content_length()
+            .is_some_and(|len| len > MAX_DID_DOCUMENT_BYTES as u64)
+        {
+            return Err(Error::Network(format!(
+                "DID document at {} exceeds {} bytes",
+                url, MAX_DID_DOCUMENT_BYTES
+            )));
+        }
+
+        // Content-Length may be absent or wrong, so cap the body as it streams
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| Error::Network(format!("Failed to read {}: {}", url, e)))?
+        {
+            if body.len() + chunk.len() > MAX_DID_DOCUMENT_BYTES {
+                return Err(Error::Network(format!(
+                    "DID document at {} exceeds {} bytes",
+                    url, MAX_DID_DOCUMENT_BYTES
+                )));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(Some(body))
+    }
+}
+
+/// Resolver for `did:web` identifiers.
+pub struct WebDidResolver {
+    fetcher: Arc<dyn DidWebFetcher>,
+}
+
+impl WebDidResolver {
+    /// Create a resolver that fetches documents through `fetcher`.
+    pub fn new(fetcher: Arc<dyn DidWebFetcher>) -> Self {
+        Self { fetcher }
+    }
+
+    /// The HTTPS URL a `did:web` document is published at.
+    ///
+    /// `did:web:example.com` maps to `https://example.com/.well-known/did.json`,
+    /// `did:web:example.com%3A8443:users:alice` to
+    /// `https://example.com:8443/users/alice/did.json`.
+    pub fn url_for(did: &str) -> Result<String> {
+        let id = did
+            .strip_prefix("did:web:")
+            .ok_or_else(|| Error::Did(format!("Not a did:web identifier: {}", did)))?;
+
+        let mut segments = id.split(':').map(|segment| {
+            urlencoding::decode(segment)
+                .map(|s| s.into_owned())
+                .map_err(|e| Error::Did(format!("Invalid did:web segment '{}': {}", segment, e)))
+        });
+
+        let host = segments.next().transpose()?.unwrap_or_default();
+        if host.is_empty() || host.contains(['/', '?', '#', '@']) {
+            return Err(Error::Did(format!("Invalid did:web host in {}", did)));
+        }
+
+        let path = segments.collect::<Result<Vec<_>>>()?;
+        if path
+            .iter()
+            .any(|s| s.is_empty() || s.contains(['/', '?', '#']) || s == "..")
+        {
+            return Err(Error::Did(format!("Invalid did:web path in {}", did)));
+        }
+
+        Ok(if path.is_empty() {
+            format!("https://{}/.well-known/did.json", host)
+        } else {
+            format!("https://{}/{}/did.json", host, path.join("/"))
+        })
+    }
+}
+
+#[async_trait]
+impl DidResolver for WebDidResolver {
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult> {
+        let url = match Self::url_for(did) {
+            Ok(url) => url,
+            Err(e) => return Ok(DIDResolutionResult::invalid_did(&e.to_string())),
+        };
+
+        let Some(body) = self.fetcher.fetch(&url).await? else {
+            return Ok(DIDResolutionResult::not_found(did));
+        };
+
+        let document: DIDDocument = serde_json::from_slice(&body)
+            .map_err(|e| Error::Did(format!("Invalid DID document at {}: {}", url, e)))?;
+        if document.id != did {
+            return Err(Error::Did(format!(
+                "DID document at {} is for {}, expected {}",
+                url, document.id, did
+            )));
+        }
+
+        Ok(DIDResolutionResult::success(document))
+    }
+}
+
+// ========== did:agoramesh node DIDs ==========
+
+/// Resolver for node DIDs: `did:agoramesh:{chain}:{peer_id}`.
+///
+/// Ed25519 PeerIds inline the public key, so the document (listing it as
+/// `#key-1`) is derived from the identifier, like `did:key`. Nodes therefore
+/// need neither a TrustRegistry entry nor a DHT record to sign gossip.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PeerDidResolver;
+
+impl PeerDidResolver {
+    /// The PeerId of a node DID, if `did` is one.
+    pub fn peer_id(did: &str) -> Option<PeerId> {
+        let (_, _, identifier) = DIDDocument::parse_did(did).ok()?;
+        let peer_id: PeerId = identifier.parse().ok()?;
+        Self::public_key(&peer_id).map(|_| peer_id)
+    }
+
+    /// The Ed25519 key inlined in `peer_id` (hashed PeerIds carry none).
+    fn public_key(peer_id: &PeerId) -> Option<ed25519::PublicKey> {
+        let multihash = peer_id.as_ref();
+        if multihash.code() != 0 {
+            return None;
+        }
+        PublicKey::try_decode_protobuf(multihash.digest())
+            .ok()?
+            .try_into_ed25519()
+            .ok()
+    }
+
+    /// Derive the DID document of a node DID.
+    pub fn document(did: &str) -> Result<DIDDocument> {
+        let (_, chain, identifier) = DIDDocument::parse_did(did)?;
+        let public_key = identifier
+            .parse::<PeerId>()
+            .ok()
+            .as_ref()
+            .and_then(Self::public_key)
+            .ok_or_else(|| {
+                Error::Did(format!("{} is not a node DID with an Ed25519 PeerId", did))
+            })?;
+
+        DIDDocumentBuilder::new(&chain, &identifier)
+            .add_ed25519_key(NODE_KEY_FRAGMENT, &encode_ed25519_multibase(&public_key))
+            .build()
+    }
+}
+
+#[async_trait]
+impl DidResolver for PeerDidResolver {
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult> {
+        Ok(match Self::document(did) {
+            Ok(document) => DIDResolutionResult::success(document),
+            Err(e) => DIDResolutionResult::invalid_did(&e.to_string()),
+        })
+    }
+}
+
+// ========== did:agoramesh ==========
+
+/// DHT key under which the document for `did` is published.
+pub fn dht_key(did: &str) -> Vec<u8> {
+    format!("{}{}", DID_RECORD_PREFIX, did).into_bytes()
+}
+
+/// A `did:agoramesh` document as stored in the DHT, signed by the agent's
+/// on-chain owner.
+///
+/// DHT records are unauthenticated, so the signature is what ties the
+/// document to the TrustRegistry entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedDidRecord {
+    /// The DID document as JSON (signed verbatim).
+    pub document: String,
+    /// Hex-encoded EIP-191 signature over `document` by the owner key.
+    pub signature: String,
+}
+
+impl SignedDidRecord {
+    /// Sign a document with the owner key registered for its DID.
+    pub fn new(document: &DIDDocument, owner: &PrivateKeySigner) -> Result<Self> {
+        let document = document.to_json()?;
+        let signature = owner
+            .sign_message_sync(document.as_bytes())
+            .map_err(|e| Error::Did(format!("Failed to sign DID document: {}", e)))?;
+
+        Ok(Self {
+            document,
+            signature: alloy::hex::encode(signature.as_bytes()),
+        })
+    }
+
+    /// Parse a record from DHT bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bytes).map_err(|e| Error::Did(format!("Invalid DID record: {}", e)))
+    }
+
+    /// Serialize for storing in the DHT.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| Error::Did(format!("Failed to encode DID record: {}", e)))
+    }
+
+    /// Address that signed the record.
+    pub fn signer(&self) -> Result<Address> {
+        let signature = alloy::hex::decode(&self.signature)
+            .map_err(|e| Error::Did(format!("Invalid DID record signature: {}", e)))?;
+        Signature::from_raw(&signature)
+            .and_then(|signature| signature.recover_address_from_msg(self.document.as_bytes()))
+            .map_err(|e| Error::Did(format!("Invalid DID record signature: {}", e)))
+    }
+
+    /// The signed document.
+    pub fn document(&self) -> Result<DIDDocument> {
+        DIDDocument::from_json(&self.document)
+    }
+}
+
+/// On-chain agent registry consulted for `did:agoramesh` ownership.
+#[async_trait]
+pub trait AgentRegistry: Send + Sync {
+    /// Registration record for `did`, or `None` if never registered.
+    async fn agent(&self, did: &str) -> Result<Option<OnChainAgent>>;
+}
+
+#[async_trait]
+impl AgentRegistry for TrustRegistryClient {
+    async fn agent(&self, did: &str) -> Result<Option<OnChainAgent>> {
+        self.get_agent(did).await
+    }
+}
+
+/// Resolver for `did:agoramesh` identifiers.
+///
+/// Looks the DID up in the TrustRegistry, fetches the signed document from
+/// the DHT and checks that the registered owner signed it. Agents that are
+/// registered but inactive resolve as deactivated. Node DIDs are handed to
+/// [`PeerDidResolver`].
+pub struct AgoraMeshDidResolver {
+    network_tx: mpsc::Sender<SwarmCommand>,
+    registry: Arc<dyn AgentRegistry>,
+    dht_timeout: Duration,
+}
+
+impl AgoraMeshDidResolver {
+    /// Create a resolver using the swarm's command channel for DHT lookups.
+    pub fn new(network_tx: mpsc::Sender<SwarmCommand>, registry: Arc<dyn AgentRegistry>) -> Self {
+        Self {
+            network_tx,
+            registry,
+            dht_timeout: DEFAULT_DHT_TIMEOUT,
+        }
+    }
+
+    /// Set the DHT lookup timeout.
+    pub fn with_dht_timeout(mut self, timeout: Duration) -> Self {
+        self.dht_timeout = timeout;
+        self
+    }
+
+    async fn get_record(&self, did: &str) -> Result<Option<Vec<u8>>> {
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+
+        self.network_tx
+            .send(SwarmCommand::GetRecord {
+                key: dht_key(did),
+                response_tx,
+            })
+            .await
+            .map_err(|e| Error::Network(format!("Failed to send DHT get command: {}", e)))?;
+
+        match tokio::time::timeout(self.dht_timeout, response_rx).await {
+            Ok(Ok(record)) => Ok(record),
+            Ok(Err(_)) => Err(Error::Network("DHT query channel closed".to_string())),
+            Err(_) => Err(Error::Network(format!("DHT lookup for {} timed out", did))),
+        }
+    }
+}
+
+#[async_trait]
+impl DidResolver for AgoraMeshDidResolver {
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult> {
+        if let Err(e) = DIDDocument::parse_did(did) {
+            return Ok(DIDResolutionResult::invalid_did(&e.to_string()));
+        }
+        if PeerDidResolver::peer_id(did).is_some() {
+            return PeerDidResolver.resolve(did).await;
+        }
+
+        let Some(agent) = self.registry.agent(did).await? else {
+            return Ok(DIDResolutionResult::not_found(did));
+        };
+        let Some(bytes) = self.get_record(did).await? else {
+            return Ok(DIDResolutionResult::not_found(did));
+        };
+
+        let record = SignedDidRecord::from_bytes(&bytes)?;
+        let signer = record.signer()?;
+        if signer != agent.owner {
+            return Err(Error::Did(format!(
+                "DID document for {} is signed by {}, not its owner {}",
+                did, signer, agent.owner
+            )));
+        }
+
+        let document = record.document()?;
+        if document.id != did {
+            return Err(Error::Did(format!(
+                "DHT record for {} holds the document of {}",
+                did, document.id
+            )));
+        }
+        document.validate()?;
+
+        Ok(if agent.is_active {
+            DIDResolutionResult::success(document)
+        } else {
+            DIDResolutionResult::deactivated(document)
+        })
+    }
+}
+
+// ========== Dispatch and caching ==========
+
+/// Resolves any supported DID method, caching successful results.
+pub struct UniversalResolver {
+    resolvers: HashMap<String, Arc<dyn DidResolver>>,
+    cache: Cache<String, DIDResolutionResult>,
+}
+
+impl Default for UniversalResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UniversalResolver {
+    /// Create a resolver with no methods registered.
+    pub fn new() -> Self {
+        Self {
+            resolvers: HashMap::new(),
+            cache: Self::build_cache(DEFAULT_CACHE_TTL),
+        }
+    }
+
+    /// Create a resolver for `did:key` and `did:web` (over HTTPS).
+    ///
+    /// Agent `did:agoramesh` DIDs need the network and a TrustRegistry, so
+    /// [`AgoraMeshDidResolver`] is added separately with
+    /// [`UniversalResolver::with_method`]; without one, register
+    /// [`PeerDidResolver`] so node DIDs still resolve.
+    pub fn with_defaults() -> Result<Self> {
+        Ok(Self::new()
+            .with_method("key", Arc::new(KeyDidResolver))
+            .with_method(
+                "web",
+                Arc::new(WebDidResolver::new(Arc::new(HttpDidWebFetcher::new()?))),
+            ))
+    }
+
+    /// Register the resolver for a DID method (e.g. `"web"`).
+    pub fn with_method(mut self, method: &str, resolver: Arc<dyn DidResolver>) -> Self {
+        self.resolvers.insert(method.to_string(), resolver);
+        self
+    }
+
+    /// Set how long successful resolutions are cached (clears the cache).
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = Self::build_cache(ttl);
+        self
+    }
+
+    fn build_cache(ttl: Duration) -> Cache<String, DIDResolutionResult> {
+        Cache::builder()
+            .max_capacity(DEFAULT_CACHE_CAPACITY)
+            .time_to_live(ttl)
+            .build()
+    }
+
+    /// Registered DID methods, sorted.
+    pub fn methods(&self) -> Vec<&str> {
+        let mut methods: Vec<&str> = self.resolvers.keys().map(String::as_str).collect();
+        methods.sort_unstable();
+        methods
+    }
+
+    /// Drop a cached resolution (e.g. after the document changed).
+    pub async fn invalidate(&self, did: &str) {
+        self.cache.invalidate(did).await;
+    }
+
+    /// Resolve `did`, mapping resolver failures to an `internalError` result.
+    pub async fn resolve_did(&self, did: &str) -> DIDResolutionResult {
+        let Some(method) = did_method(did) else {
+            return DIDResolutionResult::invalid_did(&format!("Invalid DID: '{}'", did));
+        };
+
+        if let Some(cached) = self.cache.get(did).await {
+            return cached;
+        }
+
+        let Some(resolver) = self.resolvers.get(method) else {
+            return DIDResolutionResult::method_not_supported(method);
+        };
+
+        match resolver.resolve(did).await {
+            Ok(result) => {
+                // Only documents are cached so new DIDs become visible promptly
+                if result.did_document.is_some() {
+                    self.cache.insert(did.to_string(), result.clone()).await;
+                }
+                result
+            }
+            Err(e) => {
+                tracing::warn!("Failed to resolve {}: {}", did, e);
+                DIDResolutionResult::internal_error(&e.to_string())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DidResolver for UniversalResolver {
+    async fn resolve(&self, did: &str) -> Result<DIDResolutionResult> {
+        Ok(self.resolve_did(did).await)
+    }
+}
+
+/// Lets the gossip message handler verify envelopes against resolved documents.
+#[async_trait]
+impl DidDocumentSource for UniversalResolver {
+    async fn document(&self, did: &str) -> Result<Option<DIDDocument>> {
+        let result = self.resolve_did(did).await;
+        match result.error_code() {
+            None if result.is_deactivated() => Ok(None),
+            None => Ok(result.did_document),
+            Some("internalError") => Err(Error::Did(
+                result
+                    .did_resolution_metadata
+                    .message
+                    .unwrap_or_else(|| format!("Failed to resolve {}", did)),
+            )),
+            Some(_) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::NodeIdentity;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // ========== Test helpers ==========
+
+    /// Agent registry stand-in keyed by DID.
+    #[derive(Default)]
+    struct StaticRegistry {
+        agents: HashMap<String, OnChainAgent>,
+    }
+
+    impl StaticRegistry {
+        fn with_agent(mut self, did: &str, owner: Address, is_active: bool) -> Self {
+            self.agents.insert(
+                did.to_string(),
+                OnChainAgent {
+                    owner,
+                    capability_card_cid: "bafy-test".to_string(),
+                    registered_at: 1_700_000_000,
+                    is_active,
+                },
+            );
+            self
+        }
+    }
+
+    #[async_trait]
+    impl AgentRegistry for StaticRegistry {
+        async fn agent(&self, did: &str) -> Result<Option<OnChainAgent>> {
+            Ok(self.agents.get(did).cloned())
+        }
+    }
+
+    /// Answer DHT lookups from a fixed set of records.
+    fn spawn_dht(records: HashMap<Vec<u8>, Vec<u8>>) -> mpsc::Sender<SwarmCommand> {
+        let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
+        tokio::spawn(async move {
+            while let Some(command) = rx.recv().await {
+                if let SwarmCommand::GetRecord { key, response_tx } = command {
+                    let _ = response_tx.send(records.get(&key).cloned());
+                }
+            }
+        });
+        tx
+    }
+
+    fn agent_document(identifier: &str) -> DIDDocument {
+        DIDDocumentBuilder::new("base", identifier)
+            .add_ed25519_key(
+                "key-1",
+                &encode_ed25519_multibase(&ed25519::Keypair::generate().public()),
+            )
+            .add_a2a_service("https://agent.example.com/a2a")
+            .build()
+            .unwrap()
+    }
+
+    /// Serve a did.json for `did:web:127.0.0.1%3A{port}{path}` on localhost.
+    ///
+    /// The DID embeds the port, so the listener is bound before the document
+    /// is built; `document_id` maps the host's DID to the id it serves.
+    async fn spawn_web_host(path: &str, document_id: impl FnOnce(&str) -> String) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let did = format!("did:web:127.0.0.1%3A{}{}", port, path.replace('/', ":"));
+
+        let id = document_id(&did);
+        let body = serde_json::json!({
+            "@context": [DID_CORE_CONTEXT],
+            "id": id,
+            "verificationMethod": [{
+                "id": format!("{}#key-1", id),
+                "type": ED25519_METHOD_TYPE,
+                "controller": id,
+                "publicKeyMultibase": encode_ed25519_multibase(&ed25519::Keypair::generate().public()),
+            }]
+        })
+        .to_string();
+        let route = if path.is_empty() {
+            "/.well-known/did.json".to_string()
+        } else {
+            format!("{}/did.json", path)
+        };
+
+        let router =
+            axum::Router::new().route(&route, axum::routing::get(move || async move { body }));
+        tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+        did
+    }
+
+    /// Fetches `https://` URLs from the local stand-in over `http://`.
+    struct LocalFetcher {
+        inner: HttpDidWebFetcher,
+        requests: AtomicUsize,
+    }
+
+    impl LocalFetcher {
+        fn new() -> Self {
+            Self {
+                inner: HttpDidWebFetcher::new().unwrap(),
+                requests: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DidWebFetcher for LocalFetcher {
+        async fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            let url = url.replacen("https://", "http://", 1);
+            self.inner.fetch(&url).await
+        }
+    }
+
+    // ========== TDD Tests: did:key ==========
+
+    #[tokio::test]
+    async fn test_did_key_resolves_to_ed25519_document() {
+        let keypair = ed25519::Keypair::generate();
+        let did = KeyDidResolver::did_for(&keypair.public());
+
+        let result = KeyDidResolver.resolve(&did).await.unwrap();
+
+        let document = result.did_document.expect("did:key should resolve");
+        assert_eq!(document.id, did);
+        let method = &document.verification_method.unwrap()[0];
+        assert_eq!(method.method_type, ED25519_METHOD_TYPE);
+        assert_eq!(
+            decode_ed25519_multibase(method.public_key_multibase.as_deref().unwrap()).unwrap(),
+            keypair.public()
+        );
+        assert_eq!(document.authentication, Some(vec![method.id.clone()]));
+    }
+
+    #[tokio::test]
+    async fn test_did_key_rejects_non_ed25519_keys() {
+        // secp256k1 did:key (multicodec 0xe7) is not supported
+        let result = KeyDidResolver
+            .resolve("did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme")
+            .await
+            .unwrap();
+
+        assert_eq!(result.error_code(), Some("invalidDid"));
+    }
+
+    // ========== TDD Tests: did:web ==========
+
+    #[test]
+    fn test_did_web_url_mapping() {
+        assert_eq!(
+            WebDidResolver::url_for("did:web:example.com").unwrap(),
+            "https://example.com/.well-known/did.json"
+        );
+        assert_eq!(
+            WebDidResolver::url_for("did:web:example.com%3A8443:users:alice").unwrap(),
+            "https://example.com:8443/users/alice/did.json"
+        );
+        assert!(WebDidResolver::url_for("did:web:evil.com%2Fpath").is_err());
+        assert!(WebDidResolver::url_for("did:web:example.com:..:admin").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_did_web_fetches_document_from_host() {
+        // Arrange
+        let did = spawn_web_host("/agents/alice", str::to_string).await;
+        let resolver = WebDidResolver::new(Arc::new(LocalFetcher::new()));
+
+        // Act
+        let result = resolver.resolve(&did).await.unwrap();
+
+        // Assert
+        let document = result.did_document.expect("did:web should resolve");
+        assert_eq!(document.id, did);
+        assert!(document.get_verification_method("key-1").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_did_web_missing_document_is_not_found() {
+        let served = spawn_web_host("/agents/alice", str::to_string).await;
+        let resolver = WebDidResolver::new(Arc::new(LocalFetcher::new()));
+
+        let did = served.replace(":alice", ":bob");
+        let result = resolver.resolve(&did).await.unwrap();
+
+        assert_eq!(result.error_code(), Some("notFound"));
+    }
+
+    #[tokio::test]
+    async fn test_did_web_rejects_document_for_other_did() {
+        let did = spawn_web_host("", |_| "did:web:someone-else.example".to_string()).await;
+        let resolver = WebDidResolver::new(Arc::new(LocalFetcher::new()));
+
+        let result = resolver.resolve(&did).await;
+
+        assert!(result.is_err(), "Documents must be for the requested DID");
+    }
+
+    #[tokio::test]
+    async fn test_http_fetcher_caps_streamed_body_without_content_length() {
+        // Arrange: a chunked body with no Content-Length, larger than the cap
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/did.json", listener.local_addr().unwrap());
+        let router = axum::Router::new().route(
+            "/did.json",
+            axum::routing::get(|| async {
+                let chunks = (0..=MAX_DID_DOCUMENT_BYTES / 1024)
+                    .map(|_| Ok::<_, std::io::Error>(vec![b' '; 1024]));
+                axum::body::Body::from_stream(futures::stream::iter(chunks))
+            }),
+        );
+        tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+
+        // Act
+        let result = HttpDidWebFetcher::new().unwrap().fetch(&url).await;
+
+        // Assert
+        assert!(matches!(result, Err(Error::Network(msg)) if msg.contains("exceeds")));
+    }
+
+    // ========== TDD Tests: did:agoramesh ==========
+
+    #[tokio::test]
+    async fn test_agoramesh_did_resolves_owner_signed_record() {
+        // Arrange
+        let owner = PrivateKeySigner::random();
+        let document = agent_document("agent-1");
+        let did = document.id.clone();
+        let record = SignedDidRecord::new(&document, &owner).unwrap();
+        let dht = spawn_dht(HashMap::from([(dht_key(&did), record.to_bytes().unwrap())]));
+        let registry = StaticRegistry::default().with_agent(&did, owner.address(), true);
+        let resolver = AgoraMeshDidResolver::new(dht, Arc::new(registry));
+
+        // Act
+        let result = resolver.resolve(&did).await.unwrap();
+
+        // Assert
+        assert_eq!(result.error_code(), None);
+        assert!(!result.is_deactivated());
+        assert_eq!(result.did_document.unwrap().id, did);
+    }
+
+    #[tokio::test]
+    async fn test_agoramesh_did_rejects_record_not_signed_by_owner() {
+        let document = agent_document("agent-2");
+        let did = document.id.clone();
+        let record = SignedDidRecord::new(&document, &PrivateKeySigner::random()).unwrap();
+        let dht = spawn_dht(HashMap::from([(dht_key(&did), record.to_bytes().unwrap())]));
+        let registry =
+            StaticRegistry::default().with_agent(&did, PrivateKeySigner::random().address(), true);
+        let resolver = AgoraMeshDidResolver::new(dht, Arc::new(registry));
+
+        let result = resolver.resolve(&did).await;
+
+        assert!(
+            result.is_err(),
+            "Only the registered owner may publish the document"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_agoramesh_did_unregistered_is_not_found() {
+        let owner = PrivateKeySigner::random();
+        let document = agent_document("agent-3");
+        let did = document.id.clone();
+        let record = SignedDidRecord::new(&document, &owner).unwrap();
+        let dht = spawn_dht(HashMap::from([(dht_key(&did), record.to_bytes().unwrap())]));
+        let resolver = AgoraMeshDidResolver::new(dht, Arc::new(StaticRegistry::default()));
+
+        let result = resolver.resolve(&did).await.unwrap();
+
+        assert_eq!(result.error_code(), Some("notFound"));
+    }
+
+    #[tokio::test]
+    async fn test_agoramesh_did_inactive_agent_is_deactivated() {
+        let owner = PrivateKeySigner::random();
+        let document = agent_document("agent-4");
+        let did = document.id.clone();
+        let record = SignedDidRecord::new(&document, &owner).unwrap();
+        let dht = spawn_dht(HashMap::from([(dht_key(&did), record.to_bytes().unwrap())]));
+        let registry = StaticRegistry::default().with_agent(&did, owner.address(), false);
+        let resolver = AgoraMeshDidResolver::new(dht, Arc::new(registry));
+
+        let result = resolver.resolve(&did).await.unwrap();
+
+        assert!(result.is_deactivated());
+    }
+
+    #[tokio::test]
+    async fn test_agoramesh_did_missing_dht_record_is_not_found() {
+        let did = "did:agoramesh:base:agent-5";
+        let registry =
+            StaticRegistry::default().with_agent(did, PrivateKeySigner::random().address(), true);
+        let resolver = AgoraMeshDidResolver::new(spawn_dht(HashMap::new()), Arc::new(registry));
+
+        let result = resolver.resolve(did).await.unwrap();
+
+        assert_eq!(result.error_code(), Some("notFound"));
+    }
+
+    #[tokio::test]
+    async fn test_agoramesh_did_resolves_node_dids_without_registration() {
+        let identity = NodeIdentity::generate();
+        let did = identity.did(8453);
+        let resolver = AgoraMeshDidResolver::new(
+            spawn_dht(HashMap::new()),
+            Arc::new(StaticRegistry::default()),
+        );
+
+        let result = resolver.resolve(&did).await.unwrap();
+
+        assert_eq!(result.did_document.unwrap().id, did);
+    }
+
+    // ========== TDD Tests: node DIDs ==========
+
+    #[tokio::test]
+    async fn test_node_did_resolves_to_key_in_peer_id() {
+        let identity = NodeIdentity::generate();
+        let did = identity.did(8453);
+
+        let result = PeerDidResolver.resolve(&did).await.unwrap();
+
+        let document = result.did_document.expect("node DID should resolve");
+        assert_eq!(document.id, did);
+        let method = document.get_verification_method(NODE_KEY_FRAGMENT).unwrap();
+        assert_eq!(
+            decode_ed25519_multibase(method.public_key_multibase.as_deref().unwrap()).unwrap(),
+            identity.public_key()
+        );
+        assert_eq!(PeerDidResolver::peer_id(&did), Some(identity.peer_id()));
+    }
+
+    #[tokio::test]
+    async fn test_node_did_rejects_identifiers_without_peer_key() {
+        let did = "did:agoramesh:base:agent-1";
+
+        let result = PeerDidResolver.resolve(did).await.unwrap();
+
+        assert_eq!(result.error_code(), Some("invalidDid"));
+        assert_eq!(PeerDidResolver::peer_id(did), None);
+    }
+
+    // ========== TDD Tests: UniversalResolver ==========
+
+    #[tokio::test]
+    async fn test_universal_resolver_dispatches_by_method() {
+        let resolver = UniversalResolver::new().with_method("key", Arc::new(KeyDidResolver));
+        let did = KeyDidResolver::did_for(&ed25519::Keypair::generate().public());
+
+        assert!(resolver.resolve_did(&did).await.did_document.is_some());
+        assert_eq!(
+            resolver.resolve_did("did:ion:abc").await.error_code(),
+            Some("methodNotSupported")
+        );
+        assert_eq!(
+            resolver.resolve_did("not-a-did").await.error_code(),
+            Some("invalidDid")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_universal_resolver_caches_until_ttl() {
+        // Arrange
+        let did = spawn_web_host("", str::to_string).await;
+        let fetcher = Arc::new(LocalFetcher::new());
+        let resolver = UniversalResolver::new()
+            .with_method("web", Arc::new(WebDidResolver::new(fetcher.clone())))
+            .with_cache_ttl(Duration::from_millis(200));
+
+        // Act + Assert: served from cache within the TTL
+        let _ = resolver.resolve_did(&did).await;
+        let _ = resolver.resolve_did(&did).await;
+        assert_eq!(fetcher.requests.load(Ordering::SeqCst), 1);
+
+        // ...and fetched again once it expires
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let _ = resolver.resolve_did(&did).await;
+        assert_eq!(fetcher.requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_universal_resolver_serves_envelope_documents() {
+        let resolver = UniversalResolver::new().with_method("key", Arc::new(KeyDidResolver));
+        let did = KeyDidResolver::did_for(&ed25519::Keypair::generate().public());
+
+        let document = DidDocumentSource::document(&resolver, &did).await.unwrap();
+        assert_eq!(document.map(|d| d.id), Some(did));
+
+        let missing = DidDocumentSource::document(&resolver, "did:ion:abc")
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+    }
+}
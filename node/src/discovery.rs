@@ -5,15 +5,19 @@
 //! - Semantic search for agent discovery
 //! - DHT-based decentralized registry
 
+use alloy::primitives::FixedBytes;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use crate::contract::TrustRegistryClient;
 use crate::error::{Error, Result};
-use crate::network::SwarmCommand;
-use crate::search::HybridSearch;
+use crate::network::{topics, DiscoveryMessage, GossipPublisher, SignedEnvelope, SwarmCommand};
+use crate::persistence::CapabilityCardStore;
+use crate::search::{HybridSearch, SchemaQuery, SearchFilter, SkillFit};
+use crate::trust::{TrustInfo, TrustService};
 
 /// A2A-compatible Capability Card for agent discovery.
 ///
@@ -144,7 +148,7 @@ pub struct PricingInfo {
 }
 
 /// Pricing model types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PricingModel {
     /// Fixed price per request.
@@ -264,6 +268,11 @@ impl DiscoveryCacheState {
     }
 }
 
+/// DID of the agent behind `card`, for stable ordering.
+fn card_did(card: &CapabilityCard) -> Option<&str> {
+    card.agoramesh.as_ref().map(|ext| ext.did.as_str())
+}
+
 /// Discovery service for finding agents.
 ///
 /// Provides agent registration, search, and lookup functionality.
@@ -272,16 +281,28 @@ impl DiscoveryCacheState {
 pub struct DiscoveryService {
     /// Local cache of capability cards indexed by DID.
     cache: RwLock<DiscoveryCacheState>,
+    /// Reverse index from `did_to_hash(did)` to every DID ever cached, so
+    /// contract events can be resolved without scanning the card store.
+    did_hashes: RwLock<HashMap<FixedBytes<32>, String>>,
     /// Cache behavior configuration.
     cache_config: DiscoveryCacheConfig,
 
     /// Optional network command sender for DHT operations.
     network_tx: Option<mpsc::Sender<SwarmCommand>>,
 
+    /// Optional signed publisher for GossipSub announcements.
+    publisher: Option<GossipPublisher>,
+
     /// Optional hybrid search for semantic search capabilities.
     /// Falls back to simple keyword matching if not available.
     /// Wrapped in Arc so it can be shared with the API layer for semantic search queries.
     hybrid_search: Option<Arc<tokio::sync::RwLock<HybridSearch>>>,
+
+    /// Optional durable store that registrations are written through to.
+    card_store: Option<CapabilityCardStore>,
+
+    /// Optional source of node-computed trust scores for ranking.
+    trust: Option<Arc<TrustService>>,
 }
 
 impl DiscoveryService {
@@ -292,12 +313,70 @@ impl DiscoveryService {
     ) -> Self {
         Self {
             cache: RwLock::new(DiscoveryCacheState::default()),
+            did_hashes: RwLock::new(HashMap::new()),
             cache_config,
             network_tx,
+            publisher: None,
             hybrid_search,
+            card_store: None,
+            trust: None,
         }
     }
 
+    /// Rank fallback searches by trust scores computed by `trust`.
+    ///
+    /// Search results carry the node-computed score in place of the one
+    /// the card declares. Without a TrustService that field is cleared.
+    pub fn with_trust(mut self, trust: Arc<TrustService>) -> Self {
+        self.trust = Some(trust);
+        self
+    }
+
+    /// Announce registrations over GossipSub through `publisher`.
+    ///
+    /// Cards for the publisher's DID are announced directly; other agents'
+    /// cards are relayed, with the agent's signed registration when there is
+    /// one (see [`DiscoveryService::register_signed`]).
+    pub fn with_publisher(mut self, publisher: GossipPublisher) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    /// Attach a durable capability card store.
+    ///
+    /// Registrations are written through to the store and cache misses in
+    /// `get()` consult it before querying the DHT. Call
+    /// [`DiscoveryService::load_from_store`] to rehydrate on startup.
+    pub fn with_card_store(mut self, store: CapabilityCardStore) -> Self {
+        self.card_store = Some(store);
+        self
+    }
+
+    /// Load all persisted capability cards into the local cache.
+    ///
+    /// Cards are re-indexed in hybrid search when it is available.
+    /// Returns the number of cards loaded (0 if no store is attached).
+    pub async fn load_from_store(&self) -> Result<usize> {
+        let Some(ref store) = self.card_store else {
+            return Ok(0);
+        };
+
+        let cards = store.all()?;
+        let count = cards.len();
+
+        for (did, card) in cards {
+            self.cache_insert(did, card.clone()).await?;
+            if let Some(ref hybrid_search) = self.hybrid_search {
+                let mut search = hybrid_search.write().await;
+                if let Err(e) = search.index_card(&card).await {
+                    tracing::warn!("Failed to re-index persisted card: {}", e);
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
     /// Create a new discovery service without network integration.
     pub fn new() -> Self {
         Self::from_parts(None, None, DiscoveryCacheConfig::default())
@@ -365,12 +444,19 @@ impl DiscoveryService {
         if let Some(ref hybrid_search) = self.hybrid_search {
             let mut search = hybrid_search.write().await;
             for did in dids {
-                search.remove_card(&did);
+                if let Err(e) = search.remove_card(&did).await {
+                    tracing::warn!("Failed to remove card from hybrid search: {}", e);
+                }
             }
         }
     }
 
     async fn cache_insert(&self, did: String, card: CapabilityCard) -> Result<()> {
+        if let Ok(mut index) = self.did_hashes.write() {
+            index
+                .entry(TrustRegistryClient::did_to_hash(&did))
+                .or_insert_with(|| did.clone());
+        }
         let evicted = {
             let mut cache = self.cache.write().map_err(|e| {
                 Error::Discovery(format!("Failed to acquire cache write lock: {}", e))
@@ -401,6 +487,9 @@ impl DiscoveryService {
     }
 
     /// Invalidate a single cached DID.
+    ///
+    /// Also drops the card from the durable store, so it is not rehydrated
+    /// on the next restart.
     pub async fn invalidate(&self, did: &str) -> Result<bool> {
         let removed = {
             let mut cache = self.cache.write().map_err(|e| {
@@ -411,6 +500,9 @@ impl DiscoveryService {
         if removed {
             self.remove_from_search_index(vec![did.to_string()]).await;
         }
+        if let Some(ref store) = self.card_store {
+            store.delete(did)?;
+        }
         Ok(removed)
     }
 
@@ -424,7 +516,7 @@ impl DiscoveryService {
         }
         if let Some(ref hybrid_search) = self.hybrid_search {
             let mut search = hybrid_search.write().await;
-            search.clear();
+            search.clear().await?;
         }
         Ok(())
     }
@@ -444,6 +536,9 @@ impl DiscoveryService {
 
     /// Register a capability card.
     ///
+    /// The card is cached, persisted, indexed, stored in the DHT and
+    /// announced to peers.
+    ///
     /// # Arguments
     ///
     /// * `card` - The capability card to register
@@ -454,6 +549,34 @@ impl DiscoveryService {
     /// - The card is missing the AgoraMesh extension with DID
     /// - The DID format is invalid
     pub async fn register(&self, card: &CapabilityCard) -> Result<()> {
+        self.store(card).await?;
+        self.announce(card, None).await
+    }
+
+    /// Register a card together with the agent's signed registration of it.
+    ///
+    /// `registration` is the envelope the agent sealed the card in for
+    /// [`crate::api::REGISTER_AGENT_TOPIC`]. It is relayed with the card so
+    /// peers can check the agent's own signature.
+    pub async fn register_signed(
+        &self,
+        card: &CapabilityCard,
+        registration: &SignedEnvelope,
+    ) -> Result<()> {
+        self.store(card).await?;
+        self.announce(card, Some(registration)).await
+    }
+
+    /// Register a card received from a peer.
+    ///
+    /// Stored like [`DiscoveryService::register`], but not announced again:
+    /// GossipSub already delivers it to every subscriber.
+    pub async fn register_from_peer(&self, card: &CapabilityCard) -> Result<()> {
+        self.store(card).await
+    }
+
+    /// Validate, cache, persist, index and DHT-store a card.
+    async fn store(&self, card: &CapabilityCard) -> Result<()> {
         // Validate: card must have agoramesh extension with DID
         let agoramesh = card
             .agoramesh
@@ -473,6 +596,11 @@ impl DiscoveryService {
         // Store in local cache
         self.cache_insert(did.clone(), card.clone()).await?;
 
+        // Write through to durable storage if configured
+        if let Some(ref store) = self.card_store {
+            store.put(did, card)?;
+        }
+
         // Index in hybrid search if available
         if let Some(ref hybrid_search) = self.hybrid_search {
             let mut search = hybrid_search.write().await;
@@ -482,31 +610,55 @@ impl DiscoveryService {
             }
         }
 
-        // Store in DHT and announce via GossipSub if network is available
+        // Store in DHT for persistent lookup if network is available
         if let Some(ref tx) = self.network_tx {
             let serialized = serde_json::to_vec(card)
                 .map_err(|e| Error::Discovery(format!("Failed to serialize card: {}", e)))?;
 
-            // Store in DHT for persistent lookup
             tx.send(SwarmCommand::PutRecord {
                 key: did.as_bytes().to_vec(),
-                value: serialized.clone(),
+                value: serialized,
             })
             .await
             .map_err(|e| Error::Discovery(format!("Failed to send DHT put command: {}", e)))?;
-
-            // Announce via GossipSub for real-time discovery
-            tx.send(SwarmCommand::Publish {
-                topic: "/agoramesh/discovery/1.0.0".to_string(),
-                data: serialized,
-            })
-            .await
-            .map_err(|e| Error::Discovery(format!("Failed to send GossipSub publish: {}", e)))?;
         }
 
         Ok(())
     }
 
+    /// Announce a stored card via GossipSub for real-time discovery.
+    ///
+    /// The node's own card is announced directly; other agents' cards are
+    /// relayed under the node's signature.
+    async fn announce(
+        &self,
+        card: &CapabilityCard,
+        registration: Option<&SignedEnvelope>,
+    ) -> Result<()> {
+        let did = card_did(card).unwrap_or_default();
+        let Some(ref publisher) = self.publisher else {
+            if self.network_tx.is_some() {
+                tracing::warn!("Not announcing {}: no gossip signer configured", did);
+            }
+            return Ok(());
+        };
+
+        let message = if publisher.did() == did {
+            DiscoveryMessage::CardAnnouncement {
+                card: Box::new(card.clone()),
+            }
+        } else {
+            DiscoveryMessage::CardRelay {
+                card: Box::new(card.clone()),
+                registration: registration.cloned().map(Box::new),
+            }
+        };
+        publisher
+            .publish(topics::DISCOVERY, &message)
+            .await
+            .map_err(|e| Error::Discovery(format!("Failed to send GossipSub publish: {}", e)))
+    }
+
     /// Search for agents by capability.
     ///
     /// When HybridSearch is available, uses combined vector similarity and keyword
@@ -524,15 +676,16 @@ impl DiscoveryService {
     /// # Arguments
     ///
     /// * `query` - Search query string
+    /// * `filter` - Card constraints applied before ranking
     ///
     /// # Returns
     ///
     /// A list of matching capability cards, ranked by relevance (hybrid score or trust score).
-    pub async fn search(&self, query: &str) -> Result<Vec<CapabilityCard>> {
+    pub async fn search(&self, query: &str, filter: &SearchFilter) -> Result<Vec<CapabilityCard>> {
         // Use hybrid search if available
         if let Some(ref hybrid_search) = self.hybrid_search {
             let search = hybrid_search.read().await;
-            match search.search(query).await {
+            match search.rank(query, filter).await {
                 Ok(results) => {
                     // Extract cards from search results (already ranked by hybrid score)
                     return Ok(results.into_iter().map(|r| r.card).collect());
@@ -545,27 +698,31 @@ impl DiscoveryService {
         }
 
         // Fall back to simple keyword matching
-        self.search_simple(query).await
+        self.search_simple(query, filter).await
     }
 
     /// Simple keyword search (fallback when hybrid search is unavailable).
-    async fn search_simple(&self, query: &str) -> Result<Vec<CapabilityCard>> {
+    async fn search_simple(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<CapabilityCard>> {
         self.prune_expired_cache().await?;
 
-        let cache = self
-            .cache
-            .read()
-            .map_err(|e| Error::Discovery(format!("Failed to acquire cache read lock: {}", e)))?;
-
         let query_lower = query.to_lowercase();
 
-        let mut matches: Vec<CapabilityCard> = cache
-            .entries
-            .values()
-            .map(|entry| &entry.card)
-            .filter(|card| self.card_matches(card, &query_lower))
-            .cloned()
-            .collect();
+        let matches: Vec<CapabilityCard> = {
+            let cache = self.cache.read().map_err(|e| {
+                Error::Discovery(format!("Failed to acquire cache read lock: {}", e))
+            })?;
+            cache
+                .entries
+                .values()
+                .map(|entry| &entry.card)
+                .filter(|card| filter.matches(card) && self.card_matches(card, &query_lower))
+                .cloned()
+                .collect()
+        };
 
         // Note: DHT doesn't support text search - it's a key-value store.
         // Search works on local cache, which is populated from:
@@ -573,24 +730,91 @@ impl DiscoveryService {
         // 2. GossipSub announcements from peers
         // 3. Explicit DHT queries for known DIDs
 
-        // Rank results by trust score (highest first)
-        matches.sort_by(|a, b| {
-            let score_a = a
-                .agoramesh
-                .as_ref()
-                .and_then(|e| e.trust_score)
-                .unwrap_or(0.0);
-            let score_b = b
-                .agoramesh
-                .as_ref()
-                .and_then(|e| e.trust_score)
-                .unwrap_or(0.0);
-            // Sort descending (higher scores first)
-            score_b
-                .partial_cmp(&score_a)
-                .unwrap_or(std::cmp::Ordering::Equal)
+        // Keep agents whose live trust passes the filter and rank them by
+        // it (highest first)
+        let mut ranked = Vec::with_capacity(matches.len());
+        for mut card in matches {
+            let trust = self
+                .attach_live_trust(&mut card, filter.skill.as_deref())
+                .await;
+            if filter.admits_trust(trust.as_ref()) {
+                ranked.push((trust.map_or(0.0, |info| info.score), card));
+            }
+        }
+        ranked.sort_by(|a, b| {
+            b.0.total_cmp(&a.0)
+                .then_with(|| card_did(&a.1).cmp(&card_did(&b.1)))
         });
 
+        Ok(ranked.into_iter().map(|(_, card)| card).collect())
+    }
+
+    /// Replace the trust score `card` declares about itself with the one
+    /// this node computes, in `skill` if given (cleared without a
+    /// TrustService), returning the trust it was computed from. Only
+    /// cached TrustRegistry values are used.
+    async fn attach_live_trust(
+        &self,
+        card: &mut CapabilityCard,
+        skill: Option<&str>,
+    ) -> Option<TrustInfo> {
+        let info = match (&self.trust, &card.agoramesh) {
+            (Some(trust), Some(ext)) => trust.get_cached_skill_trust(&ext.did, skill).await.ok(),
+            _ => None,
+        };
+        if let Some(ext) = card.agoramesh.as_mut() {
+            ext.trust_score = info.as_ref().map(|info| info.score);
+        }
+        info
+    }
+
+    /// Find agents with a skill whose schemas fit `query`.
+    ///
+    /// Each agent is paired with its best-fitting skill (only `filter.skill`
+    /// is considered if set), and the list is ordered by fit, best first.
+    /// Like [`search`](Self::search), this works on the local cache.
+    pub async fn match_schema(
+        &self,
+        query: &SchemaQuery,
+        filter: &SearchFilter,
+    ) -> Result<Vec<(CapabilityCard, SkillFit)>> {
+        self.prune_expired_cache().await?;
+
+        let fitting: Vec<(CapabilityCard, SkillFit)> = {
+            let cache = self.cache.read().map_err(|e| {
+                Error::Discovery(format!("Failed to acquire cache read lock: {}", e))
+            })?;
+            cache
+                .entries
+                .values()
+                .map(|entry| &entry.card)
+                .filter(|card| filter.matches(card))
+                .filter_map(|card| {
+                    let skills = card
+                        .skills
+                        .iter()
+                        .filter(|skill| filter.skill.as_deref().is_none_or(|id| skill.id == id));
+                    Some((card.clone(), query.best_fit(skills)?))
+                })
+                .collect()
+        };
+
+        let mut matches = Vec::with_capacity(fitting.len());
+        for (mut card, fit) in fitting {
+            let trust = self
+                .attach_live_trust(&mut card, filter.skill.as_deref())
+                .await;
+            if filter.admits_trust(trust.as_ref()) {
+                matches.push((card, fit));
+            }
+        }
+
+        // Best fit first; ties in DID order so pages are stable
+        matches.sort_by(|a, b| {
+            b.1.score
+                .total_cmp(&a.1.score)
+                .then_with(|| card_did(&a.0).cmp(&card_did(&b.0)))
+        });
         Ok(matches)
     }
 
@@ -605,29 +829,70 @@ impl DiscoveryService {
     /// # Returns
     ///
     /// `true` if the request was sent, `false` if network is not available.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the network is available but no gossip signer is
+    /// configured: peers drop unsigned requests.
     pub async fn request_registry_broadcast(&self) -> Result<bool> {
-        if let Some(ref tx) = self.network_tx {
-            // Send a discovery request message
-            let request = serde_json::json!({
-                "type": "discovery_request",
-                "timestamp": std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_secs()
-            });
-
-            tx.send(SwarmCommand::Publish {
-                topic: "/agoramesh/discovery/1.0.0".to_string(),
-                data: serde_json::to_vec(&request)
-                    .map_err(|e| Error::Discovery(format!("Failed to serialize request: {}", e)))?,
-            })
+        if self.network_tx.is_none() {
+            return Ok(false);
+        }
+        let publisher = self.publisher.as_ref().ok_or_else(|| {
+            Error::Discovery("Cannot request a registry broadcast without a gossip signer".into())
+        })?;
+
+        let request = DiscoveryMessage::DiscoveryRequest {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+        publisher
+            .publish(topics::DISCOVERY, &request)
             .await
             .map_err(|e| Error::Discovery(format!("Failed to send discovery request: {}", e)))?;
 
-            Ok(true)
-        } else {
-            Ok(false)
+        Ok(true)
+    }
+
+    /// Whether a card for `did` is held locally (cache or durable store).
+    ///
+    /// Unlike [`DiscoveryService::get`] this never queries the DHT.
+    pub fn has_card(&self, did: &str) -> Result<bool> {
+        if self.cache_get(did)?.is_some() {
+            return Ok(true);
+        }
+        match self.card_store {
+            Some(ref store) => store.contains(did),
+            None => Ok(false),
+        }
+    }
+
+    /// DID whose `did_to_hash` is `did_hash`, if its card was ever cached here.
+    pub fn did_for_hash(&self, did_hash: &FixedBytes<32>) -> Option<String> {
+        self.did_hashes
+            .read()
+            .ok()
+            .and_then(|index| index.get(did_hash).cloned())
+    }
+
+    /// DIDs known to this node, from the local cache and the durable store.
+    pub fn known_dids(&self) -> Result<Vec<String>> {
+        let mut dids: HashSet<String> = self
+            .cache
+            .read()
+            .map_err(|e| Error::Discovery(format!("Failed to acquire cache read lock: {}", e)))?
+            .entries
+            .keys()
+            .cloned()
+            .collect();
+
+        if let Some(ref store) = self.card_store {
+            dids.extend(store.all()?.into_iter().map(|(did, _)| did));
         }
+
+        Ok(dids.into_iter().collect())
     }
 
     /// Get the number of agents in the local cache.
@@ -687,6 +952,14 @@ impl DiscoveryService {
             return Ok(Some(card));
         }
 
+        // Fall back to durable storage (e.g. entry expired from cache)
+        if let Some(ref store) = self.card_store {
+            if let Some(card) = store.get(did)? {
+                self.cache_insert(did.to_string(), card.clone()).await?;
+                return Ok(Some(card));
+            }
+        }
+
         // Query DHT if network is available and not in cache
         if let Some(ref tx) = self.network_tx {
             let (response_tx, response_rx) = tokio::sync::oneshot::channel();
@@ -913,7 +1186,9 @@ mod tests {
         service.register(&card).await.unwrap();
 
         // Act
-        let result = service.search("nonexistent-capability").await;
+        let result = service
+            .search("nonexistent-capability", &SearchFilter::default())
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -939,7 +1214,7 @@ mod tests {
         service.register(&card3).await.unwrap();
 
         // Act
-        let result = service.search("Translator").await;
+        let result = service.search("Translator", &SearchFilter::default()).await;
 
         // Assert
         assert!(result.is_ok());
@@ -956,7 +1231,9 @@ mod tests {
         service.register(&card).await.unwrap();
 
         // Act - search for capability name "Translation"
-        let result = service.search("Translation").await;
+        let result = service
+            .search("Translation", &SearchFilter::default())
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -968,6 +1245,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_search_applies_filter() {
+        // Arrange: two matching agents, only one within budget
+        let service = DiscoveryService::new();
+        let mut pricey = sample_capability_card("did:agoramesh:base:pricey");
+        if let Some(pricing) = pricey.agoramesh.as_mut().and_then(|e| e.pricing.as_mut()) {
+            pricing.base_price = 5_000_000;
+        }
+        service.register(&pricey).await.unwrap();
+        service
+            .register(&sample_capability_card("did:agoramesh:base:cheap"))
+            .await
+            .unwrap();
+        let filter = SearchFilter {
+            max_price: Some(1_000_000),
+            ..Default::default()
+        };
+
+        // Act
+        let matches = service.search("Translation", &filter).await.unwrap();
+
+        // Assert
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].agoramesh.as_ref().unwrap().did,
+            "did:agoramesh:base:cheap"
+        );
+    }
+
     #[tokio::test]
     async fn test_search_is_case_insensitive() {
         // Arrange
@@ -977,7 +1283,7 @@ mod tests {
         service.register(&card).await.unwrap();
 
         // Act
-        let result = service.search("myagent").await;
+        let result = service.search("myagent", &SearchFilter::default()).await;
 
         // Assert
         assert!(result.is_ok());
@@ -994,7 +1300,7 @@ mod tests {
         let service = DiscoveryService::new();
 
         // Act
-        let result = service.search("anything").await;
+        let result = service.search("anything", &SearchFilter::default()).await;
 
         // Assert
         assert!(result.is_ok());
@@ -1236,15 +1542,28 @@ mod tests {
         );
     }
 
+    /// Publisher signing as `did` over `tx`.
+    fn test_publisher(
+        tx: tokio::sync::mpsc::Sender<crate::network::SwarmCommand>,
+        did: &str,
+    ) -> GossipPublisher {
+        let keypair = libp2p::identity::ed25519::Keypair::generate();
+        GossipPublisher::new(
+            tx,
+            crate::network::EnvelopeSigner::ed25519(did, "key-1", keypair),
+        )
+    }
+
     #[tokio::test]
-    async fn test_register_sends_gossipsub_announce_when_network_available() {
-        use crate::network::SwarmCommand;
+    async fn test_register_sends_signed_gossipsub_announce_for_own_did() {
+        use crate::network::{SignedEnvelope, SwarmCommand};
         use tokio::sync::mpsc;
 
         // Arrange
         let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
-        let service = DiscoveryService::with_network(tx);
         let did = "did:agoramesh:base:announced-agent";
+        let service =
+            DiscoveryService::with_network(tx.clone()).with_publisher(test_publisher(tx, did));
         let card = sample_capability_card(did);
 
         // Act
@@ -1263,65 +1582,186 @@ mod tests {
         let cmd2 = rx.recv().await.expect("Should receive second command");
         match cmd2 {
             SwarmCommand::Publish { topic, data } => {
-                // Should publish to discovery topic
-                assert!(
-                    topic.contains("discovery"),
-                    "Should publish to discovery topic"
-                );
-                // Data should be serialized announcement
-                assert!(!data.is_empty(), "Announcement data should not be empty");
+                assert_eq!(topic, topics::DISCOVERY);
+                let envelope: SignedEnvelope = serde_json::from_slice(&data).unwrap();
+                assert_eq!(envelope.sender_did, did);
+                let message: DiscoveryMessage = serde_json::from_str(&envelope.payload).unwrap();
+                assert!(matches!(message, DiscoveryMessage::CardAnnouncement { .. }));
             }
             _ => panic!("Expected Publish command, got {:?}", cmd2),
         }
     }
 
+    /// The discovery message in the next Publish command, and its signer.
+    async fn next_published(
+        rx: &mut tokio::sync::mpsc::Receiver<crate::network::SwarmCommand>,
+    ) -> (DiscoveryMessage, String) {
+        loop {
+            match rx.recv().await.expect("Should publish") {
+                crate::network::SwarmCommand::Publish { topic, data } => {
+                    assert_eq!(topic, topics::DISCOVERY);
+                    let envelope: SignedEnvelope = serde_json::from_slice(&data).unwrap();
+                    let message = serde_json::from_str(&envelope.payload).unwrap();
+                    return (message, envelope.sender_did);
+                }
+                crate::network::SwarmCommand::PutRecord { .. } => continue,
+                other => panic!("Expected Publish command, got {:?}", other),
+            }
+        }
+    }
+
     #[tokio::test]
-    async fn test_search_ranks_results_by_trust_score() {
-        // Arrange
-        let service = DiscoveryService::new();
+    async fn test_register_relays_cards_of_other_dids() {
+        use crate::network::SwarmCommand;
+        use tokio::sync::mpsc;
 
-        // Create agents with different trust scores
-        let mut low_trust = sample_capability_card("did:agoramesh:base:low-trust");
-        low_trust.name = "Translation Agent Low".to_string();
-        if let Some(ref mut ext) = low_trust.agoramesh {
-            ext.trust_score = Some(0.3);
-        }
+        let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
+        let service = DiscoveryService::with_network(tx.clone())
+            .with_publisher(test_publisher(tx, "did:agoramesh:base:this-node"));
 
-        let mut high_trust = sample_capability_card("did:agoramesh:base:high-trust");
-        high_trust.name = "Translation Agent High".to_string();
-        if let Some(ref mut ext) = high_trust.agoramesh {
-            ext.trust_score = Some(0.9);
+        service
+            .register(&sample_capability_card("did:agoramesh:base:other-agent"))
+            .await
+            .expect("Registration should succeed");
+
+        let (message, sender) = next_published(&mut rx).await;
+        assert_eq!(sender, "did:agoramesh:base:this-node");
+        match message {
+            DiscoveryMessage::CardRelay { card, registration } => {
+                assert_eq!(card_did(&card), Some("did:agoramesh:base:other-agent"));
+                assert!(registration.is_none());
+            }
+            other => panic!("Expected a card relay, got {:?}", other),
         }
+    }
+
+    #[tokio::test]
+    async fn test_register_signed_relays_the_agent_registration() {
+        use crate::network::{EnvelopeSigner, SwarmCommand};
+        use tokio::sync::mpsc;
 
-        let mut medium_trust = sample_capability_card("did:agoramesh:base:medium-trust");
-        medium_trust.name = "Translation Agent Medium".to_string();
-        if let Some(ref mut ext) = medium_trust.agoramesh {
-            ext.trust_score = Some(0.6);
+        let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
+        let service = DiscoveryService::with_network(tx.clone())
+            .with_publisher(test_publisher(tx, "did:agoramesh:base:this-node"));
+        let did = "did:agoramesh:base:other-agent";
+        let card = sample_capability_card(did);
+        let agent =
+            EnvelopeSigner::ed25519(did, "key-1", libp2p::identity::ed25519::Keypair::generate());
+        let envelope =
+            SignedEnvelope::seal(crate::api::REGISTER_AGENT_TOPIC, &card, &agent).unwrap();
+
+        service.register_signed(&card, &envelope).await.unwrap();
+
+        match next_published(&mut rx).await.0 {
+            DiscoveryMessage::CardRelay { registration, .. } => {
+                assert_eq!(registration.as_deref(), Some(&envelope));
+            }
+            other => panic!("Expected a card relay, got {:?}", other),
         }
+    }
 
-        // Register in non-sorted order
-        service.register(&low_trust).await.unwrap();
-        service.register(&high_trust).await.unwrap();
-        service.register(&medium_trust).await.unwrap();
+    #[tokio::test]
+    async fn test_register_from_peer_is_not_announced_again() {
+        use crate::network::SwarmCommand;
+        use tokio::sync::mpsc;
 
-        // Act - search for translation agents
-        let results = service.search("Translation").await.unwrap();
+        let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
+        let service = DiscoveryService::with_network(tx.clone())
+            .with_publisher(test_publisher(tx, "did:agoramesh:base:this-node"));
+        let did = "did:agoramesh:base:other-agent";
 
-        // Assert - results should be sorted by trust score (highest first)
-        assert_eq!(results.len(), 3);
-        let scores: Vec<f64> = results
-            .iter()
-            .filter_map(|c| c.agoramesh.as_ref().and_then(|e| e.trust_score))
-            .collect();
+        service
+            .register_from_peer(&sample_capability_card(did))
+            .await
+            .unwrap();
 
+        assert!(matches!(
+            rx.recv().await,
+            Some(SwarmCommand::PutRecord { .. })
+        ));
+        assert!(rx.try_recv().is_err(), "Received cards are not re-gossiped");
+        assert!(service.has_card(did).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_results_by_trust_score() {
+        // Arrange: self-declared scores are the reverse of the earned ones
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
+        let service = DiscoveryService::new().with_trust(trust.clone());
+
+        let agents = [
+            (
+                "did:agoramesh:base:low-trust",
+                "Translation Agent Low",
+                0.9,
+                1,
+            ),
+            (
+                "did:agoramesh:base:high-trust",
+                "Translation Agent High",
+                0.3,
+                100,
+            ),
+            (
+                "did:agoramesh:base:medium-trust",
+                "Translation Agent Medium",
+                0.6,
+                10,
+            ),
+        ];
+        for (did, name, declared, successes) in agents {
+            let mut card = sample_capability_card(did);
+            card.name = name.to_string();
+            if let Some(ref mut ext) = card.agoramesh {
+                ext.trust_score = Some(declared);
+            }
+            // Register in non-sorted order
+            service.register(&card).await.unwrap();
+            trust.set_trust_data(did, 0, successes, 0, 0);
+        }
+
+        // Act - search for translation agents
+        let results = service
+            .search("Translation", &SearchFilter::default())
+            .await
+            .unwrap();
+
+        // Assert - sorted by the node-computed trust score, which replaces
+        // the declared one
+        let names: Vec<&str> = results.iter().map(|c| c.name.as_str()).collect();
         assert_eq!(
-            scores,
-            vec![0.9, 0.6, 0.3],
-            "Results should be sorted by trust score descending"
+            names,
+            vec![
+                "Translation Agent High",
+                "Translation Agent Medium",
+                "Translation Agent Low"
+            ],
+            "Results should be sorted by live trust score descending"
         );
+        for card in &results {
+            let ext = card.agoramesh.as_ref().unwrap();
+            let live = trust.get_trust(&ext.did).await.unwrap().score;
+            assert_eq!(ext.trust_score, Some(live));
+        }
     }
 
-    // ========== TDD Tests: cache_size() ==========
+    #[tokio::test]
+    async fn test_search_clears_self_declared_trust_without_trust_service() {
+        let service = DiscoveryService::new();
+        let card = sample_capability_card("did:agoramesh:base:agent");
+        service.register(&card).await.unwrap();
+
+        let results = service
+            .search("Translation", &SearchFilter::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].agoramesh.as_ref().unwrap().trust_score, None);
+    }
 
     #[tokio::test]
     async fn test_cache_size_returns_zero_for_empty_cache() {
@@ -1425,6 +1865,53 @@ mod tests {
         assert!(service.get(did).await.unwrap().is_none());
     }
 
+    // ========== TDD Tests: Persistence ==========
+
+    #[tokio::test]
+    async fn test_register_writes_through_and_rehydrates_from_card_store() {
+        use crate::persistence::MemoryStore;
+
+        let store = CapabilityCardStore::new(Arc::new(MemoryStore::new()));
+        let did = "did:agoramesh:base:persisted-card";
+
+        let service = DiscoveryService::new().with_card_store(store.clone());
+        service
+            .register(&sample_capability_card(did))
+            .await
+            .unwrap();
+        assert!(store.contains(did).unwrap());
+
+        let restored = DiscoveryService::new().with_card_store(store);
+        assert_eq!(restored.load_from_store().await.unwrap(), 1);
+        assert_eq!(restored.cache_size(), 1);
+        assert_eq!(
+            restored
+                .search("Translation", &SearchFilter::default())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_removes_card_from_store() {
+        use crate::persistence::MemoryStore;
+
+        let store = CapabilityCardStore::new(Arc::new(MemoryStore::new()));
+        let did = "did:agoramesh:base:forgotten-card";
+        let service = DiscoveryService::new().with_card_store(store.clone());
+        service
+            .register(&sample_capability_card(did))
+            .await
+            .unwrap();
+
+        service.invalidate(did).await.unwrap();
+
+        assert!(!store.contains(did).unwrap());
+        assert!(service.get(did).await.unwrap().is_none());
+    }
+
     // ========== TDD Tests: request_registry_broadcast() ==========
 
     #[tokio::test]
@@ -1438,7 +1925,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_request_registry_broadcast_sends_gossipsub_message() {
+    async fn test_request_registry_broadcast_fails_without_signer() {
         use crate::network::SwarmCommand;
         use tokio::sync::mpsc;
 
@@ -1447,20 +1934,33 @@ mod tests {
 
         let result = service.request_registry_broadcast().await;
 
+        assert!(result.is_err(), "Peers would drop an unsigned request");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_registry_broadcast_sends_gossipsub_message() {
+        use crate::network::{SignedEnvelope, SwarmCommand};
+        use tokio::sync::mpsc;
+
+        let (tx, mut rx) = mpsc::channel::<SwarmCommand>(10);
+        let service = DiscoveryService::with_network(tx.clone())
+            .with_publisher(test_publisher(tx, "did:agoramesh:base:this-node"));
+
+        let result = service.request_registry_broadcast().await;
+
         assert!(result.is_ok());
-        assert!(result.unwrap(), "Should return true with network");
+        assert!(result.unwrap(), "Should return true with a publisher");
 
         // Should receive Publish command
         let cmd = rx.recv().await.expect("Should receive command");
         match cmd {
             SwarmCommand::Publish { topic, data } => {
-                assert!(
-                    topic.contains("discovery"),
-                    "Should publish to discovery topic"
-                );
+                assert_eq!(topic, topics::DISCOVERY);
 
-                // Verify it's a discovery request
-                let msg: serde_json::Value = serde_json::from_slice(&data).unwrap();
+                // Verify it's a signed discovery request
+                let envelope: SignedEnvelope = serde_json::from_slice(&data).unwrap();
+                let msg: serde_json::Value = serde_json::from_str(&envelope.payload).unwrap();
                 assert_eq!(msg["type"], "discovery_request");
             }
             _ => panic!("Expected Publish command, got {:?}", cmd),
@@ -1523,7 +2023,9 @@ mod tests {
 
         // Verify card is indexed in hybrid search
         // We verify this by searching for it
-        let results = service.search("Translation").await;
+        let results = service
+            .search("Translation", &SearchFilter::default())
+            .await;
         assert!(results.is_ok());
         let matches = results.unwrap();
         assert!(
@@ -1553,7 +2055,9 @@ mod tests {
         service.register(&card).await.unwrap();
 
         // Search with semantic query (not exact match)
-        let results = service.search("analyze my code").await;
+        let results = service
+            .search("analyze my code", &SearchFilter::default())
+            .await;
         assert!(results.is_ok());
         let matches = results.unwrap();
 
@@ -1583,7 +2087,7 @@ mod tests {
         service.register(&card).await.unwrap();
 
         // Search should still work (using simple matching)
-        let results = service.search("Simple").await;
+        let results = service.search("Simple", &SearchFilter::default()).await;
         assert!(results.is_ok());
         let matches = results.unwrap();
         assert_eq!(matches.len(), 1, "Simple search should find matching agent");
@@ -1617,7 +2121,10 @@ mod tests {
         service.register(&low_relevance).await.unwrap();
 
         // Search for code review
-        let results = service.search("code review").await.unwrap();
+        let results = service
+            .search("code review", &SearchFilter::default())
+            .await
+            .unwrap();
 
         // With hybrid search, high relevance should come first despite lower trust
         if !results.is_empty() {
@@ -0,0 +1,301 @@
+//! EigenTrust-style trust propagation.
+//!
+//! Agents express local trust in each other through endorsements and
+//! successful transactions. Global trust is the stationary distribution of
+//! a random walk over those normalized local trust values that restarts at
+//! a set of pre-trusted seeds (the configured trust roots):
+//!
+//! ```text
+//! t = (1 - α) · Cᵀ · t + α · p
+//! ```
+//!
+//! Because every walk restarts at a seed, a cluster of agents that only
+//! trust each other receives no more global trust than the edges from
+//! honest agents into it carry, however many members it has.
+//!
+//! Reference: Kamvar, Schlosser & Garcia-Molina, "The EigenTrust Algorithm
+//! for Reputation Management in P2P Networks" (WWW 2003).
+
+use std::collections::HashMap;
+
+/// Propagation parameters.
+#[derive(Debug, Clone)]
+pub struct EigenTrustConfig {
+    /// Probability of restarting at a pre-trusted seed each step (α).
+    pub alpha: f64,
+    /// Stop when the L1 change between iterations drops below this.
+    pub tolerance: f64,
+    /// Upper bound on iterations.
+    pub max_iterations: usize,
+}
+
+impl Default for EigenTrustConfig {
+    fn default() -> Self {
+        Self {
+            alpha: 0.15,
+            tolerance: 1e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+/// Result of a propagation run.
+#[derive(Debug, Clone, Default)]
+pub struct GlobalTrust {
+    /// Trust per agent, scaled so the most trusted agent has 1.0.
+    scores: HashMap<String, f64>,
+    /// Iterations run before converging (or hitting the limit).
+    pub iterations: usize,
+}
+
+impl GlobalTrust {
+    /// Scaled global trust of `did` (0.0 for agents not in the graph).
+    pub fn score(&self, did: &str) -> f64 {
+        self.scores.get(did).copied().unwrap_or(0.0)
+    }
+
+    /// Number of agents scored.
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    /// Whether no agents were scored.
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+/// Weighted directed graph of local trust between agents.
+#[derive(Debug, Clone, Default)]
+pub struct LocalTrustGraph {
+    index: HashMap<String, usize>,
+    dids: Vec<String>,
+    /// Outgoing (target, weight) pairs per agent.
+    edges: Vec<HashMap<usize, f64>>,
+}
+
+impl LocalTrustGraph {
+    /// Create an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of agents in the graph.
+    pub fn agent_count(&self) -> usize {
+        self.dids.len()
+    }
+
+    /// Add `weight` to the local trust `from` places in `to`.
+    ///
+    /// Non-positive weights and self-trust are ignored.
+    pub fn add_local_trust(&mut self, from: &str, to: &str, weight: f64) {
+        if from == to || weight <= 0.0 || !weight.is_finite() {
+            return;
+        }
+        let from = self.node(from);
+        let to = self.node(to);
+        *self.edges[from].entry(to).or_insert(0.0) += weight;
+    }
+
+    /// Run propagation from `seeds`.
+    ///
+    /// Seeds not yet in the graph are added. Without seeds the walk
+    /// restarts uniformly over all agents, which gives no Sybil resistance.
+    pub fn propagate<'a, I>(&mut self, seeds: I, config: &EigenTrustConfig) -> GlobalTrust
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        let seeds: Vec<usize> = seeds.into_iter().map(|did| self.node(did)).collect();
+        let n = self.dids.len();
+        if n == 0 {
+            return GlobalTrust::default();
+        }
+
+        // Pre-trusted distribution p
+        let mut restart = vec![0.0; n];
+        if seeds.is_empty() {
+            restart.fill(1.0 / n as f64);
+        } else {
+            for &seed in &seeds {
+                restart[seed] = 1.0 / seeds.len() as f64;
+            }
+        }
+
+        // Row-normalize local trust into C
+        let rows: Vec<Vec<(usize, f64)>> = self
+            .edges
+            .iter()
+            .map(|row| {
+                let total: f64 = row.values().sum();
+                row.iter().map(|(&j, &w)| (j, w / total)).collect()
+            })
+            .collect();
+
+        let mut trust = restart.clone();
+        let mut next = vec![0.0; n];
+        let mut iterations = 0;
+
+        while iterations < config.max_iterations {
+            iterations += 1;
+
+            // Agents trusting nobody hand their mass back to the seeds
+            let mut dangling = 0.0;
+            for (i, row) in rows.iter().enumerate() {
+                if row.is_empty() {
+                    dangling += trust[i];
+                }
+            }
+
+            for (slot, p) in next.iter_mut().zip(&restart) {
+                *slot = (config.alpha + (1.0 - config.alpha) * dangling) * p;
+            }
+            for (i, row) in rows.iter().enumerate() {
+                let mass = (1.0 - config.alpha) * trust[i];
+                for &(j, c) in row {
+                    next[j] += mass * c;
+                }
+            }
+
+            let delta: f64 = trust.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+            std::mem::swap(&mut trust, &mut next);
+            if delta < config.tolerance {
+                break;
+            }
+        }
+
+        let max = trust.iter().copied().fold(0.0, f64::max);
+        let scores = self
+            .dids
+            .iter()
+            .zip(&trust)
+            .map(|(did, &t)| (did.clone(), if max > 0.0 { t / max } else { 0.0 }))
+            .collect();
+
+        GlobalTrust { scores, iterations }
+    }
+
+    fn node(&mut self, did: &str) -> usize {
+        if let Some(&i) = self.index.get(did) {
+            return i;
+        }
+        let i = self.dids.len();
+        self.index.insert(did.to_string(), i);
+        self.dids.push(did.to_string());
+        self.edges.push(HashMap::new());
+        i
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeds(dids: &[&str]) -> Vec<String> {
+        dids.iter().map(|d| d.to_string()).collect()
+    }
+
+    // ========== TDD Tests: Propagation ==========
+
+    #[test]
+    fn test_trust_flows_from_seeds() {
+        // Arrange
+        let mut graph = LocalTrustGraph::new();
+        graph.add_local_trust("root", "a", 1.0);
+        graph.add_local_trust("a", "b", 1.0);
+
+        // Act
+        let trust = graph.propagate(&seeds(&["root"]), &EigenTrustConfig::default());
+
+        // Assert
+        assert_eq!(trust.score("root"), 1.0);
+        assert!(trust.score("a") > trust.score("b"));
+        assert!(trust.score("b") > 0.0);
+        assert_eq!(trust.score("unknown"), 0.0);
+    }
+
+    #[test]
+    fn test_isolated_sybil_ring_gets_nothing() {
+        // Arrange: 50 fresh agents endorsing each other in a ring
+        let mut graph = LocalTrustGraph::new();
+        graph.add_local_trust("root", "honest", 1.0);
+        for i in 0..50 {
+            graph.add_local_trust(
+                &format!("sybil-{}", i),
+                &format!("sybil-{}", (i + 1) % 50),
+                1.0,
+            );
+        }
+
+        // Act
+        let trust = graph.propagate(&seeds(&["root"]), &EigenTrustConfig::default());
+
+        // Assert
+        assert!(trust.score("honest") > 0.0);
+        for i in 0..50 {
+            assert_eq!(trust.score(&format!("sybil-{}", i)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_sybil_ring_size_does_not_buy_trust() {
+        // Arrange: one compromised agent links a ring of sybils to the network
+        let ring_trust = |size: usize| {
+            let mut graph = LocalTrustGraph::new();
+            graph.add_local_trust("root", "honest", 1.0);
+            graph.add_local_trust("root", "compromised", 1.0);
+            graph.add_local_trust("compromised", "sybil-0", 1.0);
+            for i in 0..size {
+                let from = format!("sybil-{}", i);
+                for j in 0..size {
+                    graph.add_local_trust(&from, &format!("sybil-{}", j), 1.0);
+                }
+                graph.add_local_trust(&from, "target", 1.0);
+            }
+            graph.propagate(&seeds(&["root"]), &EigenTrustConfig::default())
+        };
+
+        // Act
+        let small = ring_trust(5);
+        let large = ring_trust(100);
+
+        // Assert: a 20x larger ring does not raise its beneficiary above honest agents
+        assert!(large.score("target") < large.score("honest"));
+        assert!(large.score("target") <= small.score("target") * 1.5);
+    }
+
+    #[test]
+    fn test_converges_on_cycles() {
+        let mut graph = LocalTrustGraph::new();
+        graph.add_local_trust("root", "a", 1.0);
+        graph.add_local_trust("a", "b", 1.0);
+        graph.add_local_trust("b", "a", 1.0);
+        let config = EigenTrustConfig::default();
+
+        let trust = graph.propagate(&seeds(&["root"]), &config);
+
+        assert!(trust.iterations < config.max_iterations);
+        assert!(trust.score("a") > 0.0 && trust.score("b") > 0.0);
+    }
+
+    #[test]
+    fn test_without_seeds_restarts_uniformly() {
+        let mut graph = LocalTrustGraph::new();
+        graph.add_local_trust("a", "b", 1.0);
+        graph.add_local_trust("c", "b", 1.0);
+
+        let trust = graph.propagate(&seeds(&[]), &EigenTrustConfig::default());
+
+        assert_eq!(trust.score("b"), 1.0);
+        assert!(trust.score("a") > 0.0);
+    }
+
+    #[test]
+    fn test_ignores_self_trust_and_non_positive_weights() {
+        let mut graph = LocalTrustGraph::new();
+        graph.add_local_trust("a", "a", 1.0);
+        graph.add_local_trust("a", "b", 0.0);
+        graph.add_local_trust("a", "b", -1.0);
+
+        assert_eq!(graph.agent_count(), 0);
+    }
+}
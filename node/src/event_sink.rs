@@ -0,0 +1,830 @@
+//! Applies contract events to local node state.
+//!
+//! The [`EventListener`](crate::events::EventListener) only decodes chain
+//! events; this module consumes them and keeps the node's view in sync:
+//!
+//! - **TrustRegistry reputation/stake/endorsement events** update [`TrustService`]
+//! - **`AgentDeactivated`** drops the agent's card via [`DiscoveryService::invalidate`]
+//! - **`RolledBack`** undoes stake and endorsement changes from orphaned blocks
+//! - **`Checkpoint`** commits the listener's block cursor once every earlier
+//!   event has been applied
+//!
+//! ## DID resolution
+//!
+//! Events carry `keccak256(did)` (see [`TrustRegistryClient::did_to_hash`])
+//! rather than the DID itself. The sink keeps a reverse index from hash to
+//! DID, seeded explicitly with [`EventSink::index_did`] and backed by the
+//! indexes discovery and trust maintain as DIDs reach them. Events for DIDs
+//! this node has never seen are counted as unresolved and skipped.
+//!
+//! ## Replays
+//!
+//! Logs can be delivered again after a reconnect, or after a restart from the
+//! last committed checkpoint when the node stopped mid-block. Reputation
+//! updates carry absolute values and endorsements are checked against the
+//! current graph; stake deltas are recorded per `(tx_hash, log_index)` with
+//! the agent's trust data, so each one is counted once.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use agoramesh_node::event_sink::EventSink;
+//! use agoramesh_node::events::EventListener;
+//!
+//! let (listener, rx) = EventListener::new(config)?;
+//! listener.start().await?;
+//!
+//! let sink = Arc::new(EventSink::new(trust.clone(), discovery.clone()));
+//! tokio::spawn(sink.clone().run(rx));
+//! ```
+
+use alloy::primitives::{FixedBytes, U256};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::mpsc;
+
+use crate::contract::TrustRegistryClient;
+use crate::discovery::DiscoveryService;
+use crate::error::{Error, Result};
+use crate::events::ContractEvent;
+use crate::trust::TrustService;
+
+// ========== Event Sink Statistics ==========
+
+/// Statistics for the event sink.
+#[derive(Debug, Default)]
+pub struct EventSinkStats {
+    /// Number of events applied to local state.
+    pub events_applied: AtomicU64,
+    /// Number of events with no effect on local state (e.g. escrow events).
+    pub events_ignored: AtomicU64,
+    /// Number of events skipped because a DID hash could not be resolved.
+    pub unresolved_dids: AtomicU64,
+    /// Number of events that failed to apply.
+    pub apply_errors: AtomicU64,
+}
+
+impl EventSinkStats {
+    /// Record an applied event.
+    pub fn record_applied(&self) {
+        self.events_applied.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an ignored event.
+    pub fn record_ignored(&self) {
+        self.events_ignored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an event with an unresolved DID hash.
+    pub fn record_unresolved(&self) {
+        self.unresolved_dids.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a failure to apply an event.
+    pub fn record_apply_error(&self) {
+        self.apply_errors.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// ========== Event Sink ==========
+
+/// Consumes [`ContractEvent`]s and applies them to trust and discovery state.
+pub struct EventSink {
+    /// Trust service updated by reputation, stake and endorsement events.
+    trust: Arc<TrustService>,
+
+    /// Discovery service invalidated by deactivation events.
+    discovery: Arc<DiscoveryService>,
+
+    /// Reverse index from `did_to_hash(did)` to DID.
+    did_index: RwLock<HashMap<FixedBytes<32>, String>>,
+
+    /// Sink statistics.
+    stats: Arc<EventSinkStats>,
+}
+
+impl EventSink {
+    /// Create a new event sink.
+    pub fn new(trust: Arc<TrustService>, discovery: Arc<DiscoveryService>) -> Self {
+        Self {
+            trust,
+            discovery,
+            did_index: RwLock::new(HashMap::new()),
+            stats: Arc::new(EventSinkStats::default()),
+        }
+    }
+
+    /// Get sink statistics.
+    pub fn stats(&self) -> &EventSinkStats {
+        &self.stats
+    }
+
+    /// Add a DID to the reverse index so its events can be resolved.
+    pub fn index_did(&self, did: &str) {
+        if let Ok(mut index) = self.did_index.write() {
+            index.insert(TrustRegistryClient::did_to_hash(did), did.to_string());
+        }
+    }
+
+    /// Resolve a DID hash to a DID.
+    ///
+    /// On a miss, the hash indexes of discovery and trust are consulted and
+    /// a hit is added to the sink's own index.
+    pub fn resolve(&self, did_hash: &FixedBytes<32>) -> Result<Option<String>> {
+        if let Some(did) = self.lookup(did_hash)? {
+            return Ok(Some(did));
+        }
+
+        let did = self
+            .discovery
+            .did_for_hash(did_hash)
+            .or_else(|| self.trust.did_for_hash(did_hash));
+        if let Some(ref did) = did {
+            self.index_did(did);
+        }
+
+        Ok(did)
+    }
+
+    fn lookup(&self, did_hash: &FixedBytes<32>) -> Result<Option<String>> {
+        let index = self
+            .did_index
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire DID index read lock: {}", e)))?;
+        Ok(index.get(did_hash).cloned())
+    }
+
+    /// Apply a single event to local state.
+    ///
+    /// Returns `true` if the event changed local state, `false` if it was
+    /// ignored or referenced a DID this node does not know.
+    pub async fn apply(&self, event: &ContractEvent) -> Result<bool> {
+        let result = self.apply_inner(event).await;
+        match result {
+            Ok(true) => self.stats.record_applied(),
+            Ok(false) => {}
+            Err(_) => self.stats.record_apply_error(),
+        }
+        result
+    }
+
+    async fn apply_inner(&self, event: &ContractEvent) -> Result<bool> {
+        match event {
+            ContractEvent::ReputationUpdated {
+                did_hash,
+                new_score,
+                total_transactions,
+                ..
+            } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                self.trust
+                    .apply_reputation_update(&did, *new_score, *total_transactions)
+                    .await?;
+            }
+            ContractEvent::StakeDeposited {
+                did_hash, amount, ..
+            } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                return self
+                    .trust
+                    .apply_stake_deposit(&did, saturating_u64(*amount), &event_id(event))
+                    .await;
+            }
+            ContractEvent::StakeSlashed {
+                did_hash, amount, ..
+            } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                return self
+                    .trust
+                    .apply_stake_slash(&did, saturating_u64(*amount), &event_id(event))
+                    .await;
+            }
+            ContractEvent::EndorsementAdded {
+                endorser, endorsee, ..
+            } => {
+                let Some(endorser) = self.resolve_or_skip(endorser)? else {
+                    return Ok(false);
+                };
+                let Some(endorsee) = self.resolve_or_skip(endorsee)? else {
+                    return Ok(false);
+                };
+                // Replayed logs (backfill after reconnect) must not double-count
+                if self.trust.has_endorsement(&endorser, &endorsee) {
+                    return Ok(false);
+                }
+                self.trust.add_endorsement(&endorser, &endorsee).await?;
+            }
+            ContractEvent::EndorsementRevoked {
+                endorser, endorsee, ..
+            } => {
+                let Some(endorser) = self.resolve_or_skip(endorser)? else {
+                    return Ok(false);
+                };
+                let Some(endorsee) = self.resolve_or_skip(endorsee)? else {
+                    return Ok(false);
+                };
+                return self.trust.revoke_endorsement(&endorser, &endorsee).await;
+            }
+            ContractEvent::AgentDeactivated { did_hash, .. } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                self.discovery.invalidate(&did).await?;
+            }
+            ContractEvent::RolledBack(event) => return self.undo(event).await,
+            ContractEvent::Checkpoint(checkpoint) => {
+                // Events are applied in channel order, so everything before
+                // the checkpoint is done
+                checkpoint.commit()?;
+                return Ok(false);
+            }
+            _ => {
+                self.stats.record_ignored();
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Reverse the effect of an event whose block was orphaned.
+    ///
+    /// Stake and endorsement changes are inverted (stake only if it was
+    /// applied). Reputation updates carry absolute values that the canonical
+    /// chain re-emits, and a deactivated card can only come back through
+    /// re-registration, so both are ignored.
+    async fn undo(&self, event: &ContractEvent) -> Result<bool> {
+        match event {
+            ContractEvent::StakeDeposited {
+                did_hash, amount, ..
+            } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                return self
+                    .trust
+                    .revert_stake_deposit(&did, saturating_u64(*amount), &event_id(event))
+                    .await;
+            }
+            ContractEvent::StakeSlashed {
+                did_hash, amount, ..
+            } => {
+                let Some(did) = self.resolve_or_skip(did_hash)? else {
+                    return Ok(false);
+                };
+                return self
+                    .trust
+                    .revert_stake_slash(&did, saturating_u64(*amount), &event_id(event))
+                    .await;
+            }
+            ContractEvent::EndorsementAdded {
+                endorser, endorsee, ..
+            } => {
+                let Some(endorser) = self.resolve_or_skip(endorser)? else {
+                    return Ok(false);
+                };
+                let Some(endorsee) = self.resolve_or_skip(endorsee)? else {
+                    return Ok(false);
+                };
+                return self.trust.revoke_endorsement(&endorser, &endorsee).await;
+            }
+            ContractEvent::EndorsementRevoked {
+                endorser, endorsee, ..
+            } => {
+                let Some(endorser) = self.resolve_or_skip(endorser)? else {
+                    return Ok(false);
+                };
+                let Some(endorsee) = self.resolve_or_skip(endorsee)? else {
+                    return Ok(false);
+                };
+                if self.trust.has_endorsement(&endorser, &endorsee) {
+                    return Ok(false);
+                }
+                self.trust.add_endorsement(&endorser, &endorsee).await?;
+            }
+            _ => {
+                self.stats.record_ignored();
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Resolve a DID hash, counting and logging misses.
+    fn resolve_or_skip(&self, did_hash: &FixedBytes<32>) -> Result<Option<String>> {
+        let did = self.resolve(did_hash)?;
+        if did.is_none() {
+            self.stats.record_unresolved();
+            tracing::debug!(did_hash = %did_hash, "Skipping event for unknown DID hash");
+        }
+        Ok(did)
+    }
+
+    /// Apply events from an [`EventListener`](crate::events::EventListener)
+    /// channel until it closes.
+    ///
+    /// Failures are logged and counted; they never stop the loop.
+    pub async fn run(self: Arc<Self>, mut rx: mpsc::Receiver<ContractEvent>) {
+        while let Some(event) = rx.recv().await {
+            if let Err(e) = self.apply(&event).await {
+                tracing::warn!(
+                    event = event.event_name(),
+                    block = event.block_number(),
+                    "Failed to apply contract event: {}",
+                    e
+                );
+            }
+        }
+        tracing::debug!("Contract event channel closed, event sink stopping");
+    }
+}
+
+/// Identify an event's log as `{tx_hash}:{log_index}`.
+fn event_id(event: &ContractEvent) -> String {
+    format!("{}:{}", event.tx_hash(), event.log_index())
+}
+
+/// Convert an on-chain amount to `u64`, saturating on overflow.
+fn saturating_u64(amount: U256) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::{AgoraMeshExtension, CapabilityCard};
+
+    const ALICE: &str = "did:agoramesh:base:alice";
+    const BOB: &str = "did:agoramesh:base:bob";
+
+    fn test_sink() -> EventSink {
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
+        let discovery = Arc::new(DiscoveryService::new());
+        EventSink::new(trust, discovery)
+    }
+
+    fn sample_card(did: &str) -> CapabilityCard {
+        CapabilityCard {
+            name: "Test Agent".to_string(),
+            description: "A test agent".to_string(),
+            url: "https://agent.example.com".to_string(),
+            provider: None,
+            skills: vec![],
+            authentication: None,
+            agoramesh: Some(AgoraMeshExtension {
+                did: did.to_string(),
+                trust_score: None,
+                stake: None,
+                pricing: None,
+                payment_methods: vec![],
+            }),
+        }
+    }
+
+    fn hash(did: &str) -> FixedBytes<32> {
+        TrustRegistryClient::did_to_hash(did)
+    }
+
+    fn stake_slashed(did: &str, amount: u64) -> ContractEvent {
+        ContractEvent::StakeSlashed {
+            did_hash: hash(did),
+            amount: U256::from(amount),
+            reason: FixedBytes::ZERO,
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        }
+    }
+
+    fn endorsement_added(endorser: &str, endorsee: &str) -> ContractEvent {
+        ContractEvent::EndorsementAdded {
+            endorser: hash(endorser),
+            endorsee: hash(endorsee),
+            message: "great work".to_string(),
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        }
+    }
+
+    // ========== TDD Tests: DID resolution ==========
+
+    #[test]
+    fn test_resolve_indexed_did() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+
+        // Act
+        let did = sink.resolve(&hash(ALICE)).unwrap();
+
+        // Assert
+        assert_eq!(did.as_deref(), Some(ALICE));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_uses_discovery_and_trust_indexes() {
+        // Arrange
+        let sink = test_sink();
+        sink.discovery.register(&sample_card(ALICE)).await.unwrap();
+        sink.trust.seed_trust_data(BOB, 0, 1, 0, 0);
+
+        // Act & Assert
+        assert_eq!(sink.resolve(&hash(ALICE)).unwrap().as_deref(), Some(ALICE));
+        assert_eq!(sink.resolve(&hash(BOB)).unwrap().as_deref(), Some(BOB));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_did_hash_is_counted_and_skipped() {
+        // Arrange
+        let sink = test_sink();
+
+        // Act
+        let applied = sink.apply(&stake_slashed(ALICE, 100)).await.unwrap();
+
+        // Assert
+        assert!(!applied);
+        assert_eq!(sink.stats().unresolved_dids.load(Ordering::Relaxed), 1);
+        assert!(sink.trust.known_dids().is_empty());
+    }
+
+    // ========== TDD Tests: trust events ==========
+
+    #[tokio::test]
+    async fn test_stake_slash_lowers_served_trust_score() {
+        // Arrange
+        let sink = test_sink();
+        sink.trust
+            .seed_trust_data(ALICE, crate::trust::REFERENCE_STAKE, 100, 0, 0);
+        let before = sink.trust.get_trust(ALICE).await.unwrap();
+
+        // Act
+        let applied = sink
+            .apply(&stake_slashed(ALICE, crate::trust::REFERENCE_STAKE / 2))
+            .await
+            .unwrap();
+
+        // Assert
+        let after = sink.trust.get_trust(ALICE).await.unwrap();
+        assert!(applied);
+        assert_eq!(after.stake_amount, crate::trust::REFERENCE_STAKE / 2);
+        assert!(after.score < before.score, "Slashing must lower the score");
+        assert_eq!(sink.stats().events_applied.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stake_deposit_adds_to_stake() {
+        // Arrange
+        let sink = test_sink();
+        sink.trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+        let event = ContractEvent::StakeDeposited {
+            did_hash: hash(ALICE),
+            amount: U256::from(500u64),
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        sink.apply(&event).await.unwrap();
+
+        // Assert
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 1_500);
+    }
+
+    #[tokio::test]
+    async fn test_replayed_stake_deposit_is_counted_once() {
+        // Arrange
+        let sink = test_sink();
+        sink.trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+        let deposit = |log_index| ContractEvent::StakeDeposited {
+            did_hash: hash(ALICE),
+            amount: U256::from(500u64),
+            block_number: 1,
+            tx_hash: FixedBytes::repeat_byte(0xAB),
+            log_index,
+        };
+
+        // Act
+        let first = sink.apply(&deposit(0)).await.unwrap();
+        let replayed = sink.apply(&deposit(0)).await.unwrap();
+        let second_log = sink.apply(&deposit(1)).await.unwrap();
+
+        // Assert
+        assert!(first);
+        assert!(!replayed);
+        assert!(second_log);
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 2_000);
+    }
+
+    #[tokio::test]
+    async fn test_stake_replay_after_restart_is_skipped() {
+        use crate::persistence::{MemoryStore, TrustDataStore};
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let trust = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_store(store.clone());
+        trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+        let sink = EventSink::new(Arc::new(trust), Arc::new(DiscoveryService::new()));
+        sink.apply(&stake_slashed(ALICE, 400)).await.unwrap();
+
+        let restored =
+            TrustService::new("https://sepolia.base.org".to_string(), None).with_store(store);
+        restored.load_from_store().unwrap();
+        let sink = EventSink::new(Arc::new(restored), Arc::new(DiscoveryService::new()));
+
+        // Act
+        let applied = sink.apply(&stake_slashed(ALICE, 400)).await.unwrap();
+
+        // Assert
+        assert!(!applied);
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 600);
+    }
+
+    #[tokio::test]
+    async fn test_reputation_update_replaces_transaction_counts() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        let event = ContractEvent::ReputationUpdated {
+            did_hash: hash(ALICE),
+            new_score: 8_000,
+            total_transactions: 50,
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        sink.apply(&event).await.unwrap();
+
+        // Assert
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.successful_transactions, 40);
+        assert_eq!(trust.failed_transactions, 10);
+    }
+
+    #[tokio::test]
+    async fn test_reputation_update_rejects_out_of_range_score() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        let event = ContractEvent::ReputationUpdated {
+            did_hash: hash(ALICE),
+            new_score: 10_001,
+            total_transactions: 1,
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        let result = sink.apply(&event).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(sink.stats().apply_errors.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_endorsement_added_is_idempotent() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        sink.index_did(BOB);
+        let event = endorsement_added(ALICE, BOB);
+
+        // Act
+        let first = sink.apply(&event).await.unwrap();
+        let replayed = sink.apply(&event).await.unwrap();
+
+        // Assert
+        assert!(first);
+        assert!(!replayed);
+        let trust = sink.trust.get_trust(BOB).await.unwrap();
+        assert_eq!(trust.endorsement_count, 1);
+        assert!(sink.trust.has_endorsement(ALICE, BOB));
+    }
+
+    #[tokio::test]
+    async fn test_endorsement_revoked_removes_endorsement() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        sink.index_did(BOB);
+        sink.apply(&endorsement_added(ALICE, BOB)).await.unwrap();
+        let event = ContractEvent::EndorsementRevoked {
+            endorser: hash(ALICE),
+            endorsee: hash(BOB),
+            block_number: 2,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        let applied = sink.apply(&event).await.unwrap();
+
+        // Assert
+        assert!(applied);
+        assert!(!sink.trust.has_endorsement(ALICE, BOB));
+        let trust = sink.trust.get_trust(BOB).await.unwrap();
+        assert_eq!(trust.endorsement_count, 0);
+    }
+
+    // ========== TDD Tests: discovery events ==========
+
+    #[tokio::test]
+    async fn test_agent_deactivated_invalidates_card() {
+        // Arrange
+        let sink = test_sink();
+        sink.discovery.register(&sample_card(ALICE)).await.unwrap();
+        let event = ContractEvent::AgentDeactivated {
+            did_hash: hash(ALICE),
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        let applied = sink.apply(&event).await.unwrap();
+
+        // Assert
+        assert!(applied);
+        assert_eq!(sink.discovery.cache_size(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_escrow_events_are_ignored() {
+        // Arrange
+        let sink = test_sink();
+        let event = ContractEvent::EscrowFunded {
+            escrow_id: U256::from(1u64),
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        let applied = sink.apply(&event).await.unwrap();
+
+        // Assert
+        assert!(!applied);
+        assert_eq!(sink.stats().events_ignored.load(Ordering::Relaxed), 1);
+    }
+
+    // ========== TDD Tests: rolled-back events ==========
+
+    #[tokio::test]
+    async fn test_rolled_back_stake_deposit_is_undone() {
+        // Arrange
+        let sink = test_sink();
+        sink.trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+        let deposit = ContractEvent::StakeDeposited {
+            did_hash: hash(ALICE),
+            amount: U256::from(500u64),
+            block_number: 7,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+        sink.apply(&deposit).await.unwrap();
+
+        // Act
+        let applied = sink
+            .apply(&ContractEvent::RolledBack(Box::new(deposit)))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(applied);
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_rolled_back_stake_slash_that_was_never_applied_is_ignored() {
+        // Arrange
+        let sink = test_sink();
+        sink.trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+
+        // Act
+        let applied = sink
+            .apply(&ContractEvent::RolledBack(Box::new(stake_slashed(
+                ALICE, 400,
+            ))))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!applied);
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_rolled_back_endorsement_is_removed() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        sink.index_did(BOB);
+        let added = endorsement_added(ALICE, BOB);
+        sink.apply(&added).await.unwrap();
+
+        // Act
+        let applied = sink
+            .apply(&ContractEvent::RolledBack(Box::new(added)))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(applied);
+        assert!(!sink.trust.has_endorsement(ALICE, BOB));
+    }
+
+    #[tokio::test]
+    async fn test_rolled_back_reputation_update_is_ignored() {
+        // Arrange
+        let sink = test_sink();
+        sink.index_did(ALICE);
+        let update = ContractEvent::ReputationUpdated {
+            did_hash: hash(ALICE),
+            new_score: 5_000,
+            total_transactions: 10,
+            block_number: 1,
+            tx_hash: FixedBytes::ZERO,
+            log_index: 0,
+        };
+
+        // Act
+        let applied = sink
+            .apply(&ContractEvent::RolledBack(Box::new(update)))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!applied);
+        assert_eq!(sink.stats().events_ignored.load(Ordering::Relaxed), 1);
+    }
+
+    // ========== TDD Tests: checkpoints ==========
+
+    #[tokio::test]
+    async fn test_checkpoint_commits_block_cursor() {
+        use crate::events::BlockCheckpoint;
+        use crate::persistence::{BlockCursorStore, MemoryStore};
+
+        // Arrange
+        let sink = test_sink();
+        let store = BlockCursorStore::new(Arc::new(MemoryStore::new()));
+        let checkpoint = BlockCheckpoint::new(9, store.clone(), "events:test".to_string());
+
+        // Act
+        let applied = sink
+            .apply(&ContractEvent::Checkpoint(checkpoint))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!applied);
+        assert_eq!(store.get("events:test").unwrap(), Some(9));
+        assert_eq!(sink.stats().events_ignored.load(Ordering::Relaxed), 0);
+    }
+
+    // ========== TDD Tests: run() ==========
+
+    #[tokio::test]
+    async fn test_run_applies_events_until_channel_closes() {
+        // Arrange
+        let sink = Arc::new(test_sink());
+        sink.trust.seed_trust_data(ALICE, 1_000, 0, 0, 0);
+        let (tx, rx) = mpsc::channel(8);
+        tx.send(stake_slashed(ALICE, 400)).await.unwrap();
+        tx.send(stake_slashed("did:agoramesh:base:nobody", 1))
+            .await
+            .unwrap();
+        drop(tx);
+
+        // Act
+        sink.clone().run(rx).await;
+
+        // Assert
+        let trust = sink.trust.get_trust(ALICE).await.unwrap();
+        assert_eq!(trust.stake_amount, 600);
+        assert_eq!(sink.stats().events_applied.load(Ordering::Relaxed), 1);
+        assert_eq!(sink.stats().unresolved_dids.load(Ordering::Relaxed), 1);
+    }
+}
@@ -6,8 +6,15 @@
 //! ## Features
 //!
 //! - **Real-time subscriptions**: WebSocket-based event streaming
+//! - **Polling fallback**: HTTP `eth_getLogs` polling when WebSocket is unavailable
 //! - **Type-safe events**: Strongly-typed event decoding via Alloy's `sol!` macro
 //! - **Automatic reconnection**: Resilient connections with exponential backoff
+//! - **Gap-free resumption**: Missed blocks are backfilled after a reconnect
+//! - **Durable cursor**: The last applied block survives restarts via
+//!   [`BlockCursorStore`], committed by the consumer through
+//!   [`ContractEvent::Checkpoint`], with chunked backfill from the deploy block
+//! - **Reorg handling**: Events from orphaned blocks within the confirmation
+//!   depth are re-emitted as [`ContractEvent::RolledBack`]
 //! - **Event filtering**: Filter by contract, event type, or topic
 //!
 //! ## Usage
@@ -34,14 +41,23 @@
 //! }
 //! ```
 
+use alloy::eips::BlockNumberOrTag;
 use alloy::primitives::{Address, FixedBytes, U256};
+use alloy::providers::{Provider, ProviderBuilder, WsConnect};
+use alloy::rpc::types::{Filter, Log};
 use alloy::sol;
+use alloy::sol_types::SolEventInterface;
+use futures::StreamExt;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
+use crate::config::BlockchainConfig;
 use crate::error::{Error, Result};
+use crate::persistence::BlockCursorStore;
 
 // ========== Event Definitions via sol! macro ==========
 
@@ -194,6 +210,49 @@ sol!(
 
 // ========== Event Types ==========
 
+/// Listener progress that a consumer acknowledges once applied.
+///
+/// The listener never writes its cursor store itself: it emits a checkpoint
+/// after the last event of each completed block range, and the consumer
+/// calls [`BlockCheckpoint::commit`] after applying everything received
+/// before it. A crash in between resumes from the previous commit and
+/// replays the range, so consumers must tolerate redelivered events.
+#[derive(Clone)]
+pub struct BlockCheckpoint {
+    block_number: u64,
+    store: BlockCursorStore,
+    key: String,
+}
+
+impl BlockCheckpoint {
+    pub(crate) fn new(block_number: u64, store: BlockCursorStore, key: String) -> Self {
+        Self {
+            block_number,
+            store,
+            key,
+        }
+    }
+
+    /// Block up to which every event has been emitted.
+    pub fn block_number(&self) -> u64 {
+        self.block_number
+    }
+
+    /// Persist the cursor so a restart resumes after this block.
+    pub fn commit(&self) -> Result<()> {
+        self.store.put(&self.key, self.block_number)
+    }
+}
+
+impl std::fmt::Debug for BlockCheckpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockCheckpoint")
+            .field("block_number", &self.block_number)
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
 /// Contract events emitted by AgoraMesh smart contracts.
 #[derive(Debug, Clone)]
 pub enum ContractEvent {
@@ -205,6 +264,7 @@ pub enum ContractEvent {
         capability_card_cid: String,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Agent capability card updated
     AgentUpdated {
@@ -212,12 +272,14 @@ pub enum ContractEvent {
         new_cid: String,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Agent deactivated
     AgentDeactivated {
         did_hash: FixedBytes<32>,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Reputation/trust score updated
     ReputationUpdated {
@@ -226,6 +288,7 @@ pub enum ContractEvent {
         total_transactions: u64,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Stake deposited
     StakeDeposited {
@@ -233,6 +296,7 @@ pub enum ContractEvent {
         amount: U256,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Stake slashed
     StakeSlashed {
@@ -241,6 +305,7 @@ pub enum ContractEvent {
         reason: FixedBytes<32>,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Endorsement added
     EndorsementAdded {
@@ -249,6 +314,7 @@ pub enum ContractEvent {
         message: String,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Endorsement revoked
     EndorsementRevoked {
@@ -256,6 +322,7 @@ pub enum ContractEvent {
         endorsee: FixedBytes<32>,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
 
     // Escrow events
@@ -269,24 +336,28 @@ pub enum ContractEvent {
         provider_did: FixedBytes<32>,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Escrow funded
     EscrowFunded {
         escrow_id: U256,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Escrow released to provider
     EscrowReleased {
         escrow_id: U256,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Escrow refunded to client
     EscrowRefunded {
         escrow_id: U256,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Dispute initiated
     DisputeInitiated {
@@ -294,6 +365,7 @@ pub enum ContractEvent {
         initiator: Address,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
     /// Dispute resolved
     DisputeResolved {
@@ -302,7 +374,18 @@ pub enum ContractEvent {
         provider_amount: U256,
         block_number: u64,
         tx_hash: FixedBytes<32>,
+        log_index: u64,
     },
+
+    // Chain reorganizations
+    /// A previously emitted event whose block was orphaned by a reorg.
+    /// Consumers should undo its effects.
+    RolledBack(Box<ContractEvent>),
+
+    // Listener progress
+    /// All events up to the checkpoint's block have been emitted (only sent
+    /// when a cursor store is attached).
+    Checkpoint(BlockCheckpoint),
 }
 
 impl ContractEvent {
@@ -323,6 +406,8 @@ impl ContractEvent {
             ContractEvent::EscrowRefunded { block_number, .. } => *block_number,
             ContractEvent::DisputeInitiated { block_number, .. } => *block_number,
             ContractEvent::DisputeResolved { block_number, .. } => *block_number,
+            ContractEvent::RolledBack(event) => event.block_number(),
+            ContractEvent::Checkpoint(checkpoint) => checkpoint.block_number(),
         }
     }
 
@@ -343,6 +428,30 @@ impl ContractEvent {
             ContractEvent::EscrowRefunded { tx_hash, .. } => *tx_hash,
             ContractEvent::DisputeInitiated { tx_hash, .. } => *tx_hash,
             ContractEvent::DisputeResolved { tx_hash, .. } => *tx_hash,
+            ContractEvent::RolledBack(event) => event.tx_hash(),
+            ContractEvent::Checkpoint(_) => FixedBytes::ZERO,
+        }
+    }
+
+    /// Get the index of this event's log within its block.
+    pub fn log_index(&self) -> u64 {
+        match self {
+            ContractEvent::AgentRegistered { log_index, .. } => *log_index,
+            ContractEvent::AgentUpdated { log_index, .. } => *log_index,
+            ContractEvent::AgentDeactivated { log_index, .. } => *log_index,
+            ContractEvent::ReputationUpdated { log_index, .. } => *log_index,
+            ContractEvent::StakeDeposited { log_index, .. } => *log_index,
+            ContractEvent::StakeSlashed { log_index, .. } => *log_index,
+            ContractEvent::EndorsementAdded { log_index, .. } => *log_index,
+            ContractEvent::EndorsementRevoked { log_index, .. } => *log_index,
+            ContractEvent::EscrowCreated { log_index, .. } => *log_index,
+            ContractEvent::EscrowFunded { log_index, .. } => *log_index,
+            ContractEvent::EscrowReleased { log_index, .. } => *log_index,
+            ContractEvent::EscrowRefunded { log_index, .. } => *log_index,
+            ContractEvent::DisputeInitiated { log_index, .. } => *log_index,
+            ContractEvent::DisputeResolved { log_index, .. } => *log_index,
+            ContractEvent::RolledBack(event) => event.log_index(),
+            ContractEvent::Checkpoint(_) => 0,
         }
     }
 
@@ -374,6 +483,140 @@ impl ContractEvent {
         )
     }
 
+    /// Decode a raw log emitted by the TrustRegistry or Escrow contract.
+    ///
+    /// Fails with `Error::Contract` if the log's signature is unknown or its
+    /// payload doesn't match the event ABI.
+    pub fn from_log(log: &Log) -> Result<Self> {
+        use EscrowEvents::EscrowEventsEvents as Escrow;
+        use TrustRegistryEvents::TrustRegistryEventsEvents as Trust;
+
+        let block_number = log.block_number.unwrap_or_default();
+        let tx_hash = log.transaction_hash.unwrap_or_default();
+        let log_index = log.log_index.unwrap_or_default();
+        let topics = log.topics();
+        let data = &log.data().data;
+
+        if let Ok(event) = Trust::decode_raw_log(topics, data) {
+            return Ok(match event {
+                Trust::AgentRegistered(e) => ContractEvent::AgentRegistered {
+                    did_hash: e.didHash,
+                    owner: e.owner,
+                    capability_card_cid: e.capabilityCardCID,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::AgentUpdated(e) => ContractEvent::AgentUpdated {
+                    did_hash: e.didHash,
+                    new_cid: e.newCID,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::AgentDeactivated(e) => ContractEvent::AgentDeactivated {
+                    did_hash: e.didHash,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::ReputationUpdated(e) => ContractEvent::ReputationUpdated {
+                    did_hash: e.didHash,
+                    new_score: e.newScore.try_into().unwrap_or(u64::MAX),
+                    total_transactions: e.totalTransactions.try_into().unwrap_or(u64::MAX),
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::StakeDeposited(e) => ContractEvent::StakeDeposited {
+                    did_hash: e.didHash,
+                    amount: e.amount,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::StakeSlashed(e) => ContractEvent::StakeSlashed {
+                    did_hash: e.didHash,
+                    amount: e.amount,
+                    reason: e.reason,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::EndorsementAdded(e) => ContractEvent::EndorsementAdded {
+                    endorser: e.endorser,
+                    endorsee: e.endorsee,
+                    message: e.message,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Trust::EndorsementRevoked(e) => ContractEvent::EndorsementRevoked {
+                    endorser: e.endorser,
+                    endorsee: e.endorsee,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+            });
+        }
+
+        if let Ok(event) = Escrow::decode_raw_log(topics, data) {
+            return Ok(match event {
+                Escrow::EscrowCreated(e) => ContractEvent::EscrowCreated {
+                    escrow_id: e.escrowId,
+                    client: e.client,
+                    provider: e.provider,
+                    amount: e.amount,
+                    client_did: e.clientDid,
+                    provider_did: e.providerDid,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Escrow::EscrowFunded(e) => ContractEvent::EscrowFunded {
+                    escrow_id: e.escrowId,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Escrow::EscrowReleased(e) => ContractEvent::EscrowReleased {
+                    escrow_id: e.escrowId,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Escrow::EscrowRefunded(e) => ContractEvent::EscrowRefunded {
+                    escrow_id: e.escrowId,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Escrow::DisputeInitiated(e) => ContractEvent::DisputeInitiated {
+                    escrow_id: e.escrowId,
+                    initiator: e.initiator,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+                Escrow::DisputeResolved(e) => ContractEvent::DisputeResolved {
+                    escrow_id: e.escrowId,
+                    released_to_provider: e.releasedToProvider,
+                    provider_amount: e.providerAmount,
+                    block_number,
+                    tx_hash,
+                    log_index,
+                },
+            });
+        }
+
+        Err(Error::Contract(format!(
+            "Unrecognized log from {} (topic0: {:?})",
+            log.address(),
+            log.topic0()
+        )))
+    }
+
     /// Get the event name.
     pub fn event_name(&self) -> &'static str {
         match self {
@@ -391,6 +634,8 @@ impl ContractEvent {
             ContractEvent::EscrowRefunded { .. } => "EscrowRefunded",
             ContractEvent::DisputeInitiated { .. } => "DisputeInitiated",
             ContractEvent::DisputeResolved { .. } => "DisputeResolved",
+            ContractEvent::RolledBack(_) => "RolledBack",
+            ContractEvent::Checkpoint(_) => "Checkpoint",
         }
     }
 }
@@ -409,6 +654,28 @@ pub struct EventListenerConfig {
     /// Escrow contract address (optional).
     pub escrow_address: Option<String>,
 
+    /// HTTP RPC URL used for `eth_getLogs` polling when the WebSocket
+    /// subscription cannot be established.
+    ///
+    /// If `ws_url` is itself an `http(s)://` URL the listener always polls.
+    pub http_url: Option<String>,
+
+    /// Interval between `eth_getLogs` polls. A WebSocket subscription is also
+    /// reconciled against `eth_getLogs` at this interval, since the transport
+    /// may resubscribe after a drop without replaying missed logs.
+    pub poll_interval: Duration,
+
+    /// Block the contracts were deployed at. Without a persisted cursor,
+    /// history is backfilled from here; `None` starts from the chain head.
+    pub deploy_block: Option<u64>,
+
+    /// Maximum block range per `eth_getLogs` request during backfill.
+    pub backfill_chunk_size: u64,
+
+    /// Blocks after which an event is considered final. Events from newer
+    /// blocks are rolled back if a reorg orphans them (0 = no reorg tracking).
+    pub confirmations: u64,
+
     /// Channel buffer size for events.
     pub channel_buffer_size: usize,
 
@@ -435,6 +702,16 @@ pub struct ReconnectConfig {
     pub max_attempts: u32,
 }
 
+impl ReconnectConfig {
+    /// Delay before the given (zero-based) reconnection attempt.
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let delay_secs =
+            self.initial_delay.as_secs_f64() * self.backoff_multiplier.powi(attempt as i32);
+        let clamped = delay_secs.min(self.max_delay.as_secs_f64());
+        Duration::from_secs_f64(clamped)
+    }
+}
+
 impl Default for ReconnectConfig {
     fn default() -> Self {
         Self {
@@ -453,6 +730,11 @@ impl Default for EventListenerConfig {
             ws_url: "wss://sepolia.base.org".to_string(),
             trust_registry_address: None,
             escrow_address: None,
+            http_url: None,
+            poll_interval: Duration::from_secs(4),
+            deploy_block: None,
+            backfill_chunk_size: 2_000,
+            confirmations: 12,
             channel_buffer_size: 1000,
             reconnect: ReconnectConfig::default(),
         }
@@ -468,6 +750,21 @@ impl EventListenerConfig {
         }
     }
 
+    /// Create a config for the contracts, deploy block and confirmation depth
+    /// of a node's `[blockchain]` section.
+    ///
+    /// An `http(s)://` RPC URL is polled with `eth_getLogs`.
+    pub fn for_chain(chain: &BlockchainConfig) -> Self {
+        Self {
+            ws_url: chain.rpc_url.clone(),
+            trust_registry_address: chain.trust_registry_address.clone(),
+            escrow_address: chain.escrow_address.clone(),
+            deploy_block: chain.deploy_block,
+            confirmations: chain.confirmations,
+            ..Default::default()
+        }
+    }
+
     /// Set the TrustRegistry contract address.
     pub fn with_trust_registry(mut self, address: impl Into<String>) -> Self {
         self.trust_registry_address = Some(address.into());
@@ -479,6 +776,48 @@ impl EventListenerConfig {
         self.escrow_address = Some(address.into());
         self
     }
+
+    /// Set the HTTP RPC URL used for polling when WebSocket is unavailable.
+    pub fn with_http_fallback(mut self, url: impl Into<String>) -> Self {
+        self.http_url = Some(url.into());
+        self
+    }
+
+    /// Set the `eth_getLogs` polling interval.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the contracts' deploy block, where backfill starts.
+    pub fn with_deploy_block(mut self, block: u64) -> Self {
+        self.deploy_block = Some(block);
+        self
+    }
+
+    /// Set the maximum block range per `eth_getLogs` backfill request.
+    pub fn with_backfill_chunk_size(mut self, blocks: u64) -> Self {
+        self.backfill_chunk_size = blocks;
+        self
+    }
+
+    /// Set the confirmation depth for reorg handling.
+    pub fn with_confirmations(mut self, blocks: u64) -> Self {
+        self.confirmations = blocks;
+        self
+    }
+
+    /// Whether `ws_url` points at a WebSocket endpoint.
+    fn is_websocket(&self) -> bool {
+        self.ws_url.starts_with("ws://") || self.ws_url.starts_with("wss://")
+    }
+
+    /// URL to poll with `eth_getLogs`, if any.
+    fn polling_url(&self) -> Option<&str> {
+        self.http_url
+            .as_deref()
+            .or_else(|| (!self.is_websocket()).then_some(self.ws_url.as_str()))
+    }
 }
 
 // ========== Event Listener Statistics ==========
@@ -496,6 +835,10 @@ pub struct EventListenerStats {
     pub reconnections: AtomicU64,
     /// Number of connection errors.
     pub connection_errors: AtomicU64,
+    /// Number of chain reorganizations detected.
+    pub reorgs: AtomicU64,
+    /// Number of events rolled back by reorganizations.
+    pub events_rolled_back: AtomicU64,
 }
 
 impl EventListenerStats {
@@ -524,6 +867,16 @@ impl EventListenerStats {
         self.connection_errors.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record a chain reorganization.
+    pub fn record_reorg(&self) {
+        self.reorgs.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an event rolled back by a reorganization.
+    pub fn record_event_rolled_back(&self) {
+        self.events_rolled_back.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Get success rate (decoded / received).
     pub fn success_rate(&self) -> f64 {
         let received = self.events_received.load(Ordering::Relaxed);
@@ -539,6 +892,9 @@ impl EventListenerStats {
 
 // ========== Event Listener ==========
 
+/// Sentinel for "no block processed yet".
+const NO_BLOCK: u64 = u64::MAX;
+
 /// Real-time event listener for AgoraMesh contracts.
 ///
 /// Subscribes to contract events via WebSocket and emits typed events
@@ -548,6 +904,9 @@ pub struct EventListener {
     stats: Arc<EventListenerStats>,
     running: Arc<AtomicBool>,
     event_tx: mpsc::Sender<ContractEvent>,
+    last_block: Arc<AtomicU64>,
+    cursor_store: Option<BlockCursorStore>,
+    task: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl EventListener {
@@ -559,6 +918,11 @@ impl EventListener {
         if config.ws_url.is_empty() {
             return Err(Error::Config("WebSocket URL is required".to_string()));
         }
+        if config.backfill_chunk_size == 0 {
+            return Err(Error::Config(
+                "Backfill chunk size must be greater than zero".to_string(),
+            ));
+        }
 
         let (event_tx, event_rx) = mpsc::channel(config.channel_buffer_size);
 
@@ -567,6 +931,9 @@ impl EventListener {
             stats: Arc::new(EventListenerStats::default()),
             running: Arc::new(AtomicBool::new(false)),
             event_tx,
+            last_block: Arc::new(AtomicU64::new(NO_BLOCK)),
+            cursor_store: None,
+            task: Mutex::new(None),
         };
 
         Ok((listener, event_rx))
@@ -581,11 +948,25 @@ impl EventListener {
             stats: Arc::new(EventListenerStats::default()),
             running: Arc::new(AtomicBool::new(false)),
             event_tx,
+            last_block: Arc::new(AtomicU64::new(NO_BLOCK)),
+            cursor_store: None,
+            task: Mutex::new(None),
         };
 
         (listener, event_rx)
     }
 
+    /// Attach a durable store for the processed-block cursor.
+    ///
+    /// On [`EventListener::start`] the listener resumes from the persisted
+    /// cursor instead of the deploy block. Advances are emitted as
+    /// [`ContractEvent::Checkpoint`]s and only written once the consumer
+    /// commits them.
+    pub fn with_cursor_store(mut self, store: BlockCursorStore) -> Self {
+        self.cursor_store = Some(store);
+        self
+    }
+
     /// Check if the listener is running.
     pub fn is_running(&self) -> bool {
         self.running.load(Ordering::SeqCst)
@@ -601,17 +982,113 @@ impl EventListener {
         &self.config
     }
 
+    /// Get the highest block whose logs have all been processed.
+    pub fn last_processed_block(&self) -> Option<u64> {
+        match self.last_block.load(Ordering::SeqCst) {
+            NO_BLOCK => None,
+            block => Some(block),
+        }
+    }
+
     /// Parse contract address from string.
-    #[allow(dead_code)]
     fn parse_address(address: &str) -> Result<Address> {
         address
             .parse::<Address>()
             .map_err(|e| Error::Config(format!("Invalid contract address: {}", e)))
     }
 
+    /// Collect the configured contract addresses to subscribe to.
+    fn contract_addresses(&self) -> Result<Vec<Address>> {
+        let addresses = [
+            self.config.trust_registry_address.as_deref(),
+            self.config.escrow_address.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(Self::parse_address)
+        .collect::<Result<Vec<_>>>()?;
+
+        if addresses.is_empty() {
+            return Err(Error::Config(
+                "No contract addresses configured for event subscription".to_string(),
+            ));
+        }
+
+        Ok(addresses)
+    }
+
+    /// Store key for the cursor of this set of contracts.
+    fn cursor_key(addresses: &[Address]) -> String {
+        let addresses: Vec<String> = addresses.iter().map(|a| a.to_string()).collect();
+        format!("events:{}", addresses.join(","))
+    }
+
+    /// Start listening for contract events in a background task.
+    ///
+    /// Opens a WebSocket log subscription, falling back to HTTP `eth_getLogs`
+    /// polling if `http_url` is configured and the WebSocket is unavailable.
+    /// Decoded events are sent to the receiver returned by [`EventListener::new`].
+    /// After a disconnect the listener waits according to the reconnect
+    /// backoff and backfills every block it missed before resuming.
+    ///
+    /// With a cursor store attached, the listener resumes after the persisted
+    /// cursor; otherwise it backfills from `deploy_block` (or starts at head).
+    pub async fn start(&self) -> Result<()> {
+        let addresses = self.contract_addresses()?;
+        let cursor_key = Self::cursor_key(&addresses);
+
+        if self.running.load(Ordering::SeqCst) {
+            return Err(Error::Config("Event listener already running".to_string()));
+        }
+
+        if let Some(ref store) = self.cursor_store {
+            if self.last_block.load(Ordering::SeqCst) == NO_BLOCK {
+                if let Some(block) = store.get(&cursor_key)? {
+                    tracing::info!(block, "Resuming contract events from persisted cursor");
+                    self.last_block.store(block, Ordering::SeqCst);
+                }
+            }
+        }
+
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Err(Error::Config("Event listener already running".to_string()));
+        }
+
+        let task = SubscriptionTask {
+            config: self.config.clone(),
+            addresses,
+            stats: self.stats.clone(),
+            running: self.running.clone(),
+            last_block: self.last_block.clone(),
+            recent: Arc::new(Mutex::new(BTreeMap::new())),
+            settled: Arc::new(AtomicU64::new(NO_BLOCK)),
+            cursor_store: self.cursor_store.clone(),
+            cursor_key,
+            event_tx: self.event_tx.clone(),
+        };
+
+        tracing::info!(
+            ws_url = %self.config.ws_url,
+            http_url = ?self.config.http_url,
+            "Starting contract event listener"
+        );
+
+        let handle = tokio::spawn(task.run());
+        if let Ok(mut slot) = self.task.lock() {
+            *slot = Some(handle);
+        }
+
+        Ok(())
+    }
+
     /// Stop the event listener.
     pub fn stop(&self) {
         self.running.store(false, Ordering::SeqCst);
+        if let Ok(mut slot) = self.task.lock() {
+            if let Some(handle) = slot.take() {
+                handle.abort();
+            }
+        }
     }
 
     /// Emit an event to the channel.
@@ -625,11 +1102,487 @@ impl EventListener {
 
     /// Calculate next reconnection delay with exponential backoff.
     pub fn calculate_reconnect_delay(&self, attempt: u32) -> Duration {
-        let config = &self.config.reconnect;
-        let delay_secs =
-            config.initial_delay.as_secs_f64() * config.backoff_multiplier.powi(attempt as i32);
-        let clamped = delay_secs.min(config.max_delay.as_secs_f64());
-        Duration::from_secs_f64(clamped)
+        self.config.reconnect.delay_for_attempt(attempt)
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// ========== Subscription Task ==========
+
+/// Identifies a log: (transaction hash, log index).
+type LogKey = (FixedBytes<32>, u64);
+
+/// A processed block that may still be deduplicated against or reorged away.
+#[derive(Debug, Default)]
+struct TrackedBlock {
+    /// Block hash as seen when the block was processed.
+    hash: Option<FixedBytes<32>>,
+    /// Logs delivered from this block, with their decoded events.
+    logs: Vec<(LogKey, Option<ContractEvent>)>,
+}
+
+/// Background task driving the subscription for a started `EventListener`.
+#[derive(Clone)]
+struct SubscriptionTask {
+    config: EventListenerConfig,
+    addresses: Vec<Address>,
+    stats: Arc<EventListenerStats>,
+    running: Arc<AtomicBool>,
+    last_block: Arc<AtomicU64>,
+    /// Recently processed blocks (unconfirmed, or newer than the previous
+    /// catch-up), used to skip overlap between backfill and live
+    /// subscription and to roll back orphaned events.
+    recent: Arc<Mutex<BTreeMap<u64, TrackedBlock>>>,
+    /// Cursor as of the previous catch-up.
+    settled: Arc<AtomicU64>,
+    cursor_store: Option<BlockCursorStore>,
+    cursor_key: String,
+    event_tx: mpsc::Sender<ContractEvent>,
+}
+
+impl SubscriptionTask {
+    /// Connect, stream, and reconnect with backoff until stopped.
+    async fn run(self) {
+        let mut attempt: u32 = 0;
+
+        while self.running.load(Ordering::SeqCst) {
+            let block_before = self.last_block.load(Ordering::SeqCst);
+
+            let error = match self.run_session().await {
+                // Clean exit: stopped or the receiver went away
+                Ok(()) => break,
+                Err(e) => e,
+            };
+
+            if !self.running.load(Ordering::SeqCst) {
+                break;
+            }
+
+            self.stats.record_connection_error();
+
+            // A session that made progress was healthy; restart the backoff
+            if self.last_block.load(Ordering::SeqCst) != block_before {
+                attempt = 0;
+            }
+
+            let reconnect = &self.config.reconnect;
+            if !reconnect.enabled
+                || (reconnect.max_attempts > 0 && attempt >= reconnect.max_attempts)
+            {
+                tracing::error!(error = %error, attempts = attempt, "Event listener giving up");
+                break;
+            }
+
+            let delay = reconnect.delay_for_attempt(attempt);
+            tracing::warn!(
+                error = %error,
+                attempt = attempt + 1,
+                delay_ms = delay.as_millis() as u64,
+                "Event subscription failed, reconnecting"
+            );
+            tokio::time::sleep(delay).await;
+
+            attempt = attempt.saturating_add(1);
+            self.stats.record_reconnection();
+        }
+
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    /// Run one connection until it fails (`Err`) or the listener is done (`Ok`).
+    async fn run_session(&self) -> Result<()> {
+        if !self.config.is_websocket() {
+            return self.run_polling().await;
+        }
+
+        match self.run_websocket().await {
+            Err(e) if self.config.polling_url().is_some() => {
+                tracing::warn!(error = %e, "WebSocket unavailable, falling back to eth_getLogs polling");
+                self.run_polling().await
+            }
+            result => result,
+        }
+    }
+
+    /// Stream logs over a WebSocket `eth_subscribe("logs")` subscription.
+    async fn run_websocket(&self) -> Result<()> {
+        // One transparent retry at most; longer outages go through our own
+        // backoff so the missed range gets backfilled.
+        let connect = WsConnect::new(self.config.ws_url.clone()).with_max_retries(1);
+        let provider = ProviderBuilder::new()
+            .connect_ws(connect)
+            .await
+            .map_err(|e| Error::Network(format!("WebSocket connection failed: {}", e)))?;
+
+        // Subscribe first so nothing lands between backfill and live stream
+        let subscription = provider
+            .subscribe_logs(&self.filter())
+            .await
+            .map_err(|e| Error::Network(format!("Log subscription failed: {}", e)))?;
+        let mut stream = subscription.into_stream();
+
+        if !self.catch_up(&provider).await? {
+            return Ok(());
+        }
+
+        let mut reconcile = tokio::time::interval(self.config.poll_interval);
+        reconcile.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        reconcile.tick().await;
+
+        loop {
+            tokio::select! {
+                log = stream.next() => {
+                    let Some(log) = log else {
+                        return Err(Error::Network(
+                            "WebSocket log subscription closed".to_string(),
+                        ));
+                    };
+                    if !self.handle_log(log).await? {
+                        return Ok(());
+                    }
+                }
+                _ = reconcile.tick() => {
+                    if !self.catch_up(&provider).await? {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Poll `eth_getLogs` over HTTP at the configured interval.
+    async fn run_polling(&self) -> Result<()> {
+        let url = self
+            .config
+            .polling_url()
+            .ok_or_else(|| Error::Config("No HTTP RPC URL for polling".to_string()))?;
+        let provider = ProviderBuilder::new().connect_http(
+            url.parse()
+                .map_err(|e| Error::Network(format!("Invalid RPC URL: {}", e)))?,
+        );
+
+        loop {
+            if !self.catch_up(&provider).await? {
+                return Ok(());
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Fetch and emit all logs between the cursor and the current head.
+    ///
+    /// Orphaned blocks are rolled back first; the range is then fetched in
+    /// `backfill_chunk_size` slices so a long outage doesn't exceed provider
+    /// limits. Returns `Ok(false)` once the listener should shut down.
+    async fn catch_up<P: Provider>(&self, provider: &P) -> Result<bool> {
+        let (head, head_hash) = self.head(provider).await?;
+
+        if !self.check_reorg(provider).await? {
+            return Ok(false);
+        }
+
+        let from = match self.last_block.load(Ordering::SeqCst) {
+            NO_BLOCK => self.config.deploy_block,
+            block => Some(block + 1),
+        };
+
+        let Some(mut from) = from else {
+            // Nothing to backfill: start tracking from the current head
+            if !self.advance_cursor(head).await {
+                return Ok(false);
+            }
+            self.checkpoint(head, head_hash)?;
+            return Ok(true);
+        };
+
+        while from <= head {
+            let to = head.min(from.saturating_add(self.config.backfill_chunk_size - 1));
+            let logs = provider
+                .get_logs(&self.filter().from_block(from).to_block(to))
+                .await
+                .map_err(|e| Error::Network(format!("eth_getLogs failed: {}", e)))?;
+
+            for log in logs {
+                if !self.handle_log(log).await? {
+                    return Ok(false);
+                }
+            }
+
+            if !self.advance_cursor(to).await {
+                return Ok(false);
+            }
+            from = to + 1;
+        }
+
+        self.checkpoint(head, head_hash)?;
+        self.prune(head)?;
+
+        Ok(self.running.load(Ordering::SeqCst))
+    }
+
+    /// Current head number, plus its hash when reorgs are tracked.
+    async fn head<P: Provider>(&self, provider: &P) -> Result<(u64, Option<FixedBytes<32>>)> {
+        if self.config.confirmations == 0 {
+            let head = provider
+                .get_block_number()
+                .await
+                .map_err(|e| Error::Network(format!("Failed to get block number: {}", e)))?;
+            return Ok((head, None));
+        }
+
+        let block = provider
+            .get_block_by_number(BlockNumberOrTag::Latest)
+            .await
+            .map_err(|e| Error::Network(format!("Failed to get latest block: {}", e)))?
+            .ok_or_else(|| Error::Network("Latest block not available".to_string()))?;
+
+        Ok((block.header.number, Some(block.header.hash)))
+    }
+
+    /// Compare tracked block hashes against the canonical chain and roll back
+    /// everything from the oldest orphaned block onward.
+    ///
+    /// Returns `Ok(false)` once the listener should shut down.
+    async fn check_reorg<P: Provider>(&self, provider: &P) -> Result<bool> {
+        if self.config.confirmations == 0 {
+            return Ok(true);
+        }
+
+        let tracked: Vec<(u64, FixedBytes<32>)> = self
+            .recent()?
+            .iter()
+            .rev()
+            .filter_map(|(number, block)| block.hash.map(|hash| (*number, hash)))
+            .collect();
+
+        // Block hashes commit to their ancestors, so the first canonical
+        // block (newest first) vouches for everything below it.
+        let mut orphaned_from = None;
+        let mut canonical_below = None;
+        for (number, hash) in tracked {
+            let canonical = provider
+                .get_block_by_number(BlockNumberOrTag::Number(number))
+                .await
+                .map_err(|e| Error::Network(format!("Failed to get block {}: {}", number, e)))?
+                .map(|block| block.header.hash);
+
+            if canonical == Some(hash) {
+                canonical_below = Some(number);
+                break;
+            }
+            orphaned_from = Some(number);
+        }
+
+        match orphaned_from {
+            Some(from) => {
+                // Untracked blocks between the last canonical block and the
+                // fork may hold logs on the new chain, so refetch from there.
+                let rewind_to = canonical_below.unwrap_or(from.saturating_sub(1));
+                self.roll_back(from, rewind_to).await
+            }
+            None => Ok(true),
+        }
+    }
+
+    /// Emit `RolledBack` for every event delivered from block `from` onward
+    /// (newest first) and rewind the cursor to `rewind_to`.
+    ///
+    /// The rewound cursor is checkpointed after the rollbacks, so it is only
+    /// committed once they were applied. Returns `Ok(false)` once the
+    /// listener should stop.
+    async fn roll_back(&self, from: u64, rewind_to: u64) -> Result<bool> {
+        let orphaned: Vec<ContractEvent> = self
+            .recent()?
+            .split_off(&from)
+            .into_values()
+            .flat_map(|block| block.logs.into_iter().filter_map(|(_, event)| event))
+            .collect();
+
+        self.stats.record_reorg();
+        let rewound = self.rewind_cursor(rewind_to);
+        tracing::warn!(
+            from_block = from,
+            events = orphaned.len(),
+            "Chain reorganization detected, rolling back events"
+        );
+
+        for event in orphaned.into_iter().rev() {
+            self.stats.record_event_rolled_back();
+            if !self.emit(ContractEvent::RolledBack(Box::new(event))).await {
+                return Ok(false);
+            }
+        }
+
+        if rewound && !self.emit_checkpoint(rewind_to).await {
+            return Ok(false);
+        }
+
+        Ok(self.running.load(Ordering::SeqCst))
+    }
+
+    /// Decode a log and forward it. Returns `Ok(false)` once the listener
+    /// should stop.
+    async fn handle_log(&self, log: Log) -> Result<bool> {
+        let block = log.block_number.unwrap_or_default();
+        let key = (
+            log.transaction_hash.unwrap_or_default(),
+            log.log_index.unwrap_or_default(),
+        );
+
+        if log.removed {
+            // The subscription reports logs from orphaned blocks explicitly
+            let delivered = self
+                .recent()?
+                .get(&block)
+                .is_some_and(|tracked| tracked.logs.iter().any(|(k, _)| *k == key));
+            if delivered {
+                return self.roll_back(block, block.saturating_sub(1)).await;
+            }
+            return Ok(self.running.load(Ordering::SeqCst));
+        }
+
+        // The same height on a different fork than the one we delivered from
+        let forked = self
+            .recent()?
+            .get(&block)
+            .and_then(|tracked| tracked.hash)
+            .zip(log.block_hash)
+            .is_some_and(|(seen, hash)| seen != hash);
+        if forked && !self.roll_back(block, block.saturating_sub(1)).await? {
+            return Ok(false);
+        }
+
+        // Backfill and subscription overlap; deliver each log once
+        let duplicate = self
+            .recent()?
+            .get(&block)
+            .is_some_and(|tracked| tracked.logs.iter().any(|(k, _)| *k == key));
+        if duplicate {
+            return Ok(self.running.load(Ordering::SeqCst));
+        }
+
+        // Logs arrive in order, so every earlier block is complete
+        if block > 0 && !self.advance_cursor(block - 1).await {
+            return Ok(false);
+        }
+
+        self.stats.record_event_received();
+        let event = match ContractEvent::from_log(&log) {
+            Ok(event) => {
+                self.stats.record_event_decoded();
+                Some(event)
+            }
+            Err(e) => {
+                self.stats.record_decode_error();
+                tracing::warn!(error = %e, "Failed to decode contract log");
+                None
+            }
+        };
+
+        {
+            let mut recent = self.recent()?;
+            let tracked = recent.entry(block).or_default();
+            if tracked.hash.is_none() {
+                tracked.hash = log.block_hash;
+            }
+            tracked.logs.push((key, event.clone()));
+        }
+
+        if let Some(event) = event {
+            if !self.emit(event).await {
+                return Ok(false);
+            }
+        }
+
+        Ok(self.running.load(Ordering::SeqCst))
+    }
+
+    /// Remember the head's hash so a reorg below it is noticed on the next pass.
+    fn checkpoint(&self, head: u64, hash: Option<FixedBytes<32>>) -> Result<()> {
+        let Some(hash) = hash else { return Ok(()) };
+        let mut recent = self.recent()?;
+        let tracked = recent.entry(head).or_default();
+        if tracked.hash.is_none() {
+            tracked.hash = Some(hash);
+        }
+        Ok(())
+    }
+
+    /// Forget blocks that are past the confirmation depth and were already
+    /// complete at the previous catch-up, so late subscription duplicates had
+    /// a full interval to arrive.
+    fn prune(&self, head: u64) -> Result<()> {
+        let settled = self
+            .settled
+            .swap(self.last_block.load(Ordering::SeqCst), Ordering::SeqCst);
+        if settled == NO_BLOCK {
+            return Ok(());
+        }
+
+        let confirmations = self.config.confirmations;
+        self.recent()?
+            .retain(|&number, _| number > settled || number + confirmations > head);
+        Ok(())
+    }
+
+    /// Lock the recently processed blocks.
+    fn recent(&self) -> Result<MutexGuard<'_, BTreeMap<u64, TrackedBlock>>> {
+        self.recent
+            .lock()
+            .map_err(|e| Error::Internal(format!("Failed to acquire recent blocks lock: {}", e)))
+    }
+
+    /// Move the processed-block cursor forward (never backward) and
+    /// checkpoint it. Returns `false` once the listener should stop.
+    async fn advance_cursor(&self, block: u64) -> bool {
+        let advanced = self
+            .last_block
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                (current == NO_BLOCK || block > current).then_some(block)
+            })
+            .is_ok();
+        !advanced || self.emit_checkpoint(block).await
+    }
+
+    /// Move the processed-block cursor back after a reorg.
+    ///
+    /// Returns whether it moved; the caller checkpoints it.
+    fn rewind_cursor(&self, block: u64) -> bool {
+        self.last_block
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                (current != NO_BLOCK && block < current).then_some(block)
+            })
+            .is_ok()
+    }
+
+    /// Emit a checkpoint for the consumer to commit, if a cursor store is
+    /// attached. Returns `false` once the listener should stop.
+    async fn emit_checkpoint(&self, block: u64) -> bool {
+        let Some(ref store) = self.cursor_store else {
+            return true;
+        };
+        let checkpoint = BlockCheckpoint::new(block, store.clone(), self.cursor_key.clone());
+        self.emit(ContractEvent::Checkpoint(checkpoint)).await
+    }
+
+    /// Send an event to the consumer. Returns `false` once the receiver is gone.
+    async fn emit(&self, event: ContractEvent) -> bool {
+        if self.event_tx.send(event).await.is_err() {
+            tracing::info!("Event receiver dropped, stopping listener");
+            self.running.store(false, Ordering::SeqCst);
+            return false;
+        }
+        true
+    }
+
+    /// Log filter for all configured contracts.
+    fn filter(&self) -> Filter {
+        Filter::new().address(self.addresses.clone())
     }
 }
 
@@ -649,6 +1602,7 @@ mod tests {
             total_transactions: 100,
             block_number: 12345,
             tx_hash: FixedBytes::ZERO,
+            log_index: 0,
         };
 
         assert_eq!(event.block_number(), 12345);
@@ -663,9 +1617,11 @@ mod tests {
             capability_card_cid: "QmTest".to_string(),
             block_number: 1,
             tx_hash,
+            log_index: 4,
         };
 
         assert_eq!(event.tx_hash(), tx_hash);
+        assert_eq!(event.log_index(), 4);
     }
 
     #[test]
@@ -677,6 +1633,7 @@ mod tests {
                 capability_card_cid: "".to_string(),
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
             ContractEvent::ReputationUpdated {
                 did_hash: FixedBytes::ZERO,
@@ -684,6 +1641,7 @@ mod tests {
                 total_transactions: 0,
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
             ContractEvent::EndorsementAdded {
                 endorser: FixedBytes::ZERO,
@@ -691,6 +1649,7 @@ mod tests {
                 message: "".to_string(),
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
         ];
 
@@ -720,11 +1679,13 @@ mod tests {
                 provider_did: FixedBytes::ZERO,
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
             ContractEvent::EscrowReleased {
                 escrow_id: U256::ZERO,
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
             ContractEvent::DisputeResolved {
                 escrow_id: U256::ZERO,
@@ -732,6 +1693,7 @@ mod tests {
                 provider_amount: U256::ZERO,
                 block_number: 0,
                 tx_hash: FixedBytes::ZERO,
+                log_index: 0,
             },
         ];
 
@@ -757,6 +1719,7 @@ mod tests {
             total_transactions: 0,
             block_number: 0,
             tx_hash: FixedBytes::ZERO,
+            log_index: 0,
         };
 
         assert_eq!(event.event_name(), "ReputationUpdated");
@@ -793,6 +1756,30 @@ mod tests {
         assert!(config.escrow_address.is_some());
     }
 
+    #[test]
+    fn test_config_for_chain() {
+        let chain = BlockchainConfig {
+            chain_id: 84532,
+            rpc_url: "https://sepolia.base.org".to_string(),
+            trust_registry_address: Some(TRUST_REGISTRY.to_string()),
+            escrow_address: None,
+            listen_events: true,
+            deploy_block: Some(1_000),
+            confirmations: 3,
+        };
+
+        let config = EventListenerConfig::for_chain(&chain);
+
+        assert_eq!(config.ws_url, "https://sepolia.base.org");
+        assert_eq!(
+            config.trust_registry_address.as_deref(),
+            Some(TRUST_REGISTRY)
+        );
+        assert!(config.escrow_address.is_none());
+        assert_eq!(config.deploy_block, Some(1_000));
+        assert_eq!(config.confirmations, 3);
+    }
+
     // ========== RED Phase: EventListenerStats Tests ==========
 
     #[test]
@@ -978,6 +1965,7 @@ mod tests {
             total_transactions: 50,
             block_number: 100,
             tx_hash: FixedBytes::ZERO,
+            log_index: 0,
         };
 
         listener.emit_event(event).await.unwrap();
@@ -998,6 +1986,7 @@ mod tests {
             escrow_id: U256::from(42),
             block_number: 200,
             tx_hash: FixedBytes::ZERO,
+            log_index: 0,
         };
 
         listener.emit_event(event).await.unwrap();
@@ -1018,6 +2007,7 @@ mod tests {
                     capability_card_cid: "".to_string(),
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1027,6 +2017,7 @@ mod tests {
                     new_cid: "".to_string(),
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1035,6 +2026,7 @@ mod tests {
                     did_hash: FixedBytes::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1045,6 +2037,7 @@ mod tests {
                     total_transactions: 0,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1054,6 +2047,7 @@ mod tests {
                     amount: U256::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1064,6 +2058,7 @@ mod tests {
                     reason: FixedBytes::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1074,6 +2069,7 @@ mod tests {
                     message: "".to_string(),
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1083,6 +2079,7 @@ mod tests {
                     endorsee: FixedBytes::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
         ];
@@ -1106,6 +2103,7 @@ mod tests {
                     provider_did: FixedBytes::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1114,6 +2112,7 @@ mod tests {
                     escrow_id: U256::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1122,6 +2121,7 @@ mod tests {
                     escrow_id: U256::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1130,6 +2130,7 @@ mod tests {
                     escrow_id: U256::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1139,6 +2140,7 @@ mod tests {
                     initiator: Address::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
             (
@@ -1149,6 +2151,7 @@ mod tests {
                     provider_amount: U256::ZERO,
                     block_number: 0,
                     tx_hash: FixedBytes::ZERO,
+                    log_index: 0,
                 },
             ),
         ];
@@ -1157,4 +2160,900 @@ mod tests {
             assert_eq!(event.event_name(), expected_name);
         }
     }
+
+    // ========== Local JSON-RPC Stand-in ==========
+    //
+    // Serves eth_blockNumber / eth_getBlockByNumber / eth_getLogs over HTTP and
+    // WebSocket, and pushes eth_subscription notifications for logs added after
+    // a client subscribes. `reorg` swaps out every block from a height onward.
+
+    use alloy::primitives::B256;
+    use alloy::sol_types::SolEvent;
+    use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+    use serde_json::{json, Value};
+    use tokio::sync::broadcast;
+
+    const TRUST_REGISTRY: &str = "0x1234567890123456789012345678901234567890";
+    const ESCROW: &str = "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd";
+
+    #[derive(Default)]
+    struct MockRpcState {
+        head: u64,
+        logs: Vec<Log>,
+        /// Number of reorgs so far and the height the latest one started at.
+        fork: u8,
+        forked_from: u64,
+        /// (fromBlock, toBlock) of every eth_getLogs request.
+        get_logs_ranges: Vec<(u64, u64)>,
+    }
+
+    impl MockRpcState {
+        fn block_hash(&self, number: u64) -> B256 {
+            if self.fork > 0 && number >= self.forked_from {
+                B256::left_padding_from(&[0xf0, self.fork, number as u8])
+            } else {
+                B256::repeat_byte(number as u8)
+            }
+        }
+
+        fn add_log(&mut self, mut log: Log) -> Log {
+            let number = log.block_number.unwrap_or_default();
+            log.block_hash = Some(self.block_hash(number));
+            self.head = self.head.max(number);
+            self.logs.push(log.clone());
+            log
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockRpcNode {
+        state: Arc<Mutex<MockRpcState>>,
+        live: broadcast::Sender<Log>,
+        kill: broadcast::Sender<()>,
+        subscribers: Arc<AtomicU64>,
+        reject_ws: Arc<AtomicBool>,
+    }
+
+    impl MockRpcNode {
+        /// Serve the stand-in on an ephemeral port; returns (http_url, ws_url).
+        async fn start() -> (String, String, Self) {
+            let node = Self {
+                state: Arc::new(Mutex::new(MockRpcState::default())),
+                live: broadcast::channel(64).0,
+                kill: broadcast::channel(4).0,
+                subscribers: Arc::new(AtomicU64::new(0)),
+                reject_ws: Arc::new(AtomicBool::new(false)),
+            };
+
+            let app = axum::Router::new()
+                .route("/", axum::routing::post(Self::http).get(Self::ws))
+                .with_state(node.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+            (format!("http://{}", addr), format!("ws://{}", addr), node)
+        }
+
+        /// Add a log, advancing the head to its block and notifying subscribers.
+        fn push_log(&self, log: Log) {
+            let log = self.state.lock().unwrap().add_log(log);
+            let _ = self.live.send(log);
+        }
+
+        /// Add a log without notifying subscribers (mined while disconnected).
+        fn push_log_silently(&self, log: Log) {
+            self.state.lock().unwrap().add_log(log);
+        }
+
+        /// Replace every block from `from` onward with a new fork, dropping
+        /// their logs.
+        fn reorg(&self, from: u64) {
+            let mut state = self.state.lock().unwrap();
+            state
+                .logs
+                .retain(|log| log.block_number.unwrap_or_default() < from);
+            state.fork += 1;
+            state.forked_from = from;
+        }
+
+        /// Notify subscribers that a previously pushed log was removed.
+        fn push_removed(&self, mut log: Log) {
+            {
+                let mut state = self.state.lock().unwrap();
+                log.block_hash = Some(state.block_hash(log.block_number.unwrap_or_default()));
+                state.logs.retain(|l| {
+                    (l.transaction_hash, l.log_index) != (log.transaction_hash, log.log_index)
+                });
+            }
+            log.removed = true;
+            let _ = self.live.send(log);
+        }
+
+        fn get_logs_ranges(&self) -> Vec<(u64, u64)> {
+            self.state.lock().unwrap().get_logs_ranges.clone()
+        }
+
+        fn set_head(&self, head: u64) {
+            self.state.lock().unwrap().head = head;
+        }
+
+        /// Close every open WebSocket connection.
+        fn drop_connections(&self) {
+            let _ = self.kill.send(());
+        }
+
+        /// Refuse (or accept again) new WebSocket connections.
+        fn set_reject_ws(&self, reject: bool) {
+            self.reject_ws.store(reject, Ordering::SeqCst);
+        }
+
+        fn subscriber_count(&self) -> u64 {
+            self.subscribers.load(Ordering::SeqCst)
+        }
+
+        async fn http(
+            axum::extract::State(node): axum::extract::State<Self>,
+            axum::Json(request): axum::Json<Value>,
+        ) -> axum::Json<Value> {
+            match request {
+                Value::Array(batch) => axum::Json(Value::Array(
+                    batch.iter().map(|r| node.respond(r)).collect(),
+                )),
+                single => axum::Json(node.respond(&single)),
+            }
+        }
+
+        async fn ws(
+            axum::extract::State(node): axum::extract::State<Self>,
+            upgrade: WebSocketUpgrade,
+        ) -> axum::response::Response {
+            use axum::response::IntoResponse;
+
+            if node.reject_ws.load(Ordering::SeqCst) {
+                return axum::http::StatusCode::SERVICE_UNAVAILABLE.into_response();
+            }
+            upgrade.on_upgrade(move |socket| node.serve_socket(socket))
+        }
+
+        async fn serve_socket(self, mut socket: WebSocket) {
+            let mut live = self.live.subscribe();
+            let mut kill = self.kill.subscribe();
+            let mut subscription: Option<&str> = None;
+
+            loop {
+                tokio::select! {
+                    message = socket.recv() => {
+                        let Some(Ok(Message::Text(text))) = message else { break };
+                        let request: Value = serde_json::from_str(&text).unwrap();
+                        let response = if request["method"] == "eth_subscribe" {
+                            subscription = Some("0x1");
+                            self.subscribers.fetch_add(1, Ordering::SeqCst);
+                            json!({"jsonrpc": "2.0", "id": request["id"], "result": "0x1"})
+                        } else {
+                            self.respond(&request)
+                        };
+                        if socket.send(Message::Text(response.to_string().into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(log) = live.recv() => {
+                        let Some(id) = subscription else { continue };
+                        let notification = json!({
+                            "jsonrpc": "2.0",
+                            "method": "eth_subscription",
+                            "params": {"subscription": id, "result": log}
+                        });
+                        if socket.send(Message::Text(notification.to_string().into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    _ = kill.recv() => break,
+                }
+            }
+
+            if subscription.is_some() {
+                self.subscribers.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+
+        fn respond(&self, request: &Value) -> Value {
+            let mut state = self.state.lock().unwrap();
+            let result = match request["method"].as_str().unwrap_or_default() {
+                "eth_chainId" => json!("0x7a69"),
+                "eth_blockNumber" => json!(format!("{:#x}", state.head)),
+                "eth_getBlockByNumber" => {
+                    let number = match request["params"][0].as_str().unwrap_or_default() {
+                        "latest" => Some(state.head),
+                        tag => u64::from_str_radix(tag.trim_start_matches("0x"), 16).ok(),
+                    };
+                    match number.filter(|n| *n <= state.head) {
+                        Some(number) => {
+                            let header = alloy::rpc::types::Header {
+                                hash: state.block_hash(number),
+                                inner: alloy::consensus::Header {
+                                    number,
+                                    ..Default::default()
+                                },
+                                total_difficulty: None,
+                                size: None,
+                            };
+                            json!(
+                                alloy::rpc::types::Block::<alloy::rpc::types::Transaction>::empty(
+                                    header
+                                )
+                            )
+                        }
+                        None => Value::Null,
+                    }
+                }
+                "eth_getLogs" => {
+                    let filter = &request["params"][0];
+                    let block = |key: &str| {
+                        filter[key]
+                            .as_str()
+                            .and_then(|b| u64::from_str_radix(b.trim_start_matches("0x"), 16).ok())
+                    };
+                    let from = block("fromBlock").unwrap_or(0);
+                    let to = block("toBlock").unwrap_or(state.head);
+                    state.get_logs_ranges.push((from, to));
+                    let logs: Vec<&Log> = state
+                        .logs
+                        .iter()
+                        .filter(|log| {
+                            let number = log.block_number.unwrap_or_default();
+                            number >= from && number <= to
+                        })
+                        .collect();
+                    json!(logs)
+                }
+                other => {
+                    return json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": {"code": -32601, "message": format!("method not found: {}", other)}
+                    })
+                }
+            };
+
+            json!({"jsonrpc": "2.0", "id": request["id"], "result": result})
+        }
+    }
+
+    /// Build an RPC log for a contract event.
+    fn rpc_log<E: SolEvent>(address: &str, event: &E, block: u64, log_index: u64) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: address.parse().unwrap(),
+                data: event.encode_log_data(),
+            },
+            block_hash: Some(B256::repeat_byte(block as u8)),
+            block_number: Some(block),
+            block_timestamp: None,
+            transaction_hash: Some(B256::left_padding_from(&[block as u8, log_index as u8])),
+            transaction_index: Some(0),
+            log_index: Some(log_index),
+            removed: false,
+        }
+    }
+
+    fn reputation_log(block: u64, log_index: u64, score: u64) -> Log {
+        let event = TrustRegistryEvents::ReputationUpdated {
+            didHash: FixedBytes::repeat_byte(0x11),
+            newScore: U256::from(score),
+            totalTransactions: U256::from(10),
+        };
+        rpc_log(TRUST_REGISTRY, &event, block, log_index)
+    }
+
+    /// Listener config with fast polling and reconnects for tests.
+    fn fast_config(url: &str) -> EventListenerConfig {
+        EventListenerConfig {
+            poll_interval: Duration::from_millis(20),
+            reconnect: ReconnectConfig {
+                initial_delay: Duration::from_millis(20),
+                max_delay: Duration::from_millis(100),
+                ..Default::default()
+            },
+            ..EventListenerConfig::new(url)
+        }
+        .with_trust_registry(TRUST_REGISTRY)
+    }
+
+    async fn recv_any(rx: &mut mpsc::Receiver<ContractEvent>) -> ContractEvent {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for event")
+            .expect("channel closed")
+    }
+
+    /// Next contract event, committing checkpoints along the way like a
+    /// consumer that applied everything before them.
+    async fn recv_event(rx: &mut mpsc::Receiver<ContractEvent>) -> ContractEvent {
+        loop {
+            match recv_any(rx).await {
+                ContractEvent::Checkpoint(checkpoint) => checkpoint.commit().unwrap(),
+                event => return event,
+            }
+        }
+    }
+
+    /// Commit checkpoints until one reaches `block`.
+    async fn commit_through(rx: &mut mpsc::Receiver<ContractEvent>, block: u64) {
+        loop {
+            match recv_any(rx).await {
+                ContractEvent::Checkpoint(checkpoint) => {
+                    checkpoint.commit().unwrap();
+                    if checkpoint.block_number() >= block {
+                        return;
+                    }
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+    }
+
+    async fn recv_score(rx: &mut mpsc::Receiver<ContractEvent>) -> u64 {
+        match recv_event(rx).await {
+            ContractEvent::ReputationUpdated { new_score, .. } => new_score,
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    async fn recv_rolled_back_score(rx: &mut mpsc::Receiver<ContractEvent>) -> u64 {
+        match recv_event(rx).await {
+            ContractEvent::RolledBack(event) => match *event {
+                ContractEvent::ReputationUpdated { new_score, .. } => new_score,
+                other => panic!("unexpected rolled back event: {:?}", other),
+            },
+            other => panic!("expected a rolled back event, got {:?}", other),
+        }
+    }
+
+    async fn wait_until(what: &str, mut condition: impl FnMut() -> bool) {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "timed out: {}",
+                what
+            );
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    // ========== TDD Tests: Log Decoding ==========
+
+    #[test]
+    fn test_from_log_decodes_trust_registry_event() {
+        let log = reputation_log(42, 3, 8_750);
+
+        let event = ContractEvent::from_log(&log).unwrap();
+
+        match event {
+            ContractEvent::ReputationUpdated {
+                did_hash,
+                new_score,
+                total_transactions,
+                block_number,
+                tx_hash,
+                log_index,
+            } => {
+                assert_eq!(did_hash, FixedBytes::repeat_byte(0x11));
+                assert_eq!(new_score, 8_750);
+                assert_eq!(total_transactions, 10);
+                assert_eq!(block_number, 42);
+                assert_eq!(tx_hash, log.transaction_hash.unwrap());
+                assert_eq!(log_index, 3);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_log_decodes_escrow_event() {
+        let client: Address = "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let provider: Address = "0x2222222222222222222222222222222222222222"
+            .parse()
+            .unwrap();
+        let created = EscrowEvents::EscrowCreated {
+            escrowId: U256::from(7),
+            client,
+            provider,
+            amount: U256::from(5_000_000),
+            clientDid: FixedBytes::repeat_byte(0xc1),
+            providerDid: FixedBytes::repeat_byte(0xb2),
+        };
+        let log = rpc_log(ESCROW, &created, 9, 0);
+
+        let event = ContractEvent::from_log(&log).unwrap();
+
+        assert!(event.is_escrow_event());
+        match event {
+            ContractEvent::EscrowCreated {
+                escrow_id,
+                client: c,
+                provider: p,
+                amount,
+                provider_did,
+                ..
+            } => {
+                assert_eq!(escrow_id, U256::from(7));
+                assert_eq!(c, client);
+                assert_eq!(p, provider);
+                assert_eq!(amount, U256::from(5_000_000));
+                assert_eq!(provider_did, FixedBytes::repeat_byte(0xb2));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_log_rejects_unknown_signature() {
+        let mut log = reputation_log(1, 0, 1);
+        log.inner.data = alloy::primitives::LogData::new_unchecked(
+            vec![B256::repeat_byte(0xee)],
+            Default::default(),
+        );
+
+        let result = ContractEvent::from_log(&log);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Unrecognized log"));
+    }
+
+    // ========== TDD Tests: EventListener::start() ==========
+
+    #[tokio::test]
+    async fn test_start_requires_contract_address() {
+        let (listener, _rx) = EventListener::new(EventListenerConfig::default()).unwrap();
+
+        let result = listener.start().await;
+
+        assert!(result.is_err());
+        assert!(!listener.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_start_rejects_invalid_contract_address() {
+        let config = EventListenerConfig::default().with_trust_registry("not-an-address");
+        let (listener, _rx) = EventListener::new(config).unwrap();
+
+        let result = listener.start().await;
+
+        assert!(result.is_err());
+        assert!(!listener.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_start_twice_fails() {
+        let (http_url, _ws_url, _node) = MockRpcNode::start().await;
+        let (listener, _rx) = EventListener::new(fast_config(&http_url)).unwrap();
+
+        listener.start().await.unwrap();
+        let second = listener.start().await;
+
+        assert!(second.is_err());
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_polling_backfills_from_deploy_block_and_tracks_cursor() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log(reputation_log(3, 0, 100));
+        node.push_log(reputation_log(5, 0, 200));
+        node.set_head(6);
+
+        let config = fast_config(&http_url).with_deploy_block(1);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+
+        assert_eq!(recv_score(&mut rx).await, 100);
+        assert_eq!(recv_score(&mut rx).await, 200);
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(6)
+        })
+        .await;
+
+        // New block shows up on the next poll
+        node.push_log(reputation_log(8, 0, 300));
+        node.set_head(9);
+
+        assert_eq!(recv_score(&mut rx).await, 300);
+        wait_until("cursor advanced", || {
+            listener.last_processed_block() == Some(9)
+        })
+        .await;
+        assert_eq!(listener.stats().events_decoded.load(Ordering::Relaxed), 3);
+
+        listener.stop();
+        assert!(!listener.is_running());
+    }
+
+    #[tokio::test]
+    async fn test_polling_without_deploy_block_begins_at_head() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log(reputation_log(4, 0, 100));
+
+        let (listener, mut rx) = EventListener::new(fast_config(&http_url)).unwrap();
+        listener.start().await.unwrap();
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(4)
+        })
+        .await;
+
+        node.push_log(reputation_log(5, 0, 200));
+
+        // Historic log at block 4 is skipped; only the new one arrives
+        assert_eq!(recv_score(&mut rx).await, 200);
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_log_counts_decode_error() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        let mut bad = reputation_log(2, 0, 1);
+        bad.inner.data = alloy::primitives::LogData::new_unchecked(
+            vec![B256::repeat_byte(0xee)],
+            Default::default(),
+        );
+        node.push_log(bad);
+        node.push_log(reputation_log(2, 1, 500));
+
+        let (listener, mut rx) =
+            EventListener::new(fast_config(&http_url).with_deploy_block(0)).unwrap();
+        listener.start().await.unwrap();
+
+        assert_eq!(recv_score(&mut rx).await, 500);
+        assert_eq!(listener.stats().events_received.load(Ordering::Relaxed), 2);
+        assert_eq!(listener.stats().decode_errors.load(Ordering::Relaxed), 1);
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_subscription_streams_live_logs() {
+        let (_http_url, ws_url, node) = MockRpcNode::start().await;
+        node.set_head(10);
+
+        let mut config = fast_config(&ws_url);
+        config.poll_interval = Duration::from_secs(60);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+        wait_until("subscribed", || node.subscriber_count() == 1).await;
+
+        node.push_log(reputation_log(11, 0, 700));
+        node.push_log(reputation_log(12, 0, 800));
+
+        assert_eq!(recv_score(&mut rx).await, 700);
+        assert_eq!(recv_score(&mut rx).await, 800);
+        // Block 12 may still receive logs, so only 11 is known complete
+        assert_eq!(listener.last_processed_block(), Some(11));
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_reconnects_and_backfills_missed_blocks() {
+        let (_http_url, ws_url, node) = MockRpcNode::start().await;
+        node.set_head(20);
+
+        let mut config = fast_config(&ws_url);
+        // Keep reconciliation out of the way so the backfill comes from reconnecting
+        config.poll_interval = Duration::from_secs(60);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+        wait_until("subscribed", || node.subscriber_count() == 1).await;
+
+        node.push_log(reputation_log(21, 0, 1));
+        assert_eq!(recv_score(&mut rx).await, 1);
+
+        // Node goes away; a block is mined during the outage
+        node.set_reject_ws(true);
+        node.drop_connections();
+        wait_until("reconnecting", || {
+            listener.stats().reconnections.load(Ordering::Relaxed) >= 1
+        })
+        .await;
+        node.push_log_silently(reputation_log(22, 0, 2));
+        node.set_reject_ws(false);
+
+        // Reconnect backfills block 22, then live streaming resumes
+        assert_eq!(recv_score(&mut rx).await, 2);
+        wait_until("resubscribed", || node.subscriber_count() == 1).await;
+        node.push_log(reputation_log(23, 0, 3));
+        assert_eq!(recv_score(&mut rx).await, 3);
+
+        assert!(listener.stats().connection_errors.load(Ordering::Relaxed) >= 1);
+        assert_eq!(listener.stats().events_decoded.load(Ordering::Relaxed), 3);
+        assert!(listener.is_running());
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_reconciles_logs_missed_by_subscription() {
+        let (_http_url, ws_url, node) = MockRpcNode::start().await;
+        node.set_head(30);
+
+        let (listener, mut rx) = EventListener::new(fast_config(&ws_url)).unwrap();
+        listener.start().await.unwrap();
+        wait_until("subscribed", || node.subscriber_count() == 1).await;
+
+        // A log that never reaches the subscription
+        node.push_log_silently(reputation_log(31, 0, 9));
+
+        assert_eq!(recv_score(&mut rx).await, 9);
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(31)
+        })
+        .await;
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_failure_falls_back_to_polling() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log(reputation_log(2, 0, 42));
+
+        // Nothing listens on port 1
+        let config = fast_config("ws://127.0.0.1:1")
+            .with_http_fallback(http_url)
+            .with_deploy_block(0);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+
+        assert_eq!(recv_score(&mut rx).await, 42);
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_listener_gives_up_after_max_attempts() {
+        let mut config = fast_config("ws://127.0.0.1:1");
+        config.reconnect.max_attempts = 2;
+        let (listener, _rx) = EventListener::new(config).unwrap();
+
+        listener.start().await.unwrap();
+        wait_until("listener stopped", || !listener.is_running()).await;
+
+        // Initial attempt plus two retries
+        assert_eq!(
+            listener.stats().connection_errors.load(Ordering::Relaxed),
+            3
+        );
+        assert_eq!(listener.stats().reconnections.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_listener_stops_when_receiver_dropped() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log(reputation_log(1, 0, 1));
+
+        let (listener, rx) =
+            EventListener::new(fast_config(&http_url).with_deploy_block(0)).unwrap();
+        drop(rx);
+        listener.start().await.unwrap();
+
+        wait_until("listener stopped", || !listener.is_running()).await;
+    }
+
+    // ========== TDD Tests: Durable Cursor & Backfill ==========
+
+    #[test]
+    fn test_new_rejects_zero_backfill_chunk_size() {
+        let config = EventListenerConfig::default().with_backfill_chunk_size(0);
+
+        let result = EventListener::new(config);
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_is_split_into_chunks() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        for (block, score) in [(1, 10), (3, 30), (5, 50), (7, 70)] {
+            node.push_log_silently(reputation_log(block, 0, score));
+        }
+        node.set_head(8);
+
+        let config = fast_config(&http_url)
+            .with_deploy_block(0)
+            .with_backfill_chunk_size(3)
+            .with_confirmations(0);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+
+        for expected in [10, 30, 50, 70] {
+            assert_eq!(recv_score(&mut rx).await, expected);
+        }
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(8)
+        })
+        .await;
+        listener.stop();
+
+        let ranges = node.get_logs_ranges();
+        assert_eq!(&ranges[..3], &[(0, 2), (3, 5), (6, 8)]);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_logs_are_delivered_once() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log_silently(reputation_log(2, 0, 100));
+        node.push_log_silently(reputation_log(2, 0, 100));
+        node.push_log_silently(reputation_log(2, 1, 200));
+        node.set_head(3);
+
+        let (listener, mut rx) =
+            EventListener::new(fast_config(&http_url).with_deploy_block(0)).unwrap();
+        listener.start().await.unwrap();
+
+        assert_eq!(recv_score(&mut rx).await, 100);
+        assert_eq!(recv_score(&mut rx).await, 200);
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(3)
+        })
+        .await;
+        listener.stop();
+
+        assert!(rx.try_recv().is_err(), "duplicate log was delivered");
+        assert_eq!(listener.stats().events_received.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_persists_across_restart() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        let store = BlockCursorStore::new(Arc::new(crate::persistence::MemoryStore::new()));
+        node.push_log_silently(reputation_log(3, 0, 100));
+        node.set_head(5);
+
+        let config = fast_config(&http_url).with_deploy_block(0);
+        {
+            let (listener, mut rx) = EventListener::new(config.clone()).unwrap();
+            let listener = listener.with_cursor_store(store.clone());
+            listener.start().await.unwrap();
+
+            assert_eq!(recv_score(&mut rx).await, 100);
+            commit_through(&mut rx, 5).await;
+        }
+
+        // Mined while the node was down
+        node.push_log_silently(reputation_log(7, 0, 200));
+        node.set_head(8);
+
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        let listener = listener.with_cursor_store(store);
+        listener.start().await.unwrap();
+
+        // Resumes after block 5 instead of replaying from the deploy block
+        assert_eq!(recv_score(&mut rx).await, 200);
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_uncommitted_block_is_replayed_after_restart() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        let store = BlockCursorStore::new(Arc::new(crate::persistence::MemoryStore::new()));
+        node.push_log_silently(reputation_log(3, 0, 100));
+        node.push_log_silently(reputation_log(4, 0, 200));
+        node.set_head(5);
+
+        let config = fast_config(&http_url).with_deploy_block(0);
+        let cursor_key = {
+            let (listener, mut rx) = EventListener::new(config.clone()).unwrap();
+            let listener = listener.with_cursor_store(store.clone());
+            listener.start().await.unwrap();
+
+            // Block 3 is applied and committed; the node stops before
+            // applying block 4
+            assert_eq!(recv_score(&mut rx).await, 100);
+            match recv_any(&mut rx).await {
+                ContractEvent::Checkpoint(checkpoint) => {
+                    assert_eq!(checkpoint.block_number(), 3);
+                    checkpoint.commit().unwrap();
+                }
+                other => panic!("expected a checkpoint, got {:?}", other),
+            }
+            assert_eq!(recv_score(&mut rx).await, 200);
+            wait_until("cursor at head", || {
+                listener.last_processed_block() == Some(5)
+            })
+            .await;
+            EventListener::cursor_key(&listener.contract_addresses().unwrap())
+        };
+        assert_eq!(store.get(&cursor_key).unwrap(), Some(3));
+
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        let listener = listener.with_cursor_store(store);
+        listener.start().await.unwrap();
+
+        assert_eq!(recv_score(&mut rx).await, 200);
+        listener.stop();
+    }
+
+    // ========== TDD Tests: Reorg Handling ==========
+
+    #[tokio::test]
+    async fn test_reorg_rolls_back_orphaned_events() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log_silently(reputation_log(3, 0, 100));
+        node.push_log_silently(reputation_log(4, 0, 200));
+        node.set_head(6);
+
+        let (listener, mut rx) =
+            EventListener::new(fast_config(&http_url).with_deploy_block(0)).unwrap();
+        listener.start().await.unwrap();
+        assert_eq!(recv_score(&mut rx).await, 100);
+        assert_eq!(recv_score(&mut rx).await, 200);
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(6)
+        })
+        .await;
+
+        // Block 4 onward is replaced by a fork carrying a different log
+        node.reorg(4);
+        node.push_log_silently(reputation_log(4, 0, 300));
+        node.set_head(7);
+
+        assert_eq!(recv_rolled_back_score(&mut rx).await, 200);
+        assert_eq!(recv_score(&mut rx).await, 300);
+        assert_eq!(listener.stats().reorgs.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            listener.stats().events_rolled_back.load(Ordering::Relaxed),
+            1
+        );
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_events_past_confirmation_depth_are_final() {
+        let (http_url, _ws_url, node) = MockRpcNode::start().await;
+        node.push_log_silently(reputation_log(3, 0, 100));
+        node.set_head(10);
+
+        let config = fast_config(&http_url)
+            .with_deploy_block(0)
+            .with_confirmations(2);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+        assert_eq!(recv_score(&mut rx).await, 100);
+        wait_until("cursor at head", || {
+            listener.last_processed_block() == Some(10)
+        })
+        .await;
+
+        // Let a few more polls settle block 3 past the confirmation depth
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // A reorg deeper than the confirmation depth is not rolled back
+        node.reorg(3);
+        node.push_log_silently(reputation_log(12, 0, 500));
+
+        assert_eq!(recv_score(&mut rx).await, 500);
+        assert_eq!(
+            listener.stats().events_rolled_back.load(Ordering::Relaxed),
+            0
+        );
+        listener.stop();
+    }
+
+    #[tokio::test]
+    async fn test_websocket_removed_log_rolls_back_event() {
+        let (_http_url, ws_url, node) = MockRpcNode::start().await;
+        node.set_head(10);
+
+        let mut config = fast_config(&ws_url);
+        config.poll_interval = Duration::from_secs(60);
+        let (listener, mut rx) = EventListener::new(config).unwrap();
+        listener.start().await.unwrap();
+        wait_until("subscribed", || node.subscriber_count() == 1).await;
+
+        node.push_log(reputation_log(11, 0, 700));
+        assert_eq!(recv_score(&mut rx).await, 700);
+
+        node.push_removed(reputation_log(11, 0, 700));
+
+        assert_eq!(recv_rolled_back_score(&mut rx).await, 700);
+        assert_eq!(listener.last_processed_block(), Some(10));
+        listener.stop();
+    }
 }
@@ -0,0 +1,337 @@
+//! Persistent node identity.
+//!
+//! The node's Ed25519 keypair is kept in `IdentityConfig::key_file` so its
+//! PeerId (and therefore bootstrap lists pointing at it) survives restarts.
+//! The same key backs the node's own `did:agoramesh` and signs its gossip.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use libp2p::identity::{ed25519, Keypair};
+use libp2p::PeerId;
+
+use crate::did::{DIDDocument, DIDDocumentBuilder};
+use crate::error::{Error, Result};
+use crate::multichain::get_chain_info;
+use crate::network::envelope::{encode_ed25519_multibase, EnvelopeSigner};
+
+/// Fragment of the node key's verification method in its DID document.
+pub const NODE_KEY_FRAGMENT: &str = "key-1";
+
+/// The node's long-lived identity keypair.
+#[derive(Clone)]
+pub struct NodeIdentity {
+    keypair: ed25519::Keypair,
+}
+
+impl std::fmt::Debug for NodeIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeIdentity")
+            .field("peer_id", &self.peer_id())
+            .finish_non_exhaustive()
+    }
+}
+
+impl NodeIdentity {
+    /// Generate a fresh identity.
+    pub fn generate() -> Self {
+        Self {
+            keypair: ed25519::Keypair::generate(),
+        }
+    }
+
+    /// Wrap an existing Ed25519 keypair.
+    pub fn from_keypair(keypair: ed25519::Keypair) -> Self {
+        Self { keypair }
+    }
+
+    /// Load the identity stored at `path`.
+    ///
+    /// The file holds the libp2p protobuf encoding of an Ed25519 keypair.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| {
+            Error::Config(format!("Failed to read key file {}: {}", path.display(), e))
+        })?;
+        warn_if_exposed(path);
+
+        let keypair = Keypair::from_protobuf_encoding(&bytes)
+            .map_err(|e| Error::Config(format!("Invalid key file {}: {}", path.display(), e)))?
+            .try_into_ed25519()
+            .map_err(|_| {
+                Error::Config(format!(
+                    "Key file {} does not hold an Ed25519 key",
+                    path.display()
+                ))
+            })?;
+
+        Ok(Self { keypair })
+    }
+
+    /// Write the identity to a new file at `path`, readable only by the owner.
+    ///
+    /// Fails if the file already exists so a key is never overwritten.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        let bytes = Keypair::from(self.keypair.clone())
+            .to_protobuf_encoding()
+            .map_err(|e| Error::Internal(format!("Failed to encode node key: {}", e)))?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to create key file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    /// Write the identity to `path`, atomically replacing any existing key.
+    ///
+    /// The key is written to a temporary file next to `path` and renamed
+    /// over it, so a failure leaves the old key in place.
+    pub fn replace<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| Error::Config(format!("Invalid key file path {}", path.display())))?;
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(file_name);
+        temp_name.push(".tmp");
+        let temp = path.with_file_name(temp_name);
+
+        // A leftover from an interrupted run is never a live key
+        if temp.exists() {
+            fs::remove_file(&temp)?;
+        }
+        self.save(&temp)?;
+        fs::rename(&temp, path).map_err(|e| {
+            let _ = fs::remove_file(&temp);
+            Error::Config(format!(
+                "Failed to replace key file {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Load the identity at `path`, generating and saving one if missing.
+    pub fn load_or_generate<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Self::load(path);
+        }
+
+        let identity = Self::generate();
+        identity.save(path)?;
+        tracing::info!(
+            "Generated node key {} (peer ID {})",
+            path.display(),
+            identity.peer_id()
+        );
+        Ok(identity)
+    }
+
+    /// The libp2p keypair, for building the swarm.
+    pub fn keypair(&self) -> Keypair {
+        Keypair::from(self.keypair.clone())
+    }
+
+    /// The Ed25519 public key.
+    pub fn public_key(&self) -> ed25519::PublicKey {
+        self.keypair.public()
+    }
+
+    /// The node's PeerId.
+    pub fn peer_id(&self) -> PeerId {
+        PeerId::from_public_key(&self.keypair().public())
+    }
+
+    /// The node's `did:agoramesh` on `chain_id`: `did:agoramesh:{chain}:{peer_id}`.
+    pub fn did(&self, chain_id: u64) -> String {
+        format!(
+            "did:agoramesh:{}:{}",
+            did_chain_name(chain_id),
+            self.peer_id()
+        )
+    }
+
+    /// The node's DID document, listing the node key as `#key-1`.
+    pub fn did_document(&self, chain_id: u64) -> Result<DIDDocument> {
+        DIDDocumentBuilder::new(&did_chain_name(chain_id), &self.peer_id().to_string())
+            .add_ed25519_key(
+                NODE_KEY_FRAGMENT,
+                &encode_ed25519_multibase(&self.public_key()),
+            )
+            .chain_id(chain_id)
+            .build()
+    }
+
+    /// Signer for gossip envelopes sent as `did`.
+    pub fn envelope_signer(&self, did: &str) -> EnvelopeSigner {
+        EnvelopeSigner::ed25519(did, NODE_KEY_FRAGMENT, self.keypair.clone())
+    }
+}
+
+/// Chain segment used in `did:agoramesh` identifiers for `chain_id`.
+///
+/// Mainnets use the bare network name (`base`, `polygon`), testnets append
+/// theirs (`base-sepolia`); unknown chains fall back to the numeric ID.
+pub fn did_chain_name(chain_id: u64) -> String {
+    match get_chain_info(chain_id) {
+        Some(info) => info
+            .name
+            .to_lowercase()
+            .trim_end_matches(" mainnet")
+            .replace(' ', "-"),
+        None => chain_id.to_string(),
+    }
+}
+
+/// Warn when a key file is readable by anyone but its owner.
+fn warn_if_exposed(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = fs::metadata(path) {
+            let mode = metadata.permissions().mode();
+            if mode & 0o077 != 0 {
+                tracing::warn!(
+                    "Key file {} is accessible by other users (mode {:o}); run chmod 600",
+                    path.display(),
+                    mode & 0o777
+                );
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    // ========== TDD Tests: Key File ==========
+
+    #[test]
+    fn test_load_or_generate_persists_peer_id() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("keys/node.key");
+
+        // Act
+        let first = NodeIdentity::load_or_generate(&path).unwrap();
+        let second = NodeIdentity::load_or_generate(&path).unwrap();
+
+        // Assert
+        assert_eq!(first.peer_id(), second.peer_id());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_key_file_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("node.key");
+
+        NodeIdentity::generate().save(&path).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_save_refuses_to_overwrite() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("node.key");
+        let original = NodeIdentity::generate();
+        original.save(&path).unwrap();
+
+        assert!(NodeIdentity::generate().save(&path).is_err());
+        assert_eq!(
+            NodeIdentity::load(&path).unwrap().peer_id(),
+            original.peer_id()
+        );
+    }
+
+    #[test]
+    fn test_replace_overwrites_existing_key() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("node.key");
+        NodeIdentity::generate().save(&path).unwrap();
+        let replacement = NodeIdentity::generate();
+
+        // Act
+        replacement.replace(&path).unwrap();
+
+        // Assert
+        assert_eq!(
+            NodeIdentity::load(&path).unwrap().peer_id(),
+            replacement.peer_id()
+        );
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_load_rejects_garbage() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("node.key");
+        fs::write(&path, b"not a key").unwrap();
+
+        assert!(matches!(NodeIdentity::load(&path), Err(Error::Config(_))));
+    }
+
+    // ========== TDD Tests: DID ==========
+
+    #[test]
+    fn test_did_chain_names() {
+        assert_eq!(did_chain_name(8453), "base");
+        assert_eq!(did_chain_name(84532), "base-sepolia");
+        assert_eq!(did_chain_name(31337), "31337");
+    }
+
+    #[test]
+    fn test_did_document_lists_node_key() {
+        let identity = NodeIdentity::generate();
+
+        let document = identity.did_document(8453).unwrap();
+
+        assert_eq!(document.id, identity.did(8453));
+        assert!(document.validate().is_ok());
+        let method = document.get_verification_method(NODE_KEY_FRAGMENT).unwrap();
+        assert_eq!(
+            method.public_key_multibase.as_deref(),
+            Some(encode_ed25519_multibase(&identity.public_key()).as_str())
+        );
+    }
+
+    #[test]
+    fn test_envelope_signer_uses_node_key() {
+        let identity = NodeIdentity::generate();
+        let did = identity.did(8453);
+
+        let signer = identity.envelope_signer(&did);
+
+        assert_eq!(signer.key_id(), format!("{}#{}", did, NODE_KEY_FRAGMENT));
+    }
+}
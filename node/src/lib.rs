@@ -9,18 +9,26 @@ pub mod circuit_breaker;
 pub mod config;
 pub mod contract;
 pub mod did;
+pub mod did_resolver;
 pub mod discovery;
+pub mod eigentrust;
 pub mod error;
+pub mod event_sink;
 pub mod events;
+pub mod identity;
 pub mod metrics;
 pub mod multichain;
 pub mod network;
 pub mod persistence;
 pub mod plugin;
 pub mod rate_limit;
+pub mod reputation;
 pub mod search;
 pub mod trust;
 pub mod trust_cache;
+pub mod trust_graph;
+pub mod trust_history;
+pub mod trust_reconcile;
 
 pub use api::{ApiServer, AppState, NodeInfo};
 pub use arbitration::{
@@ -36,23 +44,37 @@ pub use circuit_breaker::{
 };
 pub use config::{ApiConfig, NetworkConfig, NodeConfig};
 pub use contract::TrustRegistryClient;
+pub use did_resolver::{
+    AgoraMeshDidResolver, DidResolver, DidWebFetcher, KeyDidResolver, PeerDidResolver,
+    UniversalResolver, WebDidResolver,
+};
 pub use discovery::{Capability, CapabilityCard, DiscoveryService, Skill};
 pub use error::{Error, Result};
+pub use event_sink::{EventSink, EventSinkStats};
 pub use events::{
-    ContractEvent, EventListener, EventListenerConfig, EventListenerStats, ReconnectConfig,
+    BlockCheckpoint, ContractEvent, EventListener, EventListenerConfig, EventListenerStats,
+    ReconnectConfig,
 };
+pub use identity::NodeIdentity;
 pub use metrics::{
     metrics_middleware, InFlightGuard, MetricNames, MetricsConfig, MetricsService, Timer,
 };
-pub use multichain::{ChainConfig, ChainInfo, MultiChainClient, MultiChainConfig};
-pub use network::{validate_network_config, NetworkEvent, NetworkManager, SwarmCommand};
+pub use multichain::{
+    AggregatedTrust, ChainConfig, ChainInfo, ChainTrust, MultiChainClient, MultiChainConfig,
+    TrustAggregation,
+};
+pub use network::{
+    validate_network_config, GossipPublisher, NetworkEvent, NetworkManager, SwarmCommand,
+};
 pub use persistence::{PersistenceConfig, PersistenceManager};
 pub use rate_limit::{
     headers as rate_limit_headers, RateLimitConfig, RateLimitLayer, RateLimitResult,
     RateLimitService,
 };
 pub use search::{
-    EmbeddingService, EmbeddingServiceConfig, HybridSearch, HybridSearchConfig, SearchResult,
+    EmbeddingService, EmbeddingServiceConfig, HnswIndex, HybridSearch, HybridSearchConfig, Page,
+    QdrantIndex, SchemaQuery, SearchFilter, SearchResult, SkillFit, SortOrder, VectorIndex,
+    VectorIndexConfig,
 };
 pub use trust::TrustService;
 pub use trust_cache::{CachedTrustInfo, TrustCache, TrustCacheConfig, TrustCacheStats};
@@ -6,13 +6,17 @@ use clap::{Parser, Subcommand};
 use std::env;
 use std::path::Path;
 use tokio::signal;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 use agoramesh_node::{
-    validate_network_config, ApiServer, AppState, DiscoveryService, EmbeddingService, HybridSearch,
-    MetricsConfig, MetricsService, NetworkConfig, NetworkManager, NodeConfig, RateLimitConfig,
-    RateLimitService, Result, TrustService,
+    network::{DidDocumentRegistry, DidDocumentSource, EnvelopeVerifier, MessageHandler},
+    search::{HnswIndex, QdrantIndex, VectorBackend, VectorIndex, EMBEDDING_DIM},
+    trust::TrustWeights,
+    AgoraMeshDidResolver, ApiServer, AppState, DiscoveryService, EmbeddingService, EventListener,
+    EventListenerConfig, EventSink, HybridSearch, MetricsService, MultiChainClient, NetworkManager,
+    NodeConfig, NodeIdentity, PeerDidResolver, PersistenceManager, RateLimitService, Result,
+    TrustRegistryClient, TrustService, UniversalResolver,
 };
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
@@ -42,7 +46,7 @@ struct SeedTrustEntry {
     endorsement_count: u64,
     /// Optional endorsers to create trust web entries.
     /// Each endorser is auto-created with the given reputation parameters
-    /// and a hop-1 endorsement is added to the target agent.
+    /// and endorses the target agent.
     #[serde(default)]
     endorsers: Vec<SeedEndorser>,
 }
@@ -55,17 +59,8 @@ struct SeedEndorser {
     successful_txs: u64,
     #[serde(default)]
     failed_txs: u64,
-    #[serde(default = "default_hop")]
-    hop: u32,
 }
 
-fn default_hop() -> u32 {
-    1
-}
-
-const DEFAULT_API_ADDR: &str = "0.0.0.0:8080";
-const DEFAULT_P2P_ADDR: &str = "/ip4/0.0.0.0/tcp/9000";
-
 #[derive(Parser)]
 #[command(name = "agoramesh")]
 #[command(author, version, about = "AgoraMesh P2P Node", long_about = None)]
@@ -92,20 +87,46 @@ enum Commands {
     },
 
     /// Start the AgoraMesh node
+    ///
+    /// Settings come from the configuration file, overridden by these flags,
+    /// overridden in turn by AGORAMESH_* environment variables.
     Start {
-        /// P2P listen address
-        #[arg(long, default_value = "/ip4/0.0.0.0/tcp/9000")]
-        p2p_addr: String,
+        /// P2P listen address (overrides network.listen_addresses)
+        #[arg(long)]
+        p2p_addr: Option<String>,
 
-        /// HTTP API listen address
-        #[arg(long, default_value = "0.0.0.0:8080")]
-        api_addr: String,
+        /// HTTP API listen address (overrides api.listen_address)
+        #[arg(long)]
+        api_addr: Option<String>,
 
         /// Enable semantic search (downloads ~90MB embedding model on first use)
-        #[arg(long, default_value = "false")]
+        #[arg(long)]
         enable_semantic_search: bool,
     },
 
+    /// Inspect the node configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Generate the node identity key at `identity.key_file`
+    Keygen {
+        /// Write the key here instead of the configured key file
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Replace an existing key (changes the node's PeerId and DID)
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Inspect the node identity
+    Identity {
+        #[command(subcommand)]
+        command: IdentityCommands,
+    },
+
     /// Check node health
     Health {
         /// API endpoint to check
@@ -114,6 +135,18 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Check the configuration (with env overrides) and report every problem
+    Validate,
+}
+
+#[derive(Subcommand)]
+enum IdentityCommands {
+    /// Print the PeerId, DID and DID document derived from the node key
+    Show,
+}
+
 fn init_logging(verbose: bool) {
     let filter = if verbose {
         EnvFilter::new("debug")
@@ -171,6 +204,17 @@ fn env_u64(name: &str) -> Option<u64> {
     }
 }
 
+fn env_u32(name: &str) -> Option<u32> {
+    let value = env_string(name)?;
+    match value.parse::<u32>() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            warn!("Invalid value for {}: {}", name, value);
+            None
+        }
+    }
+}
+
 fn env_csv(name: &str) -> Option<Vec<String>> {
     let value = env_string(name)?;
     let values: Vec<String> = value
@@ -233,15 +277,37 @@ fn apply_env_overrides(config: &mut NodeConfig) {
     if let Some(escrow_address) = env_string("AGORAMESH_ESCROW_ADDRESS") {
         config.blockchain.escrow_address = Some(escrow_address);
     }
+    if let Some(listen_events) = env_bool("AGORAMESH_CHAIN_EVENTS") {
+        config.blockchain.listen_events = listen_events;
+    }
 
     if let Some(data_dir) = env_string("AGORAMESH_DATA_DIR") {
         config.persistence.data_dir = data_dir;
     }
 
+    if let Some(enabled) = env_bool("AGORAMESH_RATE_LIMIT_ENABLED") {
+        config.rate_limit.enabled = enabled;
+    }
+    if let Some(requests_per_second) = env_u32("AGORAMESH_RATE_LIMIT_RPS") {
+        config.rate_limit.requests_per_second = requests_per_second;
+    }
+    if let Some(burst_size) = env_u32("AGORAMESH_RATE_LIMIT_BURST") {
+        config.rate_limit.burst_size = burst_size;
+    }
+    if let Some(prefix) = env_string("AGORAMESH_METRICS_PREFIX") {
+        config.metrics.prefix = prefix;
+    }
+    if let Some(semantic) = env_bool("AGORAMESH_SEMANTIC_SEARCH") {
+        config.search.semantic = semantic;
+    }
+
     // Node identity overrides
     if let Some(did) = env_string("AGORAMESH_NODE_DID") {
         config.identity.did = Some(did);
     }
+    if let Some(key_file) = env_string("AGORAMESH_NODE_KEY_FILE") {
+        config.identity.key_file = key_file;
+    }
 
     // Node info overrides (for agent card)
     if let Some(name) = env_string("AGORAMESH_NODE_NAME") {
@@ -257,6 +323,24 @@ fn apply_env_overrides(config: &mut NodeConfig) {
     config.api.admin_token = normalize_token(config.api.admin_token.clone());
 }
 
+/// Load the configuration file, or defaults if it does not exist.
+fn load_config_file(path: &str) -> Result<NodeConfig> {
+    if Path::new(path).exists() {
+        info!("Loading configuration from: {}", path);
+        NodeConfig::load(path)
+    } else {
+        info!("Using default configuration");
+        Ok(NodeConfig::default())
+    }
+}
+
+/// Load the configuration file (or defaults) and apply env overrides.
+fn load_config(path: &str) -> Result<NodeConfig> {
+    let mut config = load_config_file(path)?;
+    apply_env_overrides(&mut config);
+    Ok(config)
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -278,60 +362,140 @@ async fn main() -> Result<()> {
         } => {
             info!("Starting AgoraMesh node...");
 
-            // 1. Load configuration (or use defaults with CLI overrides)
-            let mut config = if Path::new(&cli.config).exists() {
-                info!("Loading configuration from: {}", cli.config);
-                NodeConfig::load(&cli.config)?
-            } else {
-                info!("Using default configuration");
-                NodeConfig::default()
-            };
+            // 1. Load configuration: file < CLI flags < environment
+            let mut config = load_config_file(&cli.config)?;
+            if let Some(p2p_addr) = p2p_addr {
+                config.network.listen_addresses = vec![p2p_addr];
+            }
+            if let Some(api_addr) = api_addr {
+                config.api.listen_address = api_addr;
+            }
+            if enable_semantic_search {
+                config.search.semantic = true;
+            }
             apply_env_overrides(&mut config);
 
-            let api_addr = if api_addr == DEFAULT_API_ADDR {
-                config.api.listen_address.clone()
-            } else {
-                api_addr.clone()
-            };
+            let problems = config.problems();
+            if !problems.is_empty() {
+                for problem in &problems {
+                    error!("Invalid configuration: {}", problem);
+                }
+                return Err(agoramesh_node::Error::Config(format!(
+                    "{} configuration problem(s); run `agoramesh config validate` for details",
+                    problems.len()
+                )));
+            }
 
-            let listen_addresses = if p2p_addr == DEFAULT_P2P_ADDR {
-                if config.network.listen_addresses.is_empty() {
-                    vec![p2p_addr.clone()]
-                } else {
-                    config.network.listen_addresses.clone()
+            // The node key is persistent so the PeerId survives restarts
+            let identity = NodeIdentity::load_or_generate(&config.identity.key_file)?;
+            let node_did = identity.did(config.blockchain.chain_id);
+            match config.identity.did {
+                Some(ref did) if *did != node_did => {
+                    warn!(
+                        "Configured DID {} is not derived from the node key ({})",
+                        did, node_did
+                    )
                 }
-            } else {
-                vec![p2p_addr.clone()]
-            };
+                Some(_) => {}
+                None => config.identity.did = Some(node_did.clone()),
+            }
+            info!("Node DID: {}", node_did);
 
-            // Create network config with CLI overrides
-            let network_config = NetworkConfig {
-                listen_addresses,
-                bootstrap_peers: config.network.bootstrap_peers.clone(),
-                max_connections: config.network.max_connections,
-            };
+            let api_addr = config.api.listen_address.clone();
+            let network_config = config.network.clone();
 
             info!(
                 "P2P address: {}",
                 network_config.listen_addresses.join(", ")
             );
             info!("API address: {}", api_addr);
-            validate_network_config(&network_config)?;
 
             // 2. Initialize P2P network
             info!("Initializing P2P network...");
-            let mut network = NetworkManager::new(network_config)?;
+            // Everything the node gossips is signed as the node DID
+            let mut network = NetworkManager::with_keypair(network_config, identity.keypair())?
+                .with_signer(identity.envelope_signer(&node_did));
             info!("Network started with peer ID: {}", network.local_peer_id());
 
             // 3. Take event receiver for processing network events
             let mut event_rx = network.take_event_receiver();
 
-            // 4. Initialize semantic search if enabled
-            let hybrid_search = if enable_semantic_search {
+            // 4. Open persistent storage and build the trust service
+            let persistence = PersistenceManager::new(config.persistence.clone())?;
+
+            let mut trust = TrustService::with_weights(
+                config.blockchain.rpc_url.clone(),
+                config.blockchain.trust_registry_address.clone(),
+                TrustWeights {
+                    endorsement_mode: config.trust.endorsement_mode,
+                    reputation_mode: config.trust.reputation_mode,
+                    ..TrustWeights::default()
+                },
+            )
+            .with_requirements(config.trust.clone())
+            .with_trust_roots(config.trust.roots.clone())
+            .with_reconciliation(config.trust.reconcile.clone());
+            if let Some(store) = persistence.trust_data() {
+                trust = trust.with_store(store.clone());
+            }
+            if config.trust.history.enabled {
+                if let Some(store) = persistence.trust_history() {
+                    trust = trust.with_history(store.clone());
+                }
+            }
+            if !config.multichain.chains.is_empty() {
+                match MultiChainClient::new(config.multichain.clone()) {
+                    Ok(client) => {
+                        info!(
+                            "Consulting TrustRegistry on {} chain(s)",
+                            client.chain_count()
+                        );
+                        trust = trust.with_multichain(Arc::new(client));
+                    }
+                    Err(e) => warn!("Multi-chain trust disabled: {}", e),
+                }
+            }
+            let trust = Arc::new(trust);
+
+            // 5. Initialize semantic search if enabled, ranking by live trust
+            let hybrid_search = if config.search.semantic {
                 info!("Initializing semantic search (downloading ~90MB model if needed)...");
                 match EmbeddingService::new() {
                     Ok(embedding_service) => {
-                        let hybrid = HybridSearch::new(embedding_service);
+                        let index_config = &config.search.index;
+                        let hnsw = || Arc::new(HnswIndex::with_config(index_config.hnsw.clone()));
+                        let index: Arc<dyn VectorIndex> = match index_config.backend {
+                            VectorBackend::Hnsw => hnsw(),
+                            VectorBackend::Qdrant => {
+                                let namespace = identity.peer_id().to_string();
+                                match QdrantIndex::connect(
+                                    &index_config.qdrant,
+                                    EMBEDDING_DIM,
+                                    &namespace,
+                                )
+                                .await
+                                {
+                                    Ok(index) => {
+                                        info!(
+                                            "Storing embeddings in Qdrant collection '{}'",
+                                            index.collection()
+                                        );
+                                        Arc::new(index)
+                                    }
+                                    Err(e) => {
+                                        warn!("Failed to connect to Qdrant: {}", e);
+                                        warn!("Falling back to the in-memory HNSW index");
+                                        hnsw()
+                                    }
+                                }
+                            }
+                        };
+                        let hybrid = HybridSearch::with_config(
+                            embedding_service,
+                            config.search.hybrid.clone(),
+                        )
+                        .with_index(index)
+                        .with_trust(trust.clone());
                         info!("Semantic search initialized successfully");
                         Some(Arc::new(RwLock::new(hybrid)))
                     }
@@ -345,9 +509,9 @@ async fn main() -> Result<()> {
                 None
             };
 
-            // 5. Create shared state for API server with DHT-enabled discovery
+            // 6. Create shared state for API server with DHT-enabled discovery
             let peer_count = Arc::new(AtomicU64::new(0));
-            let discovery = match hybrid_search {
+            let mut discovery = match hybrid_search {
                 Some(hs) => {
                     DiscoveryService::with_network_and_shared_search(network.command_channel(), hs)
                 }
@@ -357,11 +521,81 @@ async fn main() -> Result<()> {
             // the API semantic-search handler and discovery indexing use the
             // same instance.
             let shared_hybrid_search = discovery.hybrid_search();
-            let discovery = Arc::new(discovery);
-            let trust = Arc::new(TrustService::new(
-                "https://sepolia.base.org".to_string(),
-                None,
-            ));
+            if let Some(store) = persistence.capability_cards() {
+                discovery = discovery.with_card_store(store.clone());
+            }
+            if let Some(publisher) = network.publisher() {
+                discovery = discovery.with_publisher(publisher);
+            }
+            let discovery = Arc::new(discovery.with_trust(trust.clone()));
+
+            // Rehydrate state persisted by a previous run
+            match discovery.load_from_store().await {
+                Ok(count) if count > 0 => info!("Restored {} capability card(s)", count),
+                Ok(_) => {}
+                Err(e) => warn!("Failed to restore capability cards: {}", e),
+            }
+            match trust.load_from_store() {
+                Ok(count) if count > 0 => info!("Restored trust data for {} agent(s)", count),
+                Ok(_) => {}
+                Err(e) => warn!("Failed to restore trust data: {}", e),
+            }
+
+            // did:key and did:web always; agent did:agoramesh DIDs need the
+            // TrustRegistry, node DIDs resolve from their PeerId regardless
+            let did_resolver = match UniversalResolver::with_defaults() {
+                Ok(mut resolver) => {
+                    resolver = resolver.with_method("agoramesh", Arc::new(PeerDidResolver));
+                    if let Some(ref address) = config.blockchain.trust_registry_address {
+                        match TrustRegistryClient::new(config.blockchain.rpc_url.clone(), address) {
+                            Ok(registry) => {
+                                resolver = resolver.with_method(
+                                    "agoramesh",
+                                    Arc::new(AgoraMeshDidResolver::new(
+                                        network.command_channel(),
+                                        Arc::new(registry),
+                                    )),
+                                );
+                            }
+                            Err(e) => warn!("did:agoramesh resolution disabled: {}", e),
+                        }
+                    }
+                    info!(
+                        "DID resolution enabled for: {}",
+                        resolver.methods().join(", ")
+                    );
+                    Some(Arc::new(resolver))
+                }
+                Err(e) => {
+                    warn!("Failed to initialize DID resolver: {}", e);
+                    None
+                }
+            };
+
+            // Signed requests are checked against resolved DID documents;
+            // without a resolver none can verify, so they fail closed
+            let documents: Arc<dyn DidDocumentSource> = match did_resolver.clone() {
+                Some(resolver) => resolver,
+                None => {
+                    warn!("No DID resolver: every signed API request will be rejected");
+                    Arc::new(DidDocumentRegistry::new())
+                }
+            };
+            let envelope_verifier = Arc::new(EnvelopeVerifier::new(documents));
+            // Agent registrations only need a signature with trust.authenticated_writes
+            let write_verifier = config
+                .trust
+                .authenticated_writes
+                .then(|| envelope_verifier.clone());
+
+            // Gossip is only acted on once its envelope verifies against the
+            // sender's DID document; trust.gate_gossip applies to announced cards
+            let mut message_handler =
+                MessageHandler::with_services(discovery.clone(), Some(trust.clone()), None);
+            match did_resolver.clone() {
+                Some(resolver) => message_handler = message_handler.with_did_documents(resolver),
+                None => warn!("No DID resolver: every gossip message will be rejected"),
+            }
 
             let app_state = AppState {
                 discovery: discovery.clone(),
@@ -369,21 +603,18 @@ async fn main() -> Result<()> {
                 start_time: Instant::now(),
                 peer_count: peer_count.clone(),
                 node_info: config.get_node_info(),
-                rate_limiter: Arc::new(RateLimitService::new(RateLimitConfig::default())),
-                metrics: Arc::new(MetricsService::new(MetricsConfig::default())),
+                rate_limiter: Arc::new(RateLimitService::new(config.rate_limit.clone())),
+                metrics: Arc::new(MetricsService::new(config.metrics.clone())),
                 hybrid_search: shared_hybrid_search,
+                did_resolver,
                 api_token: config.api.admin_token.clone(),
+                write_verifier,
+                envelope_verifier: Some(envelope_verifier),
+                gossip: network.publisher(),
             };
 
-            // 6. Start HTTP API server in background with shared state
-            let api_config = agoramesh_node::ApiConfig {
-                listen_address: api_addr.clone(),
-                cors_enabled: config.api.cors_enabled,
-                cors_origins: config.api.cors_origins.clone(),
-                trust_proxy: config.api.trust_proxy,
-                admin_token: config.api.admin_token.clone(),
-            };
-            let api_server = ApiServer::with_state(api_config, app_state);
+            // 7. Start HTTP API server in background with shared state
+            let api_server = ApiServer::with_state(config.api.clone(), app_state);
             let api_addr_clone = api_addr.clone();
 
             tokio::spawn(async move {
@@ -392,7 +623,7 @@ async fn main() -> Result<()> {
                 }
             });
 
-            // 6b. Seed agents from AGORAMESH_SEED_AGENTS env var
+            // 7b. Seed agents from AGORAMESH_SEED_AGENTS env var
             //
             // Format: JSON array of capability card objects, or a URL to fetch.
             // Example: AGORAMESH_SEED_AGENTS='[{"name":"Bridge","description":"...","url":"https://bridge.agoramesh.ai","x-agoramesh":{"did":"did:agoramesh:base-sepolia:agent-001","payment_methods":["x402"]}}]'
@@ -423,7 +654,7 @@ async fn main() -> Result<()> {
                 }
             }
 
-            // 6c. Seed trust data from AGORAMESH_SEED_TRUST env var
+            // 7c. Seed trust data from AGORAMESH_SEED_TRUST env var
             //
             // Format: JSON array of objects with did, stake_amount, successful_txs, failed_txs, endorsement_count
             // Example: AGORAMESH_SEED_TRUST='[{"did":"did:agoramesh:base-sepolia:agent-001","stake_amount":7225000000,"successful_txs":184,"failed_txs":16,"endorsement_count":5}]'
@@ -448,13 +679,8 @@ async fn main() -> Result<()> {
                                     endorser.failed_txs,
                                     0,
                                 );
-                                if let Err(e) = trust
-                                    .add_endorsement_with_hop(
-                                        &endorser.did,
-                                        &entry.did,
-                                        endorser.hop,
-                                    )
-                                    .await
+                                if let Err(e) =
+                                    trust.add_endorsement(&endorser.did, &entry.did).await
                                 {
                                     warn!(
                                         "Failed to add endorsement from {} to {}: {}",
@@ -479,10 +705,77 @@ async fn main() -> Result<()> {
                 }
             }
 
+            // 7d. Snapshot trust history and apply its retention policy periodically
+            if config.trust.history.enabled && persistence.trust_history().is_some() {
+                let trust = trust.clone();
+                let history = config.trust.history.clone();
+                tokio::spawn(async move {
+                    let mut ticker = tokio::time::interval(std::time::Duration::from_secs(
+                        history.snapshot_interval_secs,
+                    ));
+                    loop {
+                        ticker.tick().await;
+                        match trust.snapshot_all().await {
+                            Ok(count) => debug!("Recorded {} trust snapshot(s)", count),
+                            Err(e) => warn!("Failed to record trust snapshots: {}", e),
+                        }
+                        let now = std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map(|d| d.as_secs())
+                            .unwrap_or_default();
+                        if let Some(cutoff) = history.retention_cutoff(now) {
+                            match trust.prune_history(cutoff) {
+                                Ok(0) => {}
+                                Ok(count) => info!("Pruned {} trust history entries", count),
+                                Err(e) => warn!("Failed to prune trust history: {}", e),
+                            }
+                        }
+                    }
+                });
+            }
+
+            // 7e. Follow TrustRegistry/escrow events and apply them to local state
+            let has_contracts = config.blockchain.trust_registry_address.is_some()
+                || config.blockchain.escrow_address.is_some();
+            let event_listener = if !config.blockchain.listen_events {
+                info!("Contract event listener disabled by blockchain.listen_events");
+                None
+            } else if has_contracts {
+                match EventListener::new(EventListenerConfig::for_chain(&config.blockchain)) {
+                    Ok((mut listener, contract_rx)) => {
+                        if let Some(store) = persistence.event_cursors() {
+                            listener = listener.with_cursor_store(store.clone());
+                        }
+                        match listener.start().await {
+                            Ok(()) => {
+                                info!(
+                                    "Following contract events via {}",
+                                    config.blockchain.rpc_url
+                                );
+                                let sink =
+                                    Arc::new(EventSink::new(trust.clone(), discovery.clone()));
+                                tokio::spawn(sink.run(contract_rx));
+                                Some(listener)
+                            }
+                            Err(e) => {
+                                warn!("Contract event listener disabled: {}", e);
+                                None
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Contract event listener disabled: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
             info!("AgoraMesh node started successfully");
             info!("Press Ctrl+C to stop");
 
-            // 7. Run event loop - process network events and handle shutdown
+            // 8. Run event loop - process network events and handle shutdown
             loop {
                 tokio::select! {
                     // Handle network events
@@ -505,13 +798,16 @@ async fn main() -> Result<()> {
                             agoramesh_node::NetworkEvent::PeerDiscovered(peer_id) => {
                                 info!("Peer discovered via mDNS: {}", peer_id);
                             }
-                            agoramesh_node::NetworkEvent::Message { topic, source, data, .. } => {
-                                info!(
+                            agoramesh_node::NetworkEvent::Message { ref topic, ref source, ref data, .. } => {
+                                debug!(
                                     "Message on {}: {} bytes from {:?}",
                                     topic,
                                     data.len(),
                                     source
                                 );
+                                if let Err(e) = message_handler.handle_event(&event).await {
+                                    debug!("Dropped message on {}: {}", topic, e);
+                                }
                             }
                             agoramesh_node::NetworkEvent::BootstrapComplete => {
                                 info!("DHT bootstrap complete");
@@ -532,6 +828,9 @@ async fn main() -> Result<()> {
                     // Handle shutdown signal
                     _ = signal::ctrl_c() => {
                         info!("Received shutdown signal");
+                        if let Some(ref listener) = event_listener {
+                            listener.stop();
+                        }
                         if let Err(e) = network.shutdown().await {
                             warn!("Error during shutdown: {}", e);
                         }
@@ -542,6 +841,77 @@ async fn main() -> Result<()> {
             }
         }
 
+        Commands::Config {
+            command: ConfigCommands::Validate,
+        } => {
+            if !Path::new(&cli.config).exists() {
+                error!("Configuration file {} not found", cli.config);
+                std::process::exit(1);
+            }
+            let config = match load_config(&cli.config) {
+                Ok(config) => config,
+                Err(e) => {
+                    error!("Failed to parse {}: {}", cli.config, e);
+                    std::process::exit(1);
+                }
+            };
+
+            let problems = config.problems();
+            if problems.is_empty() {
+                println!("{}: OK", cli.config);
+            } else {
+                println!("{}: {} problem(s)", cli.config, problems.len());
+                for problem in &problems {
+                    println!("  - {}", problem);
+                }
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Keygen { output, force } => {
+            let config = load_config(&cli.config)?;
+            let path = output.unwrap_or(config.identity.key_file);
+
+            let exists = Path::new(&path).exists();
+            if exists && !force {
+                error!(
+                    "Key file {} already exists; pass --force to replace it (this changes the node's PeerId)",
+                    path
+                );
+                std::process::exit(1);
+            }
+
+            let identity = NodeIdentity::generate();
+            if exists {
+                identity.replace(&path)?;
+            } else {
+                identity.save(&path)?;
+            }
+            info!("Node key written to {}", path);
+            println!("Peer ID: {}", identity.peer_id());
+            println!("DID:     {}", identity.did(config.blockchain.chain_id));
+        }
+
+        Commands::Identity {
+            command: IdentityCommands::Show,
+        } => {
+            let config = load_config(&cli.config)?;
+            if !Path::new(&config.identity.key_file).exists() {
+                error!(
+                    "No node key at {}; run `agoramesh keygen` first",
+                    config.identity.key_file
+                );
+                std::process::exit(1);
+            }
+
+            let identity = NodeIdentity::load(&config.identity.key_file)?;
+            let chain_id = config.blockchain.chain_id;
+            println!("Key file: {}", config.identity.key_file);
+            println!("Peer ID:  {}", identity.peer_id());
+            println!("DID:      {}", identity.did(chain_id));
+            println!("{}", identity.did_document(chain_id)?.to_json()?);
+        }
+
         Commands::Health { endpoint } => {
             info!("Checking node health at: {}", endpoint);
 
@@ -3,7 +3,7 @@
 //! This module provides infrastructure for interacting with multiple EVM-compatible
 //! chains simultaneously. It supports:
 //! - Chain configuration management
-//! - Trust score aggregation across chains
+//! - Trust score aggregation across chains (by priority, max, stake or recency)
 //! - Contract interactions on any supported chain
 //!
 //! Supported chains:
@@ -15,8 +15,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::contract::TrustRegistryClient;
+use crate::contract::{OnChainTrustDetails, TrustRegistryClient};
 use crate::error::{Error, Result};
+use crate::reputation::REPUTATION_HALF_LIFE_SECS;
 
 // =============================================================================
 // Chain Information (Static)
@@ -204,6 +205,10 @@ pub struct MultiChainConfig {
     /// Whether to aggregate trust scores across all chains.
     #[serde(default)]
     pub aggregate_trust: bool,
+
+    /// How per-chain trust scores are combined.
+    #[serde(default)]
+    pub aggregation: TrustAggregation,
 }
 
 impl MultiChainConfig {
@@ -248,6 +253,151 @@ impl MultiChainConfig {
     }
 }
 
+// =============================================================================
+// Trust Aggregation
+// =============================================================================
+
+/// How trust scores from several chains are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustAggregation {
+    /// Average weighted by chain priority (priority + 1).
+    #[default]
+    Priority,
+    /// Scores of the chain with the highest composite score.
+    Max,
+    /// Average weighted by the agent's stake on each chain.
+    StakeWeighted,
+    /// Average weighted by how recently the agent was active on each chain
+    /// (halving every 30 days).
+    RecencyWeighted,
+}
+
+/// An agent's trust on one chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainTrust {
+    /// Chain ID.
+    pub chain_id: u64,
+    /// Chain name, for well-known chains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Configured chain priority.
+    pub priority: u32,
+    /// Component scores (0-10000), if the chain answered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<OnChainTrustDetails>,
+    /// Staked USDC (6 decimals).
+    pub stake_amount: u64,
+    /// Last activity on the chain (Unix seconds, 0 if never active).
+    pub last_activity: u64,
+    /// Share of the aggregate this chain contributed (0.0 - 1.0).
+    pub weight: f64,
+    /// Why the chain could not be read, if it could not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ChainTrust {
+    /// A chain that has not been read yet.
+    pub fn new(chain_id: u64, priority: u32) -> Self {
+        Self {
+            chain_id,
+            name: get_chain_info(chain_id).map(|c| c.name.to_string()),
+            priority,
+            details: None,
+            stake_amount: 0,
+            last_activity: 0,
+            weight: 0.0,
+            error: None,
+        }
+    }
+}
+
+/// An agent's trust on every configured chain and their aggregate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedTrust {
+    /// Agent's DID.
+    pub did: String,
+    /// How the chains were combined.
+    pub aggregation: TrustAggregation,
+    /// Aggregated component scores (0-10000), if any chain answered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<OnChainTrustDetails>,
+    /// Per-chain scores, highest priority first.
+    pub chains: Vec<ChainTrust>,
+}
+
+/// Combine the chains that answered using `method`, as of `now`.
+///
+/// Sets every chain's `weight` and returns the aggregated scores, or `None`
+/// if no chain answered. Weighted methods fall back to equal weights when
+/// no chain has stake (or activity) to weigh by.
+pub fn aggregate_trust(
+    chains: &mut [ChainTrust],
+    method: TrustAggregation,
+    now: u64,
+) -> Option<OnChainTrustDetails> {
+    let half_life = REPUTATION_HALF_LIFE_SECS as f64;
+    let mut weights: Vec<f64> = chains
+        .iter()
+        .map(|chain| match (&chain.details, method) {
+            (None, _) => 0.0,
+            (Some(_), TrustAggregation::Priority) => chain.priority as f64 + 1.0,
+            (Some(_), TrustAggregation::Max) => 0.0,
+            (Some(_), TrustAggregation::StakeWeighted) => chain.stake_amount as f64,
+            (Some(_), TrustAggregation::RecencyWeighted) if chain.last_activity == 0 => 0.0,
+            (Some(_), TrustAggregation::RecencyWeighted) => {
+                let age = now.saturating_sub(chain.last_activity) as f64;
+                0.5_f64.powf(age / half_life)
+            }
+        })
+        .collect();
+
+    if method == TrustAggregation::Max {
+        let best = chains
+            .iter()
+            .enumerate()
+            .filter_map(|(i, chain)| chain.details.as_ref().map(|d| (i, d.composite_score)))
+            .max_by_key(|&(i, score)| (score, std::cmp::Reverse(i)))?
+            .0;
+        weights[best] = 1.0;
+    } else if weights.iter().all(|&w| w == 0.0) {
+        for (weight, chain) in weights.iter_mut().zip(chains.iter()) {
+            if chain.details.is_some() {
+                *weight = 1.0;
+            }
+        }
+    }
+
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 {
+        return None;
+    }
+
+    let mut sums = [0.0; 4];
+    for (chain, weight) in chains.iter_mut().zip(weights) {
+        chain.weight = weight / total;
+        if let Some(ref d) = chain.details {
+            let scores = [
+                d.reputation_score,
+                d.stake_score,
+                d.endorsement_score,
+                d.composite_score,
+            ];
+            for (sum, score) in sums.iter_mut().zip(scores) {
+                *sum += score as f64 * chain.weight;
+            }
+        }
+    }
+
+    Some(OnChainTrustDetails {
+        reputation_score: sums[0].round() as u64,
+        stake_score: sums[1].round() as u64,
+        endorsement_score: sums[2].round() as u64,
+        composite_score: sums[3].round() as u64,
+    })
+}
+
 // =============================================================================
 // Multi-Chain Client
 // =============================================================================
@@ -296,53 +446,89 @@ impl MultiChainClient {
         client.get_trust_score(did).await
     }
 
+    /// Whether trust should be aggregated across chains.
+    pub fn aggregates_trust(&self) -> bool {
+        self.config.aggregate_trust && !self.clients.is_empty()
+    }
+
+    /// Configured aggregation method.
+    pub fn aggregation(&self) -> TrustAggregation {
+        self.config.aggregation
+    }
+
     /// Get aggregated trust score across all chains.
     ///
-    /// Uses weighted average based on chain priority.
+    /// Combines chains with the configured [`TrustAggregation`].
     pub async fn get_aggregated_trust_score(&self, did: &str) -> Result<u64> {
-        if self.clients.is_empty() {
-            return Err(Error::Config("No chains configured".to_string()));
+        Ok(self
+            .get_aggregated_trust_details(did)
+            .await?
+            .composite_score)
+    }
+
+    /// Get aggregated component scores across all chains.
+    ///
+    /// Fails with the first chain error if no chain answered.
+    pub async fn get_aggregated_trust_details(&self, did: &str) -> Result<OnChainTrustDetails> {
+        let trust = self.get_trust_across_chains(did).await?;
+        match trust.details {
+            Some(details) => Ok(details),
+            None => Err(Error::Contract(
+                trust
+                    .chains
+                    .into_iter()
+                    .find_map(|c| c.error)
+                    .unwrap_or_else(|| "No scores available".to_string()),
+            )),
         }
+    }
 
-        let chains = self.config.chains_with_trust_registry();
-        if chains.is_empty() {
-            return Err(Error::Config(
-                "No chains with TrustRegistry configured".to_string(),
-            ));
+    /// Get an agent's trust on every chain and aggregate it with the
+    /// configured method.
+    ///
+    /// Chains are queried concurrently; a chain that fails is reported with
+    /// its error and left out of the aggregate.
+    pub async fn get_trust_across_chains(&self, did: &str) -> Result<AggregatedTrust> {
+        if self.clients.is_empty() {
+            return Err(Error::Config("No chains configured".to_string()));
         }
 
-        let mut total_score: u64 = 0;
-        let mut total_weight: u64 = 0;
-        let mut errors = Vec::new();
-
-        for chain_config in chains {
-            if let Some(client) = self.clients.get(&chain_config.chain_id) {
-                match client.get_trust_score(did).await {
-                    Ok(score) => {
-                        let weight = (chain_config.priority as u64) + 1; // +1 to avoid zero weight
-                        total_score += score * weight;
-                        total_weight += weight;
+        let reads = self
+            .config
+            .chains_with_trust_registry()
+            .into_iter()
+            .filter_map(|chain| {
+                let client = self.clients.get(&chain.chain_id)?;
+                Some(async move {
+                    let mut trust = ChainTrust::new(chain.chain_id, chain.priority);
+                    match tokio::try_join!(
+                        client.get_trust_details(did),
+                        client.get_trust_data(did)
+                    ) {
+                        Ok((details, data)) => {
+                            trust.details = Some(details);
+                            trust.stake_amount = data.staked_amount;
+                            trust.last_activity = data.last_activity_timestamp;
+                        }
+                        Err(e) => trust.error = Some(e.to_string()),
                     }
-                    Err(e) => {
-                        errors.push((chain_config.chain_id, e));
-                    }
-                }
-            }
-        }
+                    trust
+                })
+            });
+        let mut chains = futures::future::join_all(reads).await;
 
-        // If we got at least one score, return the weighted average
-        if total_weight > 0 {
-            Ok(total_score / total_weight)
-        } else if !errors.is_empty() {
-            // All chains failed, return the first error
-            Err(errors
-                .into_iter()
-                .next()
-                .expect("errors verified non-empty")
-                .1)
-        } else {
-            Err(Error::Config("No scores available".to_string()))
-        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let details = aggregate_trust(&mut chains, self.config.aggregation, now);
+
+        Ok(AggregatedTrust {
+            did: did.to_string(),
+            aggregation: self.config.aggregation,
+            details,
+            chains,
+        })
     }
 
     /// Get trust scores from all chains.
@@ -683,6 +869,126 @@ mod tests {
         assert!(client.is_err());
     }
 
+    // -------------------------------------------------------------------------
+    // Trust Aggregation Tests
+    // -------------------------------------------------------------------------
+
+    const NOW: u64 = 100 * 86_400;
+
+    fn chain(chain_id: u64, composite: u64, stake: u64, last_activity: u64) -> ChainTrust {
+        ChainTrust {
+            details: Some(OnChainTrustDetails {
+                reputation_score: composite,
+                stake_score: composite,
+                endorsement_score: composite,
+                composite_score: composite,
+            }),
+            stake_amount: stake,
+            last_activity,
+            ..ChainTrust::new(chain_id, 0)
+        }
+    }
+
+    #[test]
+    fn test_aggregate_max_takes_best_chain() {
+        let mut chains = vec![chain(8453, 4_000, 0, 0), chain(137, 9_000, 0, 0)];
+
+        let details = aggregate_trust(&mut chains, TrustAggregation::Max, NOW).unwrap();
+
+        assert_eq!(details.composite_score, 9_000);
+        assert_eq!(chains[0].weight, 0.0);
+        assert_eq!(chains[1].weight, 1.0);
+    }
+
+    #[test]
+    fn test_aggregate_stake_weighted() {
+        // Three times as much stake on Base
+        let mut chains = vec![
+            chain(8453, 8_000, 3_000_000, 0),
+            chain(137, 4_000, 1_000_000, 0),
+        ];
+
+        let details = aggregate_trust(&mut chains, TrustAggregation::StakeWeighted, NOW).unwrap();
+
+        assert_eq!(details.composite_score, 7_000);
+        assert_eq!(chains[0].weight, 0.75);
+    }
+
+    #[test]
+    fn test_aggregate_recency_weighted_favors_recent_activity() {
+        // Base active now, Polygon one half-life (30 days) ago: weights 1 and 0.5
+        let mut chains = vec![
+            chain(8453, 9_000, 0, NOW),
+            chain(137, 3_000, 0, NOW - REPUTATION_HALF_LIFE_SECS),
+        ];
+
+        let details = aggregate_trust(&mut chains, TrustAggregation::RecencyWeighted, NOW).unwrap();
+
+        assert_eq!(details.composite_score, 7_000);
+    }
+
+    #[test]
+    fn test_aggregate_falls_back_to_equal_weights_without_stake() {
+        let mut chains = vec![chain(8453, 8_000, 0, 0), chain(137, 4_000, 0, 0)];
+
+        let details = aggregate_trust(&mut chains, TrustAggregation::StakeWeighted, NOW).unwrap();
+
+        assert_eq!(details.composite_score, 6_000);
+    }
+
+    #[test]
+    fn test_aggregate_skips_failed_chains() {
+        let mut failed = ChainTrust::new(137, 100);
+        failed.error = Some("connection refused".to_string());
+        let mut chains = vec![failed, chain(8453, 5_000, 0, 0)];
+
+        let details = aggregate_trust(&mut chains, TrustAggregation::Priority, NOW).unwrap();
+
+        assert_eq!(details.composite_score, 5_000);
+        assert_eq!(chains[0].weight, 0.0);
+        assert!(aggregate_trust(&mut chains[..1], TrustAggregation::Max, NOW).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_across_chains_reports_chain_errors() {
+        // A registry behind an RPC endpoint that refuses connections
+        let mut config = MultiChainConfig::new();
+        let mut chain = ChainConfig::new(8453);
+        chain.rpc_url = Some("http://127.0.0.1:1".to_string());
+        chain.trust_registry_address =
+            Some("0x1111111111111111111111111111111111111111".to_string());
+        config.add_chain(chain);
+        config.aggregation = TrustAggregation::Max;
+        let client = MultiChainClient::new(config).unwrap();
+
+        let trust = client
+            .get_trust_across_chains("did:agoramesh:base:agent")
+            .await
+            .unwrap();
+
+        assert_eq!(trust.aggregation, TrustAggregation::Max);
+        assert!(trust.details.is_none());
+        assert_eq!(trust.chains.len(), 1);
+        assert_eq!(trust.chains[0].name.as_deref(), Some("Base Mainnet"));
+        assert!(trust.chains[0].error.is_some());
+        assert!(client
+            .get_aggregated_trust_score("did:agoramesh:base:agent")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_across_chains_requires_chains() {
+        let client = MultiChainClient::new(MultiChainConfig::new()).unwrap();
+
+        let result = client
+            .get_trust_across_chains("did:agoramesh:base:agent")
+            .await;
+
+        assert!(matches!(result, Err(Error::Config(_))));
+        assert!(!client.aggregates_trust());
+    }
+
     // Note: Async tests for actual RPC calls would require mocking
     // or integration test setup with a local node.
 }
@@ -9,6 +9,7 @@
 //! - Security (Sybil/Eclipse attack protection)
 
 pub mod behaviour;
+pub mod envelope;
 pub mod message_handler;
 pub mod security;
 pub mod swarm;
@@ -16,6 +17,10 @@ pub mod transport;
 
 // Re-export main types for convenience
 pub use behaviour::{topics, AgoraMeshBehaviour, AgoraMeshEvent, PROTOCOL_VERSION};
+pub use envelope::{
+    DidDocumentRegistry, DidDocumentSource, EnvelopeRejection, EnvelopeSigner, EnvelopeVerifier,
+    SignedEnvelope,
+};
 pub use message_handler::{DiscoveryMessage, MessageHandler, MessageHandlerStats, TrustMessage};
 pub use security::{
     validate_bootstrap_peers, validate_network_config, ConnectionRateLimiter, ConnectionTracker,
@@ -27,12 +32,73 @@ pub use swarm::{NetworkEvent, SwarmCommand, SwarmManager};
 pub use transport::{build_transport, BoxedTransport};
 
 use libp2p::{Multiaddr, PeerId};
+use serde::Serialize;
 use tokio::sync::{mpsc, oneshot};
 use tracing::{debug, info};
 
 use crate::config::NetworkConfig;
+use crate::discovery::CapabilityCard;
 use crate::error::{Error, Result};
 
+/// Publishes GossipSub messages sealed in a [`SignedEnvelope`].
+///
+/// Peers reject unsigned messages, so everything this node gossips goes
+/// through a publisher holding the node's [`EnvelopeSigner`]. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct GossipPublisher {
+    command_tx: mpsc::Sender<SwarmCommand>,
+    signer: EnvelopeSigner,
+}
+
+impl GossipPublisher {
+    /// Create a publisher that sends over `command_tx` and signs with `signer`.
+    pub fn new(command_tx: mpsc::Sender<SwarmCommand>, signer: EnvelopeSigner) -> Self {
+        Self { command_tx, signer }
+    }
+
+    /// The DID messages are signed as.
+    ///
+    /// Receivers only accept messages about an agent from that agent's DID.
+    pub fn did(&self) -> &str {
+        self.signer.did()
+    }
+
+    /// Seal `message` for `topic` and publish it.
+    pub async fn publish<T: Serialize>(&self, topic: &str, message: &T) -> Result<()> {
+        let data = SignedEnvelope::seal(topic, message, &self.signer)?.to_bytes()?;
+        debug!("Publishing {} signed bytes to topic {}", data.len(), topic);
+
+        self.command_tx
+            .send(SwarmCommand::Publish {
+                topic: topic.to_string(),
+                data,
+            })
+            .await
+            .map_err(|e| Error::Network(format!("Failed to send publish command: {}", e)))
+    }
+
+    /// Publish an envelope someone else signed, unchanged, on its own topic.
+    ///
+    /// Receivers verify it against its original sender.
+    pub async fn forward(&self, envelope: &SignedEnvelope) -> Result<()> {
+        let data = envelope.to_bytes()?;
+        debug!(
+            "Forwarding {} signed bytes from {} to topic {}",
+            data.len(),
+            envelope.sender_did,
+            envelope.topic
+        );
+
+        self.command_tx
+            .send(SwarmCommand::Publish {
+                topic: envelope.topic.clone(),
+                data,
+            })
+            .await
+            .map_err(|e| Error::Network(format!("Failed to send publish command: {}", e)))
+    }
+}
+
 /// P2P network manager.
 ///
 /// Provides a high-level interface for network operations.
@@ -49,6 +115,9 @@ pub struct NetworkManager {
 
     /// Event receiver from the swarm manager.
     event_rx: Option<mpsc::Receiver<NetworkEvent>>,
+
+    /// Signer for the node's own announcements.
+    signer: Option<EnvelopeSigner>,
 }
 
 impl NetworkManager {
@@ -80,6 +149,7 @@ impl NetworkManager {
             config,
             command_tx,
             event_rx: Some(event_rx),
+            signer: None,
         })
     }
 
@@ -107,6 +177,7 @@ impl NetworkManager {
             config,
             command_tx,
             event_rx: Some(event_rx),
+            signer: None,
         })
     }
 
@@ -156,39 +227,53 @@ impl NetworkManager {
         Ok(())
     }
 
-    /// Publish a message to a topic.
-    ///
-    /// # Arguments
-    ///
-    /// * `topic` - The topic name (e.g., "/agoramesh/discovery/1.0.0")
-    /// * `message` - The message data
-    pub async fn publish(&self, topic: &str, message: &[u8]) -> Result<()> {
-        debug!("Publishing {} bytes to topic {}", message.len(), topic);
+    /// Sign the node's announcements with `signer` (usually the node DID's key).
+    pub fn with_signer(mut self, signer: EnvelopeSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
 
-        self.command_tx
-            .send(SwarmCommand::Publish {
-                topic: topic.to_string(),
-                data: message.to_vec(),
-            })
+    /// Get a signed publisher for other services, if a signer is set.
+    pub fn publisher(&self) -> Option<GossipPublisher> {
+        self.signer
+            .clone()
+            .map(|signer| GossipPublisher::new(self.command_tx.clone(), signer))
+    }
+
+    /// Wrap a message in a [`SignedEnvelope`] and publish it.
+    pub async fn publish_signed<T: Serialize>(
+        &self,
+        topic: &str,
+        message: &T,
+        signer: &EnvelopeSigner,
+    ) -> Result<()> {
+        GossipPublisher::new(self.command_tx.clone(), signer.clone())
+            .publish(topic, message)
             .await
-            .map_err(|e| Error::Network(format!("Failed to send publish command: {}", e)))?;
+    }
 
-        Ok(())
+    /// Publish a message signed with the node's own signer.
+    async fn publish_as_node<T: Serialize>(&self, topic: &str, message: &T) -> Result<()> {
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| Error::Network("No envelope signer configured".to_string()))?;
+        self.publish_signed(topic, message, signer).await
     }
 
     /// Publish an agent discovery announcement.
-    pub async fn announce_agent(&self, announcement: &[u8]) -> Result<()> {
-        self.publish(topics::DISCOVERY, announcement).await
+    pub async fn announce_agent(&self, announcement: &DiscoveryMessage) -> Result<()> {
+        self.publish_as_node(topics::DISCOVERY, announcement).await
     }
 
     /// Publish a capability card update.
-    pub async fn publish_capability(&self, capability: &[u8]) -> Result<()> {
-        self.publish(topics::CAPABILITY, capability).await
+    pub async fn publish_capability(&self, capability: &CapabilityCard) -> Result<()> {
+        self.publish_as_node(topics::CAPABILITY, capability).await
     }
 
     /// Publish a trust score update.
-    pub async fn publish_trust_update(&self, update: &[u8]) -> Result<()> {
-        self.publish(topics::TRUST, update).await
+    pub async fn publish_trust_update(&self, update: &TrustMessage) -> Result<()> {
+        self.publish_as_node(topics::TRUST, update).await
     }
 
     /// Get connected peers.
@@ -318,4 +403,39 @@ mod tests {
         let result = cmd_tx.send(SwarmCommand::Bootstrap).await;
         assert!(result.is_ok(), "Should be able to send via command channel");
     }
+
+    #[tokio::test]
+    async fn test_node_announcements_require_a_signer() {
+        let manager = NetworkManager::new(test_config()).expect("Should create manager");
+        let request = DiscoveryMessage::DiscoveryRequest { timestamp: 0 };
+
+        assert!(manager.publisher().is_none());
+        assert!(manager.announce_agent(&request).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_gossip_publisher_seals_messages() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let did = "did:agoramesh:base:publisher";
+        let keypair = libp2p::identity::ed25519::Keypair::generate();
+        let publisher = GossipPublisher::new(tx, EnvelopeSigner::ed25519(did, "key-1", keypair));
+
+        publisher
+            .publish(
+                topics::DISCOVERY,
+                &DiscoveryMessage::DiscoveryRequest { timestamp: 42 },
+            )
+            .await
+            .unwrap();
+
+        let Some(SwarmCommand::Publish { topic, data }) = rx.recv().await else {
+            panic!("Expected Publish command");
+        };
+        let envelope: SignedEnvelope = serde_json::from_slice(&data).unwrap();
+        assert_eq!(topic, topics::DISCOVERY);
+        assert_eq!(envelope.topic, topics::DISCOVERY);
+        assert_eq!(envelope.sender_did, did);
+        assert!(envelope.signature.is_some());
+        assert!(envelope.payload.contains("discovery_request"));
+    }
 }
@@ -0,0 +1,738 @@
+//! Signed envelopes for GossipSub messages.
+//!
+//! GossipSub signs every message with the libp2p peer key, but nothing ties
+//! that peer to the DID a message speaks for. A [`SignedEnvelope`] wraps the
+//! topic payload and is signed with a key listed in the sender's
+//! `DIDDocument` verification methods, so receivers can check the claimed
+//! DID before acting on the message.
+//!
+//! Supported verification method types:
+//! - `Ed25519VerificationKey2020` (`publicKeyMultibase`, base58btc)
+//! - `EcdsaSecp256k1RecoveryMethod2020` (`blockchainAccountId`, CAIP-10),
+//!   signed as an EIP-191 personal message
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+use alloy::primitives::{Address, Signature};
+use alloy::signers::local::PrivateKeySigner;
+use alloy::signers::SignerSync;
+use async_trait::async_trait;
+use libp2p::identity::ed25519;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::did::{DIDDocument, VerificationMethod};
+use crate::error::{Error, Result};
+
+/// Domain separator mixed into every signature.
+const SIGNING_DOMAIN: &str = "agoramesh-envelope-v1";
+
+/// Multicodec prefix for Ed25519 public keys (`0xed`, varint-encoded).
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+
+/// Verification method type for Ed25519 keys.
+pub const ED25519_METHOD_TYPE: &str = "Ed25519VerificationKey2020";
+
+/// Verification method type for secp256k1 (Ethereum account) keys.
+pub const SECP256K1_METHOD_TYPE: &str = "EcdsaSecp256k1RecoveryMethod2020";
+
+/// Default window (seconds) in which an envelope is accepted and its nonce remembered.
+pub const DEFAULT_REPLAY_WINDOW_SECS: u64 = 600;
+
+/// Allowed clock skew (seconds) for envelope timestamps in the future.
+pub const MAX_CLOCK_SKEW_SECS: u64 = 300;
+
+/// Maximum nonce length in characters.
+pub const MAX_NONCE_LEN: usize = 128;
+
+/// A GossipSub payload signed by a DID verification key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedEnvelope {
+    /// Topic the payload was published to.
+    pub topic: String,
+    /// DID of the sender.
+    pub sender_did: String,
+    /// Full ID of the verification method that signed (e.g. `did:...#key-1`).
+    pub key_id: String,
+    /// Unique per-sender nonce.
+    pub nonce: String,
+    /// Creation time (Unix seconds).
+    pub timestamp: u64,
+    /// The wrapped topic message as JSON.
+    pub payload: String,
+    /// Hex-encoded signature over [`SignedEnvelope::signing_bytes`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl SignedEnvelope {
+    /// Wrap a message for `topic` and sign it.
+    pub fn seal<T: Serialize>(topic: &str, message: &T, signer: &EnvelopeSigner) -> Result<Self> {
+        let payload = serde_json::to_string(message)
+            .map_err(|e| Error::Network(format!("Failed to serialize envelope payload: {}", e)))?;
+
+        let mut envelope = Self {
+            topic: topic.to_string(),
+            sender_did: signer.did().to_string(),
+            key_id: signer.key_id().to_string(),
+            nonce: uuid::Uuid::new_v4().simple().to_string(),
+            timestamp: unix_now()?,
+            payload,
+            signature: None,
+        };
+        envelope.signature = Some(alloy::hex::encode(signer.sign(&envelope.signing_bytes()?)));
+        Ok(envelope)
+    }
+
+    /// The bytes covered by the signature: every field except the signature.
+    pub fn signing_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(&(
+            SIGNING_DOMAIN,
+            &self.topic,
+            &self.sender_did,
+            &self.key_id,
+            &self.nonce,
+            self.timestamp,
+            &self.payload,
+        ))
+        .map_err(|e| Error::Network(format!("Failed to encode envelope: {}", e)))
+    }
+
+    /// Serialize for publishing.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| Error::Network(format!("Failed to serialize envelope: {}", e)))
+    }
+}
+
+/// Private key used to sign envelopes on behalf of a DID.
+#[derive(Clone)]
+pub struct EnvelopeSigner {
+    did: String,
+    key_id: String,
+    key: SigningKey,
+}
+
+#[derive(Clone)]
+enum SigningKey {
+    Ed25519(ed25519::Keypair),
+    Secp256k1(PrivateKeySigner),
+}
+
+impl std::fmt::Debug for EnvelopeSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material
+        f.debug_struct("EnvelopeSigner")
+            .field("did", &self.did)
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EnvelopeSigner {
+    /// Sign with an Ed25519 key listed as `{did}#{key_fragment}`.
+    pub fn ed25519(did: &str, key_fragment: &str, keypair: ed25519::Keypair) -> Self {
+        Self {
+            did: did.to_string(),
+            key_id: format!("{}#{}", did, key_fragment),
+            key: SigningKey::Ed25519(keypair),
+        }
+    }
+
+    /// Sign with a secp256k1 key whose address is listed as `{did}#{key_fragment}`.
+    pub fn secp256k1(did: &str, key_fragment: &str, signer: PrivateKeySigner) -> Self {
+        Self {
+            did: did.to_string(),
+            key_id: format!("{}#{}", did, key_fragment),
+            key: SigningKey::Secp256k1(signer),
+        }
+    }
+
+    /// The DID this signer speaks for.
+    pub fn did(&self) -> &str {
+        &self.did
+    }
+
+    /// Full verification method ID.
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    fn sign(&self, bytes: &[u8]) -> Vec<u8> {
+        match &self.key {
+            SigningKey::Ed25519(keypair) => keypair.sign(bytes),
+            SigningKey::Secp256k1(signer) => match signer.sign_message_sync(bytes) {
+                Ok(signature) => signature.as_bytes().to_vec(),
+                // Local signing only fails on invalid keys, which PrivateKeySigner rules out
+                Err(_) => Vec::new(),
+            },
+        }
+    }
+}
+
+/// Encode an Ed25519 public key as `publicKeyMultibase` (base58btc, multicodec-prefixed).
+pub fn encode_ed25519_multibase(public_key: &ed25519::PublicKey) -> String {
+    let mut bytes = ED25519_MULTICODEC.to_vec();
+    bytes.extend_from_slice(&public_key.to_bytes());
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+/// Decode a `publicKeyMultibase` value into an Ed25519 public key.
+///
+/// Accepts both multicodec-prefixed and bare 32-byte keys.
+pub fn decode_ed25519_multibase(multibase: &str) -> Result<ed25519::PublicKey> {
+    let encoded = multibase.strip_prefix('z').ok_or_else(|| {
+        Error::Did(format!(
+            "Unsupported multibase encoding (expected base58btc): {}",
+            multibase
+        ))
+    })?;
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| Error::Did(format!("Invalid base58 public key: {}", e)))?;
+    let key = bytes
+        .strip_prefix(&ED25519_MULTICODEC[..])
+        .unwrap_or(&bytes);
+
+    ed25519::PublicKey::try_from_bytes(key)
+        .map_err(|e| Error::Did(format!("Invalid Ed25519 public key: {}", e)))
+}
+
+/// Source of DID documents used to check envelope signatures.
+#[async_trait]
+pub trait DidDocumentSource: Send + Sync {
+    /// Look up the current document for `did`, if known.
+    async fn document(&self, did: &str) -> Result<Option<DIDDocument>>;
+}
+
+/// In-memory DID document registry.
+#[derive(Debug, Default)]
+pub struct DidDocumentRegistry {
+    documents: RwLock<HashMap<String, DIDDocument>>,
+}
+
+impl DidDocumentRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a document (validated first).
+    pub async fn register(&self, document: DIDDocument) -> Result<()> {
+        document.validate()?;
+        self.documents
+            .write()
+            .await
+            .insert(document.id.clone(), document);
+        Ok(())
+    }
+
+    /// Remove a document. Returns `true` if it was present.
+    pub async fn remove(&self, did: &str) -> bool {
+        self.documents.write().await.remove(did).is_some()
+    }
+}
+
+#[async_trait]
+impl DidDocumentSource for DidDocumentRegistry {
+    async fn document(&self, did: &str) -> Result<Option<DIDDocument>> {
+        Ok(self.documents.read().await.get(did).cloned())
+    }
+}
+
+/// Why an envelope was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EnvelopeRejection {
+    /// The message was not wrapped in an envelope or carried no signature.
+    #[error("Unsigned envelope: {0}")]
+    Unsigned(String),
+
+    /// The signature did not verify against the sender's DID document.
+    #[error("Bad envelope signature: {0}")]
+    BadSignature(String),
+
+    /// The nonce was already seen, or the envelope is outside the replay window.
+    #[error("Replayed envelope: {0}")]
+    Replayed(String),
+}
+
+impl From<EnvelopeRejection> for Error {
+    fn from(rejection: EnvelopeRejection) -> Self {
+        Error::Validation(rejection.to_string())
+    }
+}
+
+/// Checks envelope signatures and remembers nonces to reject replays.
+pub struct EnvelopeVerifier {
+    documents: std::sync::Arc<dyn DidDocumentSource>,
+    replay_window: u64,
+    nonces: Mutex<NonceCache>,
+}
+
+/// Nonces seen within the replay window, expired in arrival order.
+#[derive(Default)]
+struct NonceCache {
+    seen: HashSet<(String, String)>,
+    expiry: VecDeque<(u64, (String, String))>,
+}
+
+impl EnvelopeVerifier {
+    /// Create a verifier that resolves sender documents from `documents`.
+    pub fn new(documents: std::sync::Arc<dyn DidDocumentSource>) -> Self {
+        Self {
+            documents,
+            replay_window: DEFAULT_REPLAY_WINDOW_SECS,
+            nonces: Mutex::new(NonceCache::default()),
+        }
+    }
+
+    /// Set the replay window in seconds.
+    pub fn with_replay_window(mut self, secs: u64) -> Self {
+        self.replay_window = secs;
+        self
+    }
+
+    /// Number of nonces currently remembered.
+    pub fn tracked_nonces(&self) -> usize {
+        self.nonces
+            .lock()
+            .map(|cache| cache.seen.len())
+            .unwrap_or(0)
+    }
+
+    /// Parse raw topic data into an envelope.
+    ///
+    /// Returns `Ok(Err(_))` for well-formed JSON that is not a signed envelope
+    /// and `Err(_)` for data that is not JSON at all.
+    pub fn parse(data: &[u8]) -> Result<std::result::Result<SignedEnvelope, EnvelopeRejection>> {
+        match serde_json::from_slice::<SignedEnvelope>(data) {
+            Ok(envelope) => Ok(Ok(envelope)),
+            Err(e) if serde_json::from_slice::<serde_json::Value>(data).is_ok() => Ok(Err(
+                EnvelopeRejection::Unsigned(format!("message is not a signed envelope ({})", e)),
+            )),
+            Err(e) => Err(Error::Network(format!("Failed to parse envelope: {}", e))),
+        }
+    }
+
+    /// Verify an envelope received on `topic`.
+    ///
+    /// The nonce is only recorded once the signature checks out, so forged
+    /// envelopes cannot burn nonces of legitimate senders.
+    pub async fn verify(
+        &self,
+        topic: &str,
+        envelope: &SignedEnvelope,
+    ) -> std::result::Result<(), EnvelopeRejection> {
+        let signature = Self::signature_for(topic, envelope)?;
+        if envelope.nonce.is_empty() || envelope.nonce.len() > MAX_NONCE_LEN {
+            return Err(EnvelopeRejection::BadSignature(format!(
+                "nonce must be 1-{} characters",
+                MAX_NONCE_LEN
+            )));
+        }
+
+        let now = unix_now().map_err(|e| EnvelopeRejection::Replayed(e.to_string()))?;
+        if envelope.timestamp.saturating_add(self.replay_window) < now
+            || envelope.timestamp > now + MAX_CLOCK_SKEW_SECS
+        {
+            return Err(EnvelopeRejection::Replayed(format!(
+                "timestamp {} outside the {}s replay window",
+                envelope.timestamp, self.replay_window
+            )));
+        }
+
+        self.check_signature(envelope, signature).await?;
+        self.remember_nonce(envelope, now)
+    }
+
+    /// Verify only the signature of an envelope sealed for `topic`.
+    ///
+    /// Age and nonce are not checked: this is for signed statements carried
+    /// inside other messages (e.g. an agent's registration relayed with its
+    /// card), which are legitimately sent more than once.
+    pub async fn verify_signature(
+        &self,
+        topic: &str,
+        envelope: &SignedEnvelope,
+    ) -> std::result::Result<(), EnvelopeRejection> {
+        let signature = Self::signature_for(topic, envelope)?;
+        self.check_signature(envelope, signature).await
+    }
+
+    /// The signature of an envelope, which must be sealed for `topic`.
+    fn signature_for<'a>(
+        topic: &str,
+        envelope: &'a SignedEnvelope,
+    ) -> std::result::Result<&'a str, EnvelopeRejection> {
+        let signature = match envelope.signature.as_deref() {
+            Some(signature) if !signature.is_empty() => signature,
+            _ => {
+                return Err(EnvelopeRejection::Unsigned(format!(
+                    "no signature from {}",
+                    envelope.sender_did
+                )))
+            }
+        };
+
+        if envelope.topic != topic {
+            return Err(EnvelopeRejection::BadSignature(format!(
+                "signed for topic {}, received on {}",
+                envelope.topic, topic
+            )));
+        }
+        Ok(signature)
+    }
+
+    /// Check `signature` against the sender's verification method.
+    async fn check_signature(
+        &self,
+        envelope: &SignedEnvelope,
+        signature: &str,
+    ) -> std::result::Result<(), EnvelopeRejection> {
+        let method = self.verification_method(envelope).await?;
+        let signature = alloy::hex::decode(signature)
+            .map_err(|e| EnvelopeRejection::BadSignature(format!("invalid hex: {}", e)))?;
+        let message = envelope
+            .signing_bytes()
+            .map_err(|e| EnvelopeRejection::BadSignature(e.to_string()))?;
+        verify_with_method(&method, &message, &signature)
+    }
+
+    /// Find the signing key in the sender's DID document.
+    async fn verification_method(
+        &self,
+        envelope: &SignedEnvelope,
+    ) -> std::result::Result<VerificationMethod, EnvelopeRejection> {
+        let document = self
+            .documents
+            .document(&envelope.sender_did)
+            .await
+            .map_err(|e| EnvelopeRejection::BadSignature(e.to_string()))?
+            .filter(|document| document.id == envelope.sender_did)
+            .ok_or_else(|| {
+                EnvelopeRejection::BadSignature(format!(
+                    "no DID document for {}",
+                    envelope.sender_did
+                ))
+            })?;
+
+        document
+            .verification_method
+            .unwrap_or_default()
+            .into_iter()
+            .find(|method| method.id == envelope.key_id)
+            .ok_or_else(|| {
+                EnvelopeRejection::BadSignature(format!(
+                    "key {} is not a verification method of {}",
+                    envelope.key_id, envelope.sender_did
+                ))
+            })
+    }
+
+    /// Record the envelope's nonce, rejecting it if already seen.
+    fn remember_nonce(
+        &self,
+        envelope: &SignedEnvelope,
+        now: u64,
+    ) -> std::result::Result<(), EnvelopeRejection> {
+        let mut cache = self
+            .nonces
+            .lock()
+            .map_err(|_| EnvelopeRejection::Replayed("nonce cache poisoned".to_string()))?;
+
+        while cache
+            .expiry
+            .front()
+            .is_some_and(|(expires, _)| *expires <= now)
+        {
+            if let Some((_, key)) = cache.expiry.pop_front() {
+                cache.seen.remove(&key);
+            }
+        }
+
+        let key = (envelope.sender_did.clone(), envelope.nonce.clone());
+        if !cache.seen.insert(key.clone()) {
+            return Err(EnvelopeRejection::Replayed(format!(
+                "nonce {} from {} already seen",
+                envelope.nonce, envelope.sender_did
+            )));
+        }
+
+        // The envelope stays acceptable until timestamp + window, and the
+        // timestamp may lead our clock by up to the allowed skew.
+        let expires = now + self.replay_window + MAX_CLOCK_SKEW_SECS;
+        cache.expiry.push_back((expires, key));
+        Ok(())
+    }
+}
+
+/// Check `signature` over `message` with a DID verification method.
+fn verify_with_method(
+    method: &VerificationMethod,
+    message: &[u8],
+    signature: &[u8],
+) -> std::result::Result<(), EnvelopeRejection> {
+    match method.method_type.as_str() {
+        ED25519_METHOD_TYPE => {
+            let multibase = method.public_key_multibase.as_deref().ok_or_else(|| {
+                EnvelopeRejection::BadSignature(format!("{} has no publicKeyMultibase", method.id))
+            })?;
+            let public_key = decode_ed25519_multibase(multibase)
+                .map_err(|e| EnvelopeRejection::BadSignature(e.to_string()))?;
+
+            if public_key.verify(message, signature) {
+                Ok(())
+            } else {
+                Err(EnvelopeRejection::BadSignature(format!(
+                    "signature does not match {}",
+                    method.id
+                )))
+            }
+        }
+        SECP256K1_METHOD_TYPE => {
+            // CAIP-10: eip155:{chainId}:{address}
+            let expected = method
+                .blockchain_account_id
+                .as_deref()
+                .and_then(|account| account.rsplit(':').next())
+                .and_then(|address| address.parse::<Address>().ok())
+                .ok_or_else(|| {
+                    EnvelopeRejection::BadSignature(format!(
+                        "{} has no valid blockchainAccountId",
+                        method.id
+                    ))
+                })?;
+
+            let recovered = Signature::from_raw(signature)
+                .and_then(|signature| signature.recover_address_from_msg(message))
+                .map_err(|e| {
+                    EnvelopeRejection::BadSignature(format!("invalid signature: {}", e))
+                })?;
+
+            if recovered == expected {
+                Ok(())
+            } else {
+                Err(EnvelopeRejection::BadSignature(format!(
+                    "signature recovers {}, expected {}",
+                    recovered, expected
+                )))
+            }
+        }
+        other => Err(EnvelopeRejection::BadSignature(format!(
+            "unsupported verification method type {}",
+            other
+        ))),
+    }
+}
+
+fn unix_now() -> Result<u64> {
+    Ok(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| Error::Internal(format!("System clock error: {}", e)))?
+        .as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::did::DIDDocumentBuilder;
+    use std::sync::Arc;
+
+    const DID: &str = "did:agoramesh:base:envelope-test";
+
+    fn ed25519_identity() -> (EnvelopeSigner, DIDDocument) {
+        let keypair = ed25519::Keypair::generate();
+        let document = DIDDocumentBuilder::new("base", "envelope-test")
+            .add_ed25519_key("key-1", &encode_ed25519_multibase(&keypair.public()))
+            .build()
+            .unwrap();
+        (EnvelopeSigner::ed25519(DID, "key-1", keypair), document)
+    }
+
+    async fn verifier_for(document: DIDDocument) -> EnvelopeVerifier {
+        let registry = Arc::new(DidDocumentRegistry::new());
+        registry.register(document).await.unwrap();
+        EnvelopeVerifier::new(registry)
+    }
+
+    // ========== TDD Tests: Multibase keys ==========
+
+    #[test]
+    fn test_ed25519_multibase_roundtrip() {
+        let keypair = ed25519::Keypair::generate();
+        let encoded = encode_ed25519_multibase(&keypair.public());
+
+        assert!(
+            encoded.starts_with("z6Mk"),
+            "Multicodec-prefixed keys start with z6Mk"
+        );
+        assert_eq!(
+            decode_ed25519_multibase(&encoded).unwrap(),
+            keypair.public()
+        );
+    }
+
+    #[test]
+    fn test_decode_multibase_rejects_other_bases() {
+        assert!(decode_ed25519_multibase("mAbCd").is_err());
+        assert!(decode_ed25519_multibase("z0OIl").is_err());
+    }
+
+    // ========== TDD Tests: Signing and verification ==========
+
+    #[tokio::test]
+    async fn test_sealed_envelope_verifies() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        let envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+
+        assert_eq!(envelope.sender_did, DID);
+        assert_eq!(envelope.key_id, format!("{}#key-1", DID));
+        assert_eq!(verifier.verify("topic", &envelope).await, Ok(()));
+        assert_eq!(verifier.tracked_nonces(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_payload_is_bad_signature() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        let mut envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+        envelope.payload = "\"goodbye\"".to_string();
+
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+        assert_eq!(
+            verifier.tracked_nonces(),
+            0,
+            "Forged envelopes must not burn nonces"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_not_in_document_is_bad_signature() {
+        let (_, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        // Same DID, but a key the document does not list
+        let impostor = EnvelopeSigner::ed25519(DID, "key-1", ed25519::Keypair::generate());
+        let envelope = SignedEnvelope::seal("topic", &"hello", &impostor).unwrap();
+
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_sender_is_bad_signature() {
+        let (signer, _) = ed25519_identity();
+        let verifier = EnvelopeVerifier::new(Arc::new(DidDocumentRegistry::new()));
+
+        let envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_topic_is_bad_signature() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        let envelope = SignedEnvelope::seal("topic-a", &"hello", &signer).unwrap();
+
+        let result = verifier.verify("topic-b", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+    }
+
+    #[tokio::test]
+    async fn test_missing_signature_is_unsigned() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        let mut envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+        envelope.signature = None;
+
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::Unsigned(_))));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_nonce_is_replay() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await;
+
+        let envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+
+        assert_eq!(verifier.verify("topic", &envelope).await, Ok(()));
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::Replayed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_stale_envelope_is_replay() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await.with_replay_window(60);
+
+        // Re-sign with an old timestamp so only the age is wrong
+        let mut envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+        envelope.timestamp -= 120;
+        envelope.signature = Some(alloy::hex::encode(
+            signer.sign(&envelope.signing_bytes().unwrap()),
+        ));
+
+        let result = verifier.verify("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::Replayed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_signature_accepts_old_and_repeated_envelopes() {
+        let (signer, document) = ed25519_identity();
+        let verifier = verifier_for(document).await.with_replay_window(60);
+
+        let mut envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+        envelope.timestamp -= 120;
+        envelope.signature = Some(alloy::hex::encode(
+            signer.sign(&envelope.signing_bytes().unwrap()),
+        ));
+
+        assert_eq!(verifier.verify_signature("topic", &envelope).await, Ok(()));
+        assert_eq!(verifier.verify_signature("topic", &envelope).await, Ok(()));
+        assert_eq!(verifier.tracked_nonces(), 0);
+
+        envelope.payload = "\"goodbye\"".to_string();
+        let result = verifier.verify_signature("topic", &envelope).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+    }
+
+    #[tokio::test]
+    async fn test_secp256k1_envelope_verifies() {
+        let key = PrivateKeySigner::random();
+        let document = DIDDocumentBuilder::new("base", "envelope-test")
+            .add_ethereum_account("eth", &key.address().to_string(), 8453)
+            .build()
+            .unwrap();
+        let verifier = verifier_for(document).await;
+
+        let signer = EnvelopeSigner::secp256k1(DID, "eth", key);
+        let envelope = SignedEnvelope::seal("topic", &"hello", &signer).unwrap();
+        assert_eq!(verifier.verify("topic", &envelope).await, Ok(()));
+
+        let impostor = EnvelopeSigner::secp256k1(DID, "eth", PrivateKeySigner::random());
+        let forged = SignedEnvelope::seal("topic", &"hello", &impostor).unwrap();
+        let result = verifier.verify("topic", &forged).await;
+        assert!(matches!(result, Err(EnvelopeRejection::BadSignature(_))));
+    }
+
+    #[test]
+    fn test_parse_distinguishes_unsigned_from_garbage() {
+        let legacy = serde_json::to_vec(&serde_json::json!({"type": "trust_update"})).unwrap();
+
+        assert!(matches!(
+            EnvelopeVerifier::parse(&legacy),
+            Ok(Err(EnvelopeRejection::Unsigned(_)))
+        ));
+        assert!(EnvelopeVerifier::parse(b"not json").is_err());
+    }
+}
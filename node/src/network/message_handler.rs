@@ -1,6 +1,7 @@
 //! Network message handler for GossipSub messages.
 //!
 //! This module handles:
+//! - Verifying signed envelopes (see [`super::envelope`])
 //! - Parsing incoming GossipSub messages by topic
 //! - Routing messages to appropriate handlers
 //! - Processing discovery, capability, and trust messages
@@ -10,12 +11,17 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
+use crate::api::REGISTER_AGENT_TOPIC;
 use crate::arbitration::{AIArbitrator, Evidence, EvidenceType};
+use crate::did_resolver::PeerDidResolver;
 use crate::discovery::{CapabilityCard, DiscoveryService};
 use crate::error::{Error, Result};
 use crate::trust::TrustService;
 
 use super::behaviour::topics;
+use super::envelope::{
+    DidDocumentRegistry, DidDocumentSource, EnvelopeRejection, EnvelopeVerifier, SignedEnvelope,
+};
 use super::NetworkEvent;
 
 /// Maximum allowed length for evidence title (256 characters).
@@ -34,6 +40,16 @@ pub enum DiscoveryMessage {
         /// The capability card being announced (boxed to reduce enum size).
         card: Box<CapabilityCard>,
     },
+    /// Another agent's capability card, relayed by the sending node.
+    #[serde(rename = "card_relay")]
+    CardRelay {
+        /// The relayed capability card (boxed to reduce enum size).
+        card: Box<CapabilityCard>,
+        /// The agent's own registration of the card: a [`SignedEnvelope`]
+        /// sealed for [`crate::api::REGISTER_AGENT_TOPIC`].
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        registration: Option<Box<SignedEnvelope>>,
+    },
     /// A request for other nodes to announce their agents.
     #[serde(rename = "discovery_request")]
     DiscoveryRequest {
@@ -57,6 +73,9 @@ pub enum TrustMessage {
         timestamp: u64,
     },
     /// A reputation event (success/failure).
+    ///
+    /// Only recorded when the sender meets the trust requirements and is not
+    /// the agent the event is about.
     #[serde(rename = "reputation_event")]
     ReputationEvent {
         /// The agent's DID.
@@ -67,6 +86,21 @@ pub enum TrustMessage {
         amount: u64,
         /// Timestamp of the event.
         timestamp: u64,
+        /// ID of the skill the transaction used, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        skill: Option<String>,
+    },
+    /// An endorser withdrawing its endorsement of an agent.
+    ///
+    /// Only accepted in an envelope signed by the endorser.
+    #[serde(rename = "endorsement_revocation")]
+    EndorsementRevocation {
+        /// DID of the endorser.
+        endorser_did: String,
+        /// DID of the endorsed agent.
+        target_did: String,
+        /// Timestamp of the revocation.
+        timestamp: u64,
     },
 }
 
@@ -129,6 +163,14 @@ pub struct MessageHandlerStats {
     pub dispute_messages: u64,
     /// Unknown topic messages received.
     pub unknown_topic_messages: u64,
+    /// Messages rejected for not being signed.
+    pub unsigned_envelopes: u64,
+    /// Envelopes rejected for a signature that did not verify.
+    pub invalid_signatures: u64,
+    /// Envelopes rejected for a reused nonce or a stale timestamp.
+    pub replayed_nonces: u64,
+    /// Cards dropped because their DID did not meet the trust requirements.
+    pub untrusted_cards: u64,
 }
 
 impl MessageHandlerStats {
@@ -166,6 +208,20 @@ impl MessageHandlerStats {
     pub fn record_unknown_topic(&mut self) {
         self.unknown_topic_messages += 1;
     }
+
+    /// Record a rejected envelope under its rejection reason.
+    pub fn record_rejection(&mut self, rejection: &EnvelopeRejection) {
+        match rejection {
+            EnvelopeRejection::Unsigned(_) => self.unsigned_envelopes += 1,
+            EnvelopeRejection::BadSignature(_) => self.invalid_signatures += 1,
+            EnvelopeRejection::Replayed(_) => self.replayed_nonces += 1,
+        }
+    }
+
+    /// Record a card dropped by the gossip trust gate.
+    pub fn record_untrusted_card(&mut self) {
+        self.untrusted_cards += 1;
+    }
 }
 
 /// Handler for incoming network messages.
@@ -175,12 +231,16 @@ pub struct MessageHandler {
     /// Discovery service for caching capability cards.
     discovery_service: Arc<DiscoveryService>,
 
-    /// Optional trust service for recording reputation events.
+    /// Optional trust service for recording reputation events and gating
+    /// card announcements (`trust.gate_gossip`).
     trust_service: Option<Arc<TrustService>>,
 
     /// Optional arbitrator for handling disputes.
     arbitrator: Option<Arc<AIArbitrator>>,
 
+    /// Signature and replay checks for incoming envelopes.
+    envelopes: EnvelopeVerifier,
+
     /// Handler statistics.
     stats: RwLock<MessageHandlerStats>,
 }
@@ -192,6 +252,7 @@ impl MessageHandler {
             discovery_service,
             trust_service: None,
             arbitrator: None,
+            envelopes: Self::default_verifier(),
             stats: RwLock::new(MessageHandlerStats::default()),
         }
     }
@@ -205,6 +266,7 @@ impl MessageHandler {
             discovery_service,
             trust_service,
             arbitrator: None,
+            envelopes: Self::default_verifier(),
             stats: RwLock::new(MessageHandlerStats::default()),
         }
     }
@@ -219,10 +281,30 @@ impl MessageHandler {
             discovery_service,
             trust_service,
             arbitrator,
+            envelopes: Self::default_verifier(),
             stats: RwLock::new(MessageHandlerStats::default()),
         }
     }
 
+    /// Set where sender DID documents are resolved from.
+    ///
+    /// Until this is called no sender can be resolved, so every envelope is
+    /// rejected.
+    pub fn with_did_documents(mut self, documents: Arc<dyn DidDocumentSource>) -> Self {
+        self.envelopes = EnvelopeVerifier::new(documents);
+        self
+    }
+
+    /// Replace the envelope verifier (e.g. to change the replay window).
+    pub fn with_envelope_verifier(mut self, verifier: EnvelopeVerifier) -> Self {
+        self.envelopes = verifier;
+        self
+    }
+
+    fn default_verifier() -> EnvelopeVerifier {
+        EnvelopeVerifier::new(Arc::new(DidDocumentRegistry::new()))
+    }
+
     /// Handle an incoming network event.
     ///
     /// Routes the message to the appropriate handler based on topic.
@@ -259,23 +341,154 @@ impl MessageHandler {
         data: &[u8],
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
+        if !topics::all().contains(&topic) {
+            self.stats.write().await.record_unknown_topic();
+            warn!("Received message on unknown topic: {}", topic);
+            return Ok(());
+        }
+
+        let envelope = self.open_envelope(topic, data, source).await?;
+        let payload = envelope.payload.as_bytes();
+        let sender = envelope.sender_did.as_str();
+        Self::require_source(sender, source)?;
+
         match topic {
-            topics::DISCOVERY => self.handle_discovery_message(data, source).await,
-            topics::CAPABILITY => self.handle_capability_message(data, source).await,
-            topics::TRUST => self.handle_trust_message(data, source).await,
-            topics::DISPUTES => self.handle_dispute_message(data, source).await,
-            _ => {
-                self.stats.write().await.record_unknown_topic();
-                warn!("Received message on unknown topic: {}", topic);
-                Ok(())
+            topics::DISCOVERY => self.handle_discovery_message(payload, sender, source).await,
+            topics::CAPABILITY => {
+                self.handle_capability_message(payload, sender, source)
+                    .await
             }
+            topics::TRUST => self.handle_trust_message(payload, sender, source).await,
+            _ => self.handle_dispute_message(payload, sender, source).await,
+        }
+    }
+
+    /// Parse and verify the signed envelope around a topic message.
+    ///
+    /// Rejections are counted by reason in the handler statistics.
+    async fn open_envelope(
+        &self,
+        topic: &str,
+        data: &[u8],
+        source: Option<&libp2p::PeerId>,
+    ) -> Result<SignedEnvelope> {
+        let parsed = match EnvelopeVerifier::parse(data) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.stats.write().await.record_parse_error();
+                warn!("Failed to parse message on {}: {}", topic, e);
+                return Err(e);
+            }
+        };
+
+        let verified = match parsed {
+            Ok(envelope) => self
+                .envelopes
+                .verify(topic, &envelope)
+                .await
+                .map(|()| envelope),
+            Err(rejection) => Err(rejection),
+        };
+
+        match verified {
+            Ok(envelope) => Ok(envelope),
+            Err(rejection) => {
+                warn!(
+                    "Rejecting message on {} from {:?}: {}",
+                    topic, source, rejection
+                );
+                self.stats.write().await.record_rejection(&rejection);
+                Err(rejection.into())
+            }
+        }
+    }
+
+    /// Ensure an envelope signed by a node DID was published by that node.
+    ///
+    /// Node DIDs name their PeerId, and gossipsub's `source` is the signed
+    /// original publisher, so the two must agree.
+    fn require_source(sender: &str, source: Option<&libp2p::PeerId>) -> Result<()> {
+        let Some(peer_id) = PeerDidResolver::peer_id(sender) else {
+            return Ok(());
+        };
+        if source != Some(&peer_id) {
+            warn!(
+                "Rejecting message from {}: published by {:?}, not {}",
+                sender, source, peer_id
+            );
+            return Err(Error::Validation(format!(
+                "Node DID {} was not published by peer {}",
+                sender, peer_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Ensure a message about `did` was signed by that DID.
+    fn require_sender(what: &str, did: &str, sender: &str) -> Result<()> {
+        if did != sender {
+            warn!(
+                "Rejecting {}: {} does not match envelope sender {}",
+                what, did, sender
+            );
+            return Err(Error::Validation(format!(
+                "{} DID {} does not match envelope sender {}",
+                what, did, sender
+            )));
+        }
+        Ok(())
+    }
+
+    /// Apply the trust service's gossip gate to a card from `did`.
+    ///
+    /// Without a trust service, or with `gate_gossip` off, every card passes.
+    async fn require_trusted(&self, did: &str) -> Result<()> {
+        let Some(trust) = self.trust_service.as_ref() else {
+            return Ok(());
+        };
+        if !trust.requirements().gate_gossip || trust.meets_requirements(did).await? {
+            return Ok(());
+        }
+
+        self.stats.write().await.record_untrusted_card();
+        warn!("Dropping card from {}: below trust requirements", did);
+        Err(Error::Trust(format!(
+            "{} does not meet the trust requirements",
+            did
+        )))
+    }
+
+    /// Ensure `sender` may report reputation events about `did`.
+    ///
+    /// DIDs are free to mint, so only reporters that meet the trust
+    /// requirements themselves are heard, whatever `gate_gossip` says, and
+    /// nobody reports on itself.
+    async fn require_reporter(trust: &TrustService, did: &str, sender: &str) -> Result<()> {
+        if did == sender {
+            warn!("Rejecting reputation event: {} reported on itself", did);
+            return Err(Error::Validation(format!(
+                "{} cannot report reputation events about itself",
+                did
+            )));
         }
+        if !trust.meets_requirements(sender).await? {
+            warn!(
+                "Rejecting reputation event for {}: reporter {} is below trust requirements",
+                did, sender
+            );
+            return Err(Error::Trust(format!(
+                "Reporter {} does not meet the trust requirements",
+                sender
+            )));
+        }
+        Ok(())
     }
 
     /// Handle a message on the discovery topic.
     async fn handle_discovery_message(
         &self,
         data: &[u8],
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         self.stats.write().await.record_discovery();
@@ -283,7 +496,8 @@ impl MessageHandler {
         // Try to parse as DiscoveryMessage first
         match serde_json::from_slice::<DiscoveryMessage>(data) {
             Ok(message) => {
-                self.process_discovery_message(message, source).await?;
+                self.process_discovery_message(message, sender, source)
+                    .await?;
                 self.stats.write().await.record_processed();
                 Ok(())
             }
@@ -291,7 +505,7 @@ impl MessageHandler {
                 // Try parsing as raw CapabilityCard (backward compatibility)
                 match serde_json::from_slice::<CapabilityCard>(data) {
                     Ok(card) => {
-                        self.process_card_announcement(card, source).await?;
+                        self.process_card_announcement(card, sender, source).await?;
                         self.stats.write().await.record_processed();
                         Ok(())
                     }
@@ -312,11 +526,16 @@ impl MessageHandler {
     async fn process_discovery_message(
         &self,
         message: DiscoveryMessage,
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         match message {
             DiscoveryMessage::CardAnnouncement { card } => {
-                self.process_card_announcement(*card, source).await
+                self.process_card_announcement(*card, sender, source).await
+            }
+            DiscoveryMessage::CardRelay { card, registration } => {
+                self.process_card_relay(*card, registration.map(|r| *r), sender, source)
+                    .await
             }
             DiscoveryMessage::DiscoveryRequest { timestamp } => {
                 self.process_discovery_request(timestamp, source).await
@@ -325,9 +544,14 @@ impl MessageHandler {
     }
 
     /// Process a capability card announcement.
+    ///
+    /// Agents may only announce their own card (other agents' cards arrive
+    /// as [`DiscoveryMessage::CardRelay`]), and must pass the gossip trust
+    /// gate when it is enabled.
     async fn process_card_announcement(
         &self,
         card: CapabilityCard,
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         let did = card
@@ -335,17 +559,102 @@ impl MessageHandler {
             .as_ref()
             .map(|ext| ext.did.as_str())
             .unwrap_or("unknown");
+        Self::require_sender("Card", did, sender)?;
+        self.require_trusted(did).await?;
 
         info!("Received card announcement for {} from {:?}", did, source);
 
         // Register the card in our local discovery service
         // Note: This will also index in HybridSearch if available
-        self.discovery_service.register(&card).await?;
+        self.discovery_service.register_from_peer(&card).await?;
 
         debug!("Cached card for {}", did);
         Ok(())
     }
 
+    /// Process a card relayed by a node on behalf of another agent.
+    ///
+    /// The relay's envelope only identifies the relaying node, so the card
+    /// is checked separately: with the agent's signed registration it is
+    /// accepted like the agent's own announcement. Without one it may only
+    /// introduce an agent this node has no card for, never replace one.
+    async fn process_card_relay(
+        &self,
+        card: CapabilityCard,
+        registration: Option<SignedEnvelope>,
+        sender: &str,
+        source: Option<&libp2p::PeerId>,
+    ) -> Result<()> {
+        let did = card
+            .agoramesh
+            .as_ref()
+            .map(|ext| ext.did.clone())
+            .ok_or_else(|| Error::Validation("Relayed card has no DID".to_string()))?;
+
+        match registration {
+            Some(registration) => self.verify_registration(&card, &did, &registration).await?,
+            None => {
+                if self.discovery_service.has_card(&did)? {
+                    warn!(
+                        "Rejecting unsigned relay of {} from {}: card already known",
+                        did, sender
+                    );
+                    return Err(Error::Validation(format!(
+                        "Relayed card for {} needs the agent's signed registration to replace the known card",
+                        did
+                    )));
+                }
+                self.require_trusted(sender).await?;
+            }
+        }
+        self.require_trusted(&did).await?;
+
+        info!(
+            "Received card for {} relayed by {} ({:?})",
+            did, sender, source
+        );
+        self.discovery_service.register_from_peer(&card).await?;
+
+        debug!("Cached relayed card for {}", did);
+        Ok(())
+    }
+
+    /// Check that `registration` is `did`'s own signed registration of `card`.
+    async fn verify_registration(
+        &self,
+        card: &CapabilityCard,
+        did: &str,
+        registration: &SignedEnvelope,
+    ) -> Result<()> {
+        Self::require_sender("Registration", did, &registration.sender_did)?;
+        if let Err(rejection) = self
+            .envelopes
+            .verify_signature(REGISTER_AGENT_TOPIC, registration)
+            .await
+        {
+            warn!("Rejecting relayed card for {}: {}", did, rejection);
+            self.stats.write().await.record_rejection(&rejection);
+            return Err(rejection.into());
+        }
+
+        // Compare normalized cards: the agent's JSON may use aliases or
+        // omit defaults that the relayed copy spells out
+        let registered = serde_json::from_str::<CapabilityCard>(&registration.payload)
+            .ok()
+            .and_then(|registered| serde_json::to_value(registered).ok());
+        if registered.is_none() || registered != serde_json::to_value(card).ok() {
+            warn!(
+                "Rejecting relayed card for {}: differs from its registration",
+                did
+            );
+            return Err(Error::Validation(format!(
+                "Relayed card for {} does not match the agent's registration",
+                did
+            )));
+        }
+        Ok(())
+    }
+
     /// Process a discovery request (request for registry broadcast).
     async fn process_discovery_request(
         &self,
@@ -367,12 +676,21 @@ impl MessageHandler {
     async fn handle_capability_message(
         &self,
         data: &[u8],
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         // Capability messages are similar to discovery card announcements
         // but specifically for capability updates
         match serde_json::from_slice::<CapabilityCard>(data) {
             Ok(card) => {
+                let did = card
+                    .agoramesh
+                    .as_ref()
+                    .map(|ext| ext.did.as_str())
+                    .unwrap_or("unknown");
+                Self::require_sender("Card", did, sender)?;
+                self.require_trusted(did).await?;
+
                 info!("Received capability update from {:?}", source);
                 self.discovery_service.register(&card).await?;
                 self.stats.write().await.record_processed();
@@ -393,13 +711,14 @@ impl MessageHandler {
     async fn handle_trust_message(
         &self,
         data: &[u8],
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         self.stats.write().await.record_trust();
 
         match serde_json::from_slice::<TrustMessage>(data) {
             Ok(message) => {
-                self.process_trust_message(message, source).await?;
+                self.process_trust_message(message, sender, source).await?;
                 self.stats.write().await.record_processed();
                 Ok(())
             }
@@ -418,6 +737,7 @@ impl MessageHandler {
     async fn process_trust_message(
         &self,
         message: TrustMessage,
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         match message {
@@ -462,8 +782,8 @@ impl MessageHandler {
                 }
 
                 info!(
-                    "Received trust update for {}: score={} from {:?}",
-                    did, trust_score, source
+                    "Received trust update for {}: score={} from {} ({:?})",
+                    did, trust_score, sender, source
                 );
 
                 // Validate against existing trust data if TrustService is available
@@ -514,6 +834,7 @@ impl MessageHandler {
                 success,
                 amount,
                 timestamp,
+                skill,
             } => {
                 // Validate DID format
                 if !did.starts_with("did:") {
@@ -539,20 +860,28 @@ impl MessageHandler {
                 }
 
                 info!(
-                    "Received reputation event for {}: success={}, amount={} from {:?}",
-                    did, success, amount, source
+                    "Received reputation event for {}: success={}, amount={} from {} ({:?})",
+                    did, success, amount, sender, source
                 );
 
                 // Record in TrustService if available
                 if let Some(ref trust_service) = self.trust_service {
+                    Self::require_reporter(trust_service, &did, sender).await?;
                     if success {
-                        trust_service.record_success(&did, amount).await?;
+                        trust_service
+                            .record_success(&did, amount, skill.as_deref())
+                            .await?;
                         debug!("Recorded success for {} (amount={})", did, amount);
                     } else {
                         trust_service
-                            .record_failure(&did, "P2P reputation event")
+                            .record_failure_with_amount(
+                                &did,
+                                amount,
+                                "P2P reputation event",
+                                skill.as_deref(),
+                            )
                             .await?;
-                        debug!("Recorded failure for {}", did);
+                        debug!("Recorded failure for {} (amount={})", did, amount);
                     }
                 } else {
                     debug!(
@@ -562,6 +891,48 @@ impl MessageHandler {
                 }
                 Ok(())
             }
+            TrustMessage::EndorsementRevocation {
+                endorser_did,
+                target_did,
+                timestamp,
+            } => {
+                Self::require_sender("Endorsement revocation", &endorser_did, sender)?;
+
+                // Validate timestamp is not in the future
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_err(|e| Error::Internal(format!("System clock error: {}", e)))?
+                    .as_secs();
+                let grace_period = 300; // 5 minutes
+                if timestamp > now + grace_period {
+                    warn!(
+                        "Rejecting endorsement revocation: timestamp {} is in the future for {}",
+                        timestamp, target_did
+                    );
+                    return Err(Error::Validation(format!(
+                        "Timestamp {} is in the future",
+                        timestamp
+                    )));
+                }
+
+                info!(
+                    "Received revocation of {}'s endorsement of {} ({:?})",
+                    endorser_did, target_did, source
+                );
+
+                if let Some(ref trust_service) = self.trust_service {
+                    let revoked = trust_service
+                        .revoke_endorsement(&endorser_did, &target_did)
+                        .await?;
+                    debug!(
+                        "Endorsement of {} by {} {}",
+                        target_did,
+                        endorser_did,
+                        if revoked { "revoked" } else { "was not known" }
+                    );
+                }
+                Ok(())
+            }
         }
     }
 
@@ -569,6 +940,7 @@ impl MessageHandler {
     async fn handle_dispute_message(
         &self,
         data: &[u8],
+        sender: &str,
         source: Option<&libp2p::PeerId>,
     ) -> Result<()> {
         // Record dispute message
@@ -590,26 +962,42 @@ impl MessageHandler {
                 provider_did,
                 amount_usdc,
                 timestamp,
-            } => self.process_create_dispute(
-                escrow_id,
-                client_did,
-                provider_did,
-                amount_usdc,
-                timestamp,
-            ),
+            } => {
+                // Only a party to the escrow may open a dispute over it
+                if sender != client_did && sender != provider_did {
+                    warn!(
+                        "Rejecting dispute: envelope sender {} is neither {} nor {}",
+                        sender, client_did, provider_did
+                    );
+                    return Err(Error::Validation(format!(
+                        "Envelope sender {} is not a party to escrow {}",
+                        sender, escrow_id
+                    )));
+                }
+                self.process_create_dispute(
+                    escrow_id,
+                    client_did,
+                    provider_did,
+                    amount_usdc,
+                    timestamp,
+                )
+            }
             DisputeMessage::SubmitEvidence {
                 dispute_id,
                 submitter_did,
                 title,
                 description,
                 timestamp,
-            } => self.process_submit_evidence(
-                dispute_id,
-                submitter_did,
-                title,
-                description,
-                timestamp,
-            ),
+            } => {
+                Self::require_sender("Evidence submitter", &submitter_did, sender)?;
+                self.process_submit_evidence(
+                    dispute_id,
+                    submitter_did,
+                    title,
+                    description,
+                    timestamp,
+                )
+            }
             DisputeMessage::DisputeStatus {
                 dispute_id,
                 timestamp,
@@ -825,6 +1213,10 @@ impl MessageHandler {
             trust_messages: stats.trust_messages,
             dispute_messages: stats.dispute_messages,
             unknown_topic_messages: stats.unknown_topic_messages,
+            unsigned_envelopes: stats.unsigned_envelopes,
+            invalid_signatures: stats.invalid_signatures,
+            replayed_nonces: stats.replayed_nonces,
+            untrusted_cards: stats.untrusted_cards,
         }
     }
 }
@@ -832,10 +1224,83 @@ impl MessageHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::did::{DIDDocument, VerificationMethod};
+    use crate::did_resolver::UniversalResolver;
     use crate::discovery::{AgoraMeshExtension, PricingInfo, PricingModel, ProviderInfo, Skill};
+    use crate::identity::NodeIdentity;
+    use crate::network::envelope::{encode_ed25519_multibase, EnvelopeSigner, ED25519_METHOD_TYPE};
     use libp2p::gossipsub::MessageId;
+    use libp2p::identity::ed25519;
     use libp2p::PeerId;
 
+    /// Default envelope sender for messages that don't speak for a specific agent.
+    const TEST_PEER: &str = "did:agoramesh:base:test-peer";
+
+    /// Ed25519 key derived from the DID itself, so tests can sign as anyone.
+    fn test_key(did: &str) -> ed25519::Keypair {
+        let seed = alloy::primitives::keccak256(did.as_bytes());
+        ed25519::SecretKey::try_from_bytes(seed.0).unwrap().into()
+    }
+
+    /// Resolves every DID to a document listing its derived key as `#key-1`.
+    struct TestDocuments;
+
+    #[async_trait::async_trait]
+    impl DidDocumentSource for TestDocuments {
+        async fn document(&self, did: &str) -> Result<Option<DIDDocument>> {
+            Ok(Some(DIDDocument {
+                context: vec!["https://www.w3.org/ns/did/v1".to_string()],
+                id: did.to_string(),
+                controller: None,
+                verification_method: Some(vec![VerificationMethod {
+                    id: format!("{}#key-1", did),
+                    method_type: ED25519_METHOD_TYPE.to_string(),
+                    controller: did.to_string(),
+                    public_key_multibase: Some(encode_ed25519_multibase(&test_key(did).public())),
+                    public_key_jwk: None,
+                    blockchain_account_id: None,
+                }]),
+                authentication: None,
+                assertion_method: None,
+                service: None,
+                metadata: None,
+            }))
+        }
+    }
+
+    fn test_documents() -> Arc<dyn DidDocumentSource> {
+        Arc::new(TestDocuments)
+    }
+
+    fn test_signer(did: &str) -> EnvelopeSigner {
+        EnvelopeSigner::ed25519(did, "key-1", test_key(did))
+    }
+
+    /// Wrap a JSON payload in an envelope signed by `sender`.
+    fn seal(topic: &str, sender: &str, payload: &[u8]) -> Vec<u8> {
+        let message: serde_json::Value = serde_json::from_slice(payload).unwrap();
+        SignedEnvelope::seal(topic, &message, &test_signer(sender))
+            .unwrap()
+            .to_bytes()
+            .unwrap()
+    }
+
+    fn message_event(topic: &str, data: Vec<u8>) -> NetworkEvent {
+        NetworkEvent::Message {
+            topic: topic.to_string(),
+            source: Some(PeerId::random()),
+            data,
+            message_id: MessageId::new(b"envelope-msg"),
+        }
+    }
+
+    fn now_secs() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
     fn sample_card(did: &str) -> CapabilityCard {
         CapabilityCard {
             name: "Test Agent".to_string(),
@@ -876,7 +1341,7 @@ mod tests {
     #[test]
     fn test_message_handler_creates_successfully() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
         // Should not panic
         assert!(handler.discovery_service.cache_size() == 0);
     }
@@ -886,7 +1351,7 @@ mod tests {
     #[tokio::test]
     async fn test_handle_card_announcement_caches_card() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service.clone());
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
 
         let card = sample_card("did:agoramesh:base:test-agent");
         let message = DiscoveryMessage::CardAnnouncement {
@@ -897,7 +1362,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISCOVERY.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISCOVERY, "did:agoramesh:base:test-agent", &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -915,10 +1380,37 @@ mod tests {
         assert_eq!(cached.unwrap().name, "Test Agent");
     }
 
+    #[tokio::test]
+    async fn test_registered_card_announcement_is_accepted_by_peers() {
+        use crate::network::{GossipPublisher, SwarmCommand};
+
+        // The announcing node registers its own card with a signed publisher
+        let did = "did:agoramesh:base:announcing-node";
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let announcer =
+            DiscoveryService::new().with_publisher(GossipPublisher::new(tx, test_signer(did)));
+        announcer.register(&sample_card(did)).await.unwrap();
+
+        let Some(SwarmCommand::Publish { topic, data }) = rx.recv().await else {
+            panic!("Expected the card announcement to be published");
+        };
+
+        // A peer's handler verifies and caches it
+        let peer = discovery_service();
+        let handler = MessageHandler::new(peer.clone()).with_did_documents(test_documents());
+        handler
+            .handle_event(&message_event(&topic, data))
+            .await
+            .expect("Peer should accept the signed announcement");
+
+        assert!(peer.get(did).await.unwrap().is_some());
+        assert_eq!(handler.stats().await.unsigned_envelopes, 0);
+    }
+
     #[tokio::test]
     async fn test_handle_raw_capability_card_backward_compatible() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service.clone());
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
 
         // Send raw card without DiscoveryMessage wrapper
         let card = sample_card("did:agoramesh:base:raw-card");
@@ -927,7 +1419,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISCOVERY.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISCOVERY, "did:agoramesh:base:raw-card", &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -943,7 +1435,7 @@ mod tests {
     #[tokio::test]
     async fn test_handle_discovery_request() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let message = DiscoveryMessage::DiscoveryRequest {
             timestamp: 1704067200,
@@ -953,7 +1445,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISCOVERY.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISCOVERY, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -965,7 +1457,7 @@ mod tests {
     #[tokio::test]
     async fn test_invalid_discovery_message_returns_error() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let data = b"invalid json data";
 
@@ -983,12 +1475,94 @@ mod tests {
         assert_eq!(stats.parse_errors, 1);
     }
 
+    // ========== TDD Tests: Card relays ==========
+
+    /// A relay of `card` by `TEST_PEER`, optionally with a registration.
+    fn card_relay(card: &CapabilityCard, registration: Option<SignedEnvelope>) -> NetworkEvent {
+        let message = DiscoveryMessage::CardRelay {
+            card: Box::new(card.clone()),
+            registration: registration.map(Box::new),
+        };
+        let data = serde_json::to_vec(&message).unwrap();
+        message_event(topics::DISCOVERY, seal(topics::DISCOVERY, TEST_PEER, &data))
+    }
+
+    fn registration(card: &CapabilityCard, signer: &str) -> SignedEnvelope {
+        SignedEnvelope::seal(REGISTER_AGENT_TOPIC, card, &test_signer(signer)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_relayed_card_with_agent_registration_replaces_known_card() {
+        let service = discovery_service();
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
+        let did = "did:agoramesh:base:relayed-agent";
+        service.register(&sample_card(did)).await.unwrap();
+
+        let mut updated = sample_card(did);
+        updated.name = "Updated Agent".to_string();
+        let result = handler
+            .handle_event(&card_relay(&updated, Some(registration(&updated, did))))
+            .await;
+
+        assert!(result.is_ok(), "Signed registration: {:?}", result);
+        assert_eq!(
+            service.get(did).await.unwrap().unwrap().name,
+            "Updated Agent"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_relayed_card_must_match_the_agent_registration() {
+        let service = discovery_service();
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
+        let did = "did:agoramesh:base:relayed-agent";
+        let card = sample_card(did);
+        let mut forged = card.clone();
+        forged.url = "https://attacker.example.com".to_string();
+
+        // The agent's registration of another card, and a registration by the relayer
+        let mismatched = handler
+            .handle_event(&card_relay(&forged, Some(registration(&card, did))))
+            .await;
+        let wrong_signer = handler
+            .handle_event(&card_relay(&forged, Some(registration(&forged, TEST_PEER))))
+            .await;
+
+        assert!(mismatched.is_err(), "Card differs from the registration");
+        assert!(
+            wrong_signer.is_err(),
+            "Registration not signed by the agent"
+        );
+        assert!(service.get(did).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_relay_only_introduces_unknown_agents() {
+        let service = discovery_service();
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
+        let did = "did:agoramesh:base:relayed-agent";
+
+        let introduced = handler
+            .handle_event(&card_relay(&sample_card(did), None))
+            .await;
+        let mut replacement = sample_card(did);
+        replacement.url = "https://attacker.example.com".to_string();
+        let replaced = handler.handle_event(&card_relay(&replacement, None)).await;
+
+        assert!(introduced.is_ok(), "New agent: {:?}", introduced);
+        assert!(replaced.is_err(), "Known card needs the agent's signature");
+        assert_eq!(
+            service.get(did).await.unwrap().unwrap().url,
+            "https://test.example.com"
+        );
+    }
+
     // ========== TDD Tests: Trust message handling ==========
 
     #[tokio::test]
     async fn test_handle_trust_update() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let message = TrustMessage::TrustUpdate {
             did: "did:agoramesh:base:trust-test".to_string(),
@@ -1000,7 +1574,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1015,20 +1589,21 @@ mod tests {
     #[tokio::test]
     async fn test_handle_reputation_event() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let message = TrustMessage::ReputationEvent {
             did: "did:agoramesh:base:rep-test".to_string(),
             success: true,
             amount: 1_000_000, // 1 USDC
             timestamp: 1704067200,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1044,7 +1619,7 @@ mod tests {
     #[tokio::test]
     async fn test_handle_unknown_topic() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let event = NetworkEvent::Message {
             topic: "/unknown/topic/1.0.0".to_string(),
@@ -1066,7 +1641,7 @@ mod tests {
     #[tokio::test]
     async fn test_handle_non_message_event_is_noop() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let event = NetworkEvent::PeerConnected(PeerId::random());
 
@@ -1082,7 +1657,7 @@ mod tests {
     #[tokio::test]
     async fn test_stats_track_message_counts() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Send multiple messages
         for i in 0..3 {
@@ -1091,11 +1666,12 @@ mod tests {
                 card: Box::new(card),
             };
             let data = serde_json::to_vec(&message).unwrap();
+            let sender = format!("did:agoramesh:base:agent-{}", i);
 
             let event = NetworkEvent::Message {
                 topic: topics::DISCOVERY.to_string(),
                 source: Some(PeerId::random()),
-                data,
+                data: seal(topics::DISCOVERY, &sender, &data),
                 message_id: MessageId::new(format!("msg-{}", i).as_bytes()),
             };
 
@@ -1114,7 +1690,7 @@ mod tests {
     #[tokio::test]
     async fn test_handle_capability_update() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service.clone());
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
 
         let card = sample_card("did:agoramesh:base:cap-update");
         let data = serde_json::to_vec(&card).unwrap();
@@ -1122,7 +1698,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::CAPABILITY.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::CAPABILITY, "did:agoramesh:base:cap-update", &data),
             message_id: MessageId::new(b"cap-msg"),
         };
 
@@ -1138,7 +1714,7 @@ mod tests {
     async fn test_handle_dispute_message_without_arbitrator() {
         // Without arbitrator, dispute messages should be rejected
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1157,7 +1733,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"dispute-msg"),
         };
 
@@ -1174,7 +1750,7 @@ mod tests {
     async fn test_trust_update_validates_score_in_range() {
         // Trust scores must be in range [0.0, 1.0]
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Invalid trust score (> 1.0 should be rejected)
         let message = TrustMessage::TrustUpdate {
@@ -1187,7 +1763,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1200,7 +1776,7 @@ mod tests {
     #[tokio::test]
     async fn test_trust_update_validates_score_negative() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Negative trust score should be rejected
         let message = TrustMessage::TrustUpdate {
@@ -1213,7 +1789,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1225,7 +1801,7 @@ mod tests {
     #[tokio::test]
     async fn test_trust_update_validates_did_format() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Invalid DID format
         let message = TrustMessage::TrustUpdate {
@@ -1238,7 +1814,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1250,7 +1826,7 @@ mod tests {
     #[tokio::test]
     async fn test_trust_update_validates_timestamp_not_future() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Timestamp far in the future (year 2100) should be rejected
         let message = TrustMessage::TrustUpdate {
@@ -1263,7 +1839,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1275,7 +1851,7 @@ mod tests {
     #[tokio::test]
     async fn test_trust_update_valid_data_accepted() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         // Valid trust update should be accepted
         let now = std::time::SystemTime::now()
@@ -1293,7 +1869,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1307,20 +1883,21 @@ mod tests {
     #[tokio::test]
     async fn test_reputation_event_validates_did_format() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let message = TrustMessage::ReputationEvent {
             did: "not-a-valid-did".to_string(),
             success: true,
             amount: 1_000_000,
             timestamp: 1704067200,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1335,20 +1912,21 @@ mod tests {
     #[tokio::test]
     async fn test_reputation_event_validates_timestamp() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let message = TrustMessage::ReputationEvent {
             did: "did:agoramesh:base:test".to_string(),
             success: true,
             amount: 1_000_000,
             timestamp: 4102444800, // Year 2100 - future
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1363,7 +1941,7 @@ mod tests {
     #[tokio::test]
     async fn test_reputation_event_valid_success_accepted() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1375,13 +1953,14 @@ mod tests {
             success: true,
             amount: 1_000_000,
             timestamp: now - 60,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1396,7 +1975,7 @@ mod tests {
     #[tokio::test]
     async fn test_reputation_event_valid_failure_accepted() {
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1408,13 +1987,14 @@ mod tests {
             success: false,
             amount: 500_000,
             timestamp: now - 120,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1499,6 +2079,7 @@ mod tests {
             success: true,
             amount: 1_000_000,
             timestamp: 1704067200,
+            skill: None,
         };
 
         let json = serde_json::to_string(&message).unwrap();
@@ -1520,18 +2101,87 @@ mod tests {
     // ========== TDD Tests: TrustService Integration (Task #61) ==========
     // These tests verify that reputation events are recorded in TrustService
 
+    /// Trust service in which `TEST_PEER` is an established reporter.
     fn test_trust_service() -> Arc<crate::trust::TrustService> {
-        Arc::new(crate::trust::TrustService::new(
+        let trust = crate::trust::TrustService::new(
             "https://sepolia.base.org".to_string(),
             Some("0x1234567890abcdef".to_string()),
-        ))
+        );
+        trust.set_trust_data(TEST_PEER, 10_000_000_000, 100, 0, 0);
+        Arc::new(trust)
+    }
+
+    #[tokio::test]
+    async fn test_reputation_event_from_unestablished_reporter_rejected() {
+        // Arrange: a freshly minted did:key has no track record
+        let trust = test_trust_service();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+        let victim = "did:agoramesh:base:victim";
+        let message = TrustMessage::ReputationEvent {
+            did: victim.to_string(),
+            success: false,
+            amount: 1_000_000,
+            timestamp: now_secs() - 60,
+            skill: None,
+        };
+        let data = serde_json::to_vec(&message).unwrap();
+        let sybil = "did:key:z6MkSybilReporter";
+
+        // Act
+        let result = handler
+            .handle_event(&message_event(
+                topics::TRUST,
+                seal(topics::TRUST, sybil, &data),
+            ))
+            .await;
+
+        // Assert
+        assert!(result.is_err(), "Unrelated reporter must be rejected");
+        assert_eq!(
+            trust.get_trust(victim).await.unwrap().failed_transactions,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reputation_event_about_sender_itself_rejected() {
+        let trust = test_trust_service();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+        let message = TrustMessage::ReputationEvent {
+            did: TEST_PEER.to_string(),
+            success: true,
+            amount: 1_000_000,
+            timestamp: now_secs() - 60,
+            skill: None,
+        };
+        let data = serde_json::to_vec(&message).unwrap();
+
+        let result = handler
+            .handle_event(&message_event(
+                topics::TRUST,
+                seal(topics::TRUST, TEST_PEER, &data),
+            ))
+            .await;
+
+        assert!(result.is_err(), "Self-reports must be rejected");
+        assert_eq!(
+            trust
+                .get_trust(TEST_PEER)
+                .await
+                .unwrap()
+                .successful_transactions,
+            100
+        );
     }
 
     #[tokio::test]
     async fn test_reputation_event_records_success_in_trust_service() {
         let discovery = discovery_service();
         let trust = test_trust_service();
-        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()));
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1543,13 +2193,14 @@ mod tests {
             success: true,
             amount: 1_000_000,
             timestamp: now - 60,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1566,11 +2217,50 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_reputation_event_records_skill_reputation() {
+        let discovery = discovery_service();
+        let trust = test_trust_service();
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let message = TrustMessage::ReputationEvent {
+            did: "did:agoramesh:base:skilled".to_string(),
+            success: true,
+            amount: 1_000_000,
+            timestamp: now - 60,
+            skill: Some("translate".to_string()),
+        };
+        let data = serde_json::to_vec(&message).unwrap();
+
+        let event = NetworkEvent::Message {
+            topic: topics::TRUST.to_string(),
+            source: Some(PeerId::random()),
+            data: seal(topics::TRUST, TEST_PEER, &data),
+            message_id: MessageId::new(b"test-id"),
+        };
+
+        handler.handle_event(&event).await.unwrap();
+
+        let trust_info = trust
+            .get_skill_trust("did:agoramesh:base:skilled", Some("translate"))
+            .await
+            .unwrap();
+        assert_eq!(trust_info.skill.as_deref(), Some("translate"));
+        assert_eq!(trust_info.successful_transactions, 1);
+    }
+
     #[tokio::test]
     async fn test_reputation_event_records_failure_in_trust_service() {
         let discovery = discovery_service();
         let trust = test_trust_service();
-        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()));
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1582,13 +2272,14 @@ mod tests {
             success: false,
             amount: 500_000,
             timestamp: now - 60,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1609,7 +2300,7 @@ mod tests {
     async fn test_handler_without_trust_service_still_validates() {
         // Handler without TrustService should still validate but not record
         let service = discovery_service();
-        let handler = MessageHandler::new(service);
+        let handler = MessageHandler::new(service).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1621,13 +2312,14 @@ mod tests {
             success: true,
             amount: 1_000_000,
             timestamp: now - 60,
+            skill: None,
         };
         let data = serde_json::to_vec(&message).unwrap();
 
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1651,7 +2343,8 @@ mod tests {
     async fn test_create_dispute_message_creates_dispute_in_arbitrator() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1670,7 +2363,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"dispute-msg"),
         };
 
@@ -1689,7 +2382,8 @@ mod tests {
     async fn test_create_dispute_validates_amount_tier() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1709,7 +2403,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"tier1-msg"),
         };
 
@@ -1722,7 +2416,8 @@ mod tests {
     async fn test_submit_evidence_adds_to_dispute() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         // First create a dispute
         let dispute_id = arbitrator
@@ -1752,7 +2447,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"evidence-msg"),
         };
 
@@ -1768,7 +2463,8 @@ mod tests {
     async fn test_dispute_validates_did_format() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1788,7 +2484,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:provider", &data),
             message_id: MessageId::new(b"invalid-did-msg"),
         };
 
@@ -1800,7 +2496,8 @@ mod tests {
     async fn test_dispute_validates_timestamp() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         // Future timestamp
         let message = DisputeMessage::CreateDispute {
@@ -1815,7 +2512,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"future-ts-msg"),
         };
 
@@ -1856,7 +2553,7 @@ mod tests {
     async fn test_handler_without_arbitrator_rejects_disputes() {
         let discovery = discovery_service();
         // Handler without arbitrator
-        let handler = MessageHandler::new(discovery);
+        let handler = MessageHandler::new(discovery).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1875,7 +2572,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"no-arb-msg"),
         };
 
@@ -1908,7 +2605,8 @@ mod tests {
             .unwrap();
         let existing_score = existing.score;
 
-        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()));
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1927,7 +2625,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1956,7 +2654,8 @@ mod tests {
             .unwrap();
         let existing_score = existing.score;
 
-        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()));
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1976,7 +2675,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -1994,7 +2693,8 @@ mod tests {
         // New agents without transaction history should have their trust updates accepted
         let discovery = discovery_service();
         let trust = test_trust_service();
-        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()));
+        let handler = MessageHandler::with_trust_service(discovery, Some(trust.clone()))
+            .with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -2012,7 +2712,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -2027,7 +2727,7 @@ mod tests {
     async fn test_trust_update_without_trust_service_skips_validation() {
         // Without TrustService, on-chain validation is skipped (basic validation only)
         let discovery = discovery_service();
-        let handler = MessageHandler::new(discovery);
+        let handler = MessageHandler::new(discovery).with_did_documents(test_documents());
 
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -2044,7 +2744,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::TRUST.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::TRUST, TEST_PEER, &data),
             message_id: MessageId::new(b"test-id"),
         };
 
@@ -2063,7 +2763,8 @@ mod tests {
     async fn test_evidence_rejects_title_too_long() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         // Create a dispute first
         let dispute_id = arbitrator
@@ -2094,7 +2795,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"long-title-msg"),
         };
 
@@ -2110,7 +2811,8 @@ mod tests {
     async fn test_evidence_rejects_description_too_long() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         // Create a dispute first
         let dispute_id = arbitrator
@@ -2141,7 +2843,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"long-desc-msg"),
         };
 
@@ -2157,7 +2859,8 @@ mod tests {
     async fn test_evidence_rejects_empty_title() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let dispute_id = arbitrator
             .create_dispute(
@@ -2185,7 +2888,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"empty-title-msg"),
         };
 
@@ -2197,7 +2900,8 @@ mod tests {
     async fn test_evidence_rejects_empty_description() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let dispute_id = arbitrator
             .create_dispute(
@@ -2225,7 +2929,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"empty-desc-msg"),
         };
 
@@ -2240,7 +2944,8 @@ mod tests {
     async fn test_evidence_accepts_valid_submission() {
         let discovery = discovery_service();
         let arbitrator = test_arbitrator();
-        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()));
+        let handler = MessageHandler::with_services(discovery, None, Some(arbitrator.clone()))
+            .with_did_documents(test_documents());
 
         let dispute_id = arbitrator
             .create_dispute(
@@ -2268,7 +2973,7 @@ mod tests {
         let event = NetworkEvent::Message {
             topic: topics::DISPUTES.to_string(),
             source: Some(PeerId::random()),
-            data,
+            data: seal(topics::DISPUTES, "did:agoramesh:base:client", &data),
             message_id: MessageId::new(b"valid-evidence-msg"),
         };
 
@@ -2282,4 +2987,479 @@ mod tests {
             "Evidence should be recorded"
         );
     }
+
+    // ========== TDD Tests: Signed Envelopes ==========
+
+    #[tokio::test]
+    async fn test_raw_message_rejected_as_unsigned() {
+        let handler = MessageHandler::new(discovery_service()).with_did_documents(test_documents());
+
+        let message = TrustMessage::TrustUpdate {
+            did: "did:agoramesh:base:trust-test".to_string(),
+            trust_score: 0.85,
+            timestamp: 1704067200,
+        };
+        let data = serde_json::to_vec(&message).unwrap();
+
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, data))
+            .await;
+
+        assert!(result.is_err(), "Unwrapped messages must be rejected");
+        let stats = handler.stats().await;
+        assert_eq!(stats.unsigned_envelopes, 1);
+        assert_eq!(
+            stats.trust_messages, 0,
+            "Rejected before reaching the topic handler"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_envelope_without_signature_rejected_as_unsigned() {
+        let handler = MessageHandler::new(discovery_service()).with_did_documents(test_documents());
+
+        let message = TrustMessage::TrustUpdate {
+            did: "did:agoramesh:base:trust-test".to_string(),
+            trust_score: 0.85,
+            timestamp: 1704067200,
+        };
+        let mut envelope =
+            SignedEnvelope::seal(topics::TRUST, &message, &test_signer(TEST_PEER)).unwrap();
+        envelope.signature = None;
+
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, envelope.to_bytes().unwrap()))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(handler.stats().await.unsigned_envelopes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_forged_reputation_event_rejected_as_bad_signature() {
+        // Arrange: an attacker claims to be the reporter but signs with its own key
+        let trust = test_trust_service();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+
+        let message = TrustMessage::ReputationEvent {
+            did: "did:agoramesh:base:victim".to_string(),
+            success: false,
+            amount: 0,
+            timestamp: now_secs() - 60,
+            skill: None,
+        };
+        let forger = EnvelopeSigner::ed25519(TEST_PEER, "key-1", ed25519::Keypair::generate());
+        let envelope = SignedEnvelope::seal(topics::TRUST, &message, &forger).unwrap();
+
+        // Act
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, envelope.to_bytes().unwrap()))
+            .await;
+
+        // Assert
+        assert!(result.is_err(), "Forged envelope must be rejected");
+        assert_eq!(handler.stats().await.invalid_signatures, 1);
+        let data = trust.get_trust("did:agoramesh:base:victim").await.unwrap();
+        assert_eq!(
+            data.failed_transactions, 0,
+            "Forged event must not be recorded"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tampered_payload_rejected_as_bad_signature() {
+        let handler = MessageHandler::new(discovery_service()).with_did_documents(test_documents());
+
+        let message = TrustMessage::TrustUpdate {
+            did: "did:agoramesh:base:trust-test".to_string(),
+            trust_score: 0.5,
+            timestamp: now_secs() - 60,
+        };
+        let mut envelope =
+            SignedEnvelope::seal(topics::TRUST, &message, &test_signer(TEST_PEER)).unwrap();
+        envelope.payload = envelope.payload.replace("0.5", "0.9");
+
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, envelope.to_bytes().unwrap()))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(handler.stats().await.invalid_signatures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_envelope_replayed_on_other_topic_rejected() {
+        let handler = MessageHandler::new(discovery_service()).with_did_documents(test_documents());
+
+        let message = DiscoveryMessage::DiscoveryRequest {
+            timestamp: now_secs(),
+        };
+        let data = SignedEnvelope::seal(topics::DISCOVERY, &message, &test_signer(TEST_PEER))
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, data))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(handler.stats().await.invalid_signatures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_replayed_nonce_rejected() {
+        // Arrange
+        let trust = test_trust_service();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+
+        let message = TrustMessage::ReputationEvent {
+            did: "did:agoramesh:base:replayed".to_string(),
+            success: true,
+            amount: 1_000_000,
+            timestamp: now_secs() - 60,
+            skill: None,
+        };
+        let data = seal(
+            topics::TRUST,
+            TEST_PEER,
+            &serde_json::to_vec(&message).unwrap(),
+        );
+
+        // Act: deliver the same envelope twice
+        let first = handler
+            .handle_event(&message_event(topics::TRUST, data.clone()))
+            .await;
+        let second = handler
+            .handle_event(&message_event(topics::TRUST, data))
+            .await;
+
+        // Assert
+        assert!(
+            first.is_ok(),
+            "First delivery should be accepted: {:?}",
+            first
+        );
+        assert!(second.is_err(), "Replay must be rejected");
+        let stats = handler.stats().await;
+        assert_eq!(stats.replayed_nonces, 1);
+        assert_eq!(stats.messages_processed, 1);
+        let data = trust
+            .get_trust("did:agoramesh:base:replayed")
+            .await
+            .unwrap();
+        assert_eq!(data.successful_transactions, 1, "Event applied only once");
+    }
+
+    #[tokio::test]
+    async fn test_handler_without_did_documents_rejects_envelopes() {
+        let handler = MessageHandler::new(discovery_service());
+
+        let message = DiscoveryMessage::DiscoveryRequest {
+            timestamp: now_secs(),
+        };
+        let data = seal(
+            topics::DISCOVERY,
+            TEST_PEER,
+            &serde_json::to_vec(&message).unwrap(),
+        );
+
+        let result = handler
+            .handle_event(&message_event(topics::DISCOVERY, data))
+            .await;
+
+        assert!(result.is_err(), "Unresolvable senders must be rejected");
+        assert_eq!(handler.stats().await.invalid_signatures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_evidence_from_other_did_rejected() {
+        // Arrange: the provider tries to submit evidence as the client
+        let arbitrator = test_arbitrator();
+        let handler =
+            MessageHandler::with_services(discovery_service(), None, Some(arbitrator.clone()))
+                .with_did_documents(test_documents());
+        let dispute_id = arbitrator
+            .create_dispute(
+                "escrow-impersonation",
+                "did:agoramesh:base:client",
+                "did:agoramesh:base:provider",
+                50_000_000,
+            )
+            .unwrap();
+
+        let message = DisputeMessage::SubmitEvidence {
+            dispute_id: dispute_id.clone(),
+            submitter_did: "did:agoramesh:base:client".to_string(),
+            title: "Fake".to_string(),
+            description: "Submitted on the client's behalf".to_string(),
+            timestamp: now_secs() - 60,
+        };
+        let data = seal(
+            topics::DISPUTES,
+            "did:agoramesh:base:provider",
+            &serde_json::to_vec(&message).unwrap(),
+        );
+
+        // Act
+        let result = handler
+            .handle_event(&message_event(topics::DISPUTES, data))
+            .await;
+
+        // Assert
+        assert!(result.is_err(), "Submitter must match the envelope sender");
+        let dispute = arbitrator.get_dispute(&dispute_id).unwrap();
+        assert_eq!(dispute.total_evidence_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispute_from_non_party_rejected() {
+        let arbitrator = test_arbitrator();
+        let handler =
+            MessageHandler::with_services(discovery_service(), None, Some(arbitrator.clone()))
+                .with_did_documents(test_documents());
+
+        let message = DisputeMessage::CreateDispute {
+            escrow_id: "escrow-outsider".to_string(),
+            client_did: "did:agoramesh:base:client".to_string(),
+            provider_did: "did:agoramesh:base:provider".to_string(),
+            amount_usdc: 50_000_000,
+            timestamp: now_secs() - 60,
+        };
+        let data = seal(
+            topics::DISPUTES,
+            "did:agoramesh:base:outsider",
+            &serde_json::to_vec(&message).unwrap(),
+        );
+
+        let result = handler
+            .handle_event(&message_event(topics::DISPUTES, data))
+            .await;
+
+        assert!(result.is_err(), "Only escrow parties may open disputes");
+    }
+
+    #[tokio::test]
+    async fn test_card_announced_by_other_did_rejected() {
+        let service = discovery_service();
+        let handler = MessageHandler::new(service.clone()).with_did_documents(test_documents());
+
+        let message = DiscoveryMessage::CardAnnouncement {
+            card: Box::new(sample_card("did:agoramesh:base:someone-else")),
+        };
+        let data = seal(
+            topics::DISCOVERY,
+            TEST_PEER,
+            &serde_json::to_vec(&message).unwrap(),
+        );
+
+        let result = handler
+            .handle_event(&message_event(topics::DISCOVERY, data))
+            .await;
+
+        assert!(result.is_err(), "Agents may only announce their own card");
+        assert_eq!(service.cache_size(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_node_did_envelope_must_be_published_by_its_peer() {
+        // Arrange: node DIDs resolve from the key inlined in their PeerId
+        let identity = NodeIdentity::generate();
+        let did = identity.did(8453);
+        let resolver = UniversalResolver::new().with_method("agoramesh", Arc::new(PeerDidResolver));
+        let service = discovery_service();
+        let handler = MessageHandler::new(service.clone()).with_did_documents(Arc::new(resolver));
+        let announcement = |source: PeerId| {
+            let message = DiscoveryMessage::CardAnnouncement {
+                card: Box::new(sample_card(&did)),
+            };
+            let envelope =
+                SignedEnvelope::seal(topics::DISCOVERY, &message, &identity.envelope_signer(&did))
+                    .unwrap();
+            NetworkEvent::Message {
+                topic: topics::DISCOVERY.to_string(),
+                source: Some(source),
+                data: envelope.to_bytes().unwrap(),
+                message_id: MessageId::new(b"node-announcement"),
+            }
+        };
+
+        // Act
+        let from_other_peer = handler.handle_event(&announcement(PeerId::random())).await;
+        let from_node = handler
+            .handle_event(&announcement(identity.peer_id()))
+            .await;
+
+        // Assert
+        assert!(from_other_peer.is_err(), "Source must match the node DID");
+        assert!(
+            from_node.is_ok(),
+            "Node's own announcement: {:?}",
+            from_node
+        );
+        assert!(service.get(&did).await.unwrap().is_some());
+    }
+
+    // ========== TDD Tests: Gossip trust gate ==========
+
+    fn gated_trust_service() -> Arc<crate::trust::TrustService> {
+        Arc::new(
+            crate::trust::TrustService::new("https://sepolia.base.org".to_string(), None)
+                .with_requirements(crate::config::TrustConfig {
+                    min_trust_score: 0.3,
+                    gate_gossip: true,
+                    ..Default::default()
+                }),
+        )
+    }
+
+    fn announcement(did: &str) -> NetworkEvent {
+        let message = DiscoveryMessage::CardAnnouncement {
+            card: Box::new(sample_card(did)),
+        };
+        let data = seal(
+            topics::DISCOVERY,
+            did,
+            &serde_json::to_vec(&message).unwrap(),
+        );
+        message_event(topics::DISCOVERY, data)
+    }
+
+    #[tokio::test]
+    async fn test_gossip_gate_drops_cards_from_low_trust_dids() {
+        // Arrange: a brand-new agent has no trust yet
+        let service = discovery_service();
+        let handler =
+            MessageHandler::with_trust_service(service.clone(), Some(gated_trust_service()))
+                .with_did_documents(test_documents());
+
+        // Act
+        let result = handler
+            .handle_event(&announcement("did:agoramesh:base:newcomer"))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(Error::Trust(_))));
+        assert_eq!(service.cache_size(), 0);
+        assert_eq!(handler.stats().await.untrusted_cards, 1);
+    }
+
+    #[tokio::test]
+    async fn test_gossip_gate_admits_trusted_dids() {
+        // Arrange
+        let service = discovery_service();
+        let trust = gated_trust_service();
+        let did = "did:agoramesh:base:veteran";
+        trust.set_trust_data(did, 0, 100, 0, 0);
+        let handler = MessageHandler::with_trust_service(service.clone(), Some(trust))
+            .with_did_documents(test_documents());
+
+        // Act
+        let result = handler.handle_event(&announcement(did)).await;
+
+        // Assert
+        assert!(result.is_ok(), "{:?}", result);
+        assert_eq!(service.cache_size(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gossip_gate_applies_to_capability_updates() {
+        let service = discovery_service();
+        let handler =
+            MessageHandler::with_trust_service(service.clone(), Some(gated_trust_service()))
+                .with_did_documents(test_documents());
+        let did = "did:agoramesh:base:newcomer";
+        let card = serde_json::to_vec(&sample_card(did)).unwrap();
+
+        let result = handler
+            .handle_event(&message_event(
+                topics::CAPABILITY,
+                seal(topics::CAPABILITY, did, &card),
+            ))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(service.cache_size(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_gossip_gate_off_by_default() {
+        let service = discovery_service();
+        let handler =
+            MessageHandler::with_trust_service(service.clone(), Some(test_trust_service()))
+                .with_did_documents(test_documents());
+
+        let result = handler
+            .handle_event(&announcement("did:agoramesh:base:newcomer"))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(service.cache_size(), 1);
+    }
+
+    // ========== TDD Tests: Endorsement revocation ==========
+
+    fn revocation(endorser_did: &str, target_did: &str) -> Vec<u8> {
+        serde_json::to_vec(&TrustMessage::EndorsementRevocation {
+            endorser_did: endorser_did.to_string(),
+            target_did: target_did.to_string(),
+            timestamp: now_secs(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_signed_revocation_removes_endorsement() {
+        // Arrange
+        let trust = test_trust_service();
+        let endorser = "did:agoramesh:base:endorser";
+        let target = "did:agoramesh:base:endorsed";
+        trust.add_endorsement(endorser, target).await.unwrap();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+        let data = seal(topics::TRUST, endorser, &revocation(endorser, target));
+
+        // Act
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, data))
+            .await;
+
+        // Assert
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(!trust.has_endorsement(endorser, target));
+        assert_eq!(trust.get_trust(target).await.unwrap().endorsement_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_revocation_signed_by_someone_else_rejected() {
+        // Arrange: the endorsed agent tries to drop an endorsement it was given
+        let trust = test_trust_service();
+        let endorser = "did:agoramesh:base:endorser";
+        let target = "did:agoramesh:base:endorsed";
+        trust.add_endorsement(endorser, target).await.unwrap();
+        let handler = MessageHandler::with_trust_service(discovery_service(), Some(trust.clone()))
+            .with_did_documents(test_documents());
+        let data = seal(topics::TRUST, target, &revocation(endorser, target));
+
+        // Act
+        let result = handler
+            .handle_event(&message_event(topics::TRUST, data))
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(trust.has_endorsement(endorser, target));
+    }
+
+    #[test]
+    fn test_trust_message_serialization_endorsement_revocation() {
+        let data = revocation("did:agoramesh:base:a", "did:agoramesh:base:b");
+
+        let json: serde_json::Value = serde_json::from_slice(&data).unwrap();
+
+        assert_eq!(json["type"], "endorsement_revocation");
+        assert_eq!(json["endorser_did"], "did:agoramesh:base:a");
+    }
 }
@@ -3,6 +3,8 @@
 //! Provides durable storage for:
 //! - Capability cards (agent metadata)
 //! - Trust data (reputation, stake, endorsements)
+//! - Trust history (score snapshots and trust events)
+//! - Contract event block cursors
 //! - DHT records (optional)
 //!
 //! Uses RocksDB as the underlying key-value store for high performance
@@ -10,9 +12,14 @@
 
 use crate::discovery::CapabilityCard;
 use crate::error::{Error, Result};
-use rocksdb::{Options, DB};
+use crate::reputation::{TransactionStats, WeightedOutcomes};
+use crate::trust_history::{TrustEvent, TrustSnapshot};
+use rocksdb::{Direction, IteratorMode, Options, WriteBatch, DB};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Bound;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
@@ -39,6 +46,14 @@ pub struct PersistenceConfig {
     #[serde(default = "default_true")]
     pub trust_data: bool,
 
+    /// Whether to persist contract event block cursors.
+    #[serde(default = "default_true")]
+    pub event_cursors: bool,
+
+    /// Whether to persist trust score history.
+    #[serde(default = "default_true")]
+    pub trust_history: bool,
+
     /// Whether to persist DHT records.
     #[serde(default = "default_false")]
     pub dht_records: bool,
@@ -67,6 +82,8 @@ impl Default for PersistenceConfig {
             data_dir: "./data".to_string(),
             capability_cards: true,
             trust_data: true,
+            event_cursors: true,
+            trust_history: true,
             dht_records: false,
         }
     }
@@ -87,6 +104,58 @@ pub struct TrustData {
     pub total_volume: u64,
     /// Last activity timestamp (Unix seconds).
     pub last_activity: u64,
+    /// Endorsements received, with hop distances.
+    pub endorsements: Vec<EndorsementRecord>,
+    /// Amount- and recency-weighted transaction outcomes.
+    #[serde(default)]
+    pub weighted: WeightedOutcomes,
+    /// Transactions per skill ID.
+    #[serde(default)]
+    pub skills: HashMap<String, TransactionStats>,
+    /// On-chain stake events already applied to `stake_amount`, as
+    /// `{tx_hash}:{log_index}`.
+    #[serde(default)]
+    pub stake_events: BTreeSet<String>,
+}
+
+/// Trust data as encoded with bincode, before it moved to JSON.
+///
+/// This is the only layout ever written with bincode; bincode is not
+/// self-describing, so it must match field for field.
+#[derive(Deserialize)]
+struct LegacyTrustData {
+    stake_amount: u64,
+    successful_transactions: u64,
+    failed_transactions: u64,
+    endorsement_count: u64,
+    total_volume: u64,
+    last_activity: u64,
+}
+
+impl From<LegacyTrustData> for TrustData {
+    fn from(legacy: LegacyTrustData) -> Self {
+        Self {
+            stake_amount: legacy.stake_amount,
+            successful_transactions: legacy.successful_transactions,
+            failed_transactions: legacy.failed_transactions,
+            endorsement_count: legacy.endorsement_count,
+            total_volume: legacy.total_volume,
+            last_activity: legacy.last_activity,
+            ..Default::default()
+        }
+    }
+}
+
+/// Persisted endorsement edge.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EndorsementRecord {
+    /// DID of the endorser.
+    pub endorser_did: String,
+    /// Hop distance from trust root.
+    pub hop_distance: u32,
+    /// Unix timestamp the endorsement expires at (`None` never expires).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
 }
 
 // =============================================================================
@@ -112,6 +181,15 @@ pub trait Store: Send + Sync {
 
     /// Get all keys.
     fn keys(&self) -> Result<Vec<String>>;
+
+    /// Entries with keys in `start..end`, in key order.
+    fn iter_range(&self, start: &str, end: &str) -> Result<Vec<(String, Vec<u8>)>>;
+
+    /// First key at or after `start`, if any.
+    fn next_key(&self, start: &str) -> Result<Option<String>>;
+
+    /// Delete all keys in `start..end`, returning how many were deleted.
+    fn delete_range(&self, start: &str, end: &str) -> Result<usize>;
 }
 
 // =============================================================================
@@ -225,6 +303,60 @@ impl Store for RocksStore {
 
         Ok(keys)
     }
+
+    fn iter_range(&self, start: &str, end: &str) -> Result<Vec<(String, Vec<u8>)>> {
+        let iter = self
+            .db
+            .iterator(IteratorMode::From(start.as_bytes(), Direction::Forward));
+        let mut results = Vec::new();
+
+        for item in iter {
+            let (key, value) =
+                item.map_err(|e| Error::Persistence(format!("RocksDB iteration error: {}", e)))?;
+            if key.as_ref() >= end.as_bytes() {
+                break;
+            }
+            if let Ok(key_str) = String::from_utf8(key.to_vec()) {
+                results.push((key_str, value.to_vec()));
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn next_key(&self, start: &str) -> Result<Option<String>> {
+        let mut iter = self
+            .db
+            .iterator(IteratorMode::From(start.as_bytes(), Direction::Forward));
+        match iter.next() {
+            Some(Ok((key, _))) => Ok(String::from_utf8(key.to_vec()).ok()),
+            Some(Err(e)) => Err(Error::Persistence(format!(
+                "RocksDB iteration error: {}",
+                e
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    fn delete_range(&self, start: &str, end: &str) -> Result<usize> {
+        let count = self
+            .db
+            .iterator(IteratorMode::From(start.as_bytes(), Direction::Forward))
+            .map_while(|item| item.ok())
+            .take_while(|(key, _)| key.as_ref() < end.as_bytes())
+            .count();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let mut batch = WriteBatch::default();
+        batch.delete_range(start.as_bytes(), end.as_bytes());
+        self.db
+            .write(batch)
+            .map_err(|e| Error::Persistence(format!("RocksDB delete range error: {}", e)))?;
+        debug!("Deleted {} keys in range {}..{}", count, start, end);
+        Ok(count)
+    }
 }
 
 // =============================================================================
@@ -234,7 +366,7 @@ impl Store for RocksStore {
 /// In-memory store for testing purposes.
 #[derive(Default)]
 pub struct MemoryStore {
-    data: std::sync::RwLock<std::collections::HashMap<String, Vec<u8>>>,
+    data: std::sync::RwLock<std::collections::BTreeMap<String, Vec<u8>>>,
 }
 
 impl MemoryStore {
@@ -298,6 +430,43 @@ impl Store for MemoryStore {
             .map_err(|_| Error::Persistence("Memory store lock poisoned (read)".to_string()))?;
         Ok(guard.keys().cloned().collect())
     }
+
+    fn iter_range(&self, start: &str, end: &str) -> Result<Vec<(String, Vec<u8>)>> {
+        let guard = self
+            .data
+            .read()
+            .map_err(|_| Error::Persistence("Memory store lock poisoned (read)".to_string()))?;
+        Ok(guard
+            .range::<str, _>((Bound::Included(start), Bound::Excluded(end)))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect())
+    }
+
+    fn next_key(&self, start: &str) -> Result<Option<String>> {
+        let guard = self
+            .data
+            .read()
+            .map_err(|_| Error::Persistence("Memory store lock poisoned (read)".to_string()))?;
+        Ok(guard
+            .range::<str, _>((Bound::Included(start), Bound::Unbounded))
+            .next()
+            .map(|(k, _)| k.clone()))
+    }
+
+    fn delete_range(&self, start: &str, end: &str) -> Result<usize> {
+        let mut guard = self
+            .data
+            .write()
+            .map_err(|_| Error::Persistence("Memory store lock poisoned (write)".to_string()))?;
+        let keys: Vec<String> = guard
+            .range::<str, _>((Bound::Included(start), Bound::Excluded(end)))
+            .map(|(k, _)| k.clone())
+            .collect();
+        for key in &keys {
+            guard.remove(key);
+        }
+        Ok(keys.len())
+    }
 }
 
 // =============================================================================
@@ -305,6 +474,7 @@ impl Store for MemoryStore {
 // =============================================================================
 
 /// Store for capability cards with JSON serialization.
+#[derive(Clone)]
 pub struct CapabilityCardStore {
     store: Arc<dyn Store>,
 }
@@ -370,7 +540,8 @@ impl CapabilityCardStore {
     }
 }
 
-/// Store for trust data with bincode serialization.
+/// Store for trust data with JSON serialization (bincode records are still read).
+#[derive(Clone)]
 pub struct TrustDataStore {
     store: Arc<dyn Store>,
 }
@@ -385,9 +556,16 @@ impl TrustDataStore {
     pub fn get(&self, did: &str) -> Result<Option<TrustData>> {
         match self.store.get(did)? {
             Some(data) => {
-                let trust: TrustData = bincode::deserialize(&data).map_err(|e| {
-                    Error::Persistence(format!("Failed to deserialize trust data: {}", e))
-                })?;
+                // Records written by older nodes are bincode
+                let trust: TrustData = serde_json::from_slice(&data)
+                    .or_else(|e| {
+                        bincode::deserialize::<LegacyTrustData>(&data)
+                            .map(TrustData::from)
+                            .map_err(|_| e)
+                    })
+                    .map_err(|e| {
+                        Error::Persistence(format!("Failed to deserialize trust data: {}", e))
+                    })?;
                 Ok(Some(trust))
             }
             None => Ok(None),
@@ -396,7 +574,7 @@ impl TrustDataStore {
 
     /// Store trust data.
     pub fn put(&self, did: &str, trust: &TrustData) -> Result<()> {
-        let data = bincode::serialize(trust)
+        let data = serde_json::to_vec(trust)
             .map_err(|e| Error::Persistence(format!("Failed to serialize trust data: {}", e)))?;
         self.store.put(did, &data)
     }
@@ -422,6 +600,20 @@ impl TrustDataStore {
         Ok(trust)
     }
 
+    /// Get all trust data entries.
+    pub fn all(&self) -> Result<Vec<(String, TrustData)>> {
+        let keys = self.store.keys()?;
+        let mut entries = Vec::new();
+
+        for key in keys {
+            if let Some(trust) = self.get(&key)? {
+                entries.push((key, trust));
+            }
+        }
+
+        Ok(entries)
+    }
+
     /// Get the number of stored entries.
     pub fn len(&self) -> Result<usize> {
         Ok(self.store.keys()?.len())
@@ -433,6 +625,156 @@ impl TrustDataStore {
     }
 }
 
+/// Store for contract event listener block cursors.
+///
+/// Each cursor is the highest block whose logs have all been delivered,
+/// stored as a big-endian `u64` under a caller-chosen key.
+#[derive(Clone)]
+pub struct BlockCursorStore {
+    store: Arc<dyn Store>,
+}
+
+impl BlockCursorStore {
+    /// Create a new block cursor store.
+    pub fn new(store: Arc<dyn Store>) -> Self {
+        Self { store }
+    }
+
+    /// Get the cursor stored under `key`.
+    pub fn get(&self, key: &str) -> Result<Option<u64>> {
+        match self.store.get(key)? {
+            Some(data) => {
+                let bytes: [u8; 8] = data.as_slice().try_into().map_err(|_| {
+                    Error::Persistence(format!(
+                        "Corrupt block cursor for {}: expected 8 bytes, got {}",
+                        key,
+                        data.len()
+                    ))
+                })?;
+                Ok(Some(u64::from_be_bytes(bytes)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Store the cursor under `key`.
+    pub fn put(&self, key: &str, block: u64) -> Result<()> {
+        self.store.put(key, &block.to_be_bytes())
+    }
+
+    /// Delete the cursor stored under `key`.
+    pub fn delete(&self, key: &str) -> Result<()> {
+        self.store.delete(key)
+    }
+}
+
+/// Store for per-agent trust snapshots and events with JSON serialization.
+///
+/// Keys sort chronologically per agent: `s#{did}#{timestamp}` for snapshots
+/// and `e#{did}#{timestamp}#{seq}` for events, with zero-padded numbers.
+#[derive(Clone)]
+pub struct TrustHistoryStore {
+    store: Arc<dyn Store>,
+    seq: Arc<AtomicU64>,
+}
+
+impl TrustHistoryStore {
+    /// Create a new trust history store.
+    pub fn new(store: Arc<dyn Store>) -> Self {
+        // Start the event sequence at a per-process value so events logged in
+        // the same second across restarts do not overwrite each other
+        let start = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self {
+            store,
+            seq: Arc::new(AtomicU64::new(start)),
+        }
+    }
+
+    /// Store a snapshot, replacing any taken in the same second.
+    pub fn put_snapshot(&self, did: &str, snapshot: &TrustSnapshot) -> Result<()> {
+        let data = serde_json::to_vec(snapshot)
+            .map_err(|e| Error::Persistence(format!("Failed to serialize snapshot: {}", e)))?;
+        self.store
+            .put(&format!("s#{}#{:020}", did, snapshot.timestamp), &data)
+    }
+
+    /// Append an event.
+    pub fn put_event(&self, did: &str, event: &TrustEvent) -> Result<()> {
+        let data = serde_json::to_vec(event)
+            .map_err(|e| Error::Persistence(format!("Failed to serialize trust event: {}", e)))?;
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+        self.store.put(
+            &format!("e#{}#{:020}#{:020}", did, event.timestamp, seq),
+            &data,
+        )
+    }
+
+    /// Snapshots of `did` taken between `from` and `to` (inclusive), oldest first.
+    pub fn snapshots(&self, did: &str, from: u64, to: u64) -> Result<Vec<TrustSnapshot>> {
+        self.range(&format!("s#{}#", did), from, to)
+    }
+
+    /// Events of `did` between `from` and `to` (inclusive), oldest first.
+    pub fn events(&self, did: &str, from: u64, to: u64) -> Result<Vec<TrustEvent>> {
+        self.range(&format!("e#{}#", did), from, to)
+    }
+
+    /// Delete all snapshots and events older than `cutoff`.
+    ///
+    /// Skips from agent to agent and drops each one's expired entries with
+    /// a single range delete, so retained history is never read.
+    /// Returns the number of entries deleted.
+    pub fn prune(&self, cutoff: u64) -> Result<usize> {
+        let mut deleted = 0;
+        for kind in ["s#", "e#"] {
+            let mut cursor = kind.to_string();
+            while let Some(key) = self.store.next_key(&cursor)? {
+                let Some(did) = key
+                    .strip_prefix(kind)
+                    .and_then(|rest| rest.split_once('#'))
+                    .map(|(did, _)| did)
+                else {
+                    break;
+                };
+                let prefix = format!("{}{}#", kind, did);
+                deleted += self
+                    .store
+                    .delete_range(&prefix, &format!("{}{:020}", prefix, cutoff))?;
+                // '$' sorts right after '#', past every key of this agent
+                cursor = format!("{}{}$", kind, did);
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Entries under `prefix` timestamped `from..=to`, read by seeking to
+    /// the first key and stopping after the last.
+    fn range<T: serde::de::DeserializeOwned>(
+        &self,
+        prefix: &str,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<T>> {
+        // Event keys continue with `#{seq}` and '$' sorts after '#', so the
+        // end bound covers every entry stamped `to`
+        let start = format!("{}{:020}", prefix, from);
+        let end = format!("{}{:020}$", prefix, to);
+
+        self.store
+            .iter_range(&start, &end)?
+            .into_iter()
+            .map(|(_, data)| {
+                serde_json::from_slice(&data).map_err(|e| {
+                    Error::Persistence(format!("Failed to deserialize trust history: {}", e))
+                })
+            })
+            .collect()
+    }
+}
+
 // =============================================================================
 // Persistence Manager
 // =============================================================================
@@ -442,6 +784,8 @@ pub struct PersistenceManager {
     config: PersistenceConfig,
     capability_store: Option<CapabilityCardStore>,
     trust_store: Option<TrustDataStore>,
+    cursor_store: Option<BlockCursorStore>,
+    history_store: Option<TrustHistoryStore>,
 }
 
 impl PersistenceManager {
@@ -453,6 +797,8 @@ impl PersistenceManager {
                 config,
                 capability_store: None,
                 trust_store: None,
+                cursor_store: None,
+                history_store: None,
             });
         }
 
@@ -482,16 +828,38 @@ impl PersistenceManager {
             None
         };
 
+        // Open event cursor store
+        let cursor_store = if config.event_cursors {
+            let path = Path::new(&config.data_dir).join("event_cursors");
+            let store = Arc::new(RocksStore::open(&path, "event_cursors")?);
+            Some(BlockCursorStore::new(store))
+        } else {
+            None
+        };
+
+        // Open trust history store
+        let history_store = if config.trust_history {
+            let path = Path::new(&config.data_dir).join("trust_history");
+            let store = Arc::new(RocksStore::open(&path, "trust_history")?);
+            Some(TrustHistoryStore::new(store))
+        } else {
+            None
+        };
+
         info!(
-            "Persistence manager initialized: capability_cards={}, trust_data={}",
+            "Persistence manager initialized: capability_cards={}, trust_data={}, event_cursors={}, trust_history={}",
             capability_store.is_some(),
-            trust_store.is_some()
+            trust_store.is_some(),
+            cursor_store.is_some(),
+            history_store.is_some()
         );
 
         Ok(Self {
             config,
             capability_store,
             trust_store,
+            cursor_store,
+            history_store,
         })
     }
 
@@ -499,11 +867,15 @@ impl PersistenceManager {
     pub fn in_memory() -> Self {
         let capability_store = CapabilityCardStore::new(Arc::new(MemoryStore::new()));
         let trust_store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let cursor_store = BlockCursorStore::new(Arc::new(MemoryStore::new()));
+        let history_store = TrustHistoryStore::new(Arc::new(MemoryStore::new()));
 
         Self {
             config: PersistenceConfig::default(),
             capability_store: Some(capability_store),
             trust_store: Some(trust_store),
+            cursor_store: Some(cursor_store),
+            history_store: Some(history_store),
         }
     }
 
@@ -522,6 +894,16 @@ impl PersistenceManager {
         self.trust_store.as_ref()
     }
 
+    /// Get the contract event block cursor store.
+    pub fn event_cursors(&self) -> Option<&BlockCursorStore> {
+        self.cursor_store.as_ref()
+    }
+
+    /// Get the trust history store.
+    pub fn trust_history(&self) -> Option<&TrustHistoryStore> {
+        self.history_store.as_ref()
+    }
+
     /// Check if persistence is enabled.
     pub fn is_enabled(&self) -> bool {
         self.config.enabled
@@ -627,6 +1009,142 @@ mod tests {
         assert_eq!(trust.total_volume, 1000);
     }
 
+    #[test]
+    fn test_trust_data_store_reads_legacy_records() {
+        #[derive(Serialize)]
+        struct Legacy {
+            stake_amount: u64,
+            successful_transactions: u64,
+            failed_transactions: u64,
+            endorsement_count: u64,
+            total_volume: u64,
+            last_activity: u64,
+        }
+
+        // Arrange: a record written by a node that stored bincode
+        let raw = Arc::new(MemoryStore::new());
+        let legacy = Legacy {
+            stake_amount: 5,
+            successful_transactions: 7,
+            failed_transactions: 1,
+            endorsement_count: 2,
+            total_volume: 900,
+            last_activity: 42,
+        };
+        raw.put("did:test:old", &bincode::serialize(&legacy).unwrap())
+            .unwrap();
+        let store = TrustDataStore::new(raw);
+
+        // Act
+        let trust = store.get("did:test:old").unwrap().unwrap();
+
+        // Assert
+        assert_eq!(trust.stake_amount, 5);
+        assert_eq!(trust.successful_transactions, 7);
+        assert_eq!(trust.failed_transactions, 1);
+        assert_eq!(trust.endorsement_count, 2);
+        assert_eq!(trust.total_volume, 900);
+        assert_eq!(trust.last_activity, 42);
+        assert!(trust.endorsements.is_empty());
+        assert!(trust.weighted.is_empty());
+    }
+
+    #[test]
+    fn test_block_cursor_store() {
+        let store = BlockCursorStore::new(Arc::new(MemoryStore::new()));
+
+        assert_eq!(store.get("events:a").unwrap(), None);
+
+        store.put("events:a", 1_234_567).unwrap();
+        store.put("events:b", 7).unwrap();
+        assert_eq!(store.get("events:a").unwrap(), Some(1_234_567));
+        assert_eq!(store.get("events:b").unwrap(), Some(7));
+
+        store.delete("events:a").unwrap();
+        assert_eq!(store.get("events:a").unwrap(), None);
+    }
+
+    #[test]
+    fn test_block_cursor_store_rejects_corrupt_value() {
+        let raw = Arc::new(MemoryStore::new());
+        raw.put("events:a", b"oops").unwrap();
+        let store = BlockCursorStore::new(raw);
+
+        let result = store.get("events:a");
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Corrupt block cursor"));
+    }
+
+    #[test]
+    fn test_trust_history_store_ranges_and_prunes() {
+        use crate::trust_history::TrustEventKind;
+
+        // Arrange
+        let store = TrustHistoryStore::new(Arc::new(MemoryStore::new()));
+        let did = "did:agoramesh:base:agent";
+        for timestamp in [300, 100, 200] {
+            let snapshot = TrustSnapshot {
+                timestamp,
+                score: timestamp as f64 / 1000.0,
+                reputation: 0.0,
+                stake_score: 0.0,
+                endorsement_score: 0.0,
+                stake_amount: 0,
+                successful_transactions: 0,
+                failed_transactions: 0,
+                endorsement_count: 0,
+            };
+            store.put_snapshot(did, &snapshot).unwrap();
+        }
+        store
+            .put_event(did, &TrustEvent::new(TrustEventKind::StakeDeposited, 150))
+            .unwrap();
+        store
+            .put_event(
+                "did:agoramesh:base:agent:other",
+                &TrustEvent::new(TrustEventKind::StakeSlashed, 150),
+            )
+            .unwrap();
+
+        // Act / Assert: ranges are inclusive, ordered and per agent
+        let snapshots = store.snapshots(did, 100, 200).unwrap();
+        assert_eq!(
+            snapshots.iter().map(|s| s.timestamp).collect::<Vec<_>>(),
+            vec![100, 200]
+        );
+        let events = store.events(did, 0, u64::MAX).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, TrustEventKind::StakeDeposited);
+
+        // Act / Assert: pruning drops entries older than the cutoff
+        assert_eq!(store.prune(200).unwrap(), 3);
+        assert_eq!(store.snapshots(did, 0, u64::MAX).unwrap().len(), 2);
+        assert!(store.events(did, 0, u64::MAX).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rocks_store_ranges_and_range_deletes() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = RocksStore::open(temp_dir.path(), "test").unwrap();
+        for key in ["a#1", "a#2", "a#3", "b#1"] {
+            store.put(key, key.as_bytes()).unwrap();
+        }
+
+        let keys = |entries: Vec<(String, Vec<u8>)>| {
+            entries.into_iter().map(|(k, _)| k).collect::<Vec<_>>()
+        };
+        assert_eq!(keys(store.iter_range("a#2", "b").unwrap()), ["a#2", "a#3"]);
+        assert_eq!(store.next_key("a$").unwrap().as_deref(), Some("b#1"));
+        assert_eq!(store.next_key("c").unwrap(), None);
+
+        assert_eq!(store.delete_range("a#", "a#3").unwrap(), 2);
+        assert_eq!(store.keys().unwrap(), ["a#3", "b#1"]);
+    }
+
     #[test]
     fn test_persistence_manager_in_memory() {
         let manager = PersistenceManager::in_memory();
@@ -634,6 +1152,8 @@ mod tests {
         assert!(manager.is_enabled());
         assert!(manager.capability_cards().is_some());
         assert!(manager.trust_data().is_some());
+        assert!(manager.event_cursors().is_some());
+        assert!(manager.trust_history().is_some());
     }
 
     #[test]
@@ -647,6 +1167,8 @@ mod tests {
         assert!(!manager.is_enabled());
         assert!(manager.capability_cards().is_none());
         assert!(manager.trust_data().is_none());
+        assert!(manager.event_cursors().is_none());
+        assert!(manager.trust_history().is_none());
     }
 
     #[test]
@@ -657,6 +1179,8 @@ mod tests {
             data_dir: tmp_dir.path().to_string_lossy().to_string(),
             capability_cards: true,
             trust_data: true,
+            event_cursors: true,
+            trust_history: true,
             dht_records: false,
         };
 
@@ -15,6 +15,7 @@ use governor::{
     state::keyed::DefaultKeyedStateStore,
     Quota, RateLimiter,
 };
+use serde::{Deserialize, Serialize};
 use std::net::{IpAddr, SocketAddr};
 use std::num::NonZeroU32;
 use std::sync::Arc;
@@ -28,7 +29,8 @@ type InMemoryRateLimiter = RateLimiter<
 >;
 
 /// Configuration for rate limiting.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RateLimitConfig {
     /// Maximum requests per second (0 = disabled)
     pub requests_per_second: u32,
@@ -0,0 +1,325 @@
+//! Volume- and recency-weighted reputation.
+//!
+//! Count-based reputation treats every transaction alike, so a $0.01
+//! success offsets a $5,000 failure. Weighted reputation instead keeps two
+//! exponentially decayed sums of USDC volume per agent:
+//!
+//! ```text
+//! S = Σ amountᵢ · 2^(-ageᵢ / half_life)              (successes)
+//! F = Σ amountᵢ · penaltyᵢ · 2^(-ageᵢ / half_life)   (failures)
+//! success_rate = S / (S + F)
+//! ```
+//!
+//! Failures are multiplied by a penalty that depends on why they failed.
+//! The sums are decayed lazily whenever an event is added, so only three
+//! numbers are stored per agent (and per skill, for contextual reputation).
+
+use serde::{Deserialize, Serialize};
+
+/// Half-life of a transaction's weight: 30 days.
+pub const REPUTATION_HALF_LIFE_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Amount a failure is weighed at when neither its amount nor the agent's
+/// average transaction size is known: $1 USDC (6 decimals).
+pub const DEFAULT_TRANSACTION_AMOUNT: u64 = 1_000_000;
+
+/// How the reputation component is computed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReputationMode {
+    /// Success rate weighted by USDC amount, recency and failure reason.
+    #[default]
+    Weighted,
+    /// Success rate over transaction counts (trust-layer.md).
+    Count,
+}
+
+/// Why a transaction failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureReason {
+    /// The provider did not respond in time.
+    Timeout,
+    /// The client won a dispute against the provider.
+    DisputeLost,
+    /// The provider's stake was slashed for the transaction.
+    Slashed,
+    /// Any other failure.
+    Other,
+}
+
+impl FailureReason {
+    /// Classify a free-form failure reason (`"timeout"`, `"dispute_lost"`,
+    /// `"slashed"`, ...). Unrecognized reasons are [`FailureReason::Other`].
+    pub fn classify(reason: &str) -> Self {
+        let reason = reason.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        match reason.as_str() {
+            "timeout" | "timed_out" => Self::Timeout,
+            "dispute_lost" | "disputed" | "dispute" => Self::DisputeLost,
+            "slashed" | "slash" => Self::Slashed,
+            _ => Self::Other,
+        }
+    }
+
+    /// Multiplier applied to the failed amount.
+    ///
+    /// Timeouts are often transient and weigh less than an unexplained
+    /// failure; a lost dispute or a slash means the provider was found at
+    /// fault and weighs more.
+    pub fn penalty(self) -> f64 {
+        match self {
+            Self::Timeout => 0.5,
+            Self::Other => 1.0,
+            Self::DisputeLost => 2.0,
+            Self::Slashed => 4.0,
+        }
+    }
+}
+
+/// Decayed success and failure volume of an agent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightedOutcomes {
+    /// Decayed successful volume (USDC, 6 decimals).
+    pub success: f64,
+    /// Decayed failed volume times penalty (USDC, 6 decimals).
+    pub failure: f64,
+    /// Unix timestamp the sums were last decayed to.
+    pub updated_at: u64,
+}
+
+impl WeightedOutcomes {
+    /// Outcomes equivalent to `successful` and `failed` transactions of
+    /// `amount` each at `timestamp`, for agents whose history predates
+    /// weighting.
+    pub fn from_counts(successful: u64, failed: u64, amount: u64, timestamp: u64) -> Self {
+        Self {
+            success: successful as f64 * amount as f64,
+            failure: failed as f64 * amount as f64 * FailureReason::Other.penalty(),
+            updated_at: timestamp,
+        }
+    }
+
+    /// Whether no weighted events were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.success == 0.0 && self.failure == 0.0
+    }
+
+    /// Add a successful transaction of `amount` at `timestamp`.
+    pub fn record_success(&mut self, amount: u64, timestamp: u64) {
+        self.decay_to(timestamp);
+        self.success += amount as f64;
+    }
+
+    /// Add a failed transaction of `amount` at `timestamp`.
+    pub fn record_failure(&mut self, amount: u64, reason: FailureReason, timestamp: u64) {
+        self.decay_to(timestamp);
+        self.failure += amount as f64 * reason.penalty();
+    }
+
+    /// Weighted success rate, or `None` if nothing was recorded.
+    ///
+    /// Decaying both sums by the same factor leaves the ratio unchanged,
+    /// so this does not depend on the current time.
+    pub fn success_rate(&self) -> Option<f64> {
+        let total = self.success + self.failure;
+        (total > 0.0).then(|| self.success / total)
+    }
+
+    /// Decay both sums from `updated_at` to `timestamp`.
+    ///
+    /// Events older than `updated_at` are added without decay.
+    fn decay_to(&mut self, timestamp: u64) {
+        if timestamp > self.updated_at {
+            let age = (timestamp - self.updated_at) as f64;
+            let factor = 0.5_f64.powf(age / REPUTATION_HALF_LIFE_SECS as f64);
+            self.success *= factor;
+            self.failure *= factor;
+            self.updated_at = timestamp;
+        }
+    }
+}
+
+/// Transaction history a reputation score is computed from, either across
+/// all of an agent's work or for a single skill.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionStats {
+    /// Number of successful transactions.
+    pub successful_transactions: u64,
+    /// Number of failed transactions.
+    pub failed_transactions: u64,
+    /// Total successful volume in USDC (6 decimals).
+    pub total_volume: u64,
+    /// Unix timestamp of last activity (for decay calculation).
+    pub last_activity: u64,
+    /// Amount- and recency-weighted outcomes.
+    pub weighted: WeightedOutcomes,
+}
+
+impl TransactionStats {
+    /// Total number of transactions.
+    pub fn total_transactions(&self) -> u64 {
+        self.successful_transactions + self.failed_transactions
+    }
+
+    /// Average successful transaction amount, or the default if unknown.
+    pub fn average_amount(&self) -> u64 {
+        if self.successful_transactions > 0 && self.total_volume > 0 {
+            self.total_volume / self.successful_transactions
+        } else {
+            DEFAULT_TRANSACTION_AMOUNT
+        }
+    }
+
+    /// Add a successful transaction of `amount` at `timestamp`.
+    pub fn record_success(&mut self, amount: u64, timestamp: u64) {
+        self.seed_weighted();
+        self.weighted.record_success(amount, timestamp);
+        self.successful_transactions += 1;
+        self.total_volume = self.total_volume.saturating_add(amount);
+        self.last_activity = timestamp;
+    }
+
+    /// Add a failed transaction of `amount` (0 if unknown) at `timestamp`.
+    ///
+    /// Failures of unknown amount are weighed as the average successful
+    /// transaction.
+    pub fn record_failure(&mut self, amount: u64, reason: FailureReason, timestamp: u64) {
+        self.seed_weighted();
+        let amount = if amount > 0 {
+            amount
+        } else {
+            self.average_amount()
+        };
+        self.weighted.record_failure(amount, reason, timestamp);
+        self.failed_transactions += 1;
+        self.last_activity = timestamp;
+    }
+
+    /// Seed weighted outcomes from the counts for history that predates
+    /// weighting, so it is not discarded by the next event.
+    fn seed_weighted(&mut self) {
+        if self.weighted.is_empty() && self.total_transactions() > 0 {
+            self.weighted = WeightedOutcomes::from_counts(
+                self.successful_transactions,
+                self.failed_transactions,
+                self.average_amount(),
+                self.last_activity,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC: u64 = 1_000_000;
+
+    // ========== TDD Tests: Failure reasons ==========
+
+    #[test]
+    fn test_classify_failure_reasons() {
+        assert_eq!(FailureReason::classify("timeout"), FailureReason::Timeout);
+        assert_eq!(FailureReason::classify("Timed out"), FailureReason::Timeout);
+        assert_eq!(
+            FailureReason::classify("dispute-lost"),
+            FailureReason::DisputeLost
+        );
+        assert_eq!(FailureReason::classify("SLASHED"), FailureReason::Slashed);
+        assert_eq!(FailureReason::classify("error"), FailureReason::Other);
+    }
+
+    #[test]
+    fn test_penalties_increase_with_fault() {
+        assert!(FailureReason::Timeout.penalty() < FailureReason::Other.penalty());
+        assert!(FailureReason::Other.penalty() < FailureReason::DisputeLost.penalty());
+        assert!(FailureReason::DisputeLost.penalty() < FailureReason::Slashed.penalty());
+    }
+
+    // ========== TDD Tests: Weighting ==========
+
+    #[test]
+    fn test_amount_weighted_success_rate() {
+        // Arrange: a hundred $0.01 successes and one $5,000 failure
+        let mut outcomes = WeightedOutcomes::default();
+        for _ in 0..100 {
+            outcomes.record_success(USDC / 100, 1_000);
+        }
+        outcomes.record_failure(5_000 * USDC, FailureReason::Other, 1_000);
+
+        // Act
+        let rate = outcomes.success_rate().unwrap();
+
+        // Assert
+        assert!(rate < 0.001, "rate was {}", rate);
+    }
+
+    #[test]
+    fn test_recent_events_outweigh_old_ones() {
+        // Arrange: a failure, then an equal success one half-life later
+        let mut outcomes = WeightedOutcomes::default();
+        outcomes.record_failure(100 * USDC, FailureReason::Other, 0);
+        outcomes.record_success(100 * USDC, REPUTATION_HALF_LIFE_SECS);
+
+        // Act
+        let rate = outcomes.success_rate().unwrap();
+
+        // Assert: the failure counts half, so 1 / (1 + 0.5)
+        assert!((rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(outcomes.updated_at, REPUTATION_HALF_LIFE_SECS);
+    }
+
+    #[test]
+    fn test_failure_reason_scales_penalty() {
+        let rate_after = |reason| {
+            let mut outcomes = WeightedOutcomes::default();
+            outcomes.record_success(10 * USDC, 0);
+            outcomes.record_failure(10 * USDC, reason, 0);
+            outcomes.success_rate().unwrap()
+        };
+
+        assert!(rate_after(FailureReason::Timeout) > rate_after(FailureReason::DisputeLost));
+        assert!(rate_after(FailureReason::DisputeLost) > rate_after(FailureReason::Slashed));
+    }
+
+    // ========== TDD Tests: Transaction stats ==========
+
+    #[test]
+    fn test_stats_weigh_unknown_failure_as_average_success() {
+        // Arrange
+        let mut stats = TransactionStats::default();
+        stats.record_success(4 * USDC, 10);
+        stats.record_success(2 * USDC, 10);
+
+        // Act
+        stats.record_failure(0, FailureReason::Other, 10);
+
+        // Assert
+        assert_eq!(stats.total_volume, 6 * USDC);
+        assert_eq!(stats.total_transactions(), 3);
+        assert_eq!(stats.last_activity, 10);
+        assert!((stats.weighted.success_rate().unwrap() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_seed_weighting_from_counts() {
+        let mut stats = TransactionStats {
+            successful_transactions: 9,
+            failed_transactions: 1,
+            ..Default::default()
+        };
+
+        stats.record_success(USDC, 0);
+
+        assert!((stats.weighted.success_rate().unwrap() - 10.0 / 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_counts_matches_count_success_rate() {
+        let outcomes = WeightedOutcomes::from_counts(8, 2, USDC, 0);
+
+        assert!((outcomes.success_rate().unwrap() - 0.8).abs() < 1e-12);
+        assert!(WeightedOutcomes::default().success_rate().is_none());
+        assert!(WeightedOutcomes::default().is_empty());
+    }
+}
@@ -0,0 +1,375 @@
+//! BM25 keyword index over capability cards.
+//!
+//! Cards are indexed in three fields, each with its own boost, and scored
+//! with BM25F: per-field term frequencies are length-normalized, boosted
+//! and summed before BM25's saturation is applied once per term.
+//!
+//! ```text
+//! tf(t, d)    = Σ_f boost_f · tf_f(t, d) / (1 - b + b · len_f(d) / avglen_f)
+//! score(q, d) = Σ_t∈q idf(t) · tf(t, d) / (k1 + tf(t, d))
+//! idf(t)      = ln(1 + (N - df(t) + 0.5) / (df(t) + 0.5))
+//! ```
+//!
+//! Scores are divided by `Σ idf(t)` over the query terms, the score of a
+//! card matching every term infinitely often, so they fall in 0.0 - 1.0.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::text::tokenize;
+
+/// BM25 parameters and field boosts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bm25Config {
+    /// Term frequency saturation (typically 1.2 - 2.0).
+    pub k1: f32,
+
+    /// Length normalization (0.0 = none, 1.0 = full).
+    pub b: f32,
+
+    /// Boost for terms in the card name.
+    pub name_boost: f32,
+
+    /// Boost for terms in skill names.
+    pub skill_boost: f32,
+
+    /// Boost for terms in the card and skill descriptions.
+    pub description_boost: f32,
+}
+
+impl Default for Bm25Config {
+    fn default() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            name_boost: 3.0,
+            skill_boost: 2.0,
+            description_boost: 1.0,
+        }
+    }
+}
+
+impl Bm25Config {
+    fn boost(&self, field: Bm25Field) -> f32 {
+        match field {
+            Bm25Field::Name => self.name_boost,
+            Bm25Field::Skill => self.skill_boost,
+            Bm25Field::Description => self.description_boost,
+        }
+    }
+}
+
+/// Card field a piece of text belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bm25Field {
+    /// Card name.
+    Name,
+    /// Skill names.
+    Skill,
+    /// Card and skill descriptions.
+    Description,
+}
+
+const FIELDS: [Bm25Field; 3] = [Bm25Field::Name, Bm25Field::Skill, Bm25Field::Description];
+
+/// Per-field counts, indexed by `Bm25Field as usize`.
+type FieldCounts = [u32; 3];
+
+#[derive(Debug)]
+struct Document {
+    /// Terms per field.
+    lengths: FieldCounts,
+    /// Distinct terms, for removing postings.
+    terms: Vec<String>,
+}
+
+/// Inverted index scoring documents with BM25F.
+#[derive(Debug, Default)]
+pub struct Bm25Index {
+    config: Bm25Config,
+    documents: HashMap<String, Document>,
+    /// Term -> document id -> term frequency per field.
+    postings: HashMap<String, HashMap<String, FieldCounts>>,
+    /// Sum of document lengths per field.
+    total_lengths: [u64; 3],
+}
+
+impl Bm25Index {
+    /// Create an empty index.
+    pub fn new(config: Bm25Config) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Index `fields` of document `id`, replacing any previous version.
+    pub fn upsert(&mut self, id: &str, fields: &[(Bm25Field, &str)]) {
+        self.remove(id);
+
+        let mut lengths = FieldCounts::default();
+        let mut frequencies: HashMap<String, FieldCounts> = HashMap::new();
+        for &(field, text) in fields {
+            for term in tokenize(text) {
+                lengths[field as usize] += 1;
+                frequencies.entry(term).or_default()[field as usize] += 1;
+            }
+        }
+
+        for (total, length) in self.total_lengths.iter_mut().zip(lengths) {
+            *total += u64::from(length);
+        }
+        let terms = frequencies.keys().cloned().collect();
+        for (term, counts) in frequencies {
+            self.postings
+                .entry(term)
+                .or_default()
+                .insert(id.to_string(), counts);
+        }
+        self.documents
+            .insert(id.to_string(), Document { lengths, terms });
+    }
+
+    /// Remove document `id`. Returns `true` if it was indexed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(document) = self.documents.remove(id) else {
+            return false;
+        };
+        for (total, length) in self.total_lengths.iter_mut().zip(document.lengths) {
+            *total -= u64::from(length);
+        }
+        for term in document.terms {
+            if let Some(posting) = self.postings.get_mut(&term) {
+                posting.remove(id);
+                if posting.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+        true
+    }
+
+    /// Remove every document.
+    pub fn clear(&mut self) {
+        self.documents.clear();
+        self.postings.clear();
+        self.total_lengths = [0; 3];
+    }
+
+    /// Number of indexed documents.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Whether no documents are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Documents matching at least one term of `query`, with their
+    /// normalized score (0.0 - 1.0), best first.
+    pub fn search(&self, query: &str) -> Vec<(String, f32)> {
+        let mut seen = HashSet::new();
+        let terms: Vec<String> = tokenize(query)
+            .into_iter()
+            .filter(|term| seen.insert(term.clone()))
+            .collect();
+        if terms.is_empty() || self.documents.is_empty() {
+            return Vec::new();
+        }
+
+        let n = self.documents.len() as f32;
+        let avg_lengths: Vec<f32> = self.total_lengths.iter().map(|&t| t as f32 / n).collect();
+
+        let mut max_score = 0.0;
+        let mut scores: HashMap<&str, f32> = HashMap::new();
+        for term in &terms {
+            let posting = self.postings.get(term);
+            let df = posting.map_or(0, HashMap::len) as f32;
+            let idf = (1.0 + (n - df + 0.5) / (df + 0.5)).ln();
+            max_score += idf;
+
+            for (id, counts) in posting.into_iter().flatten() {
+                let lengths = &self.documents[id].lengths;
+                let tf: f32 = FIELDS
+                    .iter()
+                    .map(|&field| {
+                        let f = field as usize;
+                        if counts[f] == 0 {
+                            return 0.0;
+                        }
+                        let relative_length = if avg_lengths[f] > 0.0 {
+                            lengths[f] as f32 / avg_lengths[f]
+                        } else {
+                            1.0
+                        };
+                        let norm = 1.0 - self.config.b + self.config.b * relative_length;
+                        self.config.boost(field) * counts[f] as f32 / norm
+                    })
+                    .sum();
+                if tf > 0.0 {
+                    *scores.entry(id.as_str()).or_default() += idf * tf / (self.config.k1 + tf);
+                }
+            }
+        }
+
+        let mut results: Vec<(String, f32)> = scores
+            .into_iter()
+            .map(|(id, score)| (id.to_string(), score / max_score))
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index_of(documents: &[(&str, &str, &str, &str)]) -> Bm25Index {
+        let mut index = Bm25Index::new(Bm25Config::default());
+        for &(id, name, skills, description) in documents {
+            index.upsert(
+                id,
+                &[
+                    (Bm25Field::Name, name),
+                    (Bm25Field::Skill, skills),
+                    (Bm25Field::Description, description),
+                ],
+            );
+        }
+        index
+    }
+
+    fn ids(results: &[(String, f32)]) -> Vec<&str> {
+        results.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    // ========== TDD Tests: BM25 scoring ==========
+
+    #[test]
+    fn test_search_matches_stemmed_terms() {
+        let index = index_of(&[("reviewer", "Reviewer", "Code Reviews", "Reviews code")]);
+
+        let results = index.search("reviewing code");
+
+        assert_eq!(ids(&results), vec!["reviewer"]);
+        assert!(results[0].1 > 0.0 && results[0].1 <= 1.0);
+    }
+
+    #[test]
+    fn test_name_match_outranks_description_match() {
+        // Arrange: same term, once in the name and once in the description
+        let index = index_of(&[
+            ("in-description", "Helper", "Assist", "Translation helper"),
+            ("in-name", "Translation", "Assist", "A helper"),
+        ]);
+
+        // Act
+        let results = index.search("translation");
+
+        // Assert
+        assert_eq!(ids(&results), vec!["in-name", "in-description"]);
+    }
+
+    #[test]
+    fn test_rare_terms_weigh_more_than_common_ones() {
+        // Arrange: every card mentions "agent", one mentions "solidity"
+        let index = index_of(&[
+            ("a", "Agent", "", "Solidity auditor"),
+            ("b", "Agent", "", "Python agent tools"),
+            ("c", "Agent", "", "General agent tasks"),
+        ]);
+
+        // Act
+        let results = index.search("agent solidity");
+
+        // Assert
+        assert_eq!(results[0].0, "a");
+        assert!(results[0].1 > 2.0 * results[1].1);
+    }
+
+    #[test]
+    fn test_longer_fields_are_normalized() {
+        let index = index_of(&[
+            ("short", "Weather", "", "Forecast"),
+            (
+                "long",
+                "Weather",
+                "",
+                "Forecast plus news sports finance travel recipes music movies",
+            ),
+        ]);
+
+        let results = index.search("forecast");
+
+        assert_eq!(ids(&results), vec!["short", "long"]);
+    }
+
+    #[test]
+    fn test_term_frequency_saturates() {
+        let index = index_of(&[
+            ("once", "", "", "data pipeline"),
+            ("many", "", "", "data data data data data data"),
+        ]);
+
+        let results = index.search("data pipeline");
+
+        assert_eq!(results[0].0, "once");
+    }
+
+    #[test]
+    fn test_unmatched_query_terms_lower_the_score() {
+        let index = index_of(&[("a", "Code Review", "", "")]);
+
+        let full = index.search("code review")[0].1;
+        let partial = index.search("code review blockchain")[0].1;
+
+        assert!(partial < full);
+    }
+
+    #[test]
+    fn test_stopword_only_query_matches_nothing() {
+        let index = index_of(&[("a", "The agent", "", "")]);
+
+        assert!(index.search("the of and").is_empty());
+        assert!(index.search("").is_empty());
+    }
+
+    // ========== TDD Tests: Index maintenance ==========
+
+    #[test]
+    fn test_upsert_replaces_document() {
+        let mut index = index_of(&[("a", "Weather", "", "")]);
+
+        index.upsert("a", &[(Bm25Field::Name, "Translator")]);
+
+        assert_eq!(index.len(), 1);
+        assert!(index.search("weather").is_empty());
+        assert_eq!(ids(&index.search("translator")), vec!["a"]);
+    }
+
+    #[test]
+    fn test_remove_drops_postings_and_lengths() {
+        let mut index = index_of(&[("a", "Weather", "", "rain"), ("b", "Translator", "", "")]);
+
+        assert!(index.remove("a"));
+        assert!(!index.remove("a"));
+
+        assert!(index.search("weather rain").is_empty());
+        assert!(!index.postings.contains_key("weather"));
+        assert_eq!(index.total_lengths, [1, 0, 0]);
+    }
+
+    #[test]
+    fn test_clear_empties_index() {
+        let mut index = index_of(&[("a", "Weather", "", "")]);
+
+        index.clear();
+
+        assert!(index.is_empty());
+        assert!(index.search("weather").is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+//! Structured search filters, sort order and pagination.
+//!
+//! A [`SearchFilter`] narrows the candidate cards before they are ranked.
+//! Card attributes (pricing, payment methods, authentication, provider,
+//! skill) are checked against the card by [`SearchFilter::matches`].
+//! Minimum trust and stake are checked against live data from the
+//! `TrustService` by [`SearchFilter::admits_trust`], never against the
+//! self-declared values in the card.
+
+use serde::{Deserialize, Serialize};
+
+use crate::discovery::{CapabilityCard, PricingModel};
+use crate::error::{Error, Result};
+use crate::trust::TrustInfo;
+
+/// Constraints a card must satisfy to be returned by a search.
+///
+/// Every field is optional; an empty filter matches every card. A card
+/// that lacks the data a set field checks (e.g. no pricing when
+/// `max_price` is set) does not match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    /// Pricing model the agent must use.
+    pub pricing_model: Option<PricingModel>,
+    /// Highest acceptable base price in USDC (6 decimals).
+    pub max_price: Option<u64>,
+    /// Payment method the agent must accept (e.g. "x402").
+    pub payment_method: Option<String>,
+    /// Authentication scheme the agent must support (e.g. "did").
+    pub auth_scheme: Option<String>,
+    /// Provider organization the agent must belong to.
+    pub provider: Option<String>,
+    /// Skill ID the agent must offer.
+    pub skill: Option<String>,
+    /// Minimum live trust score (0.0 - 1.0), in `skill` if given.
+    pub min_trust: Option<f64>,
+    /// Minimum live stake in USDC (6 decimals).
+    pub min_stake: Option<u64>,
+}
+
+impl SearchFilter {
+    /// Reject a `min_trust` outside 0.0 - 1.0.
+    pub fn validate(&self) -> Result<()> {
+        match self.min_trust {
+            Some(min) if !(0.0..=1.0).contains(&min) => Err(Error::Validation(format!(
+                "min_trust must be between 0.0 and 1.0, got {}",
+                min
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `card` satisfies the card attribute constraints.
+    ///
+    /// Payment methods, authentication schemes and the provider are
+    /// compared case-insensitively; the skill ID exactly.
+    pub fn matches(&self, card: &CapabilityCard) -> bool {
+        let extension = card.agoramesh.as_ref();
+        let pricing = extension.and_then(|ext| ext.pricing.as_ref());
+
+        self.pricing_model
+            .as_ref()
+            .is_none_or(|model| pricing.is_some_and(|p| &p.model == model))
+            && self
+                .max_price
+                .is_none_or(|max| pricing.is_some_and(|p| p.base_price <= max))
+            && self.payment_method.as_deref().is_none_or(|method| {
+                extension.is_some_and(|ext| {
+                    ext.payment_methods
+                        .iter()
+                        .any(|m| m.eq_ignore_ascii_case(method))
+                })
+            })
+            && self.auth_scheme.as_deref().is_none_or(|scheme| {
+                card.authentication
+                    .as_ref()
+                    .is_some_and(|auth| auth.schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)))
+            })
+            && self.provider.as_deref().is_none_or(|organization| {
+                card.provider
+                    .as_ref()
+                    .is_some_and(|p| p.organization.eq_ignore_ascii_case(organization))
+            })
+            && self
+                .skill
+                .as_deref()
+                .is_none_or(|id| card.skills.iter().any(|s| s.id == id))
+    }
+
+    /// Whether a minimum trust score or stake was requested.
+    pub fn filters_by_trust(&self) -> bool {
+        self.min_trust.is_some() || self.min_stake.is_some()
+    }
+
+    /// Whether an agent with live trust `info` passes `min_trust` and
+    /// `min_stake`. Agents without trust data only pass filters that set
+    /// neither.
+    pub fn admits_trust(&self, info: Option<&TrustInfo>) -> bool {
+        match info {
+            Some(info) => {
+                self.min_trust.is_none_or(|min| info.score >= min)
+                    && self.min_stake.is_none_or(|min| info.stake_amount >= min)
+            }
+            None => !self.filters_by_trust(),
+        }
+    }
+}
+
+/// Order of search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Best match first (weighing trust in the skill when one is filtered on).
+    #[default]
+    Relevance,
+    /// Highest live trust score first.
+    Trust,
+    /// Lowest base price first; agents without pricing last.
+    Price,
+}
+
+/// Base price of the agent behind `card`, if it publishes one.
+pub fn base_price(card: &CapabilityCard) -> Option<u64> {
+    card.agoramesh
+        .as_ref()
+        .and_then(|ext| ext.pricing.as_ref())
+        .map(|pricing| pricing.base_price)
+}
+
+/// A window of at most `limit` results starting at `offset`.
+///
+/// The cursor returned alongside each page is the offset of the next
+/// result. Every request ranks afresh, so results can shift between pages
+/// when agents are registered, removed or re-scored in the meantime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Page {
+    /// Maximum number of results (`None` returns the rest).
+    pub limit: Option<usize>,
+    /// Number of results to skip.
+    pub offset: usize,
+}
+
+impl Page {
+    /// Largest accepted `limit`.
+    pub const MAX_LIMIT: usize = 100;
+
+    /// Page from the `limit` and `cursor` request parameters.
+    pub fn parse(limit: Option<usize>, cursor: Option<&str>) -> Result<Self> {
+        if let Some(limit) = limit {
+            if !(1..=Self::MAX_LIMIT).contains(&limit) {
+                return Err(Error::Validation(format!(
+                    "limit must be between 1 and {}, got {}",
+                    Self::MAX_LIMIT,
+                    limit
+                )));
+            }
+        }
+        let offset = match cursor {
+            Some(cursor) => cursor
+                .parse()
+                .map_err(|_| Error::Validation(format!("Invalid cursor '{}'", cursor)))?,
+            None => 0,
+        };
+        Ok(Self { limit, offset })
+    }
+
+    /// This page of `items`, and the cursor of the next page if any
+    /// results remain.
+    pub fn apply<T>(&self, items: Vec<T>) -> (Vec<T>, Option<String>) {
+        let total = items.len();
+        let page: Vec<T> = items
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect();
+        let end = self.offset.saturating_add(page.len());
+        let next = (end < total).then(|| end.to_string());
+        (page, next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::{
+        AgoraMeshExtension, AuthenticationInfo, PricingInfo, ProviderInfo, Skill,
+    };
+
+    fn card(model: PricingModel, base_price: u64) -> CapabilityCard {
+        CapabilityCard {
+            name: "Translator".to_string(),
+            description: "Translates text".to_string(),
+            url: "https://translator.example.com".to_string(),
+            provider: Some(ProviderInfo {
+                organization: "Acme Labs".to_string(),
+                url: None,
+            }),
+            skills: vec![Skill {
+                id: "translate".to_string(),
+                name: "Translate".to_string(),
+                description: None,
+                input_schema: None,
+                output_schema: None,
+            }],
+            authentication: Some(AuthenticationInfo {
+                schemes: vec!["did".to_string(), "bearer".to_string()],
+            }),
+            agoramesh: Some(AgoraMeshExtension {
+                did: "did:agoramesh:base:translator".to_string(),
+                trust_score: Some(0.9),
+                stake: Some(1_000_000_000),
+                pricing: Some(PricingInfo {
+                    base_price,
+                    currency: "USDC".to_string(),
+                    model,
+                }),
+                payment_methods: vec!["x402".to_string()],
+            }),
+        }
+    }
+
+    fn trust(score: f64, stake_amount: u64) -> TrustInfo {
+        TrustInfo {
+            did: "did:agoramesh:base:translator".to_string(),
+            score,
+            reputation: score,
+            stake_score: 0.0,
+            endorsement_score: 0.0,
+            stake_amount,
+            successful_transactions: 0,
+            failed_transactions: 0,
+            endorsement_count: 0,
+            skill: None,
+        }
+    }
+
+    // ========== TDD Tests: Card filters ==========
+
+    #[test]
+    fn test_empty_filter_matches_every_card() {
+        let mut bare = card(PricingModel::PerRequest, 1);
+        bare.provider = None;
+        bare.authentication = None;
+        bare.agoramesh = None;
+
+        assert!(SearchFilter::default().matches(&bare));
+    }
+
+    #[test]
+    fn test_filter_matches_each_card_attribute() {
+        let card = card(PricingModel::PerRequest, 50_000);
+
+        for (filter, expected) in [
+            (
+                SearchFilter {
+                    pricing_model: Some(PricingModel::PerRequest),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    pricing_model: Some(PricingModel::PerToken),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                SearchFilter {
+                    max_price: Some(50_000),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    max_price: Some(49_999),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                SearchFilter {
+                    payment_method: Some("X402".to_string()),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    payment_method: Some("stripe".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                SearchFilter {
+                    auth_scheme: Some("bearer".to_string()),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    auth_scheme: Some("oauth2".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                SearchFilter {
+                    provider: Some("acme labs".to_string()),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    provider: Some("Other".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+            (
+                SearchFilter {
+                    skill: Some("translate".to_string()),
+                    ..Default::default()
+                },
+                true,
+            ),
+            (
+                SearchFilter {
+                    skill: Some("Translate".to_string()),
+                    ..Default::default()
+                },
+                false,
+            ),
+        ] {
+            assert_eq!(filter.matches(&card), expected, "{:?}", filter);
+        }
+    }
+
+    #[test]
+    fn test_filter_rejects_cards_missing_the_filtered_data() {
+        let mut card = card(PricingModel::PerRequest, 1);
+        card.authentication = None;
+        card.agoramesh.as_mut().unwrap().pricing = None;
+
+        let by_price = SearchFilter {
+            max_price: Some(1_000_000),
+            ..Default::default()
+        };
+        let by_scheme = SearchFilter {
+            auth_scheme: Some("did".to_string()),
+            ..Default::default()
+        };
+
+        assert!(!by_price.matches(&card));
+        assert!(!by_scheme.matches(&card));
+    }
+
+    // ========== TDD Tests: Trust filters ==========
+
+    #[test]
+    fn test_admits_trust_uses_live_trust_data() {
+        let filter = SearchFilter {
+            min_trust: Some(0.5),
+            min_stake: Some(1_000),
+            ..Default::default()
+        };
+
+        assert!(filter.admits_trust(Some(&trust(0.6, 1_000))));
+        assert!(!filter.admits_trust(Some(&trust(0.4, 1_000))));
+        assert!(!filter.admits_trust(Some(&trust(0.6, 999))));
+        assert!(!filter.admits_trust(None));
+        assert!(SearchFilter::default().admits_trust(None));
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_min_trust() {
+        let filter = SearchFilter {
+            min_trust: Some(1.5),
+            ..Default::default()
+        };
+
+        let err = filter.validate().unwrap_err();
+
+        assert!(err.to_string().contains("min_trust"));
+        assert!(SearchFilter::default().validate().is_ok());
+    }
+
+    // ========== TDD Tests: Pagination ==========
+
+    #[test]
+    fn test_page_walks_results_with_cursor() {
+        let items: Vec<u32> = (0..5).collect();
+
+        let first = Page::parse(Some(2), None).unwrap();
+        let (page, cursor) = first.apply(items.clone());
+        assert_eq!(page, vec![0, 1]);
+
+        let second = Page::parse(Some(2), cursor.as_deref()).unwrap();
+        let (page, cursor) = second.apply(items.clone());
+        assert_eq!(page, vec![2, 3]);
+
+        let last = Page::parse(Some(2), cursor.as_deref()).unwrap();
+        let (page, cursor) = last.apply(items);
+        assert_eq!(page, vec![4]);
+        assert_eq!(cursor, None);
+    }
+
+    #[test]
+    fn test_page_without_limit_returns_rest() {
+        let page = Page::parse(None, Some("3")).unwrap();
+
+        assert_eq!(page.apply(vec![0, 1, 2, 3, 4]), (vec![3, 4], None));
+        assert_eq!(page.apply(vec![0, 1]), (vec![], None));
+    }
+
+    #[test]
+    fn test_page_rejects_bad_limit_and_cursor() {
+        assert!(Page::parse(Some(0), None).is_err());
+        assert!(Page::parse(Some(Page::MAX_LIMIT + 1), None).is_err());
+        assert!(Page::parse(None, Some("abc")).is_err());
+        assert!(Page::parse(None, Some("-1")).is_err());
+    }
+}
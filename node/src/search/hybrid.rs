@@ -1,17 +1,41 @@
 //! Hybrid search combining keyword (BM25) and vector similarity.
 //!
-//! Uses Reciprocal Rank Fusion (RRF) to combine results from:
-//! - BM25 keyword matching (exact term matches)
+//! Combines results from:
+//! - BM25 keyword matching (stemmed term matches, boosted by field)
 //! - Vector cosine similarity (semantic meaning)
+//!
+//! either as a weighted sum of the normalized scores or with Reciprocal
+//! Rank Fusion (RRF), depending on [`FusionMode`]. With a [`TrustService`]
+//! attached, the resulting relevance is blended with each agent's
+//! node-computed trust score; the `trust_score` a card declares about
+//! itself is never used.
 
 use crate::discovery::CapabilityCard;
 use crate::error::{Error, Result};
+use crate::trust::{TrustInfo, TrustService};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-
-use super::embedding::{Embedding, EmbeddingService};
+use std::sync::Arc;
+
+use super::bm25::{Bm25Config, Bm25Field, Bm25Index};
+use super::embedding::EmbeddingService;
+use super::filter::SearchFilter;
+use super::index::{HnswIndex, VectorIndex};
+
+/// How vector and keyword results are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FusionMode {
+    /// Weighted sum of the normalized vector and keyword scores.
+    #[default]
+    Weighted,
+    /// Weighted reciprocal rank fusion: `Σ weight / (rrf_k + rank)`.
+    Rrf,
+}
 
 /// Configuration for hybrid search.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct HybridSearchConfig {
     /// Weight for vector similarity (0.0 - 1.0)
     pub vector_weight: f32,
@@ -19,14 +43,27 @@ pub struct HybridSearchConfig {
     /// Weight for BM25 keyword matching (0.0 - 1.0)
     pub keyword_weight: f32,
 
-    /// RRF constant k (default: 60)
+    /// RRF constant k (default: 60), used when `fusion` is `rrf`
     pub rrf_k: f32,
 
+    /// How vector and keyword results are combined
+    pub fusion: FusionMode,
+
+    /// BM25 keyword scoring parameters
+    pub bm25: Bm25Config,
+
     /// Maximum number of results to return
     pub max_results: usize,
 
     /// Minimum score threshold (0.0 - 1.0)
     pub min_score: f32,
+
+    /// Nearest neighbours fetched from the vector index per query
+    pub vector_candidates: usize,
+
+    /// Share of the final score taken from the node-computed trust score
+    /// (0.0 - 1.0); unused without a TrustService
+    pub trust_weight: f32,
 }
 
 impl Default for HybridSearchConfig {
@@ -35,8 +72,12 @@ impl Default for HybridSearchConfig {
             vector_weight: 0.7,
             keyword_weight: 0.3,
             rrf_k: 60.0,
+            fusion: FusionMode::default(),
+            bm25: Bm25Config::default(),
             max_results: 20,
             min_score: 0.1,
+            vector_candidates: 100,
+            trust_weight: 0.3,
         }
     }
 }
@@ -47,26 +88,45 @@ pub struct SearchResult {
     /// Agent DID
     pub did: String,
 
-    /// Combined hybrid score (0.0 - 1.0)
+    /// Final score: relevance blended with trust (0.0 - 1.0)
     pub score: f32,
 
+    /// Combined vector and keyword score before trust (0.0 - 1.0)
+    pub relevance: f32,
+
     /// Vector similarity score
     pub vector_score: f32,
 
     /// Keyword match score
     pub keyword_score: f32,
 
-    /// The capability card
+    /// The capability card, carrying the node-computed trust score
     pub card: CapabilityCard,
 }
 
+/// A candidate's 1-based rank and normalized score in each result list.
+#[derive(Default)]
+struct Ranked {
+    vector: Option<(usize, f32)>,
+    keyword: Option<(usize, f32)>,
+}
+
 /// Hybrid search combining BM25 keyword matching and vector similarity.
 pub struct HybridSearch {
     /// Embedding service for vector generation
     embedding_service: EmbeddingService,
 
-    /// In-memory index of agent embeddings (DID -> embedding)
-    index: HashMap<String, (CapabilityCard, Embedding)>,
+    /// Indexed capability cards (DID -> card)
+    cards: HashMap<String, CapabilityCard>,
+
+    /// Nearest-neighbour index of card embeddings (DID -> embedding)
+    vectors: Arc<dyn VectorIndex>,
+
+    /// Inverted index of card text (DID -> terms)
+    keywords: Bm25Index,
+
+    /// Source of node-computed trust scores for ranking
+    trust: Option<Arc<TrustService>>,
 
     /// Configuration
     config: HybridSearchConfig,
@@ -82,14 +142,32 @@ impl HybridSearch {
     pub fn with_config(embedding_service: EmbeddingService, config: HybridSearchConfig) -> Self {
         Self {
             embedding_service,
-            index: HashMap::new(),
+            cards: HashMap::new(),
+            vectors: Arc::new(HnswIndex::new()),
+            keywords: Bm25Index::new(config.bm25.clone()),
+            trust: None,
             config,
         }
     }
 
+    /// Blend relevance with trust scores computed by `trust`.
+    pub fn with_trust(mut self, trust: Arc<TrustService>) -> Self {
+        self.trust = Some(trust);
+        self
+    }
+
+    /// Store embeddings in `index` instead of the default in-memory HNSW.
+    ///
+    /// Set this before indexing cards; vectors already indexed are not moved.
+    pub fn with_index(mut self, index: Arc<dyn VectorIndex>) -> Self {
+        self.vectors = index;
+        self
+    }
+
     /// Index a capability card for search.
     ///
-    /// Generates and stores embedding for the card.
+    /// Generates the card's embedding and upserts it into the vector index,
+    /// replacing any previous version of the card.
     pub async fn index_card(&mut self, card: &CapabilityCard) -> Result<()> {
         let did = card
             .agoramesh
@@ -106,59 +184,147 @@ impl HybridSearch {
         let embedding = self.embedding_service.embed(&text).await?;
 
         // Store in index
-        self.index.insert(did, (card.clone(), embedding));
+        self.vectors.upsert(&did, &embedding).await?;
+        self.index_keywords(&did, card);
+        self.cards.insert(did, card.clone());
 
         Ok(())
     }
 
     /// Remove a card from the index.
-    pub fn remove_card(&mut self, did: &str) -> bool {
-        self.index.remove(did).is_some()
+    ///
+    /// Returns `true` if the card was indexed.
+    pub async fn remove_card(&mut self, did: &str) -> Result<bool> {
+        if self.cards.remove(did).is_none() {
+            return Ok(false);
+        }
+        self.keywords.remove(did);
+        self.vectors.delete(did).await?;
+        Ok(true)
     }
 
-    /// Search for agents matching the query.
+    /// Search for agents matching the query, returning at most
+    /// `max_results` of them.
     ///
-    /// Combines vector similarity and keyword matching using RRF.
-    pub async fn search(&self, query: &str) -> Result<Vec<SearchResult>> {
-        if self.index.is_empty() {
+    /// See [`rank`](Self::rank) for how results are selected and ordered.
+    pub async fn search(&self, query: &str, filter: &SearchFilter) -> Result<Vec<SearchResult>> {
+        let mut results = self.rank(query, filter).await?;
+        results.truncate(self.config.max_results);
+        Ok(results)
+    }
+
+    /// Rank every agent matching the query, best first.
+    ///
+    /// Only cards matching the card attributes of `filter` whose agents'
+    /// live trust (in `filter.skill` if set) passes its trust constraints
+    /// are ranked. The filter is applied during retrieval: the vector search
+    /// is widened until `vector_candidates` admitted neighbours are found or
+    /// the index is exhausted. Vector similarity and keyword matching
+    /// are combined as set by `fusion`, and the relevance is blended with
+    /// trust (see [`with_trust`](Self::with_trust)). Without a
+    /// TrustService, trust constraints admit no agent.
+    pub async fn rank(&self, query: &str, filter: &SearchFilter) -> Result<Vec<SearchResult>> {
+        if self.cards.is_empty() {
             return Ok(vec![]);
         }
 
-        // Generate query embedding
         let query_embedding = self.embedding_service.embed(query).await?;
-        let query_lower = query.to_lowercase();
-        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
 
-        // Score all indexed cards
-        let mut results: Vec<SearchResult> = vec![];
+        // Live trust of every agent checked against the filter; the card's
+        // own claim is replaced with the node's view
+        let mut trust: HashMap<String, Option<TrustInfo>> = HashMap::new();
+
+        // Widen the vector search until `vector_candidates` neighbours pass
+        // the filter or the index has nothing more to give
+        let mut k = self.config.vector_candidates;
+        let neighbours = loop {
+            let neighbours = self.vectors.search(&query_embedding, k).await?;
+            let exhausted = neighbours.len() < k;
+
+            let mut admitted = Vec::with_capacity(neighbours.len());
+            for m in neighbours {
+                // A persistent vector index may still hold cards this node
+                // no longer knows (e.g. evicted while it was down)
+                if !self.cards.contains_key(&m.id) {
+                    if let Err(e) = self.vectors.delete(&m.id).await {
+                        tracing::warn!("Failed to drop stale vector for {}: {}", m.id, e);
+                    }
+                    continue;
+                }
+                if self.admits(&m.id, filter, &mut trust).await {
+                    admitted.push(m);
+                }
+            }
 
-        for (did, (card, embedding)) in &self.index {
-            // Vector similarity score
-            let vector_score = EmbeddingService::cosine_similarity(&query_embedding, embedding);
+            if exhausted || admitted.len() >= self.config.vector_candidates {
+                admitted.truncate(self.config.vector_candidates);
+                break admitted;
+            }
+            k = k.saturating_mul(2);
+        };
+
+        // Rank the admitted candidates from both indexes (ranks are 1-based)
+        let mut candidates: HashMap<&str, Ranked> = HashMap::new();
+        for (rank, m) in neighbours.iter().enumerate() {
             // Normalize to 0-1 range (cosine can be -1 to 1)
-            let vector_score = (vector_score + 1.0) / 2.0;
+            let vector_score = (m.similarity + 1.0) / 2.0;
+            candidates.entry(m.id.as_str()).or_default().vector = Some((rank + 1, vector_score));
+        }
+        let keyword_hits = self.keywords.search(query);
+        let mut rank = 0;
+        for (did, keyword_score) in &keyword_hits {
+            if self.admits(did, filter, &mut trust).await {
+                rank += 1;
+                candidates.entry(did.as_str()).or_default().keyword = Some((rank, *keyword_score));
+            }
+        }
 
-            // Keyword match score (simple BM25-like)
-            let keyword_score = self.compute_keyword_score(card, &query_terms);
+        let mut results: Vec<SearchResult> = vec![];
+
+        for (did, ranked) in candidates {
+            let Some(card) = self.cards.get(did) else {
+                continue;
+            };
+            let vector_score = ranked.vector.map_or(0.0, |(_, score)| score);
+            let keyword_score = ranked.keyword.map_or(0.0, |(_, score)| score);
 
             // Skip if both scores are too low
             if vector_score < 0.3 && keyword_score < 0.1 {
                 continue;
             }
 
-            // Combine scores using weighted sum (simplified from RRF for in-memory use)
-            let combined_score = self.config.vector_weight * vector_score
-                + self.config.keyword_weight * keyword_score;
-
-            if combined_score >= self.config.min_score {
-                results.push(SearchResult {
-                    did: did.clone(),
-                    score: combined_score,
-                    vector_score,
-                    keyword_score,
-                    card: card.clone(),
-                });
+            let relevance = match self.config.fusion {
+                FusionMode::Weighted => {
+                    self.config.vector_weight * vector_score
+                        + self.config.keyword_weight * keyword_score
+                }
+                FusionMode::Rrf => self.reciprocal_rank_fusion(
+                    ranked.vector.map(|(rank, _)| rank),
+                    ranked.keyword.map(|(rank, _)| rank),
+                ),
+            };
+
+            if relevance < self.config.min_score {
+                continue;
             }
+
+            let trust_score = trust
+                .get(did)
+                .and_then(|info| info.as_ref())
+                .map(|info| info.score);
+            let mut card = card.clone();
+            if let Some(ext) = card.agoramesh.as_mut() {
+                ext.trust_score = trust_score;
+            }
+
+            results.push(SearchResult {
+                did: did.to_string(),
+                score: self.blend(relevance, trust_score),
+                relevance,
+                vector_score,
+                keyword_score,
+                card,
+            });
         }
 
         // Sort by score descending
@@ -168,54 +334,109 @@ impl HybridSearch {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        // Limit results
-        results.truncate(self.config.max_results);
-
         Ok(results)
     }
 
-    /// Compute keyword match score (simplified BM25-like).
-    fn compute_keyword_score(&self, card: &CapabilityCard, query_terms: &[&str]) -> f32 {
-        if query_terms.is_empty() {
-            return 0.0;
+    /// Whether the card of `did` passes `filter`. Live trust is looked up
+    /// (once per query, kept in `trust`) only for cards whose attributes
+    /// match.
+    async fn admits(
+        &self,
+        did: &str,
+        filter: &SearchFilter,
+        trust: &mut HashMap<String, Option<TrustInfo>>,
+    ) -> bool {
+        if !self.cards.get(did).is_some_and(|card| filter.matches(card)) {
+            return false;
+        }
+        if !trust.contains_key(did) {
+            let info = self.live_trust(did, filter.skill.as_deref()).await;
+            trust.insert(did.to_string(), info);
         }
+        filter.admits_trust(trust.get(did).and_then(|info| info.as_ref()))
+    }
 
-        let card_text = format!(
-            "{} {} {}",
-            card.name,
-            card.description,
-            card.skills
-                .iter()
-                .map(|c| format!("{} {}", c.name, c.description.as_deref().unwrap_or("")))
-                .collect::<Vec<_>>()
-                .join(" ")
-        )
-        .to_lowercase();
-
-        let mut matches = 0;
-        for term in query_terms {
-            if card_text.contains(term) {
-                matches += 1;
-            }
+    /// Node-computed trust of `did` (in `skill` if given), if a
+    /// TrustService is attached. Only cached TrustRegistry values are
+    /// used, so ranking never waits on the chain.
+    async fn live_trust(&self, did: &str, skill: Option<&str>) -> Option<TrustInfo> {
+        self.trust
+            .as_ref()?
+            .get_cached_skill_trust(did, skill)
+            .await
+            .ok()
+    }
+
+    /// Blend `relevance` with `trust_score` by `trust_weight`. Without a
+    /// TrustService the relevance stands; agents it has no score for
+    /// count as untrusted.
+    fn blend(&self, relevance: f32, trust_score: Option<f64>) -> f32 {
+        if self.trust.is_none() {
+            return relevance;
+        }
+        let weight = self.config.trust_weight;
+        (1.0 - weight) * relevance + weight * trust_score.unwrap_or(0.0) as f32
+    }
+
+    /// Weighted reciprocal rank fusion of a card's 1-based ranks, scaled so
+    /// a card ranked first in both lists scores 1.0.
+    fn reciprocal_rank_fusion(
+        &self,
+        vector_rank: Option<usize>,
+        keyword_rank: Option<usize>,
+    ) -> f32 {
+        let k = self.config.rrf_k;
+        let term =
+            |weight: f32, rank: Option<usize>| rank.map_or(0.0, |rank| weight / (k + rank as f32));
+        let best = (self.config.vector_weight + self.config.keyword_weight) / (k + 1.0);
+        if best <= 0.0 {
+            return 0.0;
         }
+        (term(self.config.vector_weight, vector_rank)
+            + term(self.config.keyword_weight, keyword_rank))
+            / best
+    }
 
-        // Return ratio of matching terms
-        matches as f32 / query_terms.len() as f32
+    /// Index the card's name, skill names and descriptions for keyword search.
+    fn index_keywords(&mut self, did: &str, card: &CapabilityCard) {
+        let skills = card
+            .skills
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let descriptions = std::iter::once(card.description.as_str())
+            .chain(card.skills.iter().filter_map(|s| s.description.as_deref()))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        self.keywords.upsert(
+            did,
+            &[
+                (Bm25Field::Name, card.name.as_str()),
+                (Bm25Field::Skill, skills.as_str()),
+                (Bm25Field::Description, descriptions.as_str()),
+            ],
+        );
     }
 
     /// Get the number of indexed cards.
     pub fn index_size(&self) -> usize {
-        self.index.len()
+        self.cards.len()
     }
 
     /// Check if a DID is indexed.
     pub fn is_indexed(&self, did: &str) -> bool {
-        self.index.contains_key(did)
+        self.cards.contains_key(did)
     }
 
     /// Clear the entire index.
-    pub fn clear(&mut self) {
-        self.index.clear();
+    pub async fn clear(&mut self) -> Result<()> {
+        self.keywords.clear();
+        for did in std::mem::take(&mut self.cards).into_keys() {
+            self.vectors.delete(&did).await?;
+        }
+        Ok(())
     }
 
     /// Get configuration.
@@ -338,6 +559,71 @@ mod tests {
         assert!(search.is_indexed("did:agoramesh:base:agent1"));
     }
 
+    #[tokio::test]
+    async fn test_index_card_upserts_into_configured_index() {
+        let Some(search) = try_get_search() else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        let index = Arc::new(HnswIndex::new());
+        let mut search = search.with_index(index.clone());
+        let card = sample_card(
+            "did:agoramesh:base:agent1",
+            "Code Reviewer",
+            "AI code review agent",
+            vec!["Review"],
+        );
+
+        search.index_card(&card).await.expect("Should index");
+        search.index_card(&card).await.expect("Should re-index");
+        assert!(index.contains("did:agoramesh:base:agent1"));
+        assert_eq!(index.len(), 1);
+
+        search
+            .remove_card("did:agoramesh:base:agent1")
+            .await
+            .expect("Should remove");
+        assert!(index.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_drops_stale_vectors() {
+        let Some(search) = try_get_search_with_config(HybridSearchConfig {
+            vector_candidates: 1,
+            ..Default::default()
+        }) else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        // Arrange: the index holds a vector for a card this node forgot,
+        // identical to the query so it would take the only slot
+        let index = Arc::new(HnswIndex::new());
+        let stale = search.embedding_service.embed("code review").await.unwrap();
+        index
+            .upsert("did:agoramesh:base:forgotten", &stale)
+            .await
+            .unwrap();
+        let mut search = search.with_index(index.clone());
+        let card = sample_card(
+            "did:agoramesh:base:agent1",
+            "Code Reviewer",
+            "AI code review agent",
+            vec!["Review"],
+        );
+        search.index_card(&card).await.unwrap();
+
+        // Act
+        let results = search
+            .search("code review", &SearchFilter::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!index.contains("did:agoramesh:base:forgotten"));
+        assert_eq!(results[0].did, "did:agoramesh:base:agent1");
+        assert!(results[0].vector_score > 0.0);
+    }
+
     #[tokio::test]
     async fn test_index_card_rejects_card_without_did() {
         let Some(mut search) = try_get_search() else {
@@ -410,7 +696,10 @@ mod tests {
         );
         search.index_card(&card).await.expect("Should index");
 
-        let removed = search.remove_card("did:agoramesh:base:agent1");
+        let removed = search
+            .remove_card("did:agoramesh:base:agent1")
+            .await
+            .unwrap();
 
         assert!(removed, "Should return true when card existed");
         assert_eq!(search.index_size(), 0);
@@ -424,7 +713,10 @@ mod tests {
             return;
         };
 
-        let removed = search.remove_card("did:agoramesh:base:nonexistent");
+        let removed = search
+            .remove_card("did:agoramesh:base:nonexistent")
+            .await
+            .unwrap();
 
         assert!(!removed, "Should return false for nonexistent card");
     }
@@ -438,7 +730,7 @@ mod tests {
             return;
         };
 
-        let results = search.search("code review").await;
+        let results = search.search("code review", &SearchFilter::default()).await;
 
         assert!(results.is_ok());
         assert!(results.unwrap().is_empty());
@@ -460,7 +752,7 @@ mod tests {
         search.index_card(&card).await.expect("Should index");
 
         let results = search
-            .search("code review")
+            .search("code review", &SearchFilter::default())
             .await
             .expect("Search should work");
 
@@ -469,6 +761,187 @@ mod tests {
         assert!(results[0].keyword_score > 0.0, "Should have keyword match");
     }
 
+    #[tokio::test]
+    async fn test_search_keyword_matches_inflected_terms() {
+        let Some(mut search) = try_get_search() else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+
+        let card = sample_card(
+            "did:agoramesh:base:translator",
+            "Translator",
+            "Translates documents between languages",
+            vec!["Translation"],
+        );
+        search.index_card(&card).await.expect("Should index");
+
+        let results = search
+            .search("translating documents", &SearchFilter::default())
+            .await
+            .expect("Search should work");
+
+        assert_eq!(results[0].did, "did:agoramesh:base:translator");
+        assert!(results[0].keyword_score > 0.0, "Stems should match");
+    }
+
+    #[tokio::test]
+    async fn test_search_rrf_fusion_ranks_agent_first_in_both_lists() {
+        // Arrange
+        let config = HybridSearchConfig {
+            fusion: FusionMode::Rrf,
+            min_score: 0.0,
+            ..Default::default()
+        };
+        let Some(mut search) = try_get_search_with_config(config) else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        for card in [
+            sample_card(
+                "did:agoramesh:base:reviewer",
+                "Code Reviewer",
+                "AI-powered code review service",
+                vec!["Code Review"],
+            ),
+            sample_card(
+                "did:agoramesh:base:weather",
+                "Weather Forecaster",
+                "Provides weather forecasts",
+                vec!["Forecast"],
+            ),
+        ] {
+            search.index_card(&card).await.expect("Should index");
+        }
+
+        // Act
+        let results = search
+            .search("code review", &SearchFilter::default())
+            .await
+            .expect("Search should work");
+
+        // Assert: first in both rankings scores the maximum of 1.0
+        assert_eq!(results[0].did, "did:agoramesh:base:reviewer");
+        assert!((results[0].score - 1.0).abs() < 1e-6);
+        assert!(results.iter().all(|r| r.score <= 1.0 + 1e-6));
+    }
+
+    #[tokio::test]
+    async fn test_search_blends_live_trust_and_ignores_declared_trust() {
+        // Arrange: identical cards, one claiming perfect trust, the other
+        // with a track record on this node
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
+        let Some(search) = try_get_search() else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        let mut search = search.with_trust(trust.clone());
+        let boaster = "did:agoramesh:base:boaster";
+        let veteran = "did:agoramesh:base:veteran";
+        for did in [boaster, veteran] {
+            let mut card = sample_card(did, "Code Reviewer", "Reviews code", vec!["Code Review"]);
+            card.agoramesh.as_mut().unwrap().trust_score = Some(1.0);
+            search.index_card(&card).await.expect("Should index");
+        }
+        trust.set_trust_data(veteran, 0, 100, 0, 0);
+        let live = trust.get_trust(veteran).await.unwrap().score;
+
+        // Act
+        let results = search
+            .search("code review", &SearchFilter::default())
+            .await
+            .expect("Search should work");
+
+        // Assert
+        assert_eq!(results[0].did, veteran);
+        assert_eq!(results[0].relevance, results[1].relevance);
+        assert!(results[0].score > results[1].score);
+        let ext = results[0].card.agoramesh.as_ref().unwrap();
+        assert_eq!(ext.trust_score, Some(live));
+        let weight = search.config().trust_weight;
+        let expected = (1.0 - weight) * results[0].relevance + weight * live as f32;
+        assert!((results[0].score - expected).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_rank_applies_trust_filter_before_truncating() {
+        // Arrange: more matching agents than max_results, only the last
+        // one trusted enough
+        let trust = Arc::new(TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            None,
+        ));
+        let Some(search) = try_get_search_with_config(HybridSearchConfig {
+            max_results: 2,
+            ..Default::default()
+        }) else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        let mut search = search.with_trust(trust.clone());
+        for i in 0..4 {
+            let did = format!("did:agoramesh:base:reviewer-{}", i);
+            let card = sample_card(&did, "Code Reviewer", "Reviews code", vec!["Code Review"]);
+            search.index_card(&card).await.expect("Should index");
+        }
+        let trusted = "did:agoramesh:base:reviewer-3";
+        trust.set_trust_data(trusted, crate::trust::REFERENCE_STAKE, 100, 0, 0);
+        let filter = SearchFilter {
+            min_trust: Some(0.5),
+            ..Default::default()
+        };
+
+        // Act
+        let ranked = search.rank("code review", &SearchFilter::default()).await;
+        let trusted_only = search.search("code review", &filter).await;
+
+        // Assert
+        assert_eq!(ranked.unwrap().len(), 4);
+        let trusted_only = trusted_only.unwrap();
+        assert_eq!(trusted_only.len(), 1);
+        assert_eq!(trusted_only[0].did, trusted);
+    }
+
+    #[tokio::test]
+    async fn test_rank_widens_retrieval_past_filtered_neighbours() {
+        // Arrange: a single vector candidate per round, with the only
+        // matching agent behind others the filter rejects
+        let Some(mut search) = try_get_search_with_config(HybridSearchConfig {
+            vector_candidates: 1,
+            ..Default::default()
+        }) else {
+            eprintln!("Skipping: embedding model not available");
+            return;
+        };
+        for i in 0..4 {
+            let did = format!("did:agoramesh:base:reviewer-{}", i);
+            let mut card = sample_card(&did, "Code Reviewer", "Reviews code", vec!["Code Review"]);
+            if i == 2 {
+                card.provider = Some(ProviderInfo {
+                    organization: "Acme Labs".to_string(),
+                    url: None,
+                });
+            }
+            search.index_card(&card).await.expect("Should index");
+        }
+        let wanted = "did:agoramesh:base:reviewer-2";
+        let filter = SearchFilter {
+            provider: Some("acme labs".to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let ranked = search.rank("semantic code inspection", &filter).await;
+
+        // Assert
+        let ranked = ranked.unwrap();
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].did, wanted);
+    }
+
     #[tokio::test]
     async fn test_search_finds_semantically_similar_agent() {
         let Some(mut search) = try_get_search() else {
@@ -487,7 +960,7 @@ mod tests {
 
         // Search with semantically similar but different terms
         let results = search
-            .search("review my code for bugs")
+            .search("review my code for bugs", &SearchFilter::default())
             .await
             .expect("Search should work");
 
@@ -532,7 +1005,7 @@ mod tests {
             .expect("Should index");
 
         let results = search
-            .search("code review")
+            .search("code review", &SearchFilter::default())
             .await
             .expect("Search should work");
 
@@ -567,7 +1040,7 @@ mod tests {
         }
 
         let results = search
-            .search("AI assistant")
+            .search("AI assistant", &SearchFilter::default())
             .await
             .expect("Search should work");
 
@@ -590,7 +1063,7 @@ mod tests {
         search.index_card(&card).await.expect("Should index");
 
         let results = search
-            .search("test agent")
+            .search("test agent", &SearchFilter::default())
             .await
             .expect("Search should work");
 
@@ -625,7 +1098,7 @@ mod tests {
         search.index_card(&card).await.expect("Should index");
         assert_eq!(search.index_size(), 1);
 
-        search.clear();
+        search.clear().await.expect("Should clear");
 
         assert_eq!(search.index_size(), 0);
     }
@@ -0,0 +1,637 @@
+//! Vector index backends for hybrid search.
+//!
+//! [`HybridSearch`](super::HybridSearch) stores card embeddings in a
+//! [`VectorIndex`] and asks it for the nearest neighbours of each query
+//! instead of scanning every card. Two backends are provided:
+//!
+//! - [`HnswIndex`] - in-memory Hierarchical Navigable Small World graph
+//!   (the default, nothing to deploy)
+//! - [`QdrantIndex`](super::QdrantIndex) - an external Qdrant collection
+//!   that survives restarts; nodes sharing it each keep their own points
+//!
+//! Both are updated incrementally as cards are registered and invalidated.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+use super::embedding::Embedding;
+
+/// Vector index backend (`[search.index] backend`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VectorBackend {
+    /// In-memory HNSW graph.
+    #[default]
+    Hnsw,
+    /// External Qdrant collection.
+    Qdrant,
+}
+
+/// Vector index configuration (`[search.index]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VectorIndexConfig {
+    /// Which backend stores card embeddings.
+    pub backend: VectorBackend,
+
+    /// In-memory HNSW parameters (`[search.index.hnsw]`).
+    pub hnsw: HnswConfig,
+
+    /// Qdrant connection (`[search.index.qdrant]`).
+    pub qdrant: QdrantIndexConfig,
+}
+
+/// HNSW graph parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HnswConfig {
+    /// Links kept per node on upper layers (twice as many on the base layer).
+    pub m: usize,
+
+    /// Candidate list size while inserting (higher = better graph, slower upserts).
+    pub ef_construction: usize,
+
+    /// Candidate list size while searching (higher = better recall, slower queries).
+    pub ef_search: usize,
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 100,
+            ef_search: 64,
+        }
+    }
+}
+
+impl HnswConfig {
+    /// Maximum links per node on `level`.
+    fn max_links(&self, level: usize) -> usize {
+        if level == 0 {
+            self.m * 2
+        } else {
+            self.m
+        }
+    }
+}
+
+/// Qdrant connection parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QdrantIndexConfig {
+    /// gRPC endpoint of the Qdrant server.
+    pub url: String,
+
+    /// Collection holding card embeddings (created if missing).
+    pub collection: String,
+
+    /// API key, if the server requires one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+
+    /// Request timeout in seconds.
+    pub timeout_secs: u64,
+}
+
+impl Default for QdrantIndexConfig {
+    fn default() -> Self {
+        Self {
+            url: "http://localhost:6334".to_string(),
+            collection: "agoramesh_agents".to_string(),
+            api_key: None,
+            timeout_secs: 5,
+        }
+    }
+}
+
+/// A vector returned by [`VectorIndex::search`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorMatch {
+    /// Identifier the vector was stored under (the agent DID).
+    pub id: String,
+
+    /// Cosine similarity to the query (-1.0 - 1.0).
+    pub similarity: f32,
+}
+
+/// Nearest-neighbour index over embeddings, keyed by agent DID.
+#[async_trait]
+pub trait VectorIndex: Send + Sync {
+    /// Insert the vector for `id`, replacing any previous one.
+    async fn upsert(&self, id: &str, vector: &Embedding) -> Result<()>;
+
+    /// Remove the vector for `id`. Unknown ids are ignored.
+    async fn delete(&self, id: &str) -> Result<()>;
+
+    /// Up to `limit` vectors most similar to `query`, best first.
+    async fn search(&self, query: &Embedding, limit: usize) -> Result<Vec<VectorMatch>>;
+}
+
+/// In-memory HNSW index.
+///
+/// Vectors are normalized on insert so similarity is a dot product.
+/// Deleted and replaced vectors are tombstoned and the graph is rebuilt
+/// once tombstones exceed a quarter of the live vectors.
+///
+/// Reference: Malkov & Yashunin, "Efficient and robust approximate nearest
+/// neighbor search using Hierarchical Navigable Small World graphs" (2016).
+#[derive(Debug, Default)]
+pub struct HnswIndex {
+    config: HnswConfig,
+    graph: RwLock<HnswGraph>,
+}
+
+impl HnswIndex {
+    /// Create an empty index with default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty index with custom parameters.
+    pub fn with_config(config: HnswConfig) -> Self {
+        Self {
+            config,
+            graph: RwLock::default(),
+        }
+    }
+
+    /// Number of live vectors.
+    pub fn len(&self) -> usize {
+        self.graph.read().map(|g| g.slots.len()).unwrap_or(0)
+    }
+
+    /// Whether the index holds no live vectors.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a vector is stored for `id`.
+    pub fn contains(&self, id: &str) -> bool {
+        self.graph
+            .read()
+            .map(|g| g.slots.contains_key(id))
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait]
+impl VectorIndex for HnswIndex {
+    async fn upsert(&self, id: &str, vector: &Embedding) -> Result<()> {
+        let mut graph = self
+            .graph
+            .write()
+            .map_err(|e| Error::Search(format!("Failed to acquire index write lock: {}", e)))?;
+        graph.check_dimension(vector.len())?;
+        graph.remove(id);
+        graph.insert(id.to_string(), normalized(vector), &self.config);
+        graph.compact(&self.config);
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        let mut graph = self
+            .graph
+            .write()
+            .map_err(|e| Error::Search(format!("Failed to acquire index write lock: {}", e)))?;
+        if graph.remove(id) {
+            graph.compact(&self.config);
+        }
+        Ok(())
+    }
+
+    async fn search(&self, query: &Embedding, limit: usize) -> Result<Vec<VectorMatch>> {
+        let graph = self
+            .graph
+            .read()
+            .map_err(|e| Error::Search(format!("Failed to acquire index read lock: {}", e)))?;
+        graph.check_dimension(query.len())?;
+        Ok(graph.search(&normalized(query), limit, self.config.ef_search))
+    }
+}
+
+/// Node similarity ordered by similarity, then node index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    similarity: f32,
+    node: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.similarity
+            .total_cmp(&other.similarity)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+#[derive(Debug)]
+struct HnswNode {
+    id: String,
+    vector: Vec<f32>,
+    /// Neighbours per layer, from the base layer up to the node's level.
+    links: Vec<Vec<usize>>,
+    removed: bool,
+}
+
+#[derive(Debug)]
+struct HnswGraph {
+    nodes: Vec<HnswNode>,
+    /// Live node per id.
+    slots: HashMap<String, usize>,
+    entry: Option<usize>,
+    top_level: usize,
+    removed: usize,
+    /// Xorshift state for level assignment.
+    seed: u64,
+}
+
+impl Default for HnswGraph {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            slots: HashMap::new(),
+            entry: None,
+            top_level: 0,
+            removed: 0,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+/// Highest layer a node can be assigned to.
+const MAX_LEVEL: usize = 16;
+
+impl HnswGraph {
+    fn check_dimension(&self, len: usize) -> Result<()> {
+        match self.nodes.first() {
+            Some(node) if node.vector.len() != len => Err(Error::Search(format!(
+                "Vector has {} dimensions, index has {}",
+                len,
+                node.vector.len()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn similarity(&self, query: &[f32], node: usize) -> f32 {
+        dot(query, &self.nodes[node].vector)
+    }
+
+    /// Draw a level from the exponential distribution with scale 1/ln(m).
+    fn random_level(&mut self, m: usize) -> usize {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let uniform = ((self.seed >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let level = -uniform.ln() / (m.max(2) as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+
+    /// Best-first search of one layer, returning up to `ef` nodes, best first.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entries: &[usize],
+        ef: usize,
+        level: usize,
+    ) -> Vec<Candidate> {
+        let mut visited: HashSet<usize> = entries.iter().copied().collect();
+        let mut frontier = BinaryHeap::new();
+        let mut found = BinaryHeap::new();
+        for &node in entries {
+            let candidate = Candidate {
+                similarity: self.similarity(query, node),
+                node,
+            };
+            frontier.push(candidate);
+            found.push(Reverse(candidate));
+        }
+        while found.len() > ef {
+            found.pop();
+        }
+
+        while let Some(current) = frontier.pop() {
+            let worst = found.peek().map_or(f32::MIN, |w| w.0.similarity);
+            if found.len() >= ef && current.similarity < worst {
+                break;
+            }
+            for &neighbour in &self.nodes[current.node].links[level] {
+                if !visited.insert(neighbour) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    similarity: self.similarity(query, neighbour),
+                    node: neighbour,
+                };
+                let worst = found.peek().map_or(f32::MIN, |w| w.0.similarity);
+                if found.len() < ef || candidate.similarity > worst {
+                    frontier.push(candidate);
+                    found.push(Reverse(candidate));
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec().into_iter().map(|c| c.0).collect()
+    }
+
+    /// Greedy descent from the entry point to `level`.
+    fn descend(&self, query: &[f32], mut entry: usize, level: usize) -> usize {
+        for layer in (level + 1..=self.top_level).rev() {
+            if let Some(best) = self.search_layer(query, &[entry], 1, layer).first() {
+                entry = best.node;
+            }
+        }
+        entry
+    }
+
+    fn insert(&mut self, id: String, vector: Vec<f32>, config: &HnswConfig) {
+        let level = self.random_level(config.m);
+        let node = self.nodes.len();
+        self.nodes.push(HnswNode {
+            id: id.clone(),
+            vector,
+            links: vec![Vec::new(); level + 1],
+            removed: false,
+        });
+        self.slots.insert(id, node);
+
+        let Some(entry) = self.entry else {
+            self.entry = Some(node);
+            self.top_level = level;
+            return;
+        };
+
+        let query = self.nodes[node].vector.clone();
+        let mut entries = vec![self.descend(&query, entry, level)];
+        for layer in (0..=level.min(self.top_level)).rev() {
+            let found = self.search_layer(&query, &entries, config.ef_construction, layer);
+            let cap = config.max_links(layer);
+            let neighbours: Vec<usize> = found.iter().take(cap).map(|c| c.node).collect();
+            for &neighbour in &neighbours {
+                self.link(neighbour, node, layer, cap);
+            }
+            self.nodes[node].links[layer] = neighbours;
+            entries = found.into_iter().map(|c| c.node).collect();
+        }
+
+        if level > self.top_level {
+            self.top_level = level;
+            self.entry = Some(node);
+        }
+    }
+
+    /// Link `from` to `to` on `level`, keeping only the `cap` closest links.
+    fn link(&mut self, from: usize, to: usize, level: usize, cap: usize) {
+        self.nodes[from].links[level].push(to);
+        if self.nodes[from].links[level].len() <= cap {
+            return;
+        }
+        let base = &self.nodes[from].vector;
+        let mut scored: Vec<Candidate> = self.nodes[from].links[level]
+            .iter()
+            .map(|&node| Candidate {
+                similarity: dot(base, &self.nodes[node].vector),
+                node,
+            })
+            .collect();
+        scored.sort_unstable_by(|a, b| b.cmp(a));
+        scored.truncate(cap);
+        self.nodes[from].links[level] = scored.into_iter().map(|c| c.node).collect();
+    }
+
+    /// Tombstone the live node for `id`. Returns `true` if there was one.
+    fn remove(&mut self, id: &str) -> bool {
+        match self.slots.remove(id) {
+            Some(node) => {
+                self.nodes[node].removed = true;
+                self.removed += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Rebuild without tombstones once they exceed a quarter of the live nodes.
+    fn compact(&mut self, config: &HnswConfig) {
+        if self.removed <= self.slots.len() / 4 {
+            return;
+        }
+        let seed = self.seed;
+        let nodes = std::mem::take(&mut self.nodes);
+        *self = Self {
+            seed,
+            ..Self::default()
+        };
+        for node in nodes.into_iter().filter(|n| !n.removed) {
+            self.insert(node.id, node.vector, config);
+        }
+    }
+
+    fn search(&self, query: &[f32], limit: usize, ef: usize) -> Vec<VectorMatch> {
+        let Some(entry) = self.entry else {
+            return Vec::new();
+        };
+        if limit == 0 {
+            return Vec::new();
+        }
+
+        // Tombstones still route the search, so widen it to make up for them
+        let entry = self.descend(query, entry, 0);
+        let ef = ef.max(limit) + self.removed;
+        self.search_layer(query, &[entry], ef, 0)
+            .into_iter()
+            .filter(|c| !self.nodes[c.node].removed)
+            .take(limit)
+            .map(|c| VectorMatch {
+                id: self.nodes[c.node].id.clone(),
+                similarity: c.similarity,
+            })
+            .collect()
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn normalized(vector: &[f32]) -> Vec<f32> {
+    let norm = dot(vector, vector).sqrt();
+    if norm == 0.0 {
+        vector.to_vec()
+    } else {
+        vector.iter().map(|x| x / norm).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::EmbeddingService;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_vectors(count: usize, dimension: usize, seed: u64) -> Vec<Embedding> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| (0..dimension).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect()
+    }
+
+    fn brute_force(vectors: &[Embedding], query: &Embedding, limit: usize) -> Vec<String> {
+        let mut scored: Vec<(usize, f32)> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i, EmbeddingService::cosine_similarity(query, v)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(i, _)| format!("agent-{}", i))
+            .collect()
+    }
+
+    async fn filled_index(vectors: &[Embedding]) -> HnswIndex {
+        let index = HnswIndex::new();
+        for (i, vector) in vectors.iter().enumerate() {
+            index
+                .upsert(&format!("agent-{}", i), vector)
+                .await
+                .expect("upsert should succeed");
+        }
+        index
+    }
+
+    // ========== TDD Tests: HNSW search ==========
+
+    #[tokio::test]
+    async fn test_hnsw_recall_matches_brute_force() {
+        // Arrange
+        let vectors = random_vectors(1_000, 32, 7);
+        let index = filled_index(&vectors).await;
+        let queries = random_vectors(20, 32, 99);
+
+        // Act
+        let mut hits = 0;
+        for query in &queries {
+            let expected = brute_force(&vectors, query, 10);
+            let found = index.search(query, 10).await.unwrap();
+            hits += found.iter().filter(|m| expected.contains(&m.id)).count();
+        }
+
+        // Assert
+        let recall = hits as f64 / (queries.len() * 10) as f64;
+        assert!(recall >= 0.9, "recall was {}", recall);
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_returns_best_first_with_cosine_similarity() {
+        let index = HnswIndex::new();
+        index.upsert("same", &vec![2.0, 0.0]).await.unwrap();
+        index.upsert("close", &vec![1.0, 0.5]).await.unwrap();
+        index.upsert("opposite", &vec![-1.0, 0.0]).await.unwrap();
+
+        let found = index.search(&vec![1.0, 0.0], 3).await.unwrap();
+
+        let ids: Vec<&str> = found.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["same", "close", "opposite"]);
+        assert!((found[0].similarity - 1.0).abs() < 1e-6);
+        assert!((found[2].similarity + 1.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_empty_index_returns_nothing() {
+        let index = HnswIndex::new();
+
+        assert!(index.search(&vec![1.0, 0.0], 5).await.unwrap().is_empty());
+        assert!(index.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_rejects_dimension_mismatch() {
+        let index = HnswIndex::new();
+        index.upsert("a", &vec![1.0, 0.0]).await.unwrap();
+
+        assert!(index.upsert("b", &vec![1.0, 0.0, 0.0]).await.is_err());
+        assert!(index.search(&vec![1.0], 1).await.is_err());
+    }
+
+    // ========== TDD Tests: HNSW upsert and delete ==========
+
+    #[tokio::test]
+    async fn test_hnsw_upsert_replaces_vector() {
+        // Arrange
+        let index = HnswIndex::new();
+        index.upsert("agent", &vec![1.0, 0.0]).await.unwrap();
+        index.upsert("other", &vec![0.0, 1.0]).await.unwrap();
+
+        // Act
+        index.upsert("agent", &vec![0.0, 1.0]).await.unwrap();
+
+        // Assert
+        assert_eq!(index.len(), 2);
+        let found = index.search(&vec![1.0, 0.0], 2).await.unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|m| m.similarity.abs() < 1e-6));
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_delete_removes_from_results() {
+        // Arrange
+        let vectors = random_vectors(50, 8, 3);
+        let index = filled_index(&vectors).await;
+
+        // Act
+        index.delete("agent-0").await.unwrap();
+        index.delete("unknown").await.unwrap();
+
+        // Assert
+        assert!(!index.contains("agent-0"));
+        assert_eq!(index.len(), 49);
+        let found = index.search(&vectors[0], 5).await.unwrap();
+        assert_eq!(found.len(), 5);
+        assert!(found.iter().all(|m| m.id != "agent-0"));
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_compacts_after_many_deletes() {
+        // Arrange
+        let vectors = random_vectors(200, 16, 11);
+        let index = filled_index(&vectors).await;
+
+        // Act: delete three quarters of the vectors
+        for i in 0..150 {
+            index.delete(&format!("agent-{}", i)).await.unwrap();
+        }
+
+        // Assert: tombstones were dropped and the survivors are still found
+        {
+            let graph = index.graph.read().unwrap();
+            assert!(graph.nodes.len() < 200);
+            assert!(graph.removed <= graph.slots.len() / 4);
+        }
+        assert_eq!(index.len(), 50);
+        for (i, vector) in vectors.iter().enumerate().skip(150) {
+            let found = index.search(vector, 1).await.unwrap();
+            assert_eq!(found[0].id, format!("agent-{}", i));
+        }
+    }
+}
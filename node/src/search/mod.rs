@@ -2,8 +2,10 @@
 //!
 //! Provides vector-based semantic search using:
 //! - FastEmbed for embedding generation (ONNX-based, lightweight)
-//! - Qdrant for vector storage and similarity search
+//! - A pluggable vector index: in-memory HNSW (default) or Qdrant
 //! - Hybrid search combining BM25 keyword matching with vector similarity
+//! - Structured filters, sort order and pagination shared by all searches
+//! - Schema-aware skill matching on `inputSchema`/`outputSchema`
 //!
 //! # Architecture
 //!
@@ -23,7 +25,7 @@
 //!                   │            │            │
 //!                   ▼            ▼            ▼
 //!             ┌──────────┐ ┌──────────┐ ┌──────────┐
-//!             │  Qdrant  │ │  Cache   │ │  BM25    │
+//!             │  Vector  │ │  Cache   │ │  BM25    │
 //!             │  Index   │ │ (memory) │ │  Index   │
 //!             └──────────┘ └──────────┘ └──────────┘
 //!                   │            │            │
@@ -36,11 +38,26 @@
 //!                        └──────────────┘
 //! ```
 
+mod bm25;
 mod embedding;
+mod filter;
 mod hybrid;
+mod index;
+mod qdrant;
+mod schema;
+mod text;
 
+pub use bm25::{Bm25Config, Bm25Field, Bm25Index};
 pub use embedding::{Embedding, EmbeddingService, EmbeddingServiceConfig};
-pub use hybrid::{HybridSearch, HybridSearchConfig, SearchResult};
+pub use filter::{base_price, Page, SearchFilter, SortOrder};
+pub use hybrid::{FusionMode, HybridSearch, HybridSearchConfig, SearchResult};
+pub use index::{
+    HnswConfig, HnswIndex, QdrantIndexConfig, VectorBackend, VectorIndex, VectorIndexConfig,
+    VectorMatch,
+};
+pub use qdrant::QdrantIndex;
+pub use schema::{SchemaQuery, SkillFit};
+pub use text::{stem, tokenize};
 
 /// Default embedding model (all-MiniLM-L6-v2 - 384 dimensions, good balance of speed/quality)
 pub const DEFAULT_MODEL: &str = "all-MiniLM-L6-v2";
@@ -0,0 +1,696 @@
+//! Qdrant-backed vector index.
+//!
+//! Each card embedding is stored as one point in a cosine-distance
+//! collection. Several nodes may share a collection, so every point is
+//! tagged with the namespace of the node that wrote it (its peer ID) and
+//! a node only searches and deletes its own points. Qdrant point ids must
+//! be integers or UUIDs, so the id is derived from
+//! `keccak256(namespace, did)` and the DID itself is kept in the point
+//! payload.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use qdrant_client::qdrant::{
+    vectors_config, Condition, CreateCollectionBuilder, DeletePointsBuilder, Distance, Filter,
+    PointId, PointStruct, PointsIdsList, SearchPointsBuilder, UpsertPointsBuilder,
+    VectorParamsBuilder,
+};
+use qdrant_client::{Payload, Qdrant};
+
+use crate::error::{Error, Result};
+
+use super::embedding::Embedding;
+use super::index::{QdrantIndexConfig, VectorIndex, VectorMatch};
+
+/// Payload field holding the agent DID.
+const DID_FIELD: &str = "did";
+
+/// Payload field holding the namespace of the node that wrote the point.
+const NODE_FIELD: &str = "node";
+
+/// Vector index stored in a Qdrant collection.
+pub struct QdrantIndex {
+    client: Qdrant,
+    collection: String,
+    namespace: String,
+}
+
+impl std::fmt::Debug for QdrantIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QdrantIndex")
+            .field("collection", &self.collection)
+            .field("namespace", &self.namespace)
+            .finish_non_exhaustive()
+    }
+}
+
+impl QdrantIndex {
+    /// Connect to Qdrant and create the collection for `dimension`-sized
+    /// vectors if it does not exist yet.
+    ///
+    /// Points are kept under `namespace` (the node's peer ID), so nodes
+    /// sharing the collection do not see or delete each other's points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Qdrant is unreachable or an existing collection
+    /// holds vectors of another size.
+    pub async fn connect(
+        config: &QdrantIndexConfig,
+        dimension: usize,
+        namespace: &str,
+    ) -> Result<Self> {
+        let client = Qdrant::from_url(&config.url)
+            .api_key(config.api_key.clone())
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .skip_compatibility_check()
+            .build()
+            .map_err(|e| Error::Search(format!("Failed to create Qdrant client: {}", e)))?;
+
+        let exists = client
+            .collection_exists(config.collection.as_str())
+            .await
+            .map_err(|e| Error::Search(format!("Failed to reach Qdrant: {}", e)))?;
+        if exists {
+            let info = client
+                .collection_info(config.collection.as_str())
+                .await
+                .map_err(|e| {
+                    Error::Search(format!(
+                        "Failed to read Qdrant collection '{}': {}",
+                        config.collection, e
+                    ))
+                })?;
+            let size = info
+                .result
+                .and_then(|info| info.config)
+                .and_then(|config| config.params)
+                .and_then(|params| params.vectors_config)
+                .and_then(|vectors| vectors.config);
+            match size {
+                Some(vectors_config::Config::Params(params)) if params.size == dimension as u64 => {
+                }
+                Some(vectors_config::Config::Params(params)) => {
+                    return Err(Error::Search(format!(
+                        "Qdrant collection '{}' holds {}-dimensional vectors, expected {}",
+                        config.collection, params.size, dimension
+                    )))
+                }
+                _ => {
+                    return Err(Error::Search(format!(
+                        "Qdrant collection '{}' must have a single unnamed vector",
+                        config.collection
+                    )))
+                }
+            }
+        } else {
+            client
+                .create_collection(
+                    CreateCollectionBuilder::new(config.collection.as_str()).vectors_config(
+                        VectorParamsBuilder::new(dimension as u64, Distance::Cosine),
+                    ),
+                )
+                .await
+                .map_err(|e| {
+                    Error::Search(format!(
+                        "Failed to create Qdrant collection '{}': {}",
+                        config.collection, e
+                    ))
+                })?;
+        }
+
+        Ok(Self {
+            client,
+            collection: config.collection.clone(),
+            namespace: namespace.to_string(),
+        })
+    }
+
+    /// Name of the collection vectors are stored in.
+    pub fn collection(&self) -> &str {
+        &self.collection
+    }
+
+    /// Namespace this node's points are kept under.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Point id for `did` in `namespace`: the first 16 bytes of the
+    /// keccak256 hash of both as a UUID.
+    pub fn point_id(namespace: &str, did: &str) -> PointId {
+        let hash = alloy::primitives::keccak256(format!("{}\0{}", namespace, did).as_bytes());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&hash[..16]);
+        PointId::from(uuid::Uuid::from_bytes(bytes).to_string())
+    }
+}
+
+#[async_trait]
+impl VectorIndex for QdrantIndex {
+    async fn upsert(&self, id: &str, vector: &Embedding) -> Result<()> {
+        let mut payload = Payload::new();
+        payload.insert(DID_FIELD, id);
+        payload.insert(NODE_FIELD, self.namespace.as_str());
+        let point = PointStruct::new(Self::point_id(&self.namespace, id), vector.clone(), payload);
+
+        self.client
+            .upsert_points(
+                UpsertPointsBuilder::new(self.collection.as_str(), vec![point]).wait(true),
+            )
+            .await
+            .map_err(|e| Error::Search(format!("Failed to upsert into Qdrant: {}", e)))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .delete_points(
+                DeletePointsBuilder::new(self.collection.as_str())
+                    .points(PointsIdsList {
+                        ids: vec![Self::point_id(&self.namespace, id)],
+                    })
+                    .wait(true),
+            )
+            .await
+            .map_err(|e| Error::Search(format!("Failed to delete from Qdrant: {}", e)))?;
+        Ok(())
+    }
+
+    async fn search(&self, query: &Embedding, limit: usize) -> Result<Vec<VectorMatch>> {
+        let response = self
+            .client
+            .search_points(
+                SearchPointsBuilder::new(self.collection.as_str(), query.clone(), limit as u64)
+                    .filter(Filter::must([Condition::matches(
+                        NODE_FIELD,
+                        self.namespace.clone(),
+                    )]))
+                    .with_payload(true),
+            )
+            .await
+            .map_err(|e| Error::Search(format!("Qdrant search failed: {}", e)))?;
+
+        Ok(response
+            .result
+            .into_iter()
+            .filter_map(|point| {
+                let id = point.payload.get(DID_FIELD)?.as_str()?.to_string();
+                Some(VectorMatch {
+                    id,
+                    similarity: point.score,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::qdrant_client::qdrant::collections_server::{Collections, CollectionsServer};
+    use ::qdrant_client::qdrant::point_id::PointIdOptions;
+    use ::qdrant_client::qdrant::points_selector::PointsSelectorOneOf;
+    use ::qdrant_client::qdrant::points_server::{Points, PointsServer};
+    use ::qdrant_client::qdrant::vectors::VectorsOptions;
+    use ::qdrant_client::qdrant::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tonic::transport::server::TcpIncoming;
+    use tonic::transport::Server;
+    use tonic::{Request, Response, Status};
+
+    /// Stored point: DID payload and vector.
+    type StoredPoint = (HashMap<String, Value>, Vec<f32>);
+
+    /// Local stand-in for the parts of the Qdrant gRPC API the index uses.
+    #[derive(Clone, Default)]
+    struct StandIn {
+        /// Collection name -> vector size.
+        collections: Arc<Mutex<HashMap<String, u64>>>,
+        /// (collection, point id) -> point.
+        points: Arc<Mutex<HashMap<(String, String), StoredPoint>>>,
+    }
+
+    fn point_key(id: Option<PointId>) -> Option<String> {
+        match id?.point_id_options? {
+            PointIdOptions::Uuid(uuid) => Some(uuid),
+            PointIdOptions::Num(num) => Some(num.to_string()),
+        }
+    }
+
+    /// Whether `payload` satisfies a keyword match `condition`.
+    fn keyword_matches(condition: &Condition, payload: &HashMap<String, Value>) -> bool {
+        let Some(condition::ConditionOneOf::Field(field)) = &condition.condition_one_of else {
+            return false;
+        };
+        let Some(r#match::MatchValue::Keyword(keyword)) =
+            field.r#match.as_ref().and_then(|m| m.match_value.as_ref())
+        else {
+            return false;
+        };
+        payload.get(&field.key).and_then(|v| v.as_str()) == Some(keyword)
+    }
+
+    /// Implements the listed RPCs and answers every other one with
+    /// `UNIMPLEMENTED`.
+    macro_rules! stand_in_service {
+        (
+            impl $service:ident {
+                $($implemented:item)*
+            }
+            unimplemented { $($name:ident($req:ident) -> $resp:ident;)* }
+        ) => {
+            #[tonic::async_trait]
+            impl $service for StandIn {
+                $($implemented)*
+                $(
+                    async fn $name(
+                        &self,
+                        _request: Request<$req>,
+                    ) -> std::result::Result<Response<$resp>, Status> {
+                        Err(Status::unimplemented(stringify!($name)))
+                    }
+                )*
+            }
+        };
+    }
+
+    stand_in_service! {
+        impl Collections {
+            async fn collection_exists(
+                &self,
+                request: Request<CollectionExistsRequest>,
+            ) -> std::result::Result<Response<CollectionExistsResponse>, Status> {
+                let name = request.into_inner().collection_name;
+                let exists = self.collections.lock().unwrap().contains_key(&name);
+                Ok(Response::new(CollectionExistsResponse {
+                    result: Some(CollectionExists { exists }),
+                    time: 0.0,
+                }))
+            }
+
+            async fn get(
+                &self,
+                request: Request<GetCollectionInfoRequest>,
+            ) -> std::result::Result<Response<GetCollectionInfoResponse>, Status> {
+                let name = request.into_inner().collection_name;
+                let size = self
+                    .collections
+                    .lock()
+                    .unwrap()
+                    .get(&name)
+                    .copied()
+                    .ok_or_else(|| Status::not_found("collection not found"))?;
+                let params = VectorParams {
+                    size,
+                    distance: Distance::Cosine as i32,
+                    ..Default::default()
+                };
+                Ok(Response::new(GetCollectionInfoResponse {
+                    result: Some(CollectionInfo {
+                        config: Some(CollectionConfig {
+                            params: Some(CollectionParams {
+                                vectors_config: Some(VectorsConfig {
+                                    config: Some(vectors_config::Config::Params(params)),
+                                }),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    time: 0.0,
+                }))
+            }
+
+            async fn create(
+                &self,
+                request: Request<CreateCollection>,
+            ) -> std::result::Result<Response<CollectionOperationResponse>, Status> {
+                let request = request.into_inner();
+                let Some(vectors_config::Config::Params(params)) =
+                    request.vectors_config.and_then(|c| c.config)
+                else {
+                    return Err(Status::invalid_argument("expected a single vector config"));
+                };
+                if params.distance != Distance::Cosine as i32 {
+                    return Err(Status::invalid_argument("expected cosine distance"));
+                }
+                self.collections
+                    .lock()
+                    .unwrap()
+                    .insert(request.collection_name, params.size);
+                Ok(Response::new(CollectionOperationResponse {
+                    result: true,
+                    time: 0.0,
+                }))
+            }
+        }
+        unimplemented {
+            list(ListCollectionsRequest) -> ListCollectionsResponse;
+            update(UpdateCollection) -> CollectionOperationResponse;
+            delete(DeleteCollection) -> CollectionOperationResponse;
+            update_aliases(ChangeAliases) -> CollectionOperationResponse;
+            list_collection_aliases(ListCollectionAliasesRequest) -> ListAliasesResponse;
+            list_aliases(ListAliasesRequest) -> ListAliasesResponse;
+            collection_cluster_info(CollectionClusterInfoRequest) -> CollectionClusterInfoResponse;
+            update_collection_cluster_setup(UpdateCollectionClusterSetupRequest) -> UpdateCollectionClusterSetupResponse;
+            create_shard_key(CreateShardKeyRequest) -> CreateShardKeyResponse;
+            delete_shard_key(DeleteShardKeyRequest) -> DeleteShardKeyResponse;
+            list_shard_keys(ListShardKeysRequest) -> ListShardKeysResponse;
+        }
+    }
+
+    fn operation_completed() -> Response<PointsOperationResponse> {
+        Response::new(PointsOperationResponse {
+            result: Some(UpdateResult {
+                operation_id: Some(0),
+                status: UpdateStatus::Completed as i32,
+            }),
+            time: 0.0,
+            usage: None,
+        })
+    }
+
+    stand_in_service! {
+        impl Points {
+            async fn upsert(
+                &self,
+                request: Request<UpsertPoints>,
+            ) -> std::result::Result<Response<PointsOperationResponse>, Status> {
+                let request = request.into_inner();
+                let size = self
+                    .collections
+                    .lock()
+                    .unwrap()
+                    .get(&request.collection_name)
+                    .copied()
+                    .ok_or_else(|| Status::not_found("collection not found"))?;
+                let mut points = self.points.lock().unwrap();
+                for point in request.points {
+                    let Some(VectorsOptions::Vector(vector)) =
+                        point.vectors.and_then(|v| v.vectors_options)
+                    else {
+                        return Err(Status::invalid_argument("expected a single dense vector"));
+                    };
+                    let vector = vector
+                        .try_into_dense()
+                        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                    if vector.len() as u64 != size {
+                        return Err(Status::invalid_argument("wrong vector size"));
+                    }
+                    let key = (request.collection_name.clone(), point_key(point.id)
+                        .ok_or_else(|| Status::invalid_argument("missing point id"))?);
+                    points.insert(key, (point.payload, vector));
+                }
+                Ok(operation_completed())
+            }
+
+            async fn delete(
+                &self,
+                request: Request<DeletePoints>,
+            ) -> std::result::Result<Response<PointsOperationResponse>, Status> {
+                let request = request.into_inner();
+                let Some(PointsSelectorOneOf::Points(list)) =
+                    request.points.and_then(|p| p.points_selector_one_of)
+                else {
+                    return Err(Status::invalid_argument("expected a list of point ids"));
+                };
+                let mut points = self.points.lock().unwrap();
+                for id in list.ids {
+                    points.remove(&(request.collection_name.clone(), point_key(Some(id)).unwrap_or_default()));
+                }
+                Ok(operation_completed())
+            }
+
+            async fn search(
+                &self,
+                request: Request<SearchPoints>,
+            ) -> std::result::Result<Response<SearchResponse>, Status> {
+                let request = request.into_inner();
+                let query = request.vector;
+                let must = request.filter.map(|f| f.must).unwrap_or_default();
+                let points = self.points.lock().unwrap();
+                let mut result: Vec<ScoredPoint> = points
+                    .iter()
+                    .filter(|((collection, _), _)| *collection == request.collection_name)
+                    .filter(|(_, (payload, _))| must.iter().all(|c| keyword_matches(c, payload)))
+                    .map(|((_, id), (payload, vector))| ScoredPoint {
+                        id: Some(PointId::from(id.clone())),
+                        payload: payload.clone(),
+                        score: crate::search::EmbeddingService::cosine_similarity(&query, vector),
+                        ..Default::default()
+                    })
+                    .collect();
+                result.sort_by(|a, b| b.score.total_cmp(&a.score));
+                result.truncate(request.limit as usize);
+                Ok(Response::new(SearchResponse {
+                    result,
+                    time: 0.0,
+                    usage: None,
+                }))
+            }
+        }
+        unimplemented {
+            get(GetPoints) -> GetResponse;
+            update_vectors(UpdatePointVectors) -> PointsOperationResponse;
+            delete_vectors(DeletePointVectors) -> PointsOperationResponse;
+            set_payload(SetPayloadPoints) -> PointsOperationResponse;
+            overwrite_payload(SetPayloadPoints) -> PointsOperationResponse;
+            delete_payload(DeletePayloadPoints) -> PointsOperationResponse;
+            clear_payload(ClearPayloadPoints) -> PointsOperationResponse;
+            create_field_index(CreateFieldIndexCollection) -> PointsOperationResponse;
+            delete_field_index(DeleteFieldIndexCollection) -> PointsOperationResponse;
+            search_batch(SearchBatchPoints) -> SearchBatchResponse;
+            search_groups(SearchPointGroups) -> SearchGroupsResponse;
+            scroll(ScrollPoints) -> ScrollResponse;
+            recommend(RecommendPoints) -> RecommendResponse;
+            recommend_batch(RecommendBatchPoints) -> RecommendBatchResponse;
+            recommend_groups(RecommendPointGroups) -> RecommendGroupsResponse;
+            discover(DiscoverPoints) -> DiscoverResponse;
+            discover_batch(DiscoverBatchPoints) -> DiscoverBatchResponse;
+            count(CountPoints) -> CountResponse;
+            update_batch(UpdateBatchPoints) -> UpdateBatchResponse;
+            query(QueryPoints) -> QueryResponse;
+            query_batch(QueryBatchPoints) -> QueryBatchResponse;
+            query_groups(QueryPointGroups) -> QueryGroupsResponse;
+            facet(FacetCounts) -> FacetResponse;
+            search_matrix_pairs(SearchMatrixPoints) -> SearchMatrixPairsResponse;
+            search_matrix_offsets(SearchMatrixPoints) -> SearchMatrixOffsetsResponse;
+        }
+    }
+
+    /// Namespace the tests' node writes under.
+    const NODE: &str = "12D3KooWNodeA";
+
+    /// Serve the stand-in on a random local port and return its config.
+    async fn start_stand_in(stand_in: StandIn) -> QdrantIndexConfig {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(CollectionsServer::new(stand_in.clone()))
+                .add_service(PointsServer::new(stand_in))
+                .serve_with_incoming(incoming),
+        );
+        QdrantIndexConfig {
+            url: format!("http://{}", addr),
+            collection: "test_agents".to_string(),
+            ..Default::default()
+        }
+    }
+
+    // ========== TDD Tests: Qdrant connection ==========
+
+    #[tokio::test]
+    async fn test_connect_creates_missing_collection() {
+        // Arrange
+        let stand_in = StandIn::default();
+        let config = start_stand_in(stand_in.clone()).await;
+
+        // Act
+        let index = QdrantIndex::connect(&config, 3, NODE).await.unwrap();
+
+        // Assert
+        assert_eq!(index.collection(), "test_agents");
+        assert_eq!(
+            stand_in.collections.lock().unwrap().get("test_agents"),
+            Some(&3)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_connect_reuses_existing_collection() {
+        let stand_in = StandIn::default();
+        stand_in
+            .collections
+            .lock()
+            .unwrap()
+            .insert("test_agents".to_string(), 3);
+        let config = start_stand_in(stand_in.clone()).await;
+
+        QdrantIndex::connect(&config, 3, NODE).await.unwrap();
+
+        assert_eq!(stand_in.collections.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_existing_collection_of_other_size() {
+        let stand_in = StandIn::default();
+        stand_in
+            .collections
+            .lock()
+            .unwrap()
+            .insert("test_agents".to_string(), 768);
+        let config = start_stand_in(stand_in).await;
+
+        let result = QdrantIndex::connect(&config, 384, NODE).await;
+
+        assert!(matches!(result, Err(Error::Search(msg)) if msg.contains("768")));
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_without_server() {
+        let config = QdrantIndexConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_secs: 1,
+            ..Default::default()
+        };
+
+        let result = QdrantIndex::connect(&config, 3, NODE).await;
+
+        assert!(matches!(result, Err(Error::Search(_))));
+    }
+
+    #[test]
+    fn test_point_id_is_stable_uuid() {
+        let id = QdrantIndex::point_id(NODE, "did:agoramesh:base:agent1");
+
+        assert_eq!(id, QdrantIndex::point_id(NODE, "did:agoramesh:base:agent1"));
+        assert_ne!(id, QdrantIndex::point_id(NODE, "did:agoramesh:base:agent2"));
+        assert_ne!(
+            id,
+            QdrantIndex::point_id("other", "did:agoramesh:base:agent1")
+        );
+        let Some(PointIdOptions::Uuid(uuid)) = id.point_id_options else {
+            panic!("expected a UUID point id");
+        };
+        assert!(uuid::Uuid::parse_str(&uuid).is_ok());
+    }
+
+    // ========== TDD Tests: Qdrant upsert, delete and search ==========
+
+    #[tokio::test]
+    async fn test_upsert_and_search_round_trip_dids() {
+        // Arrange
+        let config = start_stand_in(StandIn::default()).await;
+        let index = QdrantIndex::connect(&config, 2, NODE).await.unwrap();
+        index
+            .upsert("did:agoramesh:base:east", &vec![1.0, 0.0])
+            .await
+            .unwrap();
+        index
+            .upsert("did:agoramesh:base:north", &vec![0.0, 1.0])
+            .await
+            .unwrap();
+
+        // Act
+        let found = index.search(&vec![1.0, 0.1], 2).await.unwrap();
+
+        // Assert
+        let ids: Vec<&str> = found.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["did:agoramesh:base:east", "did:agoramesh:base:north"]
+        );
+        assert!(found[0].similarity > 0.99);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_replaces_point_for_same_did() {
+        let stand_in = StandIn::default();
+        let config = start_stand_in(stand_in.clone()).await;
+        let index = QdrantIndex::connect(&config, 2, NODE).await.unwrap();
+
+        index
+            .upsert("did:agoramesh:base:a", &vec![1.0, 0.0])
+            .await
+            .unwrap();
+        index
+            .upsert("did:agoramesh:base:a", &vec![0.0, 1.0])
+            .await
+            .unwrap();
+
+        assert_eq!(stand_in.points.lock().unwrap().len(), 1);
+        let found = index.search(&vec![0.0, 1.0], 1).await.unwrap();
+        assert!(found[0].similarity > 0.99);
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_point() {
+        // Arrange
+        let stand_in = StandIn::default();
+        let config = start_stand_in(stand_in.clone()).await;
+        let index = QdrantIndex::connect(&config, 2, NODE).await.unwrap();
+        index
+            .upsert("did:agoramesh:base:a", &vec![1.0, 0.0])
+            .await
+            .unwrap();
+        index
+            .upsert("did:agoramesh:base:b", &vec![0.0, 1.0])
+            .await
+            .unwrap();
+
+        // Act
+        index.delete("did:agoramesh:base:a").await.unwrap();
+        index.delete("did:agoramesh:base:unknown").await.unwrap();
+
+        // Assert
+        let found = index.search(&vec![1.0, 0.0], 5).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, "did:agoramesh:base:b");
+    }
+
+    #[tokio::test]
+    async fn test_nodes_sharing_a_collection_keep_their_own_points() {
+        // Arrange: two nodes index the same DID in one collection
+        let stand_in = StandIn::default();
+        let config = start_stand_in(stand_in.clone()).await;
+        let node_a = QdrantIndex::connect(&config, 2, NODE).await.unwrap();
+        let node_b = QdrantIndex::connect(&config, 2, "12D3KooWNodeB")
+            .await
+            .unwrap();
+        let did = "did:agoramesh:base:shared";
+        node_a.upsert(did, &vec![1.0, 0.0]).await.unwrap();
+        node_b.upsert(did, &vec![1.0, 0.0]).await.unwrap();
+        node_b
+            .upsert("did:agoramesh:base:b-only", &vec![0.9, 0.1])
+            .await
+            .unwrap();
+
+        // Act: node A evicts the card
+        node_a.delete(did).await.unwrap();
+
+        // Assert
+        assert!(node_a.search(&vec![1.0, 0.0], 5).await.unwrap().is_empty());
+        let found = node_b.search(&vec![1.0, 0.0], 5).await.unwrap();
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].id, did);
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_surface_as_search_errors() {
+        let stand_in = StandIn::default();
+        let config = start_stand_in(stand_in.clone()).await;
+        let index = QdrantIndex::connect(&config, 2, NODE).await.unwrap();
+
+        let result = index
+            .upsert("did:agoramesh:base:a", &vec![1.0, 0.0, 0.0])
+            .await;
+
+        assert!(matches!(result, Err(Error::Search(_))));
+    }
+}
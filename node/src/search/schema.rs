@@ -0,0 +1,482 @@
+//! Schema-aware skill matching.
+//!
+//! Callers describe the data they have and the output they need as JSON
+//! Schemas. A skill fits when data flows both ways:
+//!
+//! - the caller's data satisfies the skill's `inputSchema`, and
+//! - the skill's `outputSchema` satisfies the schema the caller needs.
+//!
+//! Data described by schema `from` satisfies schema `to` when its types
+//! are assignable (`integer` to `number`, anything to an untyped schema)
+//! and `from` declares every property `to` requires, recursively through
+//! object properties and array items. The fit (0.0 - 1.0) is the share
+//! of `to`'s declared properties that `from` supplies, so an agent that
+//! uses more of what the caller has, or returns more of what it needs,
+//! ranks higher.
+//!
+//! Only the `type`, `properties`, `required` and `items` keywords are
+//! considered; a schema without a `type` is compatible with any type.
+//! A skill whose `inputSchema` is an open object (no declared
+//! properties) says nothing about which of the caller's data it uses,
+//! so it is treated like a skill without an input schema.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::discovery::Skill;
+use crate::error::{Error, Result};
+
+/// Nesting depth beyond which schemas are assumed to fit.
+const MAX_DEPTH: usize = 16;
+
+/// Schemas of the data a caller has and the output it needs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchemaQuery {
+    /// JSON Schema of the data the caller can send.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<Value>,
+    /// JSON Schema of the output the caller needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Value>,
+}
+
+/// How well a skill fits a [`SchemaQuery`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillFit {
+    /// ID of the fitting skill.
+    pub skill_id: String,
+    /// Overall fit (0.0 - 1.0): the mean of the fits below.
+    pub score: f32,
+    /// Share of the skill's input properties the caller supplies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_fit: Option<f32>,
+    /// Share of the needed output properties the skill returns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_fit: Option<f32>,
+}
+
+impl SchemaQuery {
+    /// Require at least one schema, each a JSON object or boolean.
+    pub fn validate(&self) -> Result<()> {
+        if self.input.is_none() && self.output.is_none() {
+            return Err(Error::Validation(
+                "At least one of input and output schemas is required".to_string(),
+            ));
+        }
+        for (name, schema) in [("input", &self.input), ("output", &self.output)] {
+            if let Some(schema) = schema {
+                if !schema.is_object() && !schema.is_boolean() {
+                    return Err(Error::Validation(format!(
+                        "{} must be a JSON Schema object",
+                        name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fit of `skill`, or `None` if it is incompatible or lacks a schema
+    /// the query constrains.
+    pub fn fit(&self, skill: &Skill) -> Option<SkillFit> {
+        let input_fit = match &self.input {
+            Some(have) => {
+                let accepts = skill.input_schema.as_ref().filter(|s| describes_data(s))?;
+                Some(flows(have, accepts, 0)?)
+            }
+            None => None,
+        };
+        let output_fit = match &self.output {
+            Some(need) => Some(flows(skill.output_schema.as_ref()?, need, 0)?),
+            None => None,
+        };
+
+        let fits: Vec<f32> = input_fit.into_iter().chain(output_fit).collect();
+        if fits.is_empty() {
+            return None;
+        }
+        Some(SkillFit {
+            skill_id: skill.id.clone(),
+            score: fits.iter().sum::<f32>() / fits.len() as f32,
+            input_fit,
+            output_fit,
+        })
+    }
+
+    /// Best fit among `skills` (the first on ties), if any fits.
+    pub fn best_fit<'a>(&self, skills: impl IntoIterator<Item = &'a Skill>) -> Option<SkillFit> {
+        skills.into_iter().filter_map(|skill| self.fit(skill)).fold(
+            None,
+            |best: Option<SkillFit>, fit| match best {
+                Some(best) if best.score >= fit.score => Some(best),
+                _ => Some(fit),
+            },
+        )
+    }
+}
+
+/// Declared types of `schema`, or `None` if it accepts any type.
+fn types(schema: &Value) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(t) => Some(vec![t.as_str()]),
+        Value::Array(ts) => Some(ts.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+/// Whether values of every type in `from` are valid for `to`.
+fn assignable(from: Option<Vec<&str>>, to: Option<Vec<&str>>) -> bool {
+    let (Some(from), Some(to)) = (from, to) else {
+        return true;
+    };
+    from.iter()
+        .all(|t| to.contains(t) || (*t == "integer" && to.contains(&"number")))
+}
+
+/// Declared properties of `schema`, if any.
+fn properties(schema: &Value) -> Option<&serde_json::Map<String, Value>> {
+    schema.get("properties").and_then(Value::as_object)
+}
+
+/// Whether `schema` constrains the data it accepts: it declares
+/// properties, or a type other than an open object.
+fn describes_data(schema: &Value) -> bool {
+    if properties(schema).is_some_and(|p| !p.is_empty()) {
+        return true;
+    }
+    types(schema).is_some_and(|types| !types.is_empty() && !types.contains(&"object"))
+}
+
+/// Fit of data described by `from` against schema `to`, or `None` if the
+/// data may not satisfy it.
+fn flows(from: &Value, to: &Value, depth: usize) -> Option<f32> {
+    match to {
+        Value::Bool(true) => return Some(1.0),
+        Value::Bool(false) => return None,
+        _ => {}
+    }
+    if depth >= MAX_DEPTH {
+        return Some(1.0);
+    }
+    if !assignable(types(from), types(to)) {
+        return None;
+    }
+
+    let from_properties = properties(from);
+    let to_properties = properties(to);
+    let supplied = |name: &str| from_properties.and_then(|p| p.get(name));
+
+    // Every required property must be supplied and compatible
+    let required = to
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str);
+    for name in required {
+        let expected = to_properties.and_then(|p| p.get(name));
+        flows(
+            supplied(name)?,
+            expected.unwrap_or(&Value::Bool(true)),
+            depth + 1,
+        )?;
+    }
+
+    // Optional properties that would not validate are left out, so they
+    // only lower the fit
+    let mut fit = match to_properties {
+        Some(expected) if !expected.is_empty() => {
+            let matched: f32 = expected
+                .iter()
+                .filter_map(|(name, schema)| flows(supplied(name)?, schema, depth + 1))
+                .sum();
+            matched / expected.len() as f32
+        }
+        _ => 1.0,
+    };
+
+    if let (Some(from_items), Some(to_items)) = (from.get("items"), to.get("items")) {
+        fit *= flows(from_items, to_items, depth + 1)?;
+    }
+    Some(fit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn skill(id: &str, input: Option<Value>, output: Option<Value>) -> Skill {
+        Skill {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            input_schema: input,
+            output_schema: output,
+        }
+    }
+
+    fn translate_skill() -> Skill {
+        skill(
+            "translate",
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string" },
+                    "target_language": { "type": "string" },
+                    "glossary": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["text", "target_language"]
+            })),
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "translation": { "type": "string" },
+                    "confidence": { "type": "number" }
+                },
+                "required": ["translation"]
+            })),
+        )
+    }
+
+    fn query(input: Option<Value>, output: Option<Value>) -> SchemaQuery {
+        SchemaQuery { input, output }
+    }
+
+    // ========== TDD Tests: Input compatibility ==========
+
+    #[test]
+    fn test_input_fits_when_required_properties_are_supplied() {
+        let have = json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "target_language": { "type": "string" }
+            }
+        });
+
+        let fit = query(Some(have), None).fit(&translate_skill()).unwrap();
+
+        assert_eq!(fit.skill_id, "translate");
+        assert!((fit.input_fit.unwrap() - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(fit.output_fit, None);
+        assert_eq!(fit.score, fit.input_fit.unwrap());
+    }
+
+    #[test]
+    fn test_input_rejects_missing_required_property() {
+        let have = json!({
+            "type": "object",
+            "properties": { "text": { "type": "string" } }
+        });
+
+        assert!(query(Some(have), None).fit(&translate_skill()).is_none());
+    }
+
+    #[test]
+    fn test_input_rejects_incompatible_types() {
+        let have = json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "target_language": { "type": "integer" }
+            }
+        });
+
+        assert!(query(Some(have), None).fit(&translate_skill()).is_none());
+    }
+
+    #[test]
+    fn test_supplying_optional_properties_raises_fit() {
+        let minimal = json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "target_language": { "type": "string" }
+            }
+        });
+        let mut full = minimal.clone();
+        full["properties"]["glossary"] = json!({ "type": "array", "items": { "type": "string" } });
+        let mut wrong_glossary = minimal.clone();
+        wrong_glossary["properties"]["glossary"] =
+            json!({ "type": "array", "items": { "type": "object" } });
+
+        let skill = translate_skill();
+        let minimal = query(Some(minimal), None).fit(&skill).unwrap();
+        let full = query(Some(full), None).fit(&skill).unwrap();
+        let wrong = query(Some(wrong_glossary), None).fit(&skill).unwrap();
+
+        assert_eq!(full.score, 1.0);
+        assert!(minimal.score < full.score);
+        assert_eq!(wrong.score, minimal.score);
+    }
+
+    // ========== TDD Tests: Output compatibility ==========
+
+    #[test]
+    fn test_output_fits_when_needed_properties_are_returned() {
+        let need = json!({
+            "type": "object",
+            "properties": {
+                "translation": { "type": "string" },
+                "confidence": { "type": "number" }
+            },
+            "required": ["translation"]
+        });
+
+        let fit = query(None, Some(need)).fit(&translate_skill()).unwrap();
+
+        assert_eq!(fit.output_fit, Some(1.0));
+    }
+
+    #[test]
+    fn test_output_rejects_missing_or_narrower_types() {
+        let needs_summary = json!({
+            "type": "object",
+            "properties": { "summary": { "type": "string" } },
+            "required": ["summary"]
+        });
+        let needs_integer = json!({
+            "type": "object",
+            "properties": { "confidence": { "type": "integer" } },
+            "required": ["confidence"]
+        });
+
+        assert!(query(None, Some(needs_summary))
+            .fit(&translate_skill())
+            .is_none());
+        assert!(query(None, Some(needs_integer))
+            .fit(&translate_skill())
+            .is_none());
+    }
+
+    #[test]
+    fn test_integer_is_assignable_to_number() {
+        let produces = skill(
+            "count",
+            None,
+            Some(json!({ "type": "object", "properties": { "n": { "type": "integer" } } })),
+        );
+        let need = json!({
+            "type": "object",
+            "properties": { "n": { "type": ["number", "null"] } },
+            "required": ["n"]
+        });
+
+        assert!(query(None, Some(need)).fit(&produces).is_some());
+    }
+
+    #[test]
+    fn test_nested_objects_are_checked_recursively() {
+        let produces = skill(
+            "geocode",
+            None,
+            Some(json!({
+                "type": "object",
+                "properties": {
+                    "location": {
+                        "type": "object",
+                        "properties": { "lat": { "type": "number" } }
+                    }
+                }
+            })),
+        );
+        let need = json!({
+            "type": "object",
+            "properties": {
+                "location": {
+                    "type": "object",
+                    "properties": { "lat": { "type": "number" }, "lon": { "type": "number" } },
+                    "required": ["lat", "lon"]
+                }
+            },
+            "required": ["location"]
+        });
+
+        assert!(query(None, Some(need)).fit(&produces).is_none());
+    }
+
+    // ========== TDD Tests: Skill selection ==========
+
+    #[test]
+    fn test_skill_without_schema_does_not_fit_constrained_side() {
+        let untyped = skill("chat", None, None);
+
+        assert!(query(Some(json!({ "type": "object" })), None)
+            .fit(&untyped)
+            .is_none());
+        assert!(query(None, None).fit(&translate_skill()).is_none());
+    }
+
+    #[test]
+    fn test_open_object_input_schema_does_not_fit() {
+        let have = json!({ "type": "object", "properties": { "a": { "type": "string" } } });
+        let open = skill("open", Some(json!({ "type": "object" })), None);
+        let untyped = skill("untyped", Some(json!({})), None);
+        let scalar = skill("scalar", Some(json!({ "type": "string" })), None);
+
+        let object_query = query(Some(have), None);
+
+        assert!(object_query.fit(&open).is_none());
+        assert!(object_query.fit(&untyped).is_none());
+        assert_eq!(
+            query(Some(json!({ "type": "string" })), None)
+                .fit(&scalar)
+                .map(|fit| fit.score),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn test_both_sides_average_into_score() {
+        let have = json!({
+            "type": "object",
+            "properties": {
+                "text": { "type": "string" },
+                "target_language": { "type": "string" }
+            }
+        });
+        let need = json!({
+            "type": "object",
+            "properties": { "translation": { "type": "string" } },
+            "required": ["translation"]
+        });
+
+        let fit = query(Some(have), Some(need))
+            .fit(&translate_skill())
+            .unwrap();
+
+        assert!((fit.score - (2.0 / 3.0 + 1.0) / 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_best_fit_picks_highest_scoring_skill() {
+        let loose = skill("loose", Some(json!({ "type": "object" })), None);
+        let need_all = skill(
+            "strict",
+            Some(json!({
+                "type": "object",
+                "properties": { "a": {}, "b": {} },
+                "required": ["a"]
+            })),
+            None,
+        );
+        let have = json!({ "type": "object", "properties": { "a": { "type": "string" } } });
+
+        let best = query(Some(have), None)
+            .best_fit([&need_all, &loose])
+            .unwrap();
+
+        assert_eq!(best.skill_id, "strict");
+        assert_eq!(best.score, 0.5);
+    }
+
+    #[test]
+    fn test_validate_requires_a_schema_object() {
+        assert!(query(None, None).validate().is_err());
+        assert!(query(Some(json!("object")), None).validate().is_err());
+        assert!(query(Some(json!({ "type": "object" })), None)
+            .validate()
+            .is_ok());
+    }
+}
@@ -0,0 +1,297 @@
+//! Text analysis for keyword search.
+//!
+//! Text is split on anything that is not a letter or digit, lowercased,
+//! stripped of common English stopwords and reduced to its stem with the
+//! Porter algorithm, so "reviews", "reviewing" and "reviewed" all match
+//! "review".
+//!
+//! Reference: M.F. Porter, "An algorithm for suffix stripping" (1980).
+
+/// Words too common to say anything about an agent.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "can", "for", "from", "i", "in", "into", "is",
+    "it", "me", "my", "of", "on", "or", "our", "that", "the", "this", "to", "we", "with", "you",
+    "your",
+];
+
+/// Split `text` into stemmed, lowercase terms, dropping stopwords.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .map(|word| stem(&word))
+        .collect()
+}
+
+/// Porter stem of a lowercase word.
+///
+/// Words that are not plain ASCII letters (numbers, other scripts) and
+/// words of up to two letters are returned unchanged.
+pub fn stem(word: &str) -> String {
+    if word.len() <= 2 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+
+    let mut w = word.as_bytes().to_vec();
+    step1a(&mut w);
+    step1b(&mut w);
+    step1c(&mut w);
+    step2(&mut w);
+    step3(&mut w);
+    step4(&mut w);
+    step5(&mut w);
+    String::from_utf8(w).unwrap_or_else(|_| word.to_string())
+}
+
+fn is_consonant(w: &[u8], i: usize) -> bool {
+    match w[i] {
+        b'a' | b'e' | b'i' | b'o' | b'u' => false,
+        b'y' => i == 0 || !is_consonant(w, i - 1),
+        _ => true,
+    }
+}
+
+/// Number of vowel-consonant sequences in `w` ("m" in the paper).
+fn measure(w: &[u8]) -> usize {
+    let n = w.len();
+    let mut i = 0;
+    while i < n && is_consonant(w, i) {
+        i += 1;
+    }
+    let mut m = 0;
+    loop {
+        while i < n && !is_consonant(w, i) {
+            i += 1;
+        }
+        if i == n {
+            return m;
+        }
+        while i < n && is_consonant(w, i) {
+            i += 1;
+        }
+        m += 1;
+    }
+}
+
+fn has_vowel(w: &[u8]) -> bool {
+    (0..w.len()).any(|i| !is_consonant(w, i))
+}
+
+fn ends_double_consonant(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 2 && w[n - 1] == w[n - 2] && is_consonant(w, n - 1)
+}
+
+/// Whether `w` ends consonant-vowel-consonant, the last not w, x or y.
+fn ends_cvc(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 3
+        && is_consonant(w, n - 3)
+        && !is_consonant(w, n - 2)
+        && is_consonant(w, n - 1)
+        && !matches!(w[n - 1], b'w' | b'x' | b'y')
+}
+
+/// Stem left after removing `suffix`, if `w` ends with it.
+fn stem_of<'a>(w: &'a [u8], suffix: &str) -> Option<&'a [u8]> {
+    w.strip_suffix(suffix.as_bytes())
+}
+
+fn replace_suffix(w: &mut Vec<u8>, suffix: &str, replacement: &str) {
+    w.truncate(w.len() - suffix.len());
+    w.extend_from_slice(replacement.as_bytes());
+}
+
+/// Replace the first matching suffix from `rules` if its stem has a
+/// measure above `min_measure`. Only the first match is considered.
+fn apply_rules(w: &mut Vec<u8>, rules: &[(&str, &str)], min_measure: usize) {
+    for &(suffix, replacement) in rules {
+        if let Some(stem) = stem_of(w, suffix) {
+            if measure(stem) > min_measure {
+                replace_suffix(w, suffix, replacement);
+            }
+            return;
+        }
+    }
+}
+
+fn step1a(w: &mut Vec<u8>) {
+    if w.ends_with(b"sses") || w.ends_with(b"ies") {
+        w.truncate(w.len() - 2);
+    } else if w.ends_with(b"s") && !w.ends_with(b"ss") {
+        w.pop();
+    }
+}
+
+fn step1b(w: &mut Vec<u8>) {
+    if let Some(stem) = stem_of(w, "eed") {
+        if measure(stem) > 0 {
+            w.pop();
+        }
+        return;
+    }
+
+    let suffix = if stem_of(w, "ed").is_some_and(has_vowel) {
+        "ed"
+    } else if stem_of(w, "ing").is_some_and(has_vowel) {
+        "ing"
+    } else {
+        return;
+    };
+    replace_suffix(w, suffix, "");
+
+    if w.ends_with(b"at") || w.ends_with(b"bl") || w.ends_with(b"iz") {
+        w.push(b'e');
+    } else if ends_double_consonant(w) && !matches!(w[w.len() - 1], b'l' | b's' | b'z') {
+        w.pop();
+    } else if measure(w) == 1 && ends_cvc(w) {
+        w.push(b'e');
+    }
+}
+
+fn step1c(w: &mut [u8]) {
+    if let Some((last, stem)) = w.split_last_mut() {
+        if *last == b'y' && has_vowel(stem) {
+            *last = b'i';
+        }
+    }
+}
+
+fn step2(w: &mut Vec<u8>) {
+    apply_rules(
+        w,
+        &[
+            ("ational", "ate"),
+            ("tional", "tion"),
+            ("enci", "ence"),
+            ("anci", "ance"),
+            ("izer", "ize"),
+            ("abli", "able"),
+            ("alli", "al"),
+            ("entli", "ent"),
+            ("eli", "e"),
+            ("ousli", "ous"),
+            ("ization", "ize"),
+            ("ation", "ate"),
+            ("ator", "ate"),
+            ("alism", "al"),
+            ("iveness", "ive"),
+            ("fulness", "ful"),
+            ("ousness", "ous"),
+            ("aliti", "al"),
+            ("iviti", "ive"),
+            ("biliti", "ble"),
+        ],
+        0,
+    );
+}
+
+fn step3(w: &mut Vec<u8>) {
+    apply_rules(
+        w,
+        &[
+            ("icate", "ic"),
+            ("ative", ""),
+            ("alize", "al"),
+            ("iciti", "ic"),
+            ("ical", "ic"),
+            ("ful", ""),
+            ("ness", ""),
+        ],
+        0,
+    );
+}
+
+fn step4(w: &mut Vec<u8>) {
+    const SUFFIXES: &[&str] = &[
+        "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion",
+        "ou", "ism", "ate", "iti", "ous", "ive", "ize",
+    ];
+    for &suffix in SUFFIXES {
+        if let Some(stem) = stem_of(w, suffix) {
+            let allowed = suffix != "ion" || stem.ends_with(b"s") || stem.ends_with(b"t");
+            if allowed && measure(stem) > 1 {
+                replace_suffix(w, suffix, "");
+            }
+            return;
+        }
+    }
+}
+
+fn step5(w: &mut Vec<u8>) {
+    if let Some(stem) = stem_of(w, "e") {
+        let m = measure(stem);
+        if m > 1 || (m == 1 && !ends_cvc(stem)) {
+            w.pop();
+        }
+    }
+    if w.ends_with(b"ll") && measure(w) > 1 {
+        w.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========== TDD Tests: Stemming ==========
+
+    #[test]
+    fn test_stem_matches_porter_examples() {
+        for (word, expected) in [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("cats", "cat"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("motoring", "motor"),
+            ("hopping", "hop"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("relational", "relat"),
+            ("generalization", "gener"),
+            ("hopeful", "hope"),
+            ("goodness", "good"),
+            ("adjustment", "adjust"),
+            ("adoption", "adopt"),
+            ("controll", "control"),
+            ("rate", "rate"),
+        ] {
+            assert_eq!(stem(word), expected, "stem of {}", word);
+        }
+    }
+
+    #[test]
+    fn test_stem_conflates_inflections() {
+        let review = stem("review");
+
+        assert_eq!(stem("reviews"), review);
+        assert_eq!(stem("reviewing"), review);
+        assert_eq!(stem("reviewed"), review);
+        assert_eq!(stem("translation"), stem("translate"));
+    }
+
+    #[test]
+    fn test_stem_leaves_short_and_non_ascii_words() {
+        assert_eq!(stem("ai"), "ai");
+        assert_eq!(stem("x402"), "x402");
+        assert_eq!(stem("übersetzung"), "übersetzung");
+    }
+
+    // ========== TDD Tests: Tokenization ==========
+
+    #[test]
+    fn test_tokenize_splits_lowercases_and_drops_stopwords() {
+        let terms = tokenize("Review my Code-Quality for the bugs!");
+
+        assert_eq!(terms, vec!["review", "code", "qualiti", "bug"]);
+    }
+
+    #[test]
+    fn test_tokenize_empty_and_stopword_only_text() {
+        assert!(tokenize("").is_empty());
+        assert!(tokenize("  -- ").is_empty());
+        assert!(tokenize("for the").is_empty());
+    }
+}
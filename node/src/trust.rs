@@ -6,12 +6,32 @@
 //! - Web-of-trust endorsements
 //! - On-chain reputation queries
 
+use alloy::primitives::FixedBytes;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::RwLock;
-
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::circuit_breaker::{
+    CircuitBreakerConfig, CircuitError, ResilientCircuitBreaker, DEFAULT_MAX_KEYS,
+};
+use crate::config::TrustConfig;
 use crate::contract::TrustRegistryClient;
+use crate::eigentrust::{EigenTrustConfig, GlobalTrust, LocalTrustGraph};
 use crate::error::{Error, Result};
+use crate::multichain::{AggregatedTrust, MultiChainClient};
+use crate::persistence::{
+    EndorsementRecord, TrustData as StoredTrustData, TrustDataStore, TrustHistoryStore,
+};
+use crate::reputation::{FailureReason, ReputationMode, TransactionStats, WeightedOutcomes};
+use crate::trust_cache::{CachedTrustInfo, TrustCache, TrustCacheConfig};
+use crate::trust_graph::EndorsementGraph;
+use crate::trust_history::{downsample, TrustEvent, TrustEventKind, TrustHistory, TrustSnapshot};
+use crate::trust_reconcile::{
+    onchain_trust_info, reconcile, OnChainObservation, ReconcileConfig, ReconcilePolicy,
+    ReconciledTrust, TrustProvenance,
+};
 
 /// Trust information for an agent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +62,11 @@ pub struct TrustInfo {
 
     /// Number of endorsements received.
     pub endorsement_count: u64,
+
+    /// Skill the reputation component and transaction counts are scoped to
+    /// (`None` means across all skills).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
 }
 
 /// Trust score calculation weights.
@@ -55,6 +80,14 @@ pub struct TrustWeights {
 
     /// Weight for endorsements component.
     pub endorsements: f64,
+
+    /// How the endorsements component is computed.
+    #[serde(default)]
+    pub endorsement_mode: EndorsementMode,
+
+    /// How the reputation component is computed.
+    #[serde(default)]
+    pub reputation_mode: ReputationMode,
 }
 
 impl Default for TrustWeights {
@@ -63,10 +96,25 @@ impl Default for TrustWeights {
             reputation: 0.5,
             stake: 0.3,
             endorsements: 0.2,
+            endorsement_mode: EndorsementMode::default(),
+            reputation_mode: ReputationMode::default(),
         }
     }
 }
 
+/// Scoring mode for the endorsements (web-of-trust) component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EndorsementMode {
+    /// Sum of endorser reputations decayed by hop distance from the trust
+    /// roots (trust-layer.md).
+    #[default]
+    HopDecay,
+    /// EigenTrust propagation from the trust roots over the endorsement and
+    /// transaction graph; resists rings of agents endorsing each other.
+    EigenTrust,
+}
+
 /// Reference stake amount: $10,000 USDC (6 decimals).
 pub const REFERENCE_STAKE: u64 = 10_000_000_000;
 
@@ -78,6 +126,23 @@ fn current_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Days elapsed since `timestamp`, or `None` if it is unset or in the future.
+fn days_since(timestamp: u64) -> Option<f64> {
+    let now = current_timestamp();
+    if timestamp == 0 || timestamp >= now {
+        return None;
+    }
+    Some((now - timestamp) as f64 / (24.0 * 60.0 * 60.0))
+}
+
+/// Circuit breaker for TrustRegistry reads, keeping a bounded number of
+/// last known good values that expire after `max_stale_secs`.
+fn onchain_breaker(config: &ReconcileConfig) -> ResilientCircuitBreaker<CachedTrustInfo> {
+    let ttl = (config.max_stale_secs > 0).then(|| Duration::from_secs(config.max_stale_secs));
+    ResilientCircuitBreaker::with_last_known_good(CircuitBreakerConfig::default())
+        .with_key_limits(DEFAULT_MAX_KEYS, ttl)
+}
+
 /// Trust verification service.
 ///
 /// Provides trust score calculation and verification for agents.
@@ -99,6 +164,53 @@ pub struct TrustService {
 
     /// Local cache of trust data (for testing and offline mode).
     cache: RwLock<HashMap<String, TrustData>>,
+
+    /// Reverse index from `did_to_hash(did)` to every DID ever cached, so
+    /// contract events can be resolved without rehashing the cache.
+    did_hashes: RwLock<HashMap<FixedBytes<32>, String>>,
+
+    /// Optional durable store that every cache mutation is written through to.
+    store: Option<TrustDataStore>,
+
+    /// Optional store for score snapshots and trust events.
+    history: Option<TrustHistoryStore>,
+
+    /// Endorsement graph used to derive hop distances from trust roots.
+    ///
+    /// Lock order: `cache`, then `graph`, then `interactions`, then `propagated`.
+    graph: RwLock<EndorsementGraph>,
+
+    /// Transaction outcomes per client, keyed by provider (in memory only).
+    interactions: RwLock<HashMap<String, HashMap<String, InteractionCounts>>>,
+
+    /// EigenTrust result, recomputed lazily after the graph changes.
+    propagated: RwLock<Option<Arc<GlobalTrust>>>,
+
+    /// Earliest endorsement expiry (`u64::MAX` if none expire).
+    next_expiry: AtomicU64,
+
+    /// Minimum trust and stake an agent must meet (from `[trust]`).
+    requirements: TrustConfig,
+
+    /// How on-chain and local components are merged.
+    reconcile: ReconcileConfig,
+
+    /// On-chain component scores, cached for `reconcile.cache_ttl_secs`.
+    onchain_cache: TrustCache,
+
+    /// Breaker around TrustRegistry reads, remembering the last read per DID.
+    onchain_breaker: ResilientCircuitBreaker<CachedTrustInfo>,
+
+    /// TrustRegistry clients for every chain in `[multichain]`.
+    multichain: Option<Arc<MultiChainClient>>,
+}
+
+/// Why an on-chain read did not produce a fresh value.
+enum OnChainReadError {
+    /// The registry is unreachable; the last read value is available.
+    Stale(CachedTrustInfo, String),
+    /// The registry is unreachable and was never read for the agent.
+    Unavailable(String),
 }
 
 /// Decay rate per period (5% = 0.05)
@@ -119,26 +231,233 @@ pub const MAX_ENDORSEMENTS_COUNTED: usize = 10;
 /// Normalization factor for endorsement score (divide total by this)
 pub const ENDORSEMENT_NORMALIZATION: f64 = 3.0;
 
-/// Endorsement with hop distance information.
+/// Local trust an endorsement carries in EigenTrust mode.
+pub const ENDORSEMENT_LOCAL_TRUST: f64 = 1.0;
+
+/// Local trust each net successful transaction carries in EigenTrust mode
+/// (ten successful transactions weigh as much as one endorsement).
+pub const TRANSACTION_LOCAL_TRUST: f64 = 0.1;
+
+/// Outcomes of transactions between a client and a provider.
+#[derive(Debug, Clone, Copy, Default)]
+struct InteractionCounts {
+    successful: u64,
+    failed: u64,
+}
+
+/// Endorsement received by an agent.
+///
+/// Hop distances are not stored here; they are derived from the
+/// endorsement graph so endorsers cannot claim to be closer to a root.
 #[derive(Debug, Clone)]
 struct EndorsementData {
     /// DID of the endorser
     endorser_did: String,
-    /// Hop distance from trust root (1 = direct, 2 = one intermediary, etc.)
-    hop_distance: u32,
+    /// Unix timestamp the endorsement expires at, if any
+    expires_at: Option<u64>,
+}
+
+/// Endorsement chain from the nearest trust root to an agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndorsementPath {
+    /// Agent the path leads to.
+    pub did: String,
+    /// Trust root the chain starts from.
+    pub root: String,
+    /// Number of endorsements between the root and the agent.
+    pub hops: u32,
+    /// DIDs along the chain, root first and agent last.
+    pub chain: Vec<String>,
+}
+
+/// Why an agent's trust score is what it is.
+///
+/// The score and every component score are the values
+/// [`TrustService::get_reconciled_trust`] returns for the same DID and
+/// skill. The breakdowns list the local inputs; a component whose
+/// provenance is on-chain took its score from the TrustRegistry instead,
+/// and its inputs show the local view for comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustExplanation {
+    /// Agent's DID.
+    pub did: String,
+    /// Overall trust score: the weighted sum of the three components.
+    pub score: f64,
+    /// Skill the reputation component is scoped to (`None` means across
+    /// all skills).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+    /// Where each component score came from.
+    pub provenance: TrustProvenance,
+    /// Weights applied to each component.
+    pub weights: TrustWeights,
+    /// Reputation component inputs.
+    pub reputation: ReputationBreakdown,
+    /// Stake component inputs.
+    pub stake: StakeBreakdown,
+    /// Endorsement component inputs.
+    pub endorsements: EndorsementBreakdown,
+}
+
+/// Inputs of the reputation component.
+///
+/// `score = success_rate * (0.5 + 0.5 * volume_factor) * decay_factor`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReputationBreakdown {
+    /// Resulting component score (0.0 - 1.0).
+    pub score: f64,
+    /// Scoring mode used.
+    pub mode: ReputationMode,
+    /// Number of successful transactions.
+    pub successful_transactions: u64,
+    /// Number of failed transactions.
+    pub failed_transactions: u64,
+    /// Total successful volume in USDC (6 decimals).
+    pub total_volume: u64,
+    /// Decayed, penalty-weighted volumes the success rate was computed
+    /// from (`None` if it was computed from counts).
+    pub weighted: Option<WeightedOutcomes>,
+    /// Successful / total transactions, or successful / total weighted
+    /// volume.
+    pub success_rate: f64,
+    /// Total transactions / 100, capped at 1.0.
+    pub volume_factor: f64,
+    /// Unix timestamp of last activity (0 if never active).
+    pub last_activity_timestamp: u64,
+    /// Days since last activity (`None` if never active).
+    pub days_since_activity: Option<f64>,
+    /// Inactivity decay: `1 - days * DECAY_RATE / 14`, clamped to 0.0 - 1.0.
+    pub decay_factor: f64,
+}
+
+/// Inputs of the stake component.
+///
+/// `score = sqrt(stake_amount / reference_stake)`, capped at 1.0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakeBreakdown {
+    /// Resulting component score (0.0 - 1.0).
+    pub score: f64,
+    /// Staked amount in USDC (6 decimals).
+    pub stake_amount: u64,
+    /// Stake at which the component reaches 1.0.
+    pub reference_stake: u64,
+}
+
+/// Inputs of the endorsement component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndorsementBreakdown {
+    /// Resulting component score (0.0 - 1.0).
+    pub score: f64,
+    /// Scoring mode used.
+    pub mode: EndorsementMode,
+    /// Endorsements beyond this hop distance are dropped.
+    pub max_hops: u32,
+    /// Only this many endorsements (closest first) are counted.
+    pub max_counted: usize,
+    /// Sum of counted contributions is divided by this (then capped at 1.0).
+    pub normalization: f64,
+    /// Every endorsement received. Empty in EigenTrust mode, where the
+    /// score is the agent's propagated global trust instead.
+    pub contributions: Vec<EndorsementContribution>,
+}
+
+/// One endorsement's part in the endorsement component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndorsementContribution {
+    /// DID of the endorser.
+    pub endorser_did: String,
+    /// Endorser's distance from the nearest trust root (`None` if unreachable).
+    pub hop_distance: Option<u32>,
+    /// Endorser's own reputation component.
+    pub endorser_reputation: f64,
+    /// `ENDORSEMENT_DECAY_PER_HOP ^ hop_distance` (0.0 if unreachable).
+    pub hop_decay: f64,
+    /// `endorser_reputation * hop_decay` if counted, otherwise 0.0.
+    pub contribution: f64,
+    /// Whether the endorsement was counted.
+    pub status: EndorsementStatus,
+    /// Unix timestamp the endorsement expires at, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+/// Whether an endorsement counted towards the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndorsementStatus {
+    /// Included in the score.
+    Counted,
+    /// Dropped: the endorser has no path from a trust root within
+    /// `MAX_ENDORSEMENT_HOPS`.
+    BeyondMaxHops,
+    /// Dropped: `MAX_ENDORSEMENTS_COUNTED` closer endorsements were counted.
+    OverCountLimit,
 }
 
 /// Internal trust data storage.
 #[derive(Debug, Clone, Default)]
 struct TrustData {
     stake_amount: u64,
-    successful_transactions: u64,
-    failed_transactions: u64,
     endorsement_count: u64,
-    /// Unix timestamp of last activity (for decay calculation)
-    last_activity_timestamp: u64,
-    /// List of endorsements with hop distances
+    /// Transactions across all skills
+    transactions: TransactionStats,
+    /// Transactions per skill ID (contextual reputation)
+    skills: HashMap<String, TransactionStats>,
+    /// Endorsements received, one per endorser
     endorsements: Vec<EndorsementData>,
+    /// On-chain stake events already applied, as `{tx_hash}:{log_index}`
+    stake_events: BTreeSet<String>,
+}
+
+impl From<StoredTrustData> for TrustData {
+    fn from(stored: StoredTrustData) -> Self {
+        Self {
+            stake_amount: stored.stake_amount,
+            endorsement_count: stored.endorsement_count,
+            transactions: TransactionStats {
+                successful_transactions: stored.successful_transactions,
+                failed_transactions: stored.failed_transactions,
+                total_volume: stored.total_volume,
+                last_activity: stored.last_activity,
+                weighted: stored.weighted,
+            },
+            skills: stored.skills,
+            endorsements: stored
+                .endorsements
+                .into_iter()
+                .map(|e| EndorsementData {
+                    endorser_did: e.endorser_did,
+                    expires_at: e.expires_at,
+                })
+                .collect(),
+            stake_events: stored.stake_events,
+        }
+    }
+}
+
+impl From<&TrustData> for StoredTrustData {
+    fn from(data: &TrustData) -> Self {
+        Self {
+            stake_amount: data.stake_amount,
+            successful_transactions: data.transactions.successful_transactions,
+            failed_transactions: data.transactions.failed_transactions,
+            endorsement_count: data.endorsement_count,
+            total_volume: data.transactions.total_volume,
+            last_activity: data.transactions.last_activity,
+            endorsements: data
+                .endorsements
+                .iter()
+                .map(|e| EndorsementRecord {
+                    endorser_did: e.endorser_did.clone(),
+                    hop_distance: 0,
+                    expires_at: e.expires_at,
+                })
+                .collect(),
+            weighted: data.transactions.weighted,
+            skills: data.skills.clone(),
+            stake_events: data.stake_events.clone(),
+        }
+    }
 }
 
 impl TrustService {
@@ -169,13 +488,236 @@ impl TrustService {
             registry_address,
             contract_client,
             cache: RwLock::new(HashMap::new()),
+            did_hashes: RwLock::new(HashMap::new()),
+            store: None,
+            history: None,
+            requirements: TrustConfig::default(),
+            graph: RwLock::new(EndorsementGraph::new(MAX_ENDORSEMENT_HOPS)),
+            interactions: RwLock::new(HashMap::new()),
+            propagated: RwLock::new(None),
+            next_expiry: AtomicU64::new(u64::MAX),
+            reconcile: ReconcileConfig::default(),
+            onchain_cache: TrustCache::new(TrustCacheConfig::with_ttl(Duration::from_secs(
+                ReconcileConfig::default().cache_ttl_secs,
+            ))),
+            onchain_breaker: onchain_breaker(&ReconcileConfig::default()),
+            multichain: None,
+        }
+    }
+
+    /// Consult the TrustRegistry on several chains.
+    ///
+    /// Enables [`TrustService::get_chain_trust`]. If the client aggregates
+    /// trust, reconciliation uses the aggregate instead of the primary
+    /// registry.
+    pub fn with_multichain(mut self, client: Arc<MultiChainClient>) -> Self {
+        self.multichain = Some(client);
+        self
+    }
+
+    /// Reconcile local trust with the on-chain TrustRegistry.
+    ///
+    /// Has no effect on the scores unless a registry address was given
+    /// and `config.policy` is not [`ReconcilePolicy::Local`].
+    pub fn with_reconciliation(mut self, config: ReconcileConfig) -> Self {
+        self.onchain_cache = TrustCache::new(TrustCacheConfig::with_ttl(Duration::from_secs(
+            config.cache_ttl_secs,
+        )));
+        self.onchain_breaker = onchain_breaker(&config);
+        self.reconcile = config;
+        self
+    }
+
+    /// Set the trust roots endorsement hop distances are measured from.
+    ///
+    /// Endorsements only count when the endorser is reachable from a root,
+    /// so without roots they add nothing to any score.
+    pub fn with_trust_roots(self, roots: Vec<String>) -> Self {
+        if let Ok(mut graph) = self.graph.write() {
+            graph.set_roots(roots);
+        }
+        self.invalidate_propagation();
+        self
+    }
+
+    /// Set the node's trust requirements.
+    pub fn with_requirements(mut self, requirements: TrustConfig) -> Self {
+        self.requirements = requirements;
+        self
+    }
+
+    /// The node's trust requirements.
+    pub fn requirements(&self) -> &TrustConfig {
+        &self.requirements
+    }
+
+    /// Attach a durable trust data store.
+    ///
+    /// Every subsequent mutation is written through to the store. Call
+    /// [`TrustService::load_from_store`] to rehydrate the cache on startup.
+    pub fn with_store(mut self, store: TrustDataStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Attach a trust history store.
+    ///
+    /// Every subsequent trust event is appended to it; snapshots are taken
+    /// with [`TrustService::snapshot_all`].
+    pub fn with_history(mut self, history: TrustHistoryStore) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Load all persisted trust data into the local cache.
+    ///
+    /// Returns the number of agents loaded (0 if no store is attached).
+    pub fn load_from_store(&self) -> Result<usize> {
+        let Some(ref store) = self.store else {
+            return Ok(0);
+        };
+
+        let entries = store.all()?;
+        let count = entries.len();
+
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
+        for (did, stored) in entries {
+            self.index_did(&did);
+            cache.insert(did, TrustData::from(stored));
+        }
+
+        let mut graph = self
+            .graph
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire graph write lock: {}", e)))?;
+        for (did, data) in cache.iter() {
+            for endorsement in &data.endorsements {
+                graph.add_edge(&endorsement.endorser_did, did);
+                if let Some(expires_at) = endorsement.expires_at {
+                    self.next_expiry.fetch_min(expires_at, Ordering::Relaxed);
+                }
+            }
+        }
+        self.invalidate_propagation();
+
+        Ok(count)
+    }
+
+    /// Write an agent's trust data through to the durable store, if any.
+    ///
+    /// Endorsement hop distances are recorded as computed at write time
+    /// (`MAX_ENDORSEMENT_HOPS + 1` if unreachable); they are informational
+    /// only and recomputed from the graph on load.
+    ///
+    /// Persistence failures are logged but do not fail the in-memory update.
+    /// The DID is added to the hash index either way.
+    fn persist(&self, did: &str, data: &TrustData) {
+        self.index_did(did);
+        if let Some(ref store) = self.store {
+            let mut stored = StoredTrustData::from(data);
+            if let Ok(graph) = self.graph.read() {
+                for record in &mut stored.endorsements {
+                    record.hop_distance = graph
+                        .endorsement_hops(&record.endorser_did)
+                        .unwrap_or(MAX_ENDORSEMENT_HOPS + 1);
+                }
+            }
+            if let Err(e) = store.put(did, &stored) {
+                tracing::warn!("Failed to persist trust data for {}: {}", did, e);
+            }
+        }
+    }
+
+    /// Append a trust event for `did` to the history store, if any.
+    ///
+    /// Failures are logged but do not fail the update that caused the event.
+    fn record_event(&self, did: &str, event: TrustEvent) {
+        if let Some(ref history) = self.history {
+            if let Err(e) = history.put_event(did, &event) {
+                tracing::warn!("Failed to record trust event for {}: {}", did, e);
+            }
+        }
+    }
+
+    /// Snapshot the trust components of every known agent.
+    ///
+    /// Returns the number of snapshots written (0 if no history store is
+    /// attached).
+    pub async fn snapshot_all(&self) -> Result<usize> {
+        let Some(ref history) = self.history else {
+            return Ok(0);
+        };
+
+        let timestamp = current_timestamp();
+        let mut written = 0;
+        for did in self.known_dids() {
+            let info = self.get_trust(&did).await?;
+            history.put_snapshot(&did, &TrustSnapshot::from_info(&info, timestamp))?;
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Delete history older than `cutoff` (Unix seconds).
+    ///
+    /// Returns the number of entries deleted.
+    pub fn prune_history(&self, cutoff: u64) -> Result<usize> {
+        match self.history {
+            Some(ref history) => history.prune(cutoff),
+            None => Ok(0),
+        }
+    }
+
+    /// Trust history of `did` between `from` and `to` (inclusive).
+    ///
+    /// Snapshots are averaged into buckets of `resolution` seconds (0 keeps
+    /// every snapshot); events are returned as recorded. Returns `None` if
+    /// no history store is attached.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid or `from` is after `to`.
+    pub fn history(
+        &self,
+        did: &str,
+        from: u64,
+        to: u64,
+        resolution: u64,
+    ) -> Result<Option<TrustHistory>> {
+        if !did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid DID format: '{}'. DID must start with 'did:'",
+                did
+            )));
+        }
+        if from > to {
+            return Err(Error::Validation(format!(
+                "Invalid range: from ({}) is after to ({})",
+                from, to
+            )));
         }
+        let Some(ref history) = self.history else {
+            return Ok(None);
+        };
+
+        Ok(Some(TrustHistory {
+            did: did.to_string(),
+            from,
+            to,
+            resolution,
+            snapshots: downsample(history.snapshots(did, from, to)?, resolution),
+            events: history.events(did, from, to)?,
+        }))
     }
 
     /// Seed trust data for an agent.
     ///
     /// Used to set initial trust data for known agents (e.g. on startup).
-    /// Uses current timestamp so no decay is applied initially.
+    /// Uses current timestamp so no decay is applied initially. Endorsements
+    /// already recorded for the agent are kept.
     pub fn seed_trust_data(
         &self,
         did: &str,
@@ -204,19 +746,28 @@ impl TrustService {
         endorsement_count: u64,
         last_activity_timestamp: u64,
     ) {
-        if let Ok(mut cache) = self.cache.write() {
-            cache.insert(
-                did.to_string(),
-                TrustData {
-                    stake_amount,
-                    successful_transactions: successful_txs,
-                    failed_transactions: failed_txs,
-                    endorsement_count,
-                    last_activity_timestamp,
-                    endorsements: Vec::new(),
-                },
-            );
-        }
+        let Ok(mut cache) = self.cache.write() else {
+            return;
+        };
+        // Endorsements already received stay in sync with the graph
+        let endorsements = cache
+            .remove(did)
+            .map(|existing| existing.endorsements)
+            .unwrap_or_default();
+        let data = TrustData {
+            stake_amount,
+            endorsement_count: endorsement_count.max(endorsements.len() as u64),
+            transactions: TransactionStats {
+                successful_transactions: successful_txs,
+                failed_transactions: failed_txs,
+                last_activity: last_activity_timestamp,
+                ..Default::default()
+            },
+            endorsements,
+            ..Default::default()
+        };
+        self.persist(did, &data);
+        cache.insert(did.to_string(), data);
     }
 
     /// Set trust data for testing purposes.
@@ -255,7 +806,8 @@ impl TrustService {
 
     /// Get trust information for an agent.
     ///
-    /// First checks local cache, then queries on-chain if contract is configured.
+    /// Computed from the local cache, and reconciled with the on-chain
+    /// TrustRegistry if configured (see [`TrustService::get_reconciled_trust`]).
     ///
     /// # Arguments
     ///
@@ -265,6 +817,167 @@ impl TrustService {
     ///
     /// Trust information including composite score and components.
     pub async fn get_trust(&self, did: &str) -> Result<TrustInfo> {
+        self.get_skill_trust(did, None).await
+    }
+
+    /// Get trust information for an agent in the context of a skill.
+    ///
+    /// The reputation component and transaction counts come from the
+    /// transactions recorded for `skill`; stake and endorsements are the
+    /// agent's global ones. Agents with no transactions recorded for the
+    /// skill (or `skill` of `None`) get their global trust.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn get_skill_trust(&self, did: &str, skill: Option<&str>) -> Result<TrustInfo> {
+        Ok(self.get_reconciled_trust(did, skill).await?.info)
+    }
+
+    /// Get trust information for an agent in the context of a skill
+    /// without contacting the TrustRegistry.
+    ///
+    /// Like [`get_skill_trust`](Self::get_skill_trust), but registry
+    /// components are only merged in while a value read earlier is still
+    /// cached; otherwise the local ones stand. Used to rank many agents at
+    /// once, where a registry read per agent would stall the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn get_cached_skill_trust(
+        &self,
+        did: &str,
+        skill: Option<&str>,
+    ) -> Result<TrustInfo> {
+        let local = self.local_trust(did, skill)?;
+        let policy = self.reconcile.policy;
+        if policy == ReconcilePolicy::Local {
+            return Ok(local);
+        }
+
+        let onchain = match self.onchain_cache.get(did).await {
+            Some(cached) => OnChainObservation::Fresh {
+                age_secs: cached.age_secs(),
+                info: cached.info,
+            },
+            None => OnChainObservation::Unavailable {
+                reason: "no cached TrustRegistry value".to_string(),
+            },
+        };
+        Ok(reconcile(local, onchain, policy, &self.weights).info)
+    }
+
+    /// Get trust information for an agent together with where each
+    /// component came from.
+    ///
+    /// With a reconciliation policy other than [`ReconcilePolicy::Local`]
+    /// and a TrustRegistry configured, the registry's component scores are
+    /// read through a TTL cache and a circuit breaker and merged with the
+    /// local ones. If the registry is unreachable, the last value read for
+    /// the agent is used (up to `max_stale_secs` old), and otherwise the
+    /// local components.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn get_reconciled_trust(
+        &self,
+        did: &str,
+        skill: Option<&str>,
+    ) -> Result<ReconciledTrust> {
+        let local = self.local_trust(did, skill)?;
+        let policy = self.reconcile.policy;
+        if policy == ReconcilePolicy::Local {
+            return Ok(ReconciledTrust::local(local, policy));
+        }
+
+        let onchain = self.read_onchain_trust(did).await;
+        Ok(reconcile(local, onchain, policy, &self.weights))
+    }
+
+    /// Read an agent's component scores from the TrustRegistry.
+    ///
+    /// With multi-chain aggregation enabled the scores are aggregated across
+    /// every configured chain, otherwise read from the primary registry.
+    async fn read_onchain_trust(&self, did: &str) -> OnChainObservation {
+        let multichain = self
+            .multichain
+            .as_deref()
+            .filter(|mc| mc.aggregates_trust());
+        if multichain.is_none() && self.contract_client.is_none() {
+            return OnChainObservation::Unavailable {
+                reason: "TrustRegistry is not configured".to_string(),
+            };
+        }
+
+        let loaded = self
+            .onchain_cache
+            .get_or_load(did, || async {
+                let read = self
+                    .onchain_breaker
+                    .call_with_fallback_for(did, || async {
+                        let details = match (multichain, &self.contract_client) {
+                            (Some(mc), _) => mc.get_aggregated_trust_details(did).await?,
+                            (None, Some(client)) => client.get_trust_details(did).await?,
+                            (None, None) => {
+                                return Err(Error::Config(
+                                    "TrustRegistry is not configured".to_string(),
+                                ))
+                            }
+                        };
+                        Ok::<_, Error>(CachedTrustInfo::new(onchain_trust_info(did, &details)))
+                    })
+                    .await;
+
+                // Degraded values are not cached, so they keep their read time
+                match read {
+                    Ok(result) if !result.degraded => Ok(result.value.info),
+                    Ok(result) => Err(OnChainReadError::Stale(
+                        result.value,
+                        result.reason.unwrap_or_default(),
+                    )),
+                    Err(CircuitError::Service(e)) => {
+                        Err(match self.onchain_breaker.get_last_known_good_for(did) {
+                            Some(last) => OnChainReadError::Stale(last, e.to_string()),
+                            None => OnChainReadError::Unavailable(e.to_string()),
+                        })
+                    }
+                    Err(e) => Err(OnChainReadError::Unavailable(e.to_string())),
+                }
+            })
+            .await;
+
+        match loaded {
+            Ok(cached) => OnChainObservation::Fresh {
+                age_secs: cached.age_secs(),
+                info: cached.info,
+            },
+            Err(OnChainReadError::Stale(last, reason)) => {
+                let max_stale = self.reconcile.max_stale_secs;
+                if max_stale > 0 && last.age_secs() > max_stale {
+                    OnChainObservation::Unavailable {
+                        reason: format!(
+                            "{} (last known good value is older than {}s)",
+                            reason, max_stale
+                        ),
+                    }
+                } else {
+                    OnChainObservation::Stale {
+                        age_secs: last.age_secs(),
+                        info: last.info,
+                        reason,
+                    }
+                }
+            }
+            Err(OnChainReadError::Unavailable(reason)) => {
+                OnChainObservation::Unavailable { reason }
+            }
+        }
+    }
+
+    /// Compute trust information from the local cache only.
+    fn local_trust(&self, did: &str, skill: Option<&str>) -> Result<TrustInfo> {
         // Validate DID format
         if !did.starts_with("did:") {
             return Err(Error::Trust(format!(
@@ -272,6 +985,7 @@ impl TrustService {
                 did
             )));
         }
+        self.expire_due_endorsements()?;
 
         // Get data from cache (or default for unknown agents)
         let data = {
@@ -282,10 +996,19 @@ impl TrustService {
             cache.get(did).cloned().unwrap_or_default()
         };
 
+        // Scope the reputation component to the skill if it has history
+        let (skill, transactions) = match skill.and_then(|id| data.skills.get_key_value(id)) {
+            Some((id, stats)) => (Some(id.clone()), stats),
+            None => (None, &data.transactions),
+        };
+
         // Calculate component scores
-        let reputation = self.calculate_reputation(&data);
+        let reputation = self.reputation_breakdown(transactions).score;
         let stake_score = self.calculate_stake_score(data.stake_amount);
-        let endorsement_score = self.calculate_endorsement_score(&data);
+        let endorsement_score = match self.weights.endorsement_mode {
+            EndorsementMode::HopDecay => self.calculate_endorsement_score(&data),
+            EndorsementMode::EigenTrust => self.propagated_trust()?.score(did),
+        };
 
         // Calculate composite score using weights
         let score = self.weights.reputation * reputation
@@ -299,9 +1022,68 @@ impl TrustService {
             stake_score,
             endorsement_score,
             stake_amount: data.stake_amount,
-            successful_transactions: data.successful_transactions,
-            failed_transactions: data.failed_transactions,
+            successful_transactions: transactions.successful_transactions,
+            failed_transactions: transactions.failed_transactions,
             endorsement_count: data.endorsement_count,
+            skill,
+        })
+    }
+
+    /// Explain an agent's trust score, optionally in the context of a skill.
+    ///
+    /// Lists the raw inputs and intermediate factors behind every component
+    /// of [`TrustService::get_reconciled_trust`], where each component came
+    /// from, and the endorsements that were dropped and why.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn explain(&self, did: &str, skill: Option<&str>) -> Result<TrustExplanation> {
+        let reconciled = self.get_reconciled_trust(did, skill).await?;
+
+        let data = {
+            let cache = self
+                .cache
+                .read()
+                .map_err(|e| Error::Trust(format!("Failed to acquire cache read lock: {}", e)))?;
+            cache.get(did).cloned().unwrap_or_default()
+        };
+
+        // Same skill scoping as the reconciled score
+        let transactions = reconciled
+            .info
+            .skill
+            .as_ref()
+            .and_then(|id| data.skills.get(id))
+            .unwrap_or(&data.transactions);
+        let mut reputation = self.reputation_breakdown(transactions);
+        reputation.score = reconciled.info.reputation;
+        let stake = StakeBreakdown {
+            score: reconciled.info.stake_score,
+            stake_amount: data.stake_amount,
+            reference_stake: REFERENCE_STAKE,
+        };
+        let contributions = match self.weights.endorsement_mode {
+            EndorsementMode::HopDecay => self.endorsement_contributions(&data).1,
+            EndorsementMode::EigenTrust => Vec::new(),
+        };
+
+        Ok(TrustExplanation {
+            did: did.to_string(),
+            score: reconciled.info.score,
+            skill: reconciled.info.skill,
+            provenance: reconciled.provenance,
+            weights: self.weights.clone(),
+            reputation,
+            stake,
+            endorsements: EndorsementBreakdown {
+                score: reconciled.info.endorsement_score,
+                mode: self.weights.endorsement_mode,
+                max_hops: MAX_ENDORSEMENT_HOPS,
+                max_counted: MAX_ENDORSEMENTS_COUNTED,
+                normalization: ENDORSEMENT_NORMALIZATION,
+                contributions,
+            },
         })
     }
 
@@ -335,6 +1117,28 @@ impl TrustService {
         }
     }
 
+    /// Get an agent's trust on every configured chain and its aggregate.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid or no chains are configured.
+    pub async fn get_chain_trust(&self, did: &str) -> Result<AggregatedTrust> {
+        // Validate DID format
+        if !did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid DID format: '{}'. DID must start with 'did:'",
+                did
+            )));
+        }
+
+        match self.multichain {
+            Some(ref multichain) => multichain.get_trust_across_chains(did).await,
+            None => Err(Error::Config(
+                "Multi-chain trust is not configured".to_string(),
+            )),
+        }
+    }
+
     /// Check if contract integration is available.
     pub fn has_contract(&self) -> bool {
         self.contract_client.is_some()
@@ -355,6 +1159,17 @@ impl TrustService {
         Ok(trust_info.score >= min_score)
     }
 
+    /// Check an agent against the configured [`TrustService::requirements`].
+    ///
+    /// The agent must reach `min_trust_score` and, when `require_stake` is
+    /// set, hold at least `min_stake`.
+    pub async fn meets_requirements(&self, did: &str) -> Result<bool> {
+        let trust_info = self.get_trust(did).await?;
+        let requirements = &self.requirements;
+        Ok(trust_info.score >= requirements.min_trust_score
+            && (!requirements.require_stake || trust_info.stake_amount >= requirements.min_stake))
+    }
+
     /// Calculate reputation score from transaction history with time decay.
     ///
     /// Applies decay based on inactivity:
@@ -362,22 +1177,44 @@ impl TrustService {
     /// - Decay is calculated on-demand at read time
     /// - Minimum reputation is 0.0 (never negative)
     fn calculate_reputation(&self, data: &TrustData) -> f64 {
-        let total = data.successful_transactions + data.failed_transactions;
+        self.reputation_breakdown(&data.transactions).score
+    }
+
+    /// Reputation component with the inputs it was computed from.
+    fn reputation_breakdown(&self, data: &TransactionStats) -> ReputationBreakdown {
+        let mut breakdown = ReputationBreakdown {
+            mode: self.weights.reputation_mode,
+            successful_transactions: data.successful_transactions,
+            failed_transactions: data.failed_transactions,
+            total_volume: data.total_volume,
+            last_activity_timestamp: data.last_activity,
+            days_since_activity: days_since(data.last_activity),
+            decay_factor: self.calculate_decay_factor(data.last_activity),
+            ..Default::default()
+        };
+
+        let total = data.total_transactions();
         if total == 0 {
-            return 0.0;
+            return breakdown;
         }
 
-        // Success rate with volume factor
-        let success_rate = data.successful_transactions as f64 / total as f64;
-        let volume_factor = (total as f64 / 100.0).min(1.0); // Max at 100 transactions
-
-        let base_reputation = success_rate * (0.5 + 0.5 * volume_factor);
+        // Success rate, weighted by amount, recency and failure reason unless
+        // the agent has only counts (seeded or recorded before weighting)
+        breakdown.success_rate = match self.weights.reputation_mode {
+            ReputationMode::Weighted if !data.weighted.is_empty() => {
+                breakdown.weighted = Some(data.weighted);
+                data.weighted.success_rate().unwrap_or(0.0)
+            }
+            _ => data.successful_transactions as f64 / total as f64,
+        };
+        // Volume factor stays count-based so one large transaction cannot buy it
+        breakdown.volume_factor = (total as f64 / 100.0).min(1.0); // Max at 100 transactions
 
-        // Apply time decay based on inactivity
-        let decay_factor = self.calculate_decay_factor(data.last_activity_timestamp);
+        let base_reputation = breakdown.success_rate * (0.5 + 0.5 * breakdown.volume_factor);
 
-        // Clamp to [0.0, 1.0]
-        (base_reputation * decay_factor).clamp(0.0, 1.0)
+        // Apply time decay based on inactivity, clamp to [0.0, 1.0]
+        breakdown.score = (base_reputation * breakdown.decay_factor).clamp(0.0, 1.0);
+        breakdown
     }
 
     /// Calculate decay factor based on time since last activity.
@@ -388,19 +1225,10 @@ impl TrustService {
     ///
     /// Formula: decay_factor = 1.0 - (days_inactive * DECAY_RATE / DECAY_PERIOD_DAYS)
     fn calculate_decay_factor(&self, last_activity_timestamp: u64) -> f64 {
-        if last_activity_timestamp == 0 {
-            // No activity recorded, no decay (agent is new)
-            return 1.0;
-        }
-
-        let now = current_timestamp();
-        if last_activity_timestamp >= now {
-            // Future timestamp or same time, no decay
+        // No activity recorded (agent is new): no decay
+        let Some(days_since_activity) = days_since(last_activity_timestamp) else {
             return 1.0;
-        }
-
-        let seconds_since_activity = now - last_activity_timestamp;
-        let days_since_activity = seconds_since_activity as f64 / (24.0 * 60.0 * 60.0);
+        };
 
         // Calculate decay: 5% per 14 days
         // decay_factor = 1.0 - (days * 0.05 / 14)
@@ -424,31 +1252,40 @@ impl TrustService {
     /// Calculate endorsement score with hop decay and endorser trust weighting.
     ///
     /// Spec (trust-layer.md):
+    /// - hop_distance = endorser's shortest path to a trust root
     /// - MAX_HOPS = 3 (beyond 3, endorsement is ignored)
     /// - DECAY_PER_HOP = 0.10 (decay factor = 0.9^hop_distance)
-    /// - Max 10 endorsements counted (prevent gaming)
+    /// - Max 10 endorsements counted (prevent gaming), closest first
     /// - contribution = endorser_reputation * decay
     /// - Normalize by dividing by 3.0
     fn calculate_endorsement_score(&self, data: &TrustData) -> f64 {
+        self.endorsement_contributions(data).0
+    }
+
+    /// Hop-decay endorsement score with each endorsement's contribution.
+    fn endorsement_contributions(&self, data: &TrustData) -> (f64, Vec<EndorsementContribution>) {
         if data.endorsements.is_empty() {
-            return 0.0;
+            return (0.0, Vec::new());
         }
 
         let cache = self.cache.read().ok();
-        let mut total_contribution = 0.0;
-        let mut counted = 0;
+        let Ok(graph) = self.graph.read() else {
+            return (0.0, Vec::new());
+        };
 
-        for endorsement in &data.endorsements {
-            // Skip endorsements beyond max hop distance
-            if endorsement.hop_distance > MAX_ENDORSEMENT_HOPS {
-                continue;
-            }
+        // Closest endorsers first; unreachable ones (beyond max hop distance) last
+        let mut endorsements: Vec<(Option<u32>, &EndorsementData)> = data
+            .endorsements
+            .iter()
+            .map(|e| (graph.endorsement_hops(&e.endorser_did), e))
+            .collect();
+        endorsements.sort_by_key(|(hop, _)| hop.unwrap_or(u32::MAX));
 
-            // Limit to max endorsements
-            if counted >= MAX_ENDORSEMENTS_COUNTED {
-                break;
-            }
+        let mut total_contribution = 0.0;
+        let mut counted = 0;
+        let mut contributions = Vec::with_capacity(endorsements.len());
 
+        for (hop_distance, endorsement) in endorsements {
             // Get endorser's reputation
             let endorser_reputation = cache
                 .as_ref()
@@ -457,16 +1294,105 @@ impl TrustService {
                 .unwrap_or(0.0);
 
             // Calculate hop decay: 0.9^hop_distance
-            let decay_factor = ENDORSEMENT_DECAY_PER_HOP.powi(endorsement.hop_distance as i32);
+            let hop_decay = hop_distance
+                .map(|hop| ENDORSEMENT_DECAY_PER_HOP.powi(hop as i32))
+                .unwrap_or(0.0);
+
+            let status = if hop_distance.is_none() {
+                EndorsementStatus::BeyondMaxHops
+            } else if counted >= MAX_ENDORSEMENTS_COUNTED {
+                EndorsementStatus::OverCountLimit
+            } else {
+                EndorsementStatus::Counted
+            };
 
             // Contribution = endorser_reputation * decay
-            let contribution = endorser_reputation * decay_factor;
+            let contribution = if status == EndorsementStatus::Counted {
+                counted += 1;
+                endorser_reputation * hop_decay
+            } else {
+                0.0
+            };
             total_contribution += contribution;
-            counted += 1;
+
+            contributions.push(EndorsementContribution {
+                endorser_did: endorsement.endorser_did.clone(),
+                hop_distance,
+                endorser_reputation,
+                hop_decay,
+                contribution,
+                status,
+                expires_at: endorsement.expires_at,
+            });
         }
 
         // Normalize by dividing by 3.0 and cap at 1.0
-        (total_contribution / ENDORSEMENT_NORMALIZATION).min(1.0)
+        let score = (total_contribution / ENDORSEMENT_NORMALIZATION).min(1.0);
+        (score, contributions)
+    }
+
+    /// Global trust from EigenTrust propagation, recomputing it if the
+    /// endorsement or transaction graph changed since the last run.
+    ///
+    /// Local trust from agent i to agent j is `ENDORSEMENT_LOCAL_TRUST` if i
+    /// endorsed j plus `TRANSACTION_LOCAL_TRUST` per net successful
+    /// transaction i (as client) had with j. The trust roots are the
+    /// pre-trusted seeds; without roots nothing is reachable and every
+    /// agent scores 0.
+    pub fn propagated_trust(&self) -> Result<Arc<GlobalTrust>> {
+        self.expire_due_endorsements()?;
+        if let Some(ref trust) = *self
+            .propagated
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire trust read lock: {}", e)))?
+        {
+            return Ok(trust.clone());
+        }
+
+        let cache = self
+            .cache
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire cache read lock: {}", e)))?;
+        let graph = self
+            .graph
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire graph read lock: {}", e)))?;
+        let interactions = self
+            .interactions
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire interactions lock: {}", e)))?;
+
+        let mut local = LocalTrustGraph::new();
+        for (did, data) in cache.iter() {
+            for endorsement in &data.endorsements {
+                local.add_local_trust(&endorsement.endorser_did, did, ENDORSEMENT_LOCAL_TRUST);
+            }
+        }
+        for (client, providers) in interactions.iter() {
+            for (provider, counts) in providers {
+                let net = counts.successful.saturating_sub(counts.failed);
+                local.add_local_trust(client, provider, net as f64 * TRANSACTION_LOCAL_TRUST);
+            }
+        }
+        let trust = if graph.has_roots() {
+            Arc::new(local.propagate(graph.roots(), &EigenTrustConfig::default()))
+        } else {
+            Arc::new(GlobalTrust::default())
+        };
+
+        *self
+            .propagated
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire trust write lock: {}", e)))? =
+            Some(trust.clone());
+        Ok(trust)
+    }
+
+    /// Drop the cached EigenTrust result after the graph changed.
+    fn invalidate_propagation(&self) {
+        if let Ok(mut propagated) = self.propagated.write() {
+            *propagated = None;
+        }
     }
 
     /// Calculate endorsement score from count only (legacy/fallback).
@@ -489,11 +1415,12 @@ impl TrustService {
     ///
     /// * `did` - Agent's DID
     /// * `amount` - Transaction amount in USDC (6 decimals)
+    /// * `skill` - ID of the skill the transaction used, if known
     ///
     /// # Errors
     ///
     /// Returns error if DID format is invalid.
-    pub async fn record_success(&self, did: &str, _amount: u64) -> Result<()> {
+    pub async fn record_success(&self, did: &str, amount: u64, skill: Option<&str>) -> Result<()> {
         // Validate DID format
         if !did.starts_with("did:") {
             return Err(Error::Trust(format!(
@@ -508,10 +1435,21 @@ impl TrustService {
             .write()
             .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
 
-        let data = cache.entry(did.to_string()).or_default();
-        data.successful_transactions += 1;
         // Reset decay timer on activity
-        data.last_activity_timestamp = current_timestamp();
+        let now = current_timestamp();
+        let data = cache.entry(did.to_string()).or_default();
+        data.transactions.record_success(amount, now);
+        if let Some(skill) = skill {
+            data.skills
+                .entry(skill.to_string())
+                .or_default()
+                .record_success(amount, now);
+        }
+        self.persist(did, data);
+        self.record_event(
+            did,
+            TrustEvent::new(TrustEventKind::TransactionSucceeded, now).with_value(amount),
+        );
 
         // Note: On-chain recording requires ORACLE_ROLE and a configured signer.
         // The contract client supports read operations; write operations require
@@ -520,6 +1458,18 @@ impl TrustService {
         Ok(())
     }
 
+    /// Record a failed transaction of unknown amount.
+    ///
+    /// The failure is weighed as the agent's average successful transaction.
+    /// See [`TrustService::record_failure_with_amount`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn record_failure(&self, did: &str, reason: &str, skill: Option<&str>) -> Result<()> {
+        self.record_failure_with_amount(did, 0, reason, skill).await
+    }
+
     /// Record a failed transaction.
     ///
     /// Updates the last activity timestamp to reset decay timer.
@@ -528,12 +1478,21 @@ impl TrustService {
     /// # Arguments
     ///
     /// * `did` - Agent's DID
-    /// * `reason` - Failure reason description
+    /// * `amount` - Transaction amount in USDC (6 decimals), 0 if unknown
+    /// * `reason` - Failure reason (`timeout`, `dispute_lost`, `slashed`, ...),
+    ///   which scales the reputation penalty
+    /// * `skill` - ID of the skill the transaction used, if known
     ///
     /// # Errors
     ///
     /// Returns error if DID format is invalid.
-    pub async fn record_failure(&self, did: &str, _reason: &str) -> Result<()> {
+    pub async fn record_failure_with_amount(
+        &self,
+        did: &str,
+        amount: u64,
+        reason: &str,
+        skill: Option<&str>,
+    ) -> Result<()> {
         // Validate DID format
         if !did.starts_with("did:") {
             return Err(Error::Trust(format!(
@@ -548,10 +1507,27 @@ impl TrustService {
             .write()
             .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
 
-        let data = cache.entry(did.to_string()).or_default();
-        data.failed_transactions += 1;
         // Reset decay timer on activity (even failures count as activity)
-        data.last_activity_timestamp = current_timestamp();
+        let now = current_timestamp();
+        let reason = FailureReason::classify(reason);
+        let data = cache.entry(did.to_string()).or_default();
+        data.transactions.record_failure(amount, reason, now);
+        if let Some(skill) = skill {
+            data.skills
+                .entry(skill.to_string())
+                .or_default()
+                .record_failure(amount, reason, now);
+        }
+        self.persist(did, data);
+        let event = TrustEvent::new(TrustEventKind::TransactionFailed, now);
+        self.record_event(
+            did,
+            if amount > 0 {
+                event.with_value(amount)
+            } else {
+                event
+            },
+        );
 
         // Note: On-chain recording requires ORACLE_ROLE and a configured signer.
         // The contract client supports read operations; write operations require
@@ -560,11 +1536,62 @@ impl TrustService {
         Ok(())
     }
 
-    /// Endorse another agent (simple version with default hop distance).
+    /// Record the outcome of a transaction `client_did` had with `provider_did`.
+    ///
+    /// Feeds the transaction graph used by [`EndorsementMode::EigenTrust`];
+    /// the provider's own success/failure counts are still updated through
+    /// `record_success` / `record_failure`. Interactions are kept in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either DID format is invalid or both DIDs are the same.
+    pub async fn record_interaction(
+        &self,
+        client_did: &str,
+        provider_did: &str,
+        successful: bool,
+    ) -> Result<()> {
+        for did in [client_did, provider_did] {
+            if !did.starts_with("did:") {
+                return Err(Error::Trust(format!(
+                    "Invalid DID format: '{}'. DID must start with 'did:'",
+                    did
+                )));
+            }
+        }
+        if client_did == provider_did {
+            return Err(Error::Trust(format!(
+                "Agent '{}' cannot transact with itself",
+                client_did
+            )));
+        }
+
+        let mut interactions = self
+            .interactions
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire interactions lock: {}", e)))?;
+        let counts = interactions
+            .entry(client_did.to_string())
+            .or_default()
+            .entry(provider_did.to_string())
+            .or_default();
+        if successful {
+            counts.successful += 1;
+        } else {
+            counts.failed += 1;
+        }
+        drop(interactions);
+
+        self.invalidate_propagation();
+        Ok(())
+    }
+
+    /// Endorse another agent on behalf of an anonymous endorser.
     ///
-    /// This is a simplified endorsement API that assumes hop distance of 1
-    /// (direct endorsement). For proper web-of-trust tracking, use
-    /// `add_endorsement_with_hop` instead.
+    /// This is a simplified endorsement API for legacy callers; the
+    /// anonymous endorser has no trust of its own, so the endorsement only
+    /// counts towards `endorsement_count`. For proper web-of-trust tracking,
+    /// use `add_endorsement` instead.
     ///
     /// # Arguments
     ///
@@ -603,16 +1630,27 @@ impl TrustService {
         let data = cache.entry(target_did.to_string()).or_default();
         data.endorsement_count += 1;
 
-        // For backward compatibility, add a simple endorsement at hop 1
+        // For backward compatibility, record an endorsement from a placeholder DID
         // This endorsement won't contribute to score since the endorser doesn't exist
         // but the count is tracked for legacy callers
+        let endorser_did = format!(
+            "did:agoramesh:base:anonymous-endorser-{}",
+            data.endorsement_count
+        );
+        if let Ok(mut graph) = self.graph.write() {
+            graph.add_edge(&endorser_did, target_did);
+        }
+        self.invalidate_propagation();
+        self.record_event(
+            target_did,
+            TrustEvent::new(TrustEventKind::EndorsementAdded, current_timestamp())
+                .with_counterparty(&endorser_did),
+        );
         data.endorsements.push(EndorsementData {
-            endorser_did: format!(
-                "did:agoramesh:base:anonymous-endorser-{}",
-                data.endorsement_count
-            ),
-            hop_distance: 1,
+            endorser_did,
+            expires_at: None,
         });
+        self.persist(target_did, data);
 
         // Note: On-chain endorsement requires the caller to be a registered agent.
         // The contract client supports read operations; write operations require
@@ -621,33 +1659,50 @@ impl TrustService {
         Ok(())
     }
 
-    /// Add endorsement with explicit hop distance.
-    ///
-    /// This is the proper way to record endorsements that respects the
-    /// web-of-trust hop decay model.
+    /// Record that `endorser_did` endorses `target_did`.
     ///
-    /// # Arguments
-    ///
-    /// * `endorser_did` - DID of the endorser
-    /// * `target_did` - DID of the agent being endorsed
-    /// * `hop_distance` - Distance from trust root (1 = direct, 2 = one hop, etc.)
+    /// Adds an edge to the endorsement graph; the endorsement's hop distance
+    /// is the endorser's distance from the nearest trust root and is
+    /// recomputed whenever the graph changes.
     ///
     /// # Behavior
     ///
-    /// - Endorsements with hop_distance > MAX_ENDORSEMENT_HOPS (3) are recorded
-    ///   but will be ignored in score calculation
+    /// - Endorsements from agents more than MAX_ENDORSEMENT_HOPS (3) from
+    ///   every root are recorded but ignored in score calculation
     /// - Endorsement contribution = endorser_reputation * 0.9^hop_distance
     /// - Max 10 endorsements are counted in score calculation
     ///
+    /// Returns `true` if the endorsement was added, `false` if it already
+    /// existed.
+    ///
     /// # Errors
     ///
-    /// Returns error if either DID format is invalid.
-    pub async fn add_endorsement_with_hop(
+    /// Returns error if either DID format is invalid or an agent endorses itself.
+    pub async fn add_endorsement(&self, endorser_did: &str, target_did: &str) -> Result<bool> {
+        self.add_endorsement_until(endorser_did, target_did, None)
+            .await
+    }
+
+    /// Record that `endorser_did` endorses `target_did` until `expires_at`
+    /// (Unix seconds, `None` for no expiry).
+    ///
+    /// Expired endorsements are removed before the next score is computed,
+    /// as if revoked. Re-endorsing replaces the expiry of an existing
+    /// endorsement.
+    ///
+    /// Returns `true` if the endorsement was added, `false` if it already
+    /// existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either DID format is invalid, an agent endorses
+    /// itself, or `expires_at` has passed.
+    pub async fn add_endorsement_until(
         &self,
         endorser_did: &str,
         target_did: &str,
-        hop_distance: u32,
-    ) -> Result<()> {
+        expires_at: Option<u64>,
+    ) -> Result<bool> {
         // Validate DID formats
         if !endorser_did.starts_with("did:") {
             return Err(Error::Trust(format!(
@@ -661,132 +1716,554 @@ impl TrustService {
                 target_did
             )));
         }
+        if endorser_did == target_did {
+            return Err(Error::Trust(format!(
+                "Agent '{}' cannot endorse itself",
+                endorser_did
+            )));
+        }
+        let now = current_timestamp();
+        if let Some(expires_at) = expires_at.filter(|&at| at <= now) {
+            return Err(Error::Trust(format!(
+                "Endorsement expiry {} is not in the future",
+                expires_at
+            )));
+        }
 
         // Update cache
         let mut cache = self
             .cache
             .write()
             .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
+        if let Some(expires_at) = expires_at {
+            self.next_expiry.fetch_min(expires_at, Ordering::Relaxed);
+        }
 
         let data = cache.entry(target_did.to_string()).or_default();
+        if let Some(existing) = data
+            .endorsements
+            .iter_mut()
+            .find(|e| e.endorser_did == endorser_did)
+        {
+            if existing.expires_at != expires_at {
+                existing.expires_at = expires_at;
+                self.persist(target_did, data);
+            }
+            return Ok(false);
+        }
+
+        self.graph
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire graph write lock: {}", e)))?
+            .add_edge(endorser_did, target_did);
+        self.invalidate_propagation();
+
         data.endorsement_count += 1;
         data.endorsements.push(EndorsementData {
             endorser_did: endorser_did.to_string(),
-            hop_distance,
+            expires_at,
         });
+        self.persist(target_did, data);
+        self.record_event(
+            target_did,
+            TrustEvent::new(TrustEventKind::EndorsementAdded, now).with_counterparty(endorser_did),
+        );
 
-        Ok(())
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn test_service() -> TrustService {
-        TrustService::new(
-            "https://sepolia.base.org".to_string(),
-            Some("0x1234567890abcdef".to_string()),
-        )
+        Ok(true)
     }
 
-    // ========== TDD Tests: get_trust() ==========
-
-    #[tokio::test]
-    async fn test_get_trust_returns_zero_for_new_agent() {
-        // Arrange
-        let service = test_service();
+    /// Shortest endorsement chain from a trust root to `did`.
+    ///
+    /// Returns `None` if no trust roots are configured or `did` is not
+    /// within MAX_ENDORSEMENT_HOPS of any root.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub fn endorsement_path(&self, did: &str) -> Result<Option<EndorsementPath>> {
+        if !did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid DID format: '{}'. DID must start with 'did:'",
+                did
+            )));
+        }
 
-        // Act
-        let result = service.get_trust("did:agoramesh:base:new-agent").await;
+        self.expire_due_endorsements()?;
+        let graph = self
+            .graph
+            .read()
+            .map_err(|e| Error::Trust(format!("Failed to acquire graph read lock: {}", e)))?;
 
-        // Assert
-        assert!(result.is_ok());
-        let trust = result.unwrap();
-        assert_eq!(trust.score, 0.0, "New agent should have zero trust score");
-        assert_eq!(trust.reputation, 0.0);
-        assert_eq!(trust.stake_score, 0.0);
-        assert_eq!(trust.endorsement_score, 0.0);
+        Ok(graph.path(did).map(|chain| EndorsementPath {
+            did: did.to_string(),
+            root: chain[0].clone(),
+            hops: (chain.len() - 1) as u32,
+            chain,
+        }))
     }
 
-    #[tokio::test]
-    async fn test_get_trust_calculates_stake_score() {
-        // Arrange
-        let service = test_service();
-        let did = "did:agoramesh:base:staked-agent";
-        // Stake $2,500 (25% of reference)
-        service.set_trust_data(did, 2_500_000_000, 0, 0, 0);
-
-        // Act
-        let trust = service.get_trust(did).await.unwrap();
-
-        // Assert: sqrt(0.25) = 0.5
-        assert!(
-            (trust.stake_score - 0.5).abs() < 0.01,
-            "Stake score should be ~0.5 for 25% of reference stake, got {}",
-            trust.stake_score
-        );
+    /// Check whether `endorser_did` has an endorsement recorded for `target_did`.
+    pub fn has_endorsement(&self, endorser_did: &str, target_did: &str) -> bool {
+        self.cache
+            .read()
+            .map(|cache| {
+                cache.get(target_did).is_some_and(|data| {
+                    data.endorsements
+                        .iter()
+                        .any(|e| e.endorser_did == endorser_did)
+                })
+            })
+            .unwrap_or(false)
     }
 
-    #[tokio::test]
-    async fn test_get_trust_calculates_reputation_score() {
-        // Arrange
-        let service = test_service();
-        let did = "did:agoramesh:base:reputable-agent";
-        // 90% success rate with 100 transactions
-        service.set_trust_data(did, 0, 90, 10, 0);
+    /// Remove the endorsement `endorser_did` gave `target_did`.
+    ///
+    /// Returns `true` if an endorsement was removed, `false` if none existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either DID format is invalid.
+    pub async fn revoke_endorsement(&self, endorser_did: &str, target_did: &str) -> Result<bool> {
+        if !endorser_did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid endorser DID format: '{}'. DID must start with 'did:'",
+                endorser_did
+            )));
+        }
 
-        // Act
-        let trust = service.get_trust(did).await.unwrap();
+        if !target_did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid target DID format: '{}'. DID must start with 'did:'",
+                target_did
+            )));
+        }
 
-        // Assert: 0.9 * (0.5 + 0.5 * 1.0) = 0.9
-        assert!(
-            (trust.reputation - 0.9).abs() < 0.01,
-            "Reputation should be ~0.9 for 90% success rate at 100 txs, got {}",
-            trust.reputation
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
+
+        let Some(data) = cache.get_mut(target_did) else {
+            return Ok(false);
+        };
+        let before = data.endorsements.len();
+        data.endorsements.retain(|e| e.endorser_did != endorser_did);
+        let revoked = (before - data.endorsements.len()) as u64;
+        if revoked == 0 {
+            return Ok(false);
+        }
+        self.graph
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire graph write lock: {}", e)))?
+            .remove_edge(endorser_did, target_did);
+        self.invalidate_propagation();
+        data.endorsement_count = data.endorsement_count.saturating_sub(revoked);
+        self.persist(target_did, data);
+        self.record_event(
+            target_did,
+            TrustEvent::new(TrustEventKind::EndorsementRevoked, current_timestamp())
+                .with_counterparty(endorser_did),
         );
+
+        Ok(true)
     }
 
-    #[tokio::test]
-    async fn test_get_trust_calculates_composite_score() {
-        // Arrange
-        let service = test_service();
-        let did = "did:agoramesh:base:full-agent";
-        // Full reference stake, 100% success on 100 txs
-        service.set_trust_data(did, REFERENCE_STAKE, 100, 0, 0);
+    /// Remove every endorsement that expired at or before `now`.
+    ///
+    /// Runs automatically before scores are computed; returns the number of
+    /// endorsements removed.
+    pub fn expire_endorsements(&self, now: u64) -> Result<usize> {
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
 
-        // Add real endorsements from trusted endorsers
-        // Need enough endorsements to get endorsement_score close to 1.0
-        // Each endorsement contributes: endorser_reputation * 0.9^hop / 3.0
-        // With 5 high-trust endorsers at hop 1: 5 * (1.0 * 0.9) / 3.0 = 1.5 -> capped at 1.0
-        for i in 0..5 {
-            let endorser = format!("did:agoramesh:base:high-trust-endorser-{}", i);
-            service.set_trust_data(&endorser, REFERENCE_STAKE, 100, 0, 0);
-            service
-                .add_endorsement_with_hop(&endorser, did, 1)
-                .await
-                .unwrap();
+        let mut expired = Vec::new();
+        let mut next_expiry = u64::MAX;
+        for (did, data) in cache.iter_mut() {
+            let before = data.endorsements.len();
+            data.endorsements.retain(|e| match e.expires_at {
+                Some(at) if at <= now => {
+                    expired.push((e.endorser_did.clone(), did.clone()));
+                    false
+                }
+                Some(at) => {
+                    next_expiry = next_expiry.min(at);
+                    true
+                }
+                None => true,
+            });
+            let removed = (before - data.endorsements.len()) as u64;
+            data.endorsement_count = data.endorsement_count.saturating_sub(removed);
+        }
+        self.next_expiry.store(next_expiry, Ordering::Relaxed);
+        if expired.is_empty() {
+            return Ok(0);
         }
 
-        // Act
-        let trust = service.get_trust(did).await.unwrap();
+        {
+            let mut graph = self
+                .graph
+                .write()
+                .map_err(|e| Error::Trust(format!("Failed to acquire graph write lock: {}", e)))?;
+            for (endorser, target) in &expired {
+                graph.remove_edge(endorser, target);
+            }
+        }
+        self.invalidate_propagation();
+
+        let mut persisted = HashSet::new();
+        for (endorser, target) in &expired {
+            if persisted.insert(target) {
+                if let Some(data) = cache.get(target) {
+                    self.persist(target, data);
+                }
+            }
+            self.record_event(
+                target,
+                TrustEvent::new(TrustEventKind::EndorsementExpired, now)
+                    .with_counterparty(endorser),
+            );
+        }
+        tracing::debug!("Expired {} endorsement(s)", expired.len());
 
-        // Assert: composite with default weights (0.5, 0.3, 0.2)
-        // reputation = 1.0, stake = 1.0, endorsements = 1.0 (capped)
-        // score = 0.5 * 1.0 + 0.3 * 1.0 + 0.2 * 1.0 = 1.0
-        assert!(
-            trust.score > 0.9,
-            "Full agent should have high trust score, got {}",
-            trust.score
-        );
+        Ok(expired.len())
     }
 
-    #[tokio::test]
-    async fn test_get_trust_rejects_invalid_did() {
-        // Arrange
-        let service = test_service();
+    /// Expire endorsements if the earliest expiry has passed.
+    fn expire_due_endorsements(&self) -> Result<()> {
+        let now = current_timestamp();
+        if self.next_expiry.load(Ordering::Relaxed) <= now {
+            self.expire_endorsements(now)?;
+        }
+        Ok(())
+    }
 
-        // Act
+    /// Apply an on-chain `ReputationUpdated` event.
+    ///
+    /// The registry reports a score in basis points (0-10000) over
+    /// `total_transactions`; the local success/failure split is rebuilt from
+    /// it so that [`TrustService::get_trust`] serves the chain's view.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid or the score exceeds 10000.
+    pub async fn apply_reputation_update(
+        &self,
+        did: &str,
+        score_bps: u64,
+        total_transactions: u64,
+    ) -> Result<()> {
+        if score_bps > 10_000 {
+            return Err(Error::Trust(format!(
+                "Invalid on-chain reputation score: {}. Must be between 0 and 10000",
+                score_bps
+            )));
+        }
+
+        let successful = (total_transactions as u128 * score_bps as u128 / 10_000) as u64;
+
+        self.update(did, |data| {
+            // The registry's counts are authoritative: weighting restarts from them
+            data.transactions = TransactionStats {
+                successful_transactions: successful,
+                failed_transactions: total_transactions - successful,
+                total_volume: data.transactions.total_volume,
+                // Reset decay timer on activity
+                last_activity: current_timestamp(),
+                weighted: WeightedOutcomes::default(),
+            };
+        })?;
+        self.record_event(
+            did,
+            TrustEvent::new(TrustEventKind::ReputationUpdated, current_timestamp())
+                .with_value(score_bps),
+        );
+        Ok(())
+    }
+
+    /// Apply an on-chain `StakeDeposited` event.
+    ///
+    /// `event_id` identifies the log (`{tx_hash}:{log_index}`) and is stored
+    /// with the agent's trust data, so a replayed log is not counted twice.
+    /// Returns `false` if the event was already applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn apply_stake_deposit(
+        &self,
+        did: &str,
+        amount: u64,
+        event_id: &str,
+    ) -> Result<bool> {
+        let applied =
+            self.change_stake(did, event_id, true, |stake| stake.saturating_add(amount))?;
+        if applied {
+            self.record_event(
+                did,
+                TrustEvent::new(TrustEventKind::StakeDeposited, current_timestamp())
+                    .with_value(amount),
+            );
+        }
+        Ok(applied)
+    }
+
+    /// Apply an on-chain `StakeSlashed` event.
+    ///
+    /// Replays are skipped as for [`TrustService::apply_stake_deposit`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn apply_stake_slash(&self, did: &str, amount: u64, event_id: &str) -> Result<bool> {
+        let applied =
+            self.change_stake(did, event_id, true, |stake| stake.saturating_sub(amount))?;
+        if applied {
+            self.record_event(
+                did,
+                TrustEvent::new(TrustEventKind::StakeSlashed, current_timestamp())
+                    .with_value(amount),
+            );
+        }
+        Ok(applied)
+    }
+
+    /// Undo a `StakeDeposited` event whose block was orphaned.
+    ///
+    /// Returns `false` if the event was never applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn revert_stake_deposit(
+        &self,
+        did: &str,
+        amount: u64,
+        event_id: &str,
+    ) -> Result<bool> {
+        let reverted =
+            self.change_stake(did, event_id, false, |stake| stake.saturating_sub(amount))?;
+        if reverted {
+            self.record_event(
+                did,
+                TrustEvent::new(TrustEventKind::StakeSlashed, current_timestamp())
+                    .with_value(amount),
+            );
+        }
+        Ok(reverted)
+    }
+
+    /// Undo a `StakeSlashed` event whose block was orphaned.
+    ///
+    /// Returns `false` if the event was never applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if DID format is invalid.
+    pub async fn revert_stake_slash(&self, did: &str, amount: u64, event_id: &str) -> Result<bool> {
+        let reverted =
+            self.change_stake(did, event_id, false, |stake| stake.saturating_add(amount))?;
+        if reverted {
+            self.record_event(
+                did,
+                TrustEvent::new(TrustEventKind::StakeDeposited, current_timestamp())
+                    .with_value(amount),
+            );
+        }
+        Ok(reverted)
+    }
+
+    /// Change an agent's stake for an on-chain event, unless the event was
+    /// already applied (`apply`) or was never applied (`!apply`).
+    fn change_stake(
+        &self,
+        did: &str,
+        event_id: &str,
+        apply: bool,
+        f: impl FnOnce(u64) -> u64,
+    ) -> Result<bool> {
+        let mut changed = false;
+        self.update(did, |data| {
+            changed = if apply {
+                data.stake_events.insert(event_id.to_string())
+            } else {
+                data.stake_events.remove(event_id)
+            };
+            if changed {
+                data.stake_amount = f(data.stake_amount);
+            }
+        })?;
+        Ok(changed)
+    }
+
+    /// DID whose `did_to_hash` is `did_hash`, if it ever had trust data here.
+    pub fn did_for_hash(&self, did_hash: &FixedBytes<32>) -> Option<String> {
+        self.did_hashes
+            .read()
+            .ok()
+            .and_then(|index| index.get(did_hash).cloned())
+    }
+
+    fn index_did(&self, did: &str) {
+        if let Ok(mut index) = self.did_hashes.write() {
+            index
+                .entry(TrustRegistryClient::did_to_hash(did))
+                .or_insert_with(|| did.to_string());
+        }
+    }
+
+    /// DIDs with trust data in the local cache.
+    pub fn known_dids(&self) -> Vec<String> {
+        self.cache
+            .read()
+            .map(|cache| cache.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Mutate an agent's trust data in place and write it through to the store.
+    fn update(&self, did: &str, f: impl FnOnce(&mut TrustData)) -> Result<()> {
+        if !did.starts_with("did:") {
+            return Err(Error::Trust(format!(
+                "Invalid DID format: '{}'. DID must start with 'did:'",
+                did
+            )));
+        }
+
+        let mut cache = self
+            .cache
+            .write()
+            .map_err(|e| Error::Trust(format!("Failed to acquire cache write lock: {}", e)))?;
+
+        let data = cache.entry(did.to_string()).or_default();
+        f(data);
+        self.persist(did, data);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust_reconcile::TrustSource;
+
+    fn test_service() -> TrustService {
+        TrustService::new(
+            "https://sepolia.base.org".to_string(),
+            Some("0x1234567890abcdef".to_string()),
+        )
+    }
+
+    const ROOT: &str = "did:agoramesh:base:trust-root";
+
+    /// Service with a single trust root and the given endorsement chain
+    /// leading away from it.
+    async fn rooted_service(chain: &[&str]) -> TrustService {
+        let service = test_service().with_trust_roots(vec![ROOT.to_string()]);
+        let mut previous = ROOT;
+        for did in chain {
+            service.add_endorsement(previous, did).await.unwrap();
+            previous = did;
+        }
+        service
+    }
+
+    // ========== TDD Tests: get_trust() ==========
+
+    #[tokio::test]
+    async fn test_get_trust_returns_zero_for_new_agent() {
+        // Arrange
+        let service = test_service();
+
+        // Act
+        let result = service.get_trust("did:agoramesh:base:new-agent").await;
+
+        // Assert
+        assert!(result.is_ok());
+        let trust = result.unwrap();
+        assert_eq!(trust.score, 0.0, "New agent should have zero trust score");
+        assert_eq!(trust.reputation, 0.0);
+        assert_eq!(trust.stake_score, 0.0);
+        assert_eq!(trust.endorsement_score, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_calculates_stake_score() {
+        // Arrange
+        let service = test_service();
+        let did = "did:agoramesh:base:staked-agent";
+        // Stake $2,500 (25% of reference)
+        service.set_trust_data(did, 2_500_000_000, 0, 0, 0);
+
+        // Act
+        let trust = service.get_trust(did).await.unwrap();
+
+        // Assert: sqrt(0.25) = 0.5
+        assert!(
+            (trust.stake_score - 0.5).abs() < 0.01,
+            "Stake score should be ~0.5 for 25% of reference stake, got {}",
+            trust.stake_score
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_calculates_reputation_score() {
+        // Arrange
+        let service = test_service();
+        let did = "did:agoramesh:base:reputable-agent";
+        // 90% success rate with 100 transactions
+        service.set_trust_data(did, 0, 90, 10, 0);
+
+        // Act
+        let trust = service.get_trust(did).await.unwrap();
+
+        // Assert: 0.9 * (0.5 + 0.5 * 1.0) = 0.9
+        assert!(
+            (trust.reputation - 0.9).abs() < 0.01,
+            "Reputation should be ~0.9 for 90% success rate at 100 txs, got {}",
+            trust.reputation
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_calculates_composite_score() {
+        // Arrange
+        let service = test_service().with_trust_roots(vec![ROOT.to_string()]);
+        let did = "did:agoramesh:base:full-agent";
+        // Full reference stake, 100% success on 100 txs
+        service.set_trust_data(did, REFERENCE_STAKE, 100, 0, 0);
+
+        // Add real endorsements from trusted endorsers
+        // Need enough endorsements to get endorsement_score close to 1.0
+        // Each endorsement contributes: endorser_reputation * 0.9^hop / 3.0
+        // With 5 high-trust endorsers at hop 1: 5 * (1.0 * 0.9) / 3.0 = 1.5 -> capped at 1.0
+        for i in 0..5 {
+            let endorser = format!("did:agoramesh:base:high-trust-endorser-{}", i);
+            service.set_trust_data(&endorser, REFERENCE_STAKE, 100, 0, 0);
+            service.add_endorsement(ROOT, &endorser).await.unwrap();
+            service.add_endorsement(&endorser, did).await.unwrap();
+        }
+
+        // Act
+        let trust = service.get_trust(did).await.unwrap();
+
+        // Assert: composite with default weights (0.5, 0.3, 0.2)
+        // reputation = 1.0, stake = 1.0, endorsements = 1.0 (capped)
+        // score = 0.5 * 1.0 + 0.3 * 1.0 + 0.2 * 1.0 = 1.0
+        assert!(
+            trust.score > 0.9,
+            "Full agent should have high trust score, got {}",
+            trust.score
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trust_rejects_invalid_did() {
+        // Arrange
+        let service = test_service();
+
+        // Act
         let result = service.get_trust("invalid-did").await;
 
         // Assert
@@ -847,6 +2324,26 @@ mod tests {
         assert!(result.unwrap(), "Should return true for zero threshold");
     }
 
+    #[tokio::test]
+    async fn test_meets_requirements_enforces_stake() {
+        // Arrange: high reputation, but below the required stake
+        let service = test_service().with_requirements(TrustConfig {
+            min_trust_score: 0.3,
+            require_stake: true,
+            min_stake: REFERENCE_STAKE,
+            ..TrustConfig::default()
+        });
+        let did = "did:agoramesh:base:under-staked";
+        service.set_trust_data(did, REFERENCE_STAKE / 2, 100, 0, 10);
+
+        // Act
+        let result = service.meets_requirements(did).await.unwrap();
+
+        // Assert
+        assert!(service.verify(did, 0.3).await.unwrap());
+        assert!(!result, "Stake below min_stake should fail when required");
+    }
+
     // ========== TDD Tests: record_success() ==========
 
     #[tokio::test]
@@ -856,8 +2353,8 @@ mod tests {
         let did = "did:agoramesh:base:worker";
 
         // Act
-        service.record_success(did, 1_000_000).await.unwrap();
-        service.record_success(did, 2_000_000).await.unwrap();
+        service.record_success(did, 1_000_000, None).await.unwrap();
+        service.record_success(did, 2_000_000, None).await.unwrap();
 
         // Assert
         let trust = service.get_trust(did).await.unwrap();
@@ -873,7 +2370,7 @@ mod tests {
         let service = test_service();
 
         // Act
-        let result = service.record_success("invalid-did", 1_000_000).await;
+        let result = service.record_success("invalid-did", 1_000_000, None).await;
 
         // Assert
         assert!(result.is_err(), "Should reject invalid DID");
@@ -888,7 +2385,7 @@ mod tests {
 
         // Record 10 successes
         for _ in 0..10 {
-            service.record_success(did, 100_000).await.unwrap();
+            service.record_success(did, 100_000, None).await.unwrap();
         }
 
         // Assert
@@ -908,8 +2405,8 @@ mod tests {
         let did = "did:agoramesh:base:failing";
 
         // Act
-        service.record_failure(did, "timeout").await.unwrap();
-        service.record_failure(did, "error").await.unwrap();
+        service.record_failure(did, "timeout", None).await.unwrap();
+        service.record_failure(did, "error", None).await.unwrap();
 
         // Assert
         let trust = service.get_trust(did).await.unwrap();
@@ -925,7 +2422,7 @@ mod tests {
         let service = test_service();
 
         // Act
-        let result = service.record_failure("invalid-did", "error").await;
+        let result = service.record_failure("invalid-did", "error", None).await;
 
         // Assert
         assert!(result.is_err(), "Should reject invalid DID");
@@ -939,10 +2436,10 @@ mod tests {
 
         // Record 8 successes and 2 failures (80% success rate)
         for _ in 0..8 {
-            service.record_success(did, 100_000).await.unwrap();
+            service.record_success(did, 100_000, None).await.unwrap();
         }
         for _ in 0..2 {
-            service.record_failure(did, "error").await.unwrap();
+            service.record_failure(did, "error", None).await.unwrap();
         }
 
         // Assert
@@ -1004,15 +2501,16 @@ mod tests {
     #[tokio::test]
     async fn test_endorse_improves_endorsement_score() {
         // Arrange
-        let service = test_service();
+        let service = test_service().with_trust_roots(vec![ROOT.to_string()]);
         let target_did = "did:agoramesh:base:popular";
 
-        // Create 5 endorsers with trust data and add endorsements
+        // Create 5 root-endorsed endorsers with trust data and add endorsements
         for i in 0..5 {
             let endorser = format!("did:agoramesh:base:real-endorser-{}", i);
             service.set_trust_data(&endorser, REFERENCE_STAKE, 100, 0, 0);
+            service.add_endorsement(ROOT, &endorser).await.unwrap();
             service
-                .add_endorsement_with_hop(&endorser, target_did, 1)
+                .add_endorsement(&endorser, target_did)
                 .await
                 .unwrap();
         }
@@ -1025,6 +2523,70 @@ mod tests {
         );
     }
 
+    // ========== TDD Tests: Persistence ==========
+
+    #[tokio::test]
+    async fn test_mutations_are_written_through_to_store() {
+        use crate::persistence::MemoryStore;
+        use std::sync::Arc;
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let service = test_service().with_store(store.clone());
+        let did = "did:agoramesh:base:persisted";
+
+        // Act
+        service.record_success(did, 1_000_000, None).await.unwrap();
+        service.record_failure(did, "timeout", None).await.unwrap();
+        service
+            .add_endorsement("did:agoramesh:base:endorser", did)
+            .await
+            .unwrap();
+
+        // Assert: a fresh service rehydrates the same state
+        let restored = test_service().with_store(store);
+        assert_eq!(restored.load_from_store().unwrap(), 1);
+        let trust = restored.get_trust(did).await.unwrap();
+        assert_eq!(trust.successful_transactions, 1);
+        assert_eq!(trust.failed_transactions, 1);
+        assert_eq!(trust.endorsement_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_volume_and_weighted_outcomes_are_persisted() {
+        use crate::persistence::MemoryStore;
+        use std::sync::Arc;
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let service = test_service().with_store(store.clone());
+        let did = "did:agoramesh:base:volume";
+
+        // Act
+        service.record_success(did, 3_000_000, None).await.unwrap();
+        service.record_success(did, 2_000_000, None).await.unwrap();
+        service
+            .record_failure_with_amount(did, 5_000_000, "dispute_lost", None)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(store.get(did).unwrap().unwrap().total_volume, 5_000_000);
+        let restored = test_service().with_store(store);
+        restored.load_from_store().unwrap();
+        let before = service.explain(did, None).await.unwrap().reputation;
+        let after = restored.explain(did, None).await.unwrap().reputation;
+        assert_eq!(after.total_volume, 5_000_000);
+        assert_eq!(after.weighted, before.weighted);
+        assert_eq!(after.score, before.score);
+    }
+
+    #[test]
+    fn test_load_from_store_without_store_is_noop() {
+        let service = test_service();
+        assert_eq!(service.load_from_store().unwrap(), 0);
+    }
+
     // ========== TDD Tests: Contract integration ==========
 
     #[test]
@@ -1237,7 +2799,7 @@ mod tests {
         service.set_trust_data_with_timestamp(did, 0, 50, 0, 0, old_time);
 
         // Act: Record new success (should update timestamp)
-        service.record_success(did, 1_000_000).await.unwrap();
+        service.record_success(did, 1_000_000, None).await.unwrap();
 
         // Get trust - should have minimal decay now
         let trust = service.get_trust(did).await.unwrap();
@@ -1262,16 +2824,16 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_hop_decay_at_hop_1() {
         // Arrange
-        let service = test_service();
         let endorser_did = "did:agoramesh:base:trusted-endorser";
         let target_did = "did:agoramesh:base:endorsed-agent";
+        let service = rooted_service(&[endorser_did]).await;
 
         // Endorser has high trust (1.0 reputation)
         service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
 
         // Add endorsement at hop 1
         service
-            .add_endorsement_with_hop(endorser_did, target_did, 1)
+            .add_endorsement(endorser_did, target_did)
             .await
             .unwrap();
 
@@ -1293,19 +2855,46 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_endorsements_do_not_count_without_trust_roots() {
+        // Arrange: the default config has no trust roots
+        let service = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_trust_roots(TrustConfig::default().roots);
+        let endorser_did = "did:agoramesh:base:rootless-endorser";
+        let target_did = "did:agoramesh:base:rootless-target";
+        service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
+        service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap();
+
+        // Act
+        let trust = service.get_trust(target_did).await.unwrap();
+        let explanation = service.explain(target_did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.endorsement_score, 0.0);
+        assert_eq!(trust.endorsement_count, 1);
+        assert_eq!(
+            explanation.endorsements.contributions[0].status,
+            EndorsementStatus::BeyondMaxHops
+        );
+    }
+
     #[tokio::test]
     async fn test_endorsement_hop_decay_at_hop_2() {
         // Arrange
-        let service = test_service();
         let endorser_did = "did:agoramesh:base:indirect-endorser";
         let target_did = "did:agoramesh:base:target-hop2";
 
+        // Endorser is two hops from the root: root -> middle -> endorser
+        let service = rooted_service(&["did:agoramesh:base:middle", endorser_did]).await;
+
         // Endorser has high trust
         service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
 
-        // Add endorsement at hop 2
         service
-            .add_endorsement_with_hop(endorser_did, target_did, 2)
+            .add_endorsement(endorser_did, target_did)
             .await
             .unwrap();
 
@@ -1330,16 +2919,22 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_hop_decay_at_hop_3() {
         // Arrange
-        let service = test_service();
         let endorser_did = "did:agoramesh:base:far-endorser";
         let target_did = "did:agoramesh:base:target-hop3";
 
+        // Endorser is three hops from the root (max allowed)
+        let service = rooted_service(&[
+            "did:agoramesh:base:middle-1",
+            "did:agoramesh:base:middle-2",
+            endorser_did,
+        ])
+        .await;
+
         // Endorser has high trust
         service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
 
-        // Add endorsement at hop 3 (max allowed)
         service
-            .add_endorsement_with_hop(endorser_did, target_did, 3)
+            .add_endorsement(endorser_did, target_did)
             .await
             .unwrap();
 
@@ -1364,16 +2959,23 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_ignored_beyond_max_hops() {
         // Arrange
-        let service = test_service();
         let endorser_did = "did:agoramesh:base:too-far-endorser";
         let target_did = "did:agoramesh:base:target-hop4";
 
+        // Endorser is four hops from the root (beyond max 3)
+        let service = rooted_service(&[
+            "did:agoramesh:base:middle-1",
+            "did:agoramesh:base:middle-2",
+            "did:agoramesh:base:middle-3",
+            endorser_did,
+        ])
+        .await;
+
         // Endorser has high trust
         service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
 
-        // Add endorsement at hop 4 (beyond max 3)
         service
-            .add_endorsement_with_hop(endorser_did, target_did, 4)
+            .add_endorsement(endorser_did, target_did)
             .await
             .unwrap();
 
@@ -1391,7 +2993,7 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_considers_endorser_trust_score() {
         // Arrange
-        let service = test_service();
+        let service = test_service().with_trust_roots(vec![ROOT.to_string()]);
         let high_trust_endorser = "did:agoramesh:base:high-trust";
         let low_trust_endorser = "did:agoramesh:base:low-trust";
         let target_high = "did:agoramesh:base:endorsed-by-high";
@@ -1401,14 +3003,17 @@ mod tests {
         service.set_trust_data(high_trust_endorser, 0, 100, 0, 0);
         // Low trust endorser (50% success, 100 txs)
         service.set_trust_data(low_trust_endorser, 0, 50, 50, 0);
+        for endorser in [high_trust_endorser, low_trust_endorser] {
+            service.add_endorsement(ROOT, endorser).await.unwrap();
+        }
 
         // Add endorsements at hop 1
         service
-            .add_endorsement_with_hop(high_trust_endorser, target_high, 1)
+            .add_endorsement(high_trust_endorser, target_high)
             .await
             .unwrap();
         service
-            .add_endorsement_with_hop(low_trust_endorser, target_low, 1)
+            .add_endorsement(low_trust_endorser, target_low)
             .await
             .unwrap();
 
@@ -1436,7 +3041,7 @@ mod tests {
             let endorser = format!("did:agoramesh:base:endorser-{}", i);
             service.set_trust_data(&endorser, REFERENCE_STAKE, 100, 0, 0);
             service
-                .add_endorsement_with_hop(&endorser, target_did, 1)
+                .add_endorsement(&endorser, target_did)
                 .await
                 .unwrap();
         }
@@ -1457,7 +3062,6 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_score_aggregates_multiple_endorsers() {
         // Arrange
-        let service = test_service();
         let target_did = "did:agoramesh:base:multi-endorsed";
 
         // Add 3 endorsements from different agents at different hops
@@ -1465,20 +3069,23 @@ mod tests {
         let endorser2 = "did:agoramesh:base:e2";
         let endorser3 = "did:agoramesh:base:e3";
 
+        // root -> e1 -> e2 -> e3 puts them at hops 1, 2 and 3
+        let service = rooted_service(&[endorser1, endorser2, endorser3]).await;
+
         service.set_trust_data(endorser1, REFERENCE_STAKE, 100, 0, 0); // trust ~1.0
         service.set_trust_data(endorser2, REFERENCE_STAKE, 100, 0, 0); // trust ~1.0
         service.set_trust_data(endorser3, REFERENCE_STAKE, 100, 0, 0); // trust ~1.0
 
         service
-            .add_endorsement_with_hop(endorser1, target_did, 1)
+            .add_endorsement(endorser1, target_did)
             .await
             .unwrap(); // 1.0 * 0.9 = 0.9
         service
-            .add_endorsement_with_hop(endorser2, target_did, 2)
+            .add_endorsement(endorser2, target_did)
             .await
             .unwrap(); // 1.0 * 0.81 = 0.81
         service
-            .add_endorsement_with_hop(endorser3, target_did, 3)
+            .add_endorsement(endorser3, target_did)
             .await
             .unwrap(); // 1.0 * 0.729 = 0.729
 
@@ -1499,13 +3106,13 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_endorsement_with_hop_validates_endorser_did() {
+    async fn test_add_endorsement_validates_endorser_did() {
         // Arrange
         let service = test_service();
 
         // Act
         let result = service
-            .add_endorsement_with_hop("invalid-endorser", "did:agoramesh:base:target", 1)
+            .add_endorsement("invalid-endorser", "did:agoramesh:base:target")
             .await;
 
         // Assert
@@ -1514,13 +3121,13 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_endorsement_with_hop_validates_target_did() {
+    async fn test_add_endorsement_validates_target_did() {
         // Arrange
         let service = test_service();
 
         // Act
         let result = service
-            .add_endorsement_with_hop("did:agoramesh:base:endorser", "invalid-target", 1)
+            .add_endorsement("did:agoramesh:base:endorser", "invalid-target")
             .await;
 
         // Assert
@@ -1531,18 +3138,14 @@ mod tests {
     #[tokio::test]
     async fn test_endorsement_at_hop_0_gives_full_contribution() {
         // Arrange
-        let service = test_service();
-        let endorser_did = "did:agoramesh:base:direct-endorser";
+        let service = rooted_service(&[]).await;
         let target_did = "did:agoramesh:base:direct-target";
 
-        // Endorser has high trust
-        service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
+        // Root has high trust
+        service.set_trust_data(ROOT, REFERENCE_STAKE, 100, 0, 0);
 
-        // Add endorsement at hop 0 (direct trust root)
-        service
-            .add_endorsement_with_hop(endorser_did, target_did, 0)
-            .await
-            .unwrap();
+        // Endorsement by the trust root itself is hop 0
+        service.add_endorsement(ROOT, target_did).await.unwrap();
 
         // Act
         let trust = service.get_trust(target_did).await.unwrap();
@@ -1569,7 +3172,7 @@ mod tests {
 
         // Add endorsement at hop 1
         service
-            .add_endorsement_with_hop(endorser_did, target_did, 1)
+            .add_endorsement(endorser_did, target_did)
             .await
             .unwrap();
 
@@ -1583,4 +3186,1065 @@ mod tests {
             trust.endorsement_score
         );
     }
+
+    // ========== TDD Tests: Endorsement Graph ==========
+
+    #[tokio::test]
+    async fn test_endorsement_from_unreachable_endorser_is_ignored() {
+        // Arrange: a trusted agent nobody connected to a root
+        let service = rooted_service(&[]).await;
+        let endorser_did = "did:agoramesh:base:self-claimed";
+        let target_did = "did:agoramesh:base:sybil-target";
+        service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
+
+        // Act
+        service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap();
+        let trust = service.get_trust(target_did).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.endorsement_count, 1);
+        assert_eq!(trust.endorsement_score, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_revoking_root_endorsement_disconnects_chain() {
+        // Arrange: root -> endorser -> target
+        let endorser_did = "did:agoramesh:base:chain-endorser";
+        let target_did = "did:agoramesh:base:chain-target";
+        let service = rooted_service(&[endorser_did, target_did]).await;
+        service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
+        assert!(
+            service
+                .get_trust(target_did)
+                .await
+                .unwrap()
+                .endorsement_score
+                > 0.0
+        );
+
+        // Act
+        service
+            .revoke_endorsement(ROOT, endorser_did)
+            .await
+            .unwrap();
+
+        // Assert
+        let trust = service.get_trust(target_did).await.unwrap();
+        assert_eq!(trust.endorsement_score, 0.0);
+        assert!(service.endorsement_path(target_did).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_endorsement_rejects_self_endorsement() {
+        let service = test_service();
+        let did = "did:agoramesh:base:narcissist";
+
+        let result = service.add_endorsement(did, did).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_endorsement_is_idempotent() {
+        let service = test_service();
+        let endorser_did = "did:agoramesh:base:repeat-endorser";
+        let target_did = "did:agoramesh:base:repeat-target";
+
+        assert!(service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap());
+        assert!(!service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap());
+
+        let trust = service.get_trust(target_did).await.unwrap();
+        assert_eq!(trust.endorsement_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_endorsement_path_from_root() {
+        // Arrange
+        let middle = "did:agoramesh:base:path-middle";
+        let target_did = "did:agoramesh:base:path-target";
+        let service = rooted_service(&[middle, target_did]).await;
+
+        // Act
+        let path = service.endorsement_path(target_did).unwrap().unwrap();
+
+        // Assert
+        assert_eq!(path.root, ROOT);
+        assert_eq!(path.hops, 2);
+        assert_eq!(path.chain, vec![ROOT, middle, target_did]);
+    }
+
+    #[tokio::test]
+    async fn test_endorsement_graph_is_rebuilt_from_store() {
+        use crate::persistence::MemoryStore;
+        use std::sync::Arc;
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let endorser_did = "did:agoramesh:base:stored-endorser";
+        let target_did = "did:agoramesh:base:stored-target";
+        let service = test_service()
+            .with_trust_roots(vec![ROOT.to_string()])
+            .with_store(store.clone());
+        service.add_endorsement(ROOT, endorser_did).await.unwrap();
+        service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap();
+
+        // Act
+        let restored = test_service()
+            .with_trust_roots(vec![ROOT.to_string()])
+            .with_store(store);
+        restored.load_from_store().unwrap();
+
+        // Assert
+        let path = restored.endorsement_path(target_did).unwrap().unwrap();
+        assert_eq!(path.chain, vec![ROOT, endorser_did, target_did]);
+    }
+
+    // ========== TDD Tests: Endorsement Expiry ==========
+
+    #[tokio::test]
+    async fn test_expired_endorsement_stops_counting() {
+        // Arrange: root -> endorser -> target, the last link expiring
+        let endorser_did = "did:agoramesh:base:expiring-endorser";
+        let target_did = "did:agoramesh:base:expiring-target";
+        let service = rooted_service(&[endorser_did]).await;
+        service.set_trust_data(endorser_did, REFERENCE_STAKE, 100, 0, 0);
+        let expires_at = current_timestamp() + 3_600;
+        service
+            .add_endorsement_until(endorser_did, target_did, Some(expires_at))
+            .await
+            .unwrap();
+        let before = service.get_trust(target_did).await.unwrap();
+
+        // Act
+        let expired = service.expire_endorsements(expires_at).unwrap();
+
+        // Assert
+        assert!(before.endorsement_score > 0.0);
+        assert_eq!(expired, 1);
+        let after = service.get_trust(target_did).await.unwrap();
+        assert_eq!(after.endorsement_score, 0.0);
+        assert_eq!(after.endorsement_count, 0);
+        assert!(!service.has_endorsement(endorser_did, target_did));
+    }
+
+    #[tokio::test]
+    async fn test_unexpired_endorsements_are_kept() {
+        let service = test_service();
+        let target_did = "did:agoramesh:base:kept-target";
+        let expires_at = current_timestamp() + 3_600;
+        service
+            .add_endorsement_until("did:agoramesh:base:a", target_did, Some(expires_at))
+            .await
+            .unwrap();
+        service
+            .add_endorsement("did:agoramesh:base:b", target_did)
+            .await
+            .unwrap();
+
+        assert_eq!(service.expire_endorsements(expires_at - 1).unwrap(), 0);
+        assert_eq!(service.expire_endorsements(expires_at).unwrap(), 1);
+        let trust = service.get_trust(target_did).await.unwrap();
+        assert_eq!(trust.endorsement_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_endorsement_until_rejects_past_expiry() {
+        let service = test_service();
+
+        let result = service
+            .add_endorsement_until(
+                "did:agoramesh:base:late",
+                "did:agoramesh:base:target",
+                Some(current_timestamp() - 1),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reendorsing_replaces_expiry() {
+        let service = test_service();
+        let endorser_did = "did:agoramesh:base:renewer";
+        let target_did = "did:agoramesh:base:renewed";
+        let expires_at = current_timestamp() + 60;
+        service
+            .add_endorsement_until(endorser_did, target_did, Some(expires_at))
+            .await
+            .unwrap();
+
+        let added = service
+            .add_endorsement(endorser_did, target_did)
+            .await
+            .unwrap();
+
+        assert!(!added);
+        assert_eq!(service.expire_endorsements(expires_at).unwrap(), 0);
+        assert!(service.has_endorsement(endorser_did, target_did));
+    }
+
+    #[tokio::test]
+    async fn test_endorsement_expiry_is_persisted() {
+        use crate::persistence::MemoryStore;
+        use std::sync::Arc;
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let target_did = "did:agoramesh:base:stored-expiry";
+        let expires_at = current_timestamp() + 3_600;
+        let service = test_service().with_store(store.clone());
+        service
+            .add_endorsement_until("did:agoramesh:base:e", target_did, Some(expires_at))
+            .await
+            .unwrap();
+
+        // Act
+        let restored = test_service().with_store(store.clone());
+        restored.load_from_store().unwrap();
+        let expired = restored.expire_endorsements(expires_at).unwrap();
+
+        // Assert
+        assert_eq!(expired, 1);
+        assert!(store
+            .get(target_did)
+            .unwrap()
+            .unwrap()
+            .endorsements
+            .is_empty());
+    }
+
+    // ========== TDD Tests: EigenTrust Mode ==========
+
+    fn eigentrust_service() -> TrustService {
+        TrustService::with_weights(
+            "https://sepolia.base.org".to_string(),
+            None,
+            TrustWeights {
+                endorsement_mode: EndorsementMode::EigenTrust,
+                ..TrustWeights::default()
+            },
+        )
+        .with_trust_roots(vec![ROOT.to_string()])
+    }
+
+    #[tokio::test]
+    async fn test_eigentrust_without_trust_roots_scores_nothing() {
+        // Arrange
+        let service = TrustService::with_weights(
+            "https://sepolia.base.org".to_string(),
+            None,
+            TrustWeights {
+                endorsement_mode: EndorsementMode::EigenTrust,
+                ..TrustWeights::default()
+            },
+        );
+        let target_did = "did:agoramesh:base:rootless-target";
+        service
+            .add_endorsement("did:agoramesh:base:rootless-endorser", target_did)
+            .await
+            .unwrap();
+
+        // Act
+        let trust = service.get_trust(target_did).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.endorsement_score, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_eigentrust_ignores_endorsement_ring() {
+        // Arrange: 20 fresh agents endorsing each other, one honest agent
+        // endorsed by the root
+        let service = eigentrust_service();
+        let honest = "did:agoramesh:base:honest";
+        service.add_endorsement(ROOT, honest).await.unwrap();
+        for i in 0..20 {
+            for j in 0..20 {
+                if i != j {
+                    service
+                        .add_endorsement(
+                            &format!("did:agoramesh:base:ring-{}", i),
+                            &format!("did:agoramesh:base:ring-{}", j),
+                        )
+                        .await
+                        .unwrap();
+                }
+            }
+        }
+
+        // Act
+        let honest_trust = service.get_trust(honest).await.unwrap();
+        let ring_trust = service
+            .get_trust("did:agoramesh:base:ring-0")
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(honest_trust.endorsement_score > 0.0);
+        assert_eq!(ring_trust.endorsement_score, 0.0);
+        assert_eq!(ring_trust.endorsement_count, 19);
+    }
+
+    #[tokio::test]
+    async fn test_eigentrust_counts_successful_transactions() {
+        // Arrange
+        let service = eigentrust_service();
+        let client = "did:agoramesh:base:client";
+        let provider = "did:agoramesh:base:provider";
+        service.add_endorsement(ROOT, client).await.unwrap();
+        assert_eq!(
+            service.get_trust(provider).await.unwrap().endorsement_score,
+            0.0
+        );
+
+        // Act
+        service
+            .record_interaction(client, provider, true)
+            .await
+            .unwrap();
+
+        // Assert: the cached result was invalidated
+        assert!(service.get_trust(provider).await.unwrap().endorsement_score > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_eigentrust_net_failed_transactions_carry_no_trust() {
+        let service = eigentrust_service();
+        let client = "did:agoramesh:base:client";
+        let provider = "did:agoramesh:base:unreliable";
+        service.add_endorsement(ROOT, client).await.unwrap();
+
+        service
+            .record_interaction(client, provider, true)
+            .await
+            .unwrap();
+        service
+            .record_interaction(client, provider, false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.get_trust(provider).await.unwrap().endorsement_score,
+            0.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_interaction_rejects_self_dealing() {
+        let service = eigentrust_service();
+        let did = "did:agoramesh:base:wash-trader";
+
+        assert!(service.record_interaction(did, did, true).await.is_err());
+    }
+
+    // ========== TDD Tests: History ==========
+
+    fn history_service() -> (TrustService, TrustHistoryStore) {
+        use crate::persistence::MemoryStore;
+
+        let history = TrustHistoryStore::new(Arc::new(MemoryStore::new()));
+        (test_service().with_history(history.clone()), history)
+    }
+
+    #[tokio::test]
+    async fn test_trust_events_are_recorded() {
+        // Arrange
+        let (service, _) = history_service();
+        let did = "did:agoramesh:base:historic";
+
+        // Act
+        service.record_success(did, 5_000_000, None).await.unwrap();
+        service
+            .apply_stake_deposit(did, 1_000, "0x01:0")
+            .await
+            .unwrap();
+        service
+            .add_endorsement("did:agoramesh:base:fan", did)
+            .await
+            .unwrap();
+
+        // Assert
+        let history = service.history(did, 0, u64::MAX, 0).unwrap().unwrap();
+        let kinds: Vec<_> = history.events.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds.len(), 3);
+        assert!(kinds.contains(&TrustEventKind::TransactionSucceeded));
+        assert!(kinds.contains(&TrustEventKind::StakeDeposited));
+        assert!(history.events.iter().any(|e| {
+            e.kind == TrustEventKind::EndorsementAdded
+                && e.counterparty.as_deref() == Some("did:agoramesh:base:fan")
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_all_records_components() {
+        // Arrange
+        let (service, _) = history_service();
+        let did = "did:agoramesh:base:snapshotted";
+        service.set_trust_data(did, REFERENCE_STAKE, 90, 10, 0);
+
+        // Act
+        let written = service.snapshot_all().await.unwrap();
+
+        // Assert
+        assert_eq!(written, 1);
+        let history = service.history(did, 0, u64::MAX, 0).unwrap().unwrap();
+        let trust = service.get_trust(did).await.unwrap();
+        assert_eq!(history.snapshots.len(), 1);
+        assert_eq!(history.snapshots[0].score, trust.score);
+        assert_eq!(history.snapshots[0].stake_amount, REFERENCE_STAKE);
+    }
+
+    #[tokio::test]
+    async fn test_history_downsamples_and_prunes() {
+        // Arrange: hourly snapshots over two days
+        let (service, history) = history_service();
+        let did = "did:agoramesh:base:trending";
+        for hour in 0..48u64 {
+            let snapshot = TrustSnapshot {
+                timestamp: hour * 3_600,
+                score: hour as f64 / 48.0,
+                reputation: 0.0,
+                stake_score: 0.0,
+                endorsement_score: 0.0,
+                stake_amount: 0,
+                successful_transactions: hour,
+                failed_transactions: 0,
+                endorsement_count: 0,
+            };
+            history.put_snapshot(did, &snapshot).unwrap();
+        }
+
+        // Act
+        let daily = service.history(did, 0, u64::MAX, 86_400).unwrap().unwrap();
+        let pruned = service.prune_history(86_400).unwrap();
+
+        // Assert
+        assert_eq!(daily.snapshots.len(), 2);
+        assert!(daily.snapshots[1].score > daily.snapshots[0].score);
+        assert_eq!(pruned, 24);
+    }
+
+    #[test]
+    fn test_history_without_store_is_none() {
+        let service = test_service();
+
+        assert!(service
+            .history("did:agoramesh:base:any", 0, 10, 0)
+            .unwrap()
+            .is_none());
+        assert!(service.history("did:agoramesh:base:any", 10, 0, 0).is_err());
+    }
+
+    // ========== TDD Tests: Explain ==========
+
+    #[tokio::test]
+    async fn test_explain_matches_get_trust() {
+        // Arrange
+        let service = rooted_service(&["did:agoramesh:base:mid", "did:agoramesh:base:leaf"]).await;
+        let did = "did:agoramesh:base:leaf";
+        let ten_days_ago = current_timestamp() - 10 * 86_400;
+        service.set_trust_data_with_timestamp(
+            "did:agoramesh:base:mid",
+            0,
+            100,
+            0,
+            0,
+            current_timestamp(),
+        );
+        service.set_trust_data_with_timestamp(did, REFERENCE_STAKE / 4, 40, 10, 0, ten_days_ago);
+
+        // Act
+        let info = service.get_trust(did).await.unwrap();
+        let explanation = service.explain(did, None).await.unwrap();
+
+        // Assert
+        assert!((explanation.score - info.score).abs() < 1e-12);
+        assert_eq!(explanation.reputation.score, info.reputation);
+        assert_eq!(explanation.stake.score, info.stake_score);
+        assert_eq!(explanation.endorsements.score, info.endorsement_score);
+
+        let reputation = &explanation.reputation;
+        assert_eq!(reputation.successful_transactions, 40);
+        assert_eq!(reputation.failed_transactions, 10);
+        assert!((reputation.success_rate - 0.8).abs() < 1e-12);
+        assert!((reputation.volume_factor - 0.5).abs() < 1e-12);
+        assert!((reputation.days_since_activity.unwrap() - 10.0).abs() < 0.01);
+        assert!(reputation.decay_factor < 1.0);
+        assert!((explanation.stake.score - 0.5).abs() < 1e-12);
+
+        let contribution = &explanation.endorsements.contributions[0];
+        assert_eq!(contribution.endorser_did, "did:agoramesh:base:mid");
+        assert_eq!(contribution.hop_distance, Some(1));
+        assert!((contribution.hop_decay - ENDORSEMENT_DECAY_PER_HOP).abs() < 1e-12);
+        assert_eq!(contribution.status, EndorsementStatus::Counted);
+        assert!(contribution.contribution > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_explain_flags_endorsements_beyond_max_hops() {
+        // Arrange: chain one hop longer than MAX_ENDORSEMENT_HOPS allows
+        let service = rooted_service(&[
+            "did:agoramesh:base:h1",
+            "did:agoramesh:base:h2",
+            "did:agoramesh:base:h3",
+            "did:agoramesh:base:h4",
+            "did:agoramesh:base:target",
+        ])
+        .await;
+
+        // Act
+        let explanation = service
+            .explain("did:agoramesh:base:target", None)
+            .await
+            .unwrap();
+
+        // Assert
+        let contributions = &explanation.endorsements.contributions;
+        assert_eq!(contributions.len(), 1);
+        assert_eq!(contributions[0].hop_distance, None);
+        assert_eq!(contributions[0].status, EndorsementStatus::BeyondMaxHops);
+        assert_eq!(contributions[0].contribution, 0.0);
+        assert_eq!(explanation.endorsements.max_hops, MAX_ENDORSEMENT_HOPS);
+    }
+
+    #[tokio::test]
+    async fn test_explain_flags_endorsements_over_count_limit() {
+        // Arrange: more root-endorsed endorsers than are counted
+        let service = test_service().with_trust_roots(vec![ROOT.to_string()]);
+        let target = "did:agoramesh:base:popular";
+        for i in 0..MAX_ENDORSEMENTS_COUNTED + 2 {
+            let endorser = format!("did:agoramesh:base:endorser-{}", i);
+            service.add_endorsement(ROOT, &endorser).await.unwrap();
+            service.add_endorsement(&endorser, target).await.unwrap();
+        }
+
+        // Act
+        let explanation = service.explain(target, None).await.unwrap();
+
+        // Assert
+        let statuses: Vec<_> = explanation
+            .endorsements
+            .contributions
+            .iter()
+            .map(|c| c.status)
+            .collect();
+        assert_eq!(statuses.len(), MAX_ENDORSEMENTS_COUNTED + 2);
+        assert_eq!(
+            statuses
+                .iter()
+                .filter(|s| **s == EndorsementStatus::Counted)
+                .count(),
+            MAX_ENDORSEMENTS_COUNTED
+        );
+        assert_eq!(
+            statuses
+                .iter()
+                .filter(|s| **s == EndorsementStatus::OverCountLimit)
+                .count(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_explain_names_weights_and_mode() {
+        let service = eigentrust_service();
+
+        let explanation = service
+            .explain("did:agoramesh:base:nobody", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            explanation.weights.reputation,
+            TrustWeights::default().reputation
+        );
+        assert_eq!(explanation.endorsements.mode, EndorsementMode::EigenTrust);
+        assert!(explanation.endorsements.contributions.is_empty());
+        assert_eq!(explanation.reputation.days_since_activity, None);
+        assert!(service.explain("not-a-did", None).await.is_err());
+    }
+
+    // ========== TDD Tests: Weighted Reputation ==========
+
+    fn count_service() -> TrustService {
+        TrustService::with_weights(
+            "https://sepolia.base.org".to_string(),
+            None,
+            TrustWeights {
+                reputation_mode: ReputationMode::Count,
+                ..TrustWeights::default()
+            },
+        )
+    }
+
+    /// A hundred $0.01 successes followed by one $5,000 failure.
+    async fn record_small_wins_big_loss(service: &TrustService, did: &str) {
+        for _ in 0..100 {
+            service.record_success(did, 10_000, None).await.unwrap();
+        }
+        service
+            .record_failure_with_amount(did, 5_000_000_000, "error", None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_weighted_reputation_weighs_failures_by_amount() {
+        // Arrange
+        let weighted = test_service();
+        let count = count_service();
+        let did = "did:agoramesh:base:penny-wins";
+
+        // Act
+        record_small_wins_big_loss(&weighted, did).await;
+        record_small_wins_big_loss(&count, did).await;
+
+        // Assert
+        let weighted = weighted.get_trust(did).await.unwrap();
+        let count = count.get_trust(did).await.unwrap();
+        assert!(weighted.reputation < 0.01, "was {}", weighted.reputation);
+        assert!(count.reputation > 0.9, "was {}", count.reputation);
+    }
+
+    #[tokio::test]
+    async fn test_failure_reason_scales_reputation_penalty() {
+        let reputation_after = |reason: &'static str| async move {
+            let service = test_service();
+            let did = "did:agoramesh:base:reasons";
+            for _ in 0..4 {
+                service.record_success(did, 1_000_000, None).await.unwrap();
+            }
+            service
+                .record_failure_with_amount(did, 1_000_000, reason, None)
+                .await
+                .unwrap();
+            service.get_trust(did).await.unwrap().reputation
+        };
+
+        let timeout = reputation_after("timeout").await;
+        let dispute = reputation_after("dispute_lost").await;
+        let slashed = reputation_after("slashed").await;
+
+        assert!(timeout > dispute, "{} <= {}", timeout, dispute);
+        assert!(dispute > slashed, "{} <= {}", dispute, slashed);
+    }
+
+    #[tokio::test]
+    async fn test_failure_without_amount_weighs_as_average_transaction() {
+        // Arrange
+        let service = test_service();
+        let did = "did:agoramesh:base:average";
+        for _ in 0..8 {
+            service.record_success(did, 2_000_000, None).await.unwrap();
+        }
+
+        // Act
+        service.record_failure(did, "error", None).await.unwrap();
+        service.record_failure(did, "error", None).await.unwrap();
+
+        // Assert: same 80% rate as counting
+        let explanation = service.explain(did, None).await.unwrap().reputation;
+        assert_eq!(explanation.mode, ReputationMode::Weighted);
+        assert!(explanation.weighted.is_some());
+        assert!((explanation.success_rate - 0.8).abs() < 1e-6);
+        assert_eq!(explanation.total_volume, 16_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_counts_carry_into_weighted_reputation() {
+        // Arrange: history that predates weighting
+        let service = test_service();
+        let did = "did:agoramesh:base:veteran";
+        service.set_trust_data(did, 0, 90, 10, 0);
+        let seeded = service.explain(did, None).await.unwrap().reputation;
+        assert!(seeded.weighted.is_none());
+
+        // Act
+        service.record_success(did, 0, None).await.unwrap();
+
+        // Assert: one more success barely moves a 90% rate
+        let after = service.explain(did, None).await.unwrap().reputation;
+        assert!(after.weighted.is_some());
+        assert!((after.success_rate - seeded.success_rate).abs() < 0.01);
+    }
+
+    // ========== TDD Tests: Skill Reputation ==========
+
+    /// Translator with 20 successful translations and 10 failed code reviews.
+    async fn translator_service(did: &str) -> TrustService {
+        let service = test_service();
+        for _ in 0..20 {
+            service
+                .record_success(did, 1_000_000, Some("translate"))
+                .await
+                .unwrap();
+        }
+        for _ in 0..10 {
+            service
+                .record_failure_with_amount(did, 1_000_000, "error", Some("code-review"))
+                .await
+                .unwrap();
+        }
+        service
+    }
+
+    #[tokio::test]
+    async fn test_skill_trust_separates_skills() {
+        // Arrange
+        let did = "did:agoramesh:base:translator";
+        let service = translator_service(did).await;
+
+        // Act
+        let global = service.get_trust(did).await.unwrap();
+        let translate = service
+            .get_skill_trust(did, Some("translate"))
+            .await
+            .unwrap();
+        let review = service
+            .get_skill_trust(did, Some("code-review"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(global.skill, None);
+        assert_eq!(global.successful_transactions, 20);
+        assert_eq!(global.failed_transactions, 10);
+        assert_eq!(translate.skill.as_deref(), Some("translate"));
+        assert_eq!(translate.successful_transactions, 20);
+        assert_eq!(translate.failed_transactions, 0);
+        assert_eq!(review.reputation, 0.0);
+        assert!(translate.reputation > global.reputation);
+        assert!(global.reputation > review.reputation);
+        assert_eq!(translate.stake_score, global.stake_score);
+    }
+
+    #[tokio::test]
+    async fn test_skill_trust_without_history_falls_back_to_global() {
+        let did = "did:agoramesh:base:translator";
+        let service = translator_service(did).await;
+
+        let global = service.get_trust(did).await.unwrap();
+        let summarize = service
+            .get_skill_trust(did, Some("summarize"))
+            .await
+            .unwrap();
+
+        assert_eq!(summarize.skill, None);
+        assert_eq!(summarize.score, global.score);
+    }
+
+    #[tokio::test]
+    async fn test_explain_scopes_reputation_to_skill() {
+        let did = "did:agoramesh:base:translator";
+        let service = translator_service(did).await;
+
+        let translate = service
+            .get_skill_trust(did, Some("translate"))
+            .await
+            .unwrap();
+        let explanation = service.explain(did, Some("translate")).await.unwrap();
+
+        assert_eq!(explanation.skill.as_deref(), Some("translate"));
+        assert_eq!(explanation.score, translate.score);
+        assert_eq!(explanation.reputation.score, translate.reputation);
+        assert_eq!(explanation.reputation.successful_transactions, 20);
+        assert_eq!(explanation.reputation.failed_transactions, 0);
+        assert_eq!(explanation.provenance.policy, ReconcilePolicy::Local);
+    }
+
+    #[tokio::test]
+    async fn test_skill_reputation_is_persisted() {
+        use crate::persistence::MemoryStore;
+        use std::sync::Arc;
+
+        // Arrange
+        let store = TrustDataStore::new(Arc::new(MemoryStore::new()));
+        let service = test_service().with_store(store.clone());
+        let did = "did:agoramesh:base:persisted-skill";
+
+        // Act
+        service
+            .record_success(did, 1_000_000, Some("translate"))
+            .await
+            .unwrap();
+
+        // Assert
+        let restored = test_service().with_store(store);
+        restored.load_from_store().unwrap();
+        let trust = restored
+            .get_skill_trust(did, Some("translate"))
+            .await
+            .unwrap();
+        assert_eq!(trust.skill.as_deref(), Some("translate"));
+        assert_eq!(trust.successful_transactions, 1);
+    }
+
+    // ========== TDD Tests: On-chain reconciliation ==========
+
+    /// Service whose TrustRegistry RPC refuses connections.
+    fn unreachable_registry_service(config: ReconcileConfig) -> TrustService {
+        TrustService::new(
+            "http://127.0.0.1:1".to_string(),
+            Some("0x1234567890123456789012345678901234567890".to_string()),
+        )
+        .with_reconciliation(config)
+    }
+
+    fn onchain_policy() -> ReconcileConfig {
+        ReconcileConfig {
+            policy: ReconcilePolicy::OnChain,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_policy_reports_local_provenance() {
+        // Arrange
+        let service = test_service();
+        let did = "did:agoramesh:base:local";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.provenance.policy, ReconcilePolicy::Local);
+        assert_eq!(trust.provenance.stake.source, TrustSource::Local);
+        assert!(trust.provenance.onchain_error.is_none());
+        assert_eq!(
+            trust.info.score,
+            service.get_trust(did).await.unwrap().score
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_registry_falls_back_to_local() {
+        // Arrange
+        let service = unreachable_registry_service(onchain_policy());
+        let did = "did:agoramesh:base:offline";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.provenance.reputation.source, TrustSource::Local);
+        assert_eq!(trust.provenance.stake.source, TrustSource::Local);
+        assert!(trust.provenance.onchain_error.is_some());
+        assert_eq!(trust.info.stake_score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_registry_uses_last_known_good() {
+        // Arrange: the registry last reported a low stake score a minute ago
+        let service = unreachable_registry_service(onchain_policy());
+        let did = "did:agoramesh:base:flaky";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+        let last = TrustInfo {
+            stake_score: 0.25,
+            ..service.get_trust(did).await.unwrap()
+        };
+        service.onchain_breaker.set_last_known_good_for(
+            did,
+            CachedTrustInfo {
+                info: last,
+                cached_at: current_timestamp() - 60,
+            },
+        );
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.info.stake_score, 0.25);
+        assert_eq!(trust.provenance.stake.source, TrustSource::LastKnownGood);
+        assert!(trust.provenance.stake.age_secs >= 60);
+        assert!(trust.provenance.onchain_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cached_trust_does_not_read_the_registry() {
+        // Arrange
+        let service = unreachable_registry_service(onchain_policy());
+        let did = "did:agoramesh:base:offline";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+
+        // Act
+        let trust = service.get_cached_skill_trust(did, None).await.unwrap();
+
+        // Assert: the local components stand and nothing was loaded
+        assert_eq!(trust.stake_score, 1.0);
+        assert_eq!(
+            service.onchain_cache.stats().misses.load(Ordering::Relaxed),
+            1
+        );
+        assert!(service
+            .onchain_breaker
+            .get_last_known_good_for(did)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cached_trust_merges_cached_registry_value() {
+        // Arrange: the registry reported a low stake score on an earlier read
+        let service = unreachable_registry_service(onchain_policy());
+        let did = "did:agoramesh:base:cached";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+        let onchain = TrustInfo {
+            stake_score: 0.25,
+            ..service.get_trust(did).await.unwrap()
+        };
+        service.onchain_cache.insert(did, onchain).await;
+
+        // Act
+        let trust = service.get_cached_skill_trust(did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.stake_score, 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_explain_uses_reconciled_components() {
+        // Arrange: the registry last reported a low stake score
+        let service = unreachable_registry_service(onchain_policy());
+        let did = "did:agoramesh:base:flaky";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+        let last = TrustInfo {
+            stake_score: 0.25,
+            ..service.get_trust(did).await.unwrap()
+        };
+        service.onchain_breaker.set_last_known_good_for(
+            did,
+            CachedTrustInfo {
+                info: last,
+                cached_at: current_timestamp() - 60,
+            },
+        );
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+        let explanation = service.explain(did, None).await.unwrap();
+
+        // Assert: scores match /trust/{did}; inputs stay local
+        assert_eq!(explanation.score, trust.info.score);
+        assert_eq!(explanation.stake.score, 0.25);
+        assert_eq!(explanation.stake.stake_amount, REFERENCE_STAKE);
+        assert_eq!(
+            explanation.provenance.stake.source,
+            TrustSource::LastKnownGood
+        );
+        assert_eq!(
+            explanation.provenance.reputation.source,
+            trust.provenance.reputation.source
+        );
+    }
+
+    #[tokio::test]
+    async fn test_last_known_good_older_than_max_stale_is_ignored() {
+        // Arrange
+        let service = unreachable_registry_service(ReconcileConfig {
+            max_stale_secs: 3_600,
+            ..onchain_policy()
+        });
+        let did = "did:agoramesh:base:gone";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+        let last = TrustInfo {
+            stake_score: 0.25,
+            ..service.get_trust(did).await.unwrap()
+        };
+        service.onchain_breaker.set_last_known_good_for(
+            did,
+            CachedTrustInfo {
+                info: last,
+                cached_at: current_timestamp() - 7_200,
+            },
+        );
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+
+        // Assert
+        assert_eq!(trust.info.stake_score, 1.0);
+        assert_eq!(trust.provenance.stake.source, TrustSource::Local);
+    }
+
+    // ========== TDD Tests: Multi-chain ==========
+
+    fn unreachable_multichain(aggregate_trust: bool) -> Arc<MultiChainClient> {
+        use crate::multichain::{ChainConfig, MultiChainConfig};
+
+        let mut chain = ChainConfig::new(137);
+        chain.rpc_url = Some("http://127.0.0.1:1".to_string());
+        chain.trust_registry_address =
+            Some("0x1111111111111111111111111111111111111111".to_string());
+        let mut config = MultiChainConfig {
+            aggregate_trust,
+            ..Default::default()
+        };
+        config.add_chain(chain);
+        Arc::new(MultiChainClient::new(config).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_trust_requires_multichain() {
+        let service = test_service();
+
+        let result = service.get_chain_trust("did:agoramesh:base:agent").await;
+
+        assert!(matches!(result, Err(Error::Config(_))));
+        assert!(matches!(
+            service.get_chain_trust("invalid-did").await,
+            Err(Error::Trust(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reconciliation_reads_aggregated_chains() {
+        // Arrange: no primary registry, one unreachable aggregated chain
+        let service = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_multichain(unreachable_multichain(true))
+            .with_reconciliation(onchain_policy());
+        let did = "did:agoramesh:base:bridged";
+        service.set_trust_data(did, REFERENCE_STAKE, 10, 0, 0);
+
+        // Act
+        let trust = service.get_reconciled_trust(did, None).await.unwrap();
+        let chains = service.get_chain_trust(did).await.unwrap();
+
+        // Assert: the chain was consulted and its error reported
+        let error = trust.provenance.onchain_error.unwrap();
+        assert!(!error.contains("not configured"), "{}", error);
+        assert_eq!(trust.provenance.stake.source, TrustSource::Local);
+        assert_eq!(chains.chains[0].chain_id, 137);
+        assert!(chains.chains[0].error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reconciliation_ignores_chains_without_aggregation() {
+        let service = TrustService::new("https://sepolia.base.org".to_string(), None)
+            .with_multichain(unreachable_multichain(false))
+            .with_reconciliation(onchain_policy());
+
+        let trust = service
+            .get_reconciled_trust("did:agoramesh:base:agent", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            trust.provenance.onchain_error.as_deref(),
+            Some("TrustRegistry is not configured")
+        );
+    }
 }
@@ -317,6 +317,7 @@ mod tests {
             successful_transactions: 100,
             failed_transactions: 5,
             endorsement_count: 10,
+            skill: None,
         }
     }
 
@@ -0,0 +1,456 @@
+//! Web-of-trust endorsement graph.
+//!
+//! Endorsements form a directed graph (endorser → endorsee). Hop distances
+//! are measured from a configurable set of trust roots rather than claimed
+//! by whoever submits the endorsement:
+//!
+//! - Trust roots are at distance 0
+//! - An agent's distance is one more than its closest endorser's
+//! - Agents further than `max_hops` from every root are unreachable
+//!
+//! Distances are updated incrementally: adding an edge can only shorten
+//! paths, so it relaxes outward from the endorsee; removing one re-settles
+//! only the agents whose shortest paths all ran through it. Cycles need no
+//! special handling since a node's distance is settled at most once per
+//! change.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// Directed endorsement graph with hop distances from trust roots.
+#[derive(Debug, Clone, Default)]
+pub struct EndorsementGraph {
+    /// Trust roots (distance 0).
+    roots: HashSet<String>,
+    /// Maximum distance tracked; agents beyond it are unreachable.
+    max_hops: u32,
+    /// endorser → endorsees.
+    endorsees: HashMap<String, HashSet<String>>,
+    /// endorsee → endorsers.
+    endorsers: HashMap<String, HashSet<String>>,
+    /// Distance from the nearest root, for agents within `max_hops`.
+    distances: HashMap<String, u32>,
+}
+
+impl EndorsementGraph {
+    /// Create an empty graph tracking distances up to `max_hops`.
+    pub fn new(max_hops: u32) -> Self {
+        Self {
+            max_hops,
+            ..Default::default()
+        }
+    }
+
+    /// Replace the trust roots and recompute all distances.
+    pub fn set_roots<I, S>(&mut self, roots: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.roots = roots.into_iter().map(Into::into).collect();
+        self.recompute();
+    }
+
+    /// The configured trust roots.
+    pub fn roots(&self) -> &HashSet<String> {
+        &self.roots
+    }
+
+    /// Whether any trust roots are configured.
+    pub fn has_roots(&self) -> bool {
+        !self.roots.is_empty()
+    }
+
+    /// Maximum distance tracked.
+    pub fn max_hops(&self) -> u32 {
+        self.max_hops
+    }
+
+    /// Number of endorsement edges.
+    pub fn edge_count(&self) -> usize {
+        self.endorsees.values().map(HashSet::len).sum()
+    }
+
+    /// Whether `endorser` endorses `endorsee`.
+    pub fn contains_edge(&self, endorser: &str, endorsee: &str) -> bool {
+        self.endorsees
+            .get(endorser)
+            .is_some_and(|targets| targets.contains(endorsee))
+    }
+
+    /// Agents endorsing `endorsee`.
+    pub fn endorsers_of(&self, endorsee: &str) -> impl Iterator<Item = &str> {
+        self.endorsers
+            .get(endorsee)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Distance of `did` from the nearest trust root, if within `max_hops`.
+    pub fn distance(&self, did: &str) -> Option<u32> {
+        self.distances.get(did).copied()
+    }
+
+    /// Hop distance of an endorsement given by `endorser`.
+    ///
+    /// This is the endorser's own distance from the nearest root, so a
+    /// root's endorsement is hop 0. Returns `None` for unreachable
+    /// endorsers, which is every endorser when no roots are configured.
+    pub fn endorsement_hops(&self, endorser: &str) -> Option<u32> {
+        self.distance(endorser)
+    }
+
+    /// Add an endorsement edge. Returns `false` if it already existed or is
+    /// a self-endorsement.
+    pub fn add_edge(&mut self, endorser: &str, endorsee: &str) -> bool {
+        if endorser == endorsee {
+            return false;
+        }
+        let added = self
+            .endorsees
+            .entry(endorser.to_string())
+            .or_default()
+            .insert(endorsee.to_string());
+        if !added {
+            return false;
+        }
+        self.endorsers
+            .entry(endorsee.to_string())
+            .or_default()
+            .insert(endorser.to_string());
+
+        // A new edge can only shorten paths through the endorsee
+        if let Some(d) = self.distance(endorser) {
+            self.relax(vec![(d + 1, endorsee.to_string())]);
+        }
+        true
+    }
+
+    /// Remove an endorsement edge. Returns `false` if it did not exist.
+    pub fn remove_edge(&mut self, endorser: &str, endorsee: &str) -> bool {
+        let removed = self
+            .endorsees
+            .get_mut(endorser)
+            .is_some_and(|targets| targets.remove(endorsee));
+        if !removed {
+            return false;
+        }
+        if self.endorsees.get(endorser).is_some_and(HashSet::is_empty) {
+            self.endorsees.remove(endorser);
+        }
+        if let Some(sources) = self.endorsers.get_mut(endorsee) {
+            sources.remove(endorser);
+            if sources.is_empty() {
+                self.endorsers.remove(endorsee);
+            }
+        }
+
+        // Only matters if the edge was on a shortest path to the endorsee
+        let on_shortest_path = match (self.distance(endorser), self.distance(endorsee)) {
+            (Some(du), Some(dv)) => du + 1 == dv && !self.roots.contains(endorsee),
+            _ => false,
+        };
+        if on_shortest_path && !self.has_settled_parent(endorsee, &HashSet::new()) {
+            self.resettle(endorsee);
+        }
+        true
+    }
+
+    /// Shortest endorsement chain from a trust root to `did` (root first).
+    ///
+    /// Ties are broken by DID so the result is deterministic.
+    pub fn path(&self, did: &str) -> Option<Vec<String>> {
+        let mut distance = self.distance(did)?;
+        let mut chain = vec![did.to_string()];
+        let mut current = did;
+
+        while distance > 0 {
+            current = self
+                .endorsers_of(current)
+                .filter(|p| self.distance(p) == Some(distance - 1))
+                .min()?;
+            chain.push(current.to_string());
+            distance -= 1;
+        }
+
+        chain.reverse();
+        Some(chain)
+    }
+
+    /// Recompute all distances from the roots.
+    pub fn recompute(&mut self) {
+        self.distances.clear();
+        let seeds = self.roots.iter().map(|root| (0, root.clone())).collect();
+        self.relax(seeds);
+    }
+
+    /// Whether `did` has an endorser one hop closer to a root that is not in
+    /// `excluded`.
+    fn has_settled_parent(&self, did: &str, excluded: &HashSet<String>) -> bool {
+        let Some(d) = self.distance(did) else {
+            return false;
+        };
+        self.endorsers_of(did)
+            .any(|p| !excluded.contains(p) && self.distance(p).is_some_and(|dp| dp + 1 == d))
+    }
+
+    /// Re-settle `start` and every agent whose shortest paths all ran
+    /// through it after one of its shortest-path edges was removed.
+    fn resettle(&mut self, start: &str) {
+        // Collect affected agents level by level (FIFO), so every parent at
+        // the previous level is classified before its children are checked.
+        let mut affected: HashSet<String> = HashSet::from([start.to_string()]);
+        let mut queue = VecDeque::from([start.to_string()]);
+        while let Some(node) = queue.pop_front() {
+            let Some(d) = self.distance(&node) else {
+                continue;
+            };
+            let children: Vec<String> = self
+                .endorsees
+                .get(&node)
+                .into_iter()
+                .flatten()
+                .filter(|c| {
+                    !affected.contains(*c)
+                        && !self.roots.contains(*c)
+                        && self.distance(c) == Some(d + 1)
+                })
+                .cloned()
+                .collect();
+            for child in children {
+                if !self.has_settled_parent(&child, &affected) {
+                    affected.insert(child.clone());
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        for node in &affected {
+            self.distances.remove(node);
+        }
+
+        // Seed each affected agent from its best unaffected endorser
+        let seeds = affected
+            .iter()
+            .filter_map(|node| {
+                self.endorsers_of(node)
+                    .filter_map(|p| self.distance(p))
+                    .min()
+                    .map(|d| (d + 1, node.clone()))
+            })
+            .collect();
+        self.relax(seeds);
+    }
+
+    /// Dijkstra (unit weights) from `seeds`, lowering distances that improve.
+    fn relax(&mut self, seeds: Vec<(u32, String)>) {
+        let mut heap: BinaryHeap<Reverse<(u32, String)>> = seeds
+            .into_iter()
+            .filter(|(d, _)| *d <= self.max_hops)
+            .map(Reverse)
+            .collect();
+
+        while let Some(Reverse((d, node))) = heap.pop() {
+            if self.distance(&node).is_some_and(|current| current <= d) {
+                continue;
+            }
+            self.distances.insert(node.clone(), d);
+
+            if d == self.max_hops {
+                continue;
+            }
+            for next in self.endorsees.get(&node).into_iter().flatten() {
+                if self.distance(next).is_none_or(|current| current > d + 1) {
+                    heap.push(Reverse((d + 1, next.clone())));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(roots: &[&str], edges: &[(&str, &str)]) -> EndorsementGraph {
+        let mut graph = EndorsementGraph::new(3);
+        graph.set_roots(roots.iter().copied());
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    // ========== TDD Tests: Distances ==========
+
+    #[test]
+    fn test_distances_follow_shortest_chain() {
+        let graph = graph(
+            &["root"],
+            &[("root", "a"), ("a", "b"), ("b", "c"), ("root", "c")],
+        );
+
+        assert_eq!(graph.distance("root"), Some(0));
+        assert_eq!(graph.distance("a"), Some(1));
+        assert_eq!(graph.distance("b"), Some(2));
+        assert_eq!(graph.distance("c"), Some(1));
+    }
+
+    #[test]
+    fn test_agents_beyond_max_hops_are_unreachable() {
+        let graph = graph(
+            &["root"],
+            &[("root", "a"), ("a", "b"), ("b", "c"), ("c", "d")],
+        );
+
+        assert_eq!(graph.distance("c"), Some(3));
+        assert_eq!(graph.distance("d"), None);
+        assert_eq!(graph.endorsement_hops("c"), Some(3));
+        assert_eq!(graph.endorsement_hops("d"), None);
+    }
+
+    #[test]
+    fn test_self_claimed_chains_without_root_are_unreachable() {
+        // Sybils endorsing each other gain nothing without a root path
+        let graph = graph(&["root"], &[("sybil-1", "sybil-2"), ("sybil-2", "sybil-1")]);
+
+        assert_eq!(graph.endorsement_hops("sybil-1"), None);
+        assert_eq!(graph.distance("sybil-2"), None);
+    }
+
+    #[test]
+    fn test_without_roots_no_endorser_is_reachable() {
+        let graph = graph(&[], &[("a", "b")]);
+
+        assert_eq!(graph.endorsement_hops("a"), None);
+        assert_eq!(graph.endorsement_hops("unknown"), None);
+        assert_eq!(graph.path("b"), None);
+    }
+
+    #[test]
+    fn test_cycles_terminate() {
+        let graph = graph(
+            &["root"],
+            &[("root", "a"), ("a", "b"), ("b", "a"), ("b", "root")],
+        );
+
+        assert_eq!(graph.distance("a"), Some(1));
+        assert_eq!(graph.distance("b"), Some(2));
+        assert_eq!(graph.distance("root"), Some(0));
+    }
+
+    #[test]
+    fn test_self_endorsement_is_ignored() {
+        let mut graph = graph(&["root"], &[]);
+
+        assert!(!graph.add_edge("a", "a"));
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    // ========== TDD Tests: Incremental Updates ==========
+
+    #[test]
+    fn test_remove_edge_falls_back_to_longer_path() {
+        // Arrange: c is reachable directly and via a -> b
+        let mut graph = graph(
+            &["root"],
+            &[("root", "a"), ("a", "b"), ("b", "c"), ("root", "c")],
+        );
+
+        // Act
+        graph.remove_edge("root", "c");
+
+        // Assert
+        assert_eq!(graph.distance("c"), Some(3));
+    }
+
+    #[test]
+    fn test_remove_edge_disconnects_subtree() {
+        let mut graph = graph(&["root"], &[("root", "a"), ("a", "b"), ("b", "c")]);
+
+        graph.remove_edge("root", "a");
+
+        assert_eq!(graph.distance("a"), None);
+        assert_eq!(graph.distance("b"), None);
+        assert_eq!(graph.distance("c"), None);
+    }
+
+    #[test]
+    fn test_remove_edge_keeps_agents_with_other_parents() {
+        let mut graph = graph(
+            &["root-1", "root-2"],
+            &[("root-1", "a"), ("root-2", "a"), ("a", "b")],
+        );
+
+        graph.remove_edge("root-1", "a");
+
+        assert_eq!(graph.distance("a"), Some(1));
+        assert_eq!(graph.distance("b"), Some(2));
+    }
+
+    #[test]
+    fn test_remove_edge_in_cycle_does_not_keep_stale_distances() {
+        // a and b endorse each other; only a is connected to the root
+        let mut graph = graph(&["root"], &[("root", "a"), ("a", "b"), ("b", "a")]);
+
+        graph.remove_edge("root", "a");
+
+        assert_eq!(graph.distance("a"), None);
+        assert_eq!(graph.distance("b"), None);
+    }
+
+    #[test]
+    fn test_incremental_updates_match_full_recompute() {
+        // Arrange: a small graph with shortcuts and a cycle
+        let mut graph = graph(
+            &["r1", "r2"],
+            &[
+                ("r1", "a"),
+                ("a", "b"),
+                ("b", "c"),
+                ("c", "a"),
+                ("r2", "c"),
+                ("b", "d"),
+                ("c", "d"),
+            ],
+        );
+
+        // Act
+        graph.remove_edge("r2", "c");
+        graph.add_edge("r2", "b");
+        graph.remove_edge("r1", "a");
+        let incremental = graph.distances.clone();
+        graph.recompute();
+
+        // Assert
+        assert_eq!(incremental, graph.distances);
+    }
+
+    // ========== TDD Tests: Paths ==========
+
+    #[test]
+    fn test_path_from_nearest_root() {
+        let graph = graph(
+            &["root-a", "root-b"],
+            &[
+                ("root-a", "x"),
+                ("x", "y"),
+                ("y", "target"),
+                ("root-b", "z"),
+                ("z", "target"),
+            ],
+        );
+
+        assert_eq!(
+            graph.path("target"),
+            Some(vec![
+                "root-b".to_string(),
+                "z".to_string(),
+                "target".to_string()
+            ])
+        );
+        assert_eq!(graph.path("root-a"), Some(vec!["root-a".to_string()]));
+        assert_eq!(graph.path("stranger"), None);
+    }
+}
@@ -0,0 +1,324 @@
+//! Trust score history.
+//!
+//! The node records two time series per agent:
+//!
+//! - **Snapshots** of every [`TrustInfo`] component, taken periodically
+//! - **Events** that changed the agent's trust data (transactions, stake,
+//!   endorsements, on-chain reputation updates)
+//!
+//! Both are kept in the persistence layer for `retention_days` and served
+//! by `GET /trust/{did}/history`, downsampled to the requested resolution.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::trust::TrustInfo;
+
+/// Trust history configuration (`[trust.history]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrustHistoryConfig {
+    /// Record snapshots and events.
+    pub enabled: bool,
+
+    /// Seconds between snapshots of every known agent.
+    pub snapshot_interval_secs: u64,
+
+    /// Days to keep history for (0 keeps it forever).
+    pub retention_days: u64,
+}
+
+impl Default for TrustHistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            snapshot_interval_secs: 3600,
+            retention_days: 90,
+        }
+    }
+}
+
+impl TrustHistoryConfig {
+    /// Oldest timestamp to keep at `now`, or `None` to keep everything.
+    pub fn retention_cutoff(&self, now: u64) -> Option<u64> {
+        (self.retention_days > 0).then(|| now.saturating_sub(self.retention_days * 86_400))
+    }
+}
+
+/// Trust components of an agent at a point in time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustSnapshot {
+    /// Unix timestamp (seconds).
+    pub timestamp: u64,
+    /// Overall trust score (0.0 - 1.0).
+    pub score: f64,
+    /// Reputation component (0.0 - 1.0).
+    pub reputation: f64,
+    /// Stake component (0.0 - 1.0).
+    pub stake_score: f64,
+    /// Web-of-trust component (0.0 - 1.0).
+    pub endorsement_score: f64,
+    /// Staked amount in USDC (6 decimals).
+    pub stake_amount: u64,
+    /// Number of successful transactions.
+    pub successful_transactions: u64,
+    /// Number of failed transactions.
+    pub failed_transactions: u64,
+    /// Number of endorsements received.
+    pub endorsement_count: u64,
+}
+
+impl TrustSnapshot {
+    /// Snapshot `info` as of `timestamp`.
+    pub fn from_info(info: &TrustInfo, timestamp: u64) -> Self {
+        Self {
+            timestamp,
+            score: info.score,
+            reputation: info.reputation,
+            stake_score: info.stake_score,
+            endorsement_score: info.endorsement_score,
+            stake_amount: info.stake_amount,
+            successful_transactions: info.successful_transactions,
+            failed_transactions: info.failed_transactions,
+            endorsement_count: info.endorsement_count,
+        }
+    }
+}
+
+/// Kind of change to an agent's trust data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustEventKind {
+    /// A transaction completed successfully.
+    TransactionSucceeded,
+    /// A transaction failed or was disputed.
+    TransactionFailed,
+    /// The on-chain registry reported a new reputation score.
+    ReputationUpdated,
+    /// Stake was deposited.
+    StakeDeposited,
+    /// Stake was slashed.
+    StakeSlashed,
+    /// The agent received an endorsement.
+    EndorsementAdded,
+    /// An endorsement of the agent was revoked.
+    EndorsementRevoked,
+    /// An endorsement of the agent expired.
+    EndorsementExpired,
+}
+
+/// A change to an agent's trust data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustEvent {
+    /// Unix timestamp (seconds).
+    pub timestamp: u64,
+    /// What happened.
+    pub kind: TrustEventKind,
+    /// Amount in USDC (6 decimals) or score in basis points, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+    /// Other agent involved (e.g. the endorser), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<String>,
+}
+
+impl TrustEvent {
+    /// Create an event of `kind` at `timestamp`.
+    pub fn new(kind: TrustEventKind, timestamp: u64) -> Self {
+        Self {
+            timestamp,
+            kind,
+            value: None,
+            counterparty: None,
+        }
+    }
+
+    /// Set the amount or score.
+    pub fn with_value(mut self, value: u64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the other agent involved.
+    pub fn with_counterparty(mut self, did: impl Into<String>) -> Self {
+        self.counterparty = Some(did.into());
+        self
+    }
+}
+
+/// Trust history of an agent over a time range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustHistory {
+    /// Agent's DID.
+    pub did: String,
+    /// Start of the range (inclusive, Unix seconds).
+    pub from: u64,
+    /// End of the range (inclusive, Unix seconds).
+    pub to: u64,
+    /// Bucket size in seconds the snapshots were averaged over (0 = raw).
+    pub resolution: u64,
+    /// Snapshots, oldest first.
+    pub snapshots: Vec<TrustSnapshot>,
+    /// Events, oldest first.
+    pub events: Vec<TrustEvent>,
+}
+
+/// Parse a resolution such as `300`, `15m`, `1h` or `1d` into seconds.
+///
+/// `raw` (or `0`) disables downsampling.
+pub fn parse_resolution(value: &str) -> Result<u64> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("raw") {
+        return Ok(0);
+    }
+
+    let (digits, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => value.split_at(i),
+        None => (value, "s"),
+    };
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => {
+            return Err(Error::Validation(format!(
+                "Invalid resolution '{}': unit must be one of s, m, h, d, w",
+                value
+            )))
+        }
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| Error::Validation(format!("Invalid resolution '{}'", value)))
+}
+
+/// Average `snapshots` (sorted oldest first) into buckets of `resolution`
+/// seconds.
+///
+/// Score components are averaged; counters and stake take the bucket's
+/// latest value. Each point is stamped with its bucket's start time.
+pub fn downsample(snapshots: Vec<TrustSnapshot>, resolution: u64) -> Vec<TrustSnapshot> {
+    if resolution <= 1 {
+        return snapshots;
+    }
+
+    let mut points: Vec<TrustSnapshot> = Vec::new();
+    let mut count = 0.0;
+
+    for snapshot in snapshots {
+        let bucket = snapshot.timestamp - snapshot.timestamp % resolution;
+        match points.last_mut() {
+            Some(point) if point.timestamp == bucket => {
+                count += 1.0;
+                let mean = |avg: f64, x: f64| avg + (x - avg) / count;
+                point.score = mean(point.score, snapshot.score);
+                point.reputation = mean(point.reputation, snapshot.reputation);
+                point.stake_score = mean(point.stake_score, snapshot.stake_score);
+                point.endorsement_score = mean(point.endorsement_score, snapshot.endorsement_score);
+                point.stake_amount = snapshot.stake_amount;
+                point.successful_transactions = snapshot.successful_transactions;
+                point.failed_transactions = snapshot.failed_transactions;
+                point.endorsement_count = snapshot.endorsement_count;
+            }
+            _ => {
+                count = 1.0;
+                points.push(TrustSnapshot {
+                    timestamp: bucket,
+                    ..snapshot
+                });
+            }
+        }
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: u64, score: f64) -> TrustSnapshot {
+        TrustSnapshot {
+            timestamp,
+            score,
+            reputation: score,
+            stake_score: 0.0,
+            endorsement_score: 0.0,
+            stake_amount: 0,
+            successful_transactions: timestamp,
+            failed_transactions: 0,
+            endorsement_count: 0,
+        }
+    }
+
+    // ========== TDD Tests: Resolution ==========
+
+    #[test]
+    fn test_parse_resolution_units() {
+        assert_eq!(parse_resolution("300").unwrap(), 300);
+        assert_eq!(parse_resolution("15m").unwrap(), 900);
+        assert_eq!(parse_resolution("1h").unwrap(), 3_600);
+        assert_eq!(parse_resolution("1d").unwrap(), 86_400);
+        assert_eq!(parse_resolution("raw").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_parse_resolution_rejects_garbage() {
+        assert!(parse_resolution("1y").is_err());
+        assert!(parse_resolution("h").is_err());
+        assert!(parse_resolution("-5").is_err());
+    }
+
+    // ========== TDD Tests: Downsampling ==========
+
+    #[test]
+    fn test_downsample_averages_scores_per_bucket() {
+        // Arrange: three points in the first hour, one in the second
+        let snapshots = vec![
+            snapshot(0, 0.2),
+            snapshot(1_200, 0.4),
+            snapshot(2_400, 0.6),
+            snapshot(3_600, 0.9),
+        ];
+
+        // Act
+        let points = downsample(snapshots, 3_600);
+
+        // Assert
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].timestamp, 0);
+        assert!((points[0].score - 0.4).abs() < 1e-9);
+        assert_eq!(points[0].successful_transactions, 2_400);
+        assert_eq!(points[1].timestamp, 3_600);
+        assert_eq!(points[1].score, 0.9);
+    }
+
+    #[test]
+    fn test_downsample_raw_keeps_every_point() {
+        let snapshots = vec![snapshot(10, 0.1), snapshot(20, 0.2)];
+
+        assert_eq!(downsample(snapshots.clone(), 0), snapshots);
+    }
+
+    #[test]
+    fn test_retention_cutoff() {
+        let config = TrustHistoryConfig {
+            retention_days: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(config.retention_cutoff(100_000), Some(100_000 - 86_400));
+        assert_eq!(
+            TrustHistoryConfig {
+                retention_days: 0,
+                ..Default::default()
+            }
+            .retention_cutoff(100_000),
+            None
+        );
+    }
+}
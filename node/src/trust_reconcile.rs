@@ -0,0 +1,414 @@
+//! Reconciliation of local trust with the on-chain TrustRegistry.
+//!
+//! The node computes every trust component locally from what it observes
+//! and hears over gossip. The TrustRegistry contract holds the scores every
+//! node agrees on, but reading it costs an RPC round trip and the RPC can be
+//! down. With reconciliation enabled, [`TrustService`] fetches the
+//! registry's component scores through a TTL cache, protects the RPC with a
+//! circuit breaker that falls back to the last value it returned for the
+//! agent, and merges the result with the local components according to a
+//! [`ReconcilePolicy`].
+//!
+//! Every reconciled score records, per component, whether it came from the
+//! local cache, a fresh on-chain read or a last-known-good on-chain value,
+//! and how old it is.
+//!
+//! [`TrustService`]: crate::trust::TrustService
+
+use serde::{Deserialize, Serialize};
+
+use crate::contract::OnChainTrustDetails;
+use crate::trust::{TrustInfo, TrustWeights};
+
+/// Basis points per unit score (on-chain scores are 0-10000).
+const BPS: f64 = 10_000.0;
+
+/// On-chain reconciliation configuration (`[trust.reconcile]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReconcileConfig {
+    /// How on-chain and local components are merged.
+    pub policy: ReconcilePolicy,
+
+    /// Seconds an on-chain read is cached for.
+    pub cache_ttl_secs: u64,
+
+    /// Oldest last-known-good on-chain value (in seconds) used while the
+    /// registry is unreachable (0 = no limit).
+    pub max_stale_secs: u64,
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            policy: ReconcilePolicy::default(),
+            cache_ttl_secs: 300,
+            max_stale_secs: 86_400,
+        }
+    }
+}
+
+/// How on-chain and local trust components are merged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReconcilePolicy {
+    /// Use local components only; the registry is never queried.
+    #[default]
+    Local,
+    /// Use on-chain components when available, local ones otherwise.
+    OnChain,
+    /// Use the lower of the on-chain and local value of each component.
+    Min,
+}
+
+/// Where a trust component came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustSource {
+    /// Computed from the node's local trust data.
+    Local,
+    /// Read from the TrustRegistry (possibly cached).
+    OnChain,
+    /// Last value read from the TrustRegistry before it became unreachable.
+    LastKnownGood,
+}
+
+/// Origin and age of one trust component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentProvenance {
+    /// Where the value came from.
+    pub source: TrustSource,
+    /// Seconds since the value was read (0 for local values, which are
+    /// computed on request).
+    pub age_secs: u64,
+}
+
+impl ComponentProvenance {
+    /// A component computed locally.
+    pub fn local() -> Self {
+        Self {
+            source: TrustSource::Local,
+            age_secs: 0,
+        }
+    }
+}
+
+/// Origin of every component of a trust score.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustProvenance {
+    /// Policy the components were merged with.
+    pub policy: ReconcilePolicy,
+    /// Reputation component.
+    pub reputation: ComponentProvenance,
+    /// Stake component.
+    pub stake: ComponentProvenance,
+    /// Web-of-trust component.
+    pub endorsements: ComponentProvenance,
+    /// Why fresh on-chain data was not available, if it was not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub onchain_error: Option<String>,
+}
+
+/// Trust information together with the origin of its components.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciledTrust {
+    /// Merged trust information.
+    #[serde(flatten)]
+    pub info: TrustInfo,
+    /// Where each component came from.
+    pub provenance: TrustProvenance,
+}
+
+impl ReconciledTrust {
+    /// Trust computed from local data only.
+    pub fn local(info: TrustInfo, policy: ReconcilePolicy) -> Self {
+        Self {
+            info,
+            provenance: TrustProvenance {
+                policy,
+                reputation: ComponentProvenance::local(),
+                stake: ComponentProvenance::local(),
+                endorsements: ComponentProvenance::local(),
+                onchain_error: None,
+            },
+        }
+    }
+}
+
+/// Result of reading an agent's trust from the registry.
+#[derive(Debug, Clone)]
+pub enum OnChainObservation {
+    /// Read from the registry (or the cache) `age_secs` ago.
+    Fresh {
+        /// On-chain components.
+        info: TrustInfo,
+        /// Seconds since the read.
+        age_secs: u64,
+    },
+    /// Last-known-good value, served because the registry is unreachable.
+    Stale {
+        /// On-chain components.
+        info: TrustInfo,
+        /// Seconds since the read.
+        age_secs: u64,
+        /// Why the registry could not be read.
+        reason: String,
+    },
+    /// No on-chain value is available.
+    Unavailable {
+        /// Why the registry could not be read.
+        reason: String,
+    },
+}
+
+/// Convert the registry's component scores (basis points) to [`TrustInfo`].
+///
+/// Only the score fields are set; counters are not stored on-chain.
+pub fn onchain_trust_info(did: &str, details: &OnChainTrustDetails) -> TrustInfo {
+    TrustInfo {
+        did: did.to_string(),
+        score: details.composite_score as f64 / BPS,
+        reputation: details.reputation_score as f64 / BPS,
+        stake_score: details.stake_score as f64 / BPS,
+        endorsement_score: details.endorsement_score as f64 / BPS,
+        stake_amount: 0,
+        successful_transactions: 0,
+        failed_transactions: 0,
+        endorsement_count: 0,
+        skill: None,
+    }
+}
+
+/// Merge local and on-chain components according to `policy`.
+///
+/// The composite score is recomputed from the merged components with the
+/// local `weights`. Skill-scoped reputation always stays local, since the
+/// registry only knows an agent's global reputation. Counters and stake
+/// amount are always the local ones.
+pub fn reconcile(
+    local: TrustInfo,
+    onchain: OnChainObservation,
+    policy: ReconcilePolicy,
+    weights: &TrustWeights,
+) -> ReconciledTrust {
+    let (remote, remote_source, age_secs, onchain_error) = match onchain {
+        OnChainObservation::Fresh { info, age_secs } => {
+            (info, TrustSource::OnChain, age_secs, None)
+        }
+        OnChainObservation::Stale {
+            info,
+            age_secs,
+            reason,
+        } => (info, TrustSource::LastKnownGood, age_secs, Some(reason)),
+        OnChainObservation::Unavailable { reason } => {
+            let mut merged = ReconciledTrust::local(local, policy);
+            merged.provenance.onchain_error = Some(reason);
+            return merged;
+        }
+    };
+
+    let remote_component = ComponentProvenance {
+        source: remote_source,
+        age_secs,
+    };
+    let pick = |local: f64, remote: f64| -> (f64, ComponentProvenance) {
+        match policy {
+            ReconcilePolicy::Local => (local, ComponentProvenance::local()),
+            ReconcilePolicy::OnChain => (remote, remote_component),
+            ReconcilePolicy::Min if remote < local => (remote, remote_component),
+            ReconcilePolicy::Min => (local, ComponentProvenance::local()),
+        }
+    };
+
+    let (reputation, reputation_source) = if local.skill.is_some() {
+        (local.reputation, ComponentProvenance::local())
+    } else {
+        pick(local.reputation, remote.reputation)
+    };
+    let (stake_score, stake_source) = pick(local.stake_score, remote.stake_score);
+    let (endorsement_score, endorsement_source) =
+        pick(local.endorsement_score, remote.endorsement_score);
+
+    let score = weights.reputation * reputation
+        + weights.stake * stake_score
+        + weights.endorsements * endorsement_score;
+
+    ReconciledTrust {
+        info: TrustInfo {
+            score,
+            reputation,
+            stake_score,
+            endorsement_score,
+            ..local
+        },
+        provenance: TrustProvenance {
+            policy,
+            reputation: reputation_source,
+            stake: stake_source,
+            endorsements: endorsement_source,
+            onchain_error,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_info() -> TrustInfo {
+        TrustInfo {
+            did: "did:agoramesh:base:agent".to_string(),
+            score: 0.0,
+            reputation: 0.8,
+            stake_score: 0.2,
+            endorsement_score: 0.5,
+            stake_amount: 1_000_000_000,
+            successful_transactions: 8,
+            failed_transactions: 2,
+            endorsement_count: 3,
+            skill: None,
+        }
+    }
+
+    fn onchain_info() -> TrustInfo {
+        onchain_trust_info(
+            "did:agoramesh:base:agent",
+            &OnChainTrustDetails {
+                reputation_score: 6_000,
+                stake_score: 9_000,
+                endorsement_score: 5_000,
+                composite_score: 6_800,
+            },
+        )
+    }
+
+    // ========== TDD Tests: Conversion ==========
+
+    #[test]
+    fn test_onchain_trust_info_converts_basis_points() {
+        let info = onchain_info();
+
+        assert_eq!(info.reputation, 0.6);
+        assert_eq!(info.stake_score, 0.9);
+        assert_eq!(info.endorsement_score, 0.5);
+        assert_eq!(info.score, 0.68);
+    }
+
+    // ========== TDD Tests: Policies ==========
+
+    #[test]
+    fn test_onchain_policy_prefers_chain_components() {
+        // Arrange
+        let onchain = OnChainObservation::Fresh {
+            info: onchain_info(),
+            age_secs: 42,
+        };
+
+        // Act
+        let merged = reconcile(
+            local_info(),
+            onchain,
+            ReconcilePolicy::OnChain,
+            &TrustWeights::default(),
+        );
+
+        // Assert
+        assert_eq!(merged.info.reputation, 0.6);
+        assert_eq!(merged.info.stake_score, 0.9);
+        assert_eq!(merged.info.successful_transactions, 8);
+        assert_eq!(merged.info.stake_amount, 1_000_000_000);
+        assert_eq!(merged.provenance.stake.source, TrustSource::OnChain);
+        assert_eq!(merged.provenance.stake.age_secs, 42);
+        assert!(merged.provenance.onchain_error.is_none());
+    }
+
+    #[test]
+    fn test_min_policy_takes_lower_component() {
+        let onchain = OnChainObservation::Fresh {
+            info: onchain_info(),
+            age_secs: 0,
+        };
+        let weights = TrustWeights::default();
+
+        let merged = reconcile(local_info(), onchain, ReconcilePolicy::Min, &weights);
+
+        assert_eq!(merged.info.reputation, 0.6);
+        assert_eq!(merged.provenance.reputation.source, TrustSource::OnChain);
+        assert_eq!(merged.info.stake_score, 0.2);
+        assert_eq!(merged.provenance.stake.source, TrustSource::Local);
+        assert_eq!(merged.provenance.endorsements.source, TrustSource::Local);
+        let expected = weights.reputation * 0.6 + weights.stake * 0.2 + weights.endorsements * 0.5;
+        assert!((merged.info.score - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_stale_values_are_marked_last_known_good() {
+        let onchain = OnChainObservation::Stale {
+            info: onchain_info(),
+            age_secs: 3_600,
+            reason: "Circuit open".to_string(),
+        };
+
+        let merged = reconcile(
+            local_info(),
+            onchain,
+            ReconcilePolicy::OnChain,
+            &TrustWeights::default(),
+        );
+
+        assert_eq!(merged.info.reputation, 0.6);
+        assert_eq!(
+            merged.provenance.reputation,
+            ComponentProvenance {
+                source: TrustSource::LastKnownGood,
+                age_secs: 3_600,
+            }
+        );
+        assert_eq!(
+            merged.provenance.onchain_error.as_deref(),
+            Some("Circuit open")
+        );
+    }
+
+    #[test]
+    fn test_unavailable_chain_falls_back_to_local() {
+        let local = local_info();
+        let onchain = OnChainObservation::Unavailable {
+            reason: "connection refused".to_string(),
+        };
+
+        let merged = reconcile(
+            local.clone(),
+            onchain,
+            ReconcilePolicy::OnChain,
+            &TrustWeights::default(),
+        );
+
+        assert_eq!(merged.info.reputation, local.reputation);
+        assert_eq!(merged.provenance.reputation.source, TrustSource::Local);
+        assert!(merged.provenance.onchain_error.is_some());
+    }
+
+    #[test]
+    fn test_skill_reputation_stays_local() {
+        let local = TrustInfo {
+            skill: Some("translate".to_string()),
+            ..local_info()
+        };
+        let onchain = OnChainObservation::Fresh {
+            info: onchain_info(),
+            age_secs: 0,
+        };
+
+        let merged = reconcile(
+            local,
+            onchain,
+            ReconcilePolicy::OnChain,
+            &TrustWeights::default(),
+        );
+
+        assert_eq!(merged.info.reputation, 0.8);
+        assert_eq!(merged.provenance.reputation.source, TrustSource::Local);
+        assert_eq!(merged.provenance.stake.source, TrustSource::OnChain);
+    }
+}
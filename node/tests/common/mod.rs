@@ -132,7 +132,11 @@ pub fn create_test_state() -> AppState {
         rate_limiter: Arc::new(RateLimitService::new(RateLimitConfig::default())),
         metrics: Arc::new(MetricsService::new(MetricsConfig::default())),
         hybrid_search: None,
+        did_resolver: None,
         api_token: None,
+        write_verifier: None,
+        envelope_verifier: None,
+        gossip: None,
     }
 }
 
@@ -150,7 +154,11 @@ pub fn create_test_state_with_rate_limit(config: RateLimitConfig) -> AppState {
         rate_limiter: Arc::new(RateLimitService::new(config)),
         metrics: Arc::new(MetricsService::new(MetricsConfig::default())),
         hybrid_search: None,
+        did_resolver: None,
         api_token: None,
+        write_verifier: None,
+        envelope_verifier: None,
+        gossip: None,
     }
 }
 
@@ -5,6 +5,9 @@
 //! - Trust score calculations with real data
 //! - Discovery + Trust integration
 //! - Multi-agent interaction scenarios
+//! - Persistence across node restarts
+//! - Contract events applied by a running node binary
+//! - Node gossip verified by a peer through DID resolution
 //!
 //! ## Running Tests
 //!
@@ -16,10 +19,12 @@ use std::sync::Arc;
 
 use agoramesh_node::{
     discovery::{AgoraMeshExtension, CapabilityCard, Skill},
+    network::{topics, MessageHandler, NetworkEvent},
     trust::TrustInfo,
     AIArbitrationConfig, AIArbitrator, CircuitBreaker, CircuitBreakerConfig, CircuitState,
-    DiscoveryService, Evidence, EvidenceType, JurorPool, JurorPoolConfig, TrustCache,
-    TrustCacheConfig, TrustService,
+    DiscoveryService, Evidence, EvidenceType, JurorPool, JurorPoolConfig, NetworkConfig,
+    NetworkManager, NodeIdentity, PeerDidResolver, PersistenceConfig, PersistenceManager,
+    SearchFilter, TrustCache, TrustCacheConfig, TrustService, UniversalResolver,
 };
 
 // ============================================================================
@@ -225,6 +230,7 @@ async fn test_trust_cache_operations() {
         successful_transactions: 100,
         failed_transactions: 5,
         endorsement_count: 10,
+        skill: None,
     };
 
     // Insert into cache
@@ -247,6 +253,7 @@ async fn test_trust_cache_operations() {
         successful_transactions: 150,
         failed_transactions: 5,
         endorsement_count: 15,
+        skill: None,
     };
     cache.insert(did, updated_info).await;
 
@@ -265,10 +272,7 @@ async fn test_trust_endorsement_recording() {
     let target = "did:agoramesh:base:endorsed";
 
     // Add endorsement
-    service
-        .add_endorsement_with_hop(endorser, target, 1)
-        .await
-        .unwrap();
+    service.add_endorsement(endorser, target).await.unwrap();
 
     // Get target's trust info
     let trust = service.get_trust(target).await.unwrap();
@@ -364,7 +368,10 @@ async fn test_search_returns_registered_agents() {
     }
 
     // Search for agents
-    let results = discovery.search("agent").await.unwrap();
+    let results = discovery
+        .search("agent", &SearchFilter::default())
+        .await
+        .unwrap();
 
     // Should return agents
     assert!(!results.is_empty(), "Should find agents");
@@ -481,6 +488,7 @@ async fn test_multi_agent_collaboration_setup() {
             successful_transactions: 50,
             failed_transactions: 2,
             endorsement_count: 5,
+            skill: None,
         };
         cache.insert(did, trust_info).await;
     }
@@ -614,6 +622,7 @@ async fn test_trust_cache_under_concurrent_access() {
                     successful_transactions: 100,
                     failed_transactions: 5,
                     endorsement_count: 3,
+                    skill: None,
                 };
                 cache.insert(&did, info).await;
             }
@@ -651,7 +660,7 @@ async fn test_record_success_updates_trust() {
     let did = "did:agoramesh:base:success-recording";
 
     // Record successful transaction
-    service.record_success(did, 1_000_000).await.unwrap();
+    service.record_success(did, 1_000_000, None).await.unwrap();
 
     // Check trust info
     let trust = service.get_trust(did).await.unwrap();
@@ -665,7 +674,7 @@ async fn test_record_failure_updates_trust() {
     let did = "did:agoramesh:base:failure-recording";
 
     // Record failed transaction
-    service.record_failure(did, "timeout").await.unwrap();
+    service.record_failure(did, "timeout", None).await.unwrap();
 
     // Check trust info
     let trust = service.get_trust(did).await.unwrap();
@@ -680,10 +689,10 @@ async fn test_mixed_transactions_affect_reputation() {
 
     // Record 8 successes and 2 failures (80% success rate)
     for _ in 0..8 {
-        service.record_success(did, 100_000).await.unwrap();
+        service.record_success(did, 100_000, None).await.unwrap();
     }
     for _ in 0..2 {
-        service.record_failure(did, "error").await.unwrap();
+        service.record_failure(did, "error", None).await.unwrap();
     }
 
     // Check trust info
@@ -695,3 +704,384 @@ async fn test_mixed_transactions_affect_reputation() {
         "Should have positive reputation with 80% success"
     );
 }
+
+// ============================================================================
+// Persistence Tests
+// ============================================================================
+
+/// Build discovery and trust services backed by RocksDB in `data_dir`,
+/// rehydrated from whatever a previous run persisted there.
+async fn open_persistent_node(data_dir: &std::path::Path) -> (DiscoveryService, TrustService) {
+    let persistence = PersistenceManager::new(PersistenceConfig {
+        data_dir: data_dir.to_string_lossy().to_string(),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let discovery =
+        DiscoveryService::new().with_card_store(persistence.capability_cards().unwrap().clone());
+    let trust = TrustService::new("https://sepolia.base.org".to_string(), None)
+        .with_store(persistence.trust_data().unwrap().clone());
+
+    discovery.load_from_store().await.unwrap();
+    trust.load_from_store().unwrap();
+
+    (discovery, trust)
+}
+
+#[tokio::test]
+async fn test_agents_and_trust_survive_node_restart() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let did = "did:agoramesh:base:persistent-agent";
+    let endorser = "did:agoramesh:base:persistent-endorser";
+
+    let score_before = {
+        let (discovery, trust) = open_persistent_node(tmp_dir.path()).await;
+
+        let card = CapabilityCard {
+            name: "Persistent Agent".to_string(),
+            description: "Survives restarts".to_string(),
+            url: "https://agent.example.com/a2a".to_string(),
+            provider: None,
+            skills: vec![],
+            authentication: None,
+            agoramesh: Some(AgoraMeshExtension {
+                did: did.to_string(),
+                trust_score: None,
+                stake: None,
+                pricing: None,
+                payment_methods: vec!["x402".to_string()],
+            }),
+        };
+        discovery.register(&card).await.unwrap();
+
+        trust.seed_trust_data(endorser, 0, 100, 0, 0);
+        trust.seed_trust_data(did, 1_000_000_000, 0, 0, 0);
+        for _ in 0..9 {
+            trust.record_success(did, 100_000, None).await.unwrap();
+        }
+        trust.record_failure(did, "timeout", None).await.unwrap();
+        trust.add_endorsement(endorser, did).await.unwrap();
+
+        trust.get_trust(did).await.unwrap()
+        // Services and stores are dropped here, releasing the RocksDB locks
+    };
+
+    // "Restart" the node on the same data directory
+    let (discovery, trust) = open_persistent_node(tmp_dir.path()).await;
+
+    let card = discovery.get(did).await.unwrap();
+    assert_eq!(card.map(|c| c.name).as_deref(), Some("Persistent Agent"));
+    assert_eq!(
+        discovery
+            .search("Persistent", &SearchFilter::default())
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let score_after = trust.get_trust(did).await.unwrap();
+    assert_eq!(score_after.successful_transactions, 9);
+    assert_eq!(score_after.failed_transactions, 1);
+    assert_eq!(score_after.stake_amount, 1_000_000_000);
+    assert_eq!(score_after.endorsement_count, 1);
+    // Inactivity decay moves the score by ~4e-8 per second between reads
+    assert!(
+        (score_after.score - score_before.score).abs() < 1e-6,
+        "Trust score should survive restart: {} vs {}",
+        score_before.score,
+        score_after.score
+    );
+}
+
+// ============================================================================
+// Running Node Tests
+// ============================================================================
+
+/// Minimal JSON-RPC chain stand-in: a fixed head plus the logs pushed so far.
+#[derive(Clone, Default)]
+struct ChainStandIn {
+    state: Arc<std::sync::Mutex<(u64, Vec<alloy::rpc::types::Log>)>>,
+    requests: Arc<std::sync::atomic::AtomicU64>,
+}
+
+impl ChainStandIn {
+    /// Serve the stand-in over HTTP on an ephemeral port; returns its URL.
+    async fn start(head: u64) -> (String, Self) {
+        let chain = Self::default();
+        chain.state.lock().unwrap().0 = head;
+
+        let app = axum::Router::new()
+            .route("/", axum::routing::post(Self::http))
+            .with_state(chain.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}", addr), chain)
+    }
+
+    fn block_hash(number: u64) -> alloy::primitives::B256 {
+        alloy::primitives::B256::repeat_byte(number as u8)
+    }
+
+    /// Mine `log` in block `number` and advance the head to `head`.
+    fn mine(&self, number: u64, log: alloy::primitives::Log, head: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.0 = head;
+        state.1.push(alloy::rpc::types::Log {
+            inner: log,
+            block_hash: Some(Self::block_hash(number)),
+            block_number: Some(number),
+            block_timestamp: None,
+            transaction_hash: Some(alloy::primitives::B256::repeat_byte(0xaa)),
+            transaction_index: Some(0),
+            log_index: Some(0),
+            removed: false,
+        });
+    }
+
+    async fn http(
+        axum::extract::State(chain): axum::extract::State<Self>,
+        axum::Json(request): axum::Json<serde_json::Value>,
+    ) -> axum::Json<serde_json::Value> {
+        chain
+            .requests
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let (head, logs) = chain.state.lock().unwrap().clone();
+        let number = |value: &serde_json::Value| {
+            value
+                .as_str()
+                .and_then(|n| u64::from_str_radix(n.trim_start_matches("0x"), 16).ok())
+        };
+
+        let result = match request["method"].as_str().unwrap_or_default() {
+            "eth_chainId" => serde_json::json!("0x14a34"),
+            "eth_blockNumber" => serde_json::json!(format!("{:#x}", head)),
+            "eth_getBlockByNumber" => {
+                let block = match request["params"][0].as_str() {
+                    Some("latest") => Some(head),
+                    _ => number(&request["params"][0]),
+                };
+                match block.filter(|n| *n <= head) {
+                    Some(block) => serde_json::json!(alloy::rpc::types::Block::<
+                        alloy::rpc::types::Transaction,
+                    >::empty(
+                        alloy::rpc::types::Header {
+                            hash: Self::block_hash(block),
+                            inner: alloy::consensus::Header {
+                                number: block,
+                                ..Default::default()
+                            },
+                            total_difficulty: None,
+                            size: None,
+                        }
+                    )),
+                    None => serde_json::Value::Null,
+                }
+            }
+            "eth_getLogs" => {
+                let filter = &request["params"][0];
+                let from = number(&filter["fromBlock"]).unwrap_or(0);
+                let to = number(&filter["toBlock"]).unwrap_or(head);
+                let logs: Vec<_> = logs
+                    .iter()
+                    .filter(|log| (from..=to).contains(&log.block_number.unwrap_or_default()))
+                    .collect();
+                serde_json::json!(logs)
+            }
+            other => {
+                return axum::Json(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": {"code": -32601, "message": format!("method not found: {}", other)}
+                }))
+            }
+        };
+
+        axum::Json(serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": result}))
+    }
+}
+
+/// Fetch `/trust/{did}` from a running node, if it answers yet.
+async fn fetch_trust(api: &str, did: &str) -> Option<serde_json::Value> {
+    let url = format!("{}/trust/{}", api, urlencoding::encode(did));
+    let response = alloy::transports::http::reqwest::get(url).await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    serde_json::from_str(&response.text().await.ok()?).ok()
+}
+
+#[tokio::test]
+async fn test_running_node_applies_stake_slash_from_chain() {
+    use agoramesh_node::TrustRegistryClient;
+    use alloy::primitives::{keccak256, Address, Bytes, U256};
+
+    const TRUST_REGISTRY: &str = "0x1234567890123456789012345678901234567890";
+    let did = "did:agoramesh:base-sepolia:slashed-agent";
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let (rpc_url, chain) = ChainStandIn::start(100).await;
+
+    let api_addr = {
+        let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        probe.local_addr().unwrap().to_string()
+    };
+    let api = format!("http://{}", api_addr);
+
+    let mut node = tokio::process::Command::new(env!("CARGO_BIN_EXE_agoramesh"))
+        .arg("--config")
+        .arg(tmp_dir.path().join("missing.toml"))
+        .arg("start")
+        .env("AGORAMESH_API_LISTEN", &api_addr)
+        .env("AGORAMESH_P2P_LISTEN", "/ip4/127.0.0.1/tcp/0")
+        .env("AGORAMESH_CHAIN_RPC", &rpc_url)
+        .env("AGORAMESH_TRUST_REGISTRY_ADDRESS", TRUST_REGISTRY)
+        .env("AGORAMESH_DATA_DIR", tmp_dir.path().join("data"))
+        .env("AGORAMESH_NODE_KEY_FILE", tmp_dir.path().join("node.key"))
+        .env(
+            "AGORAMESH_SEED_TRUST",
+            format!(r#"[{{"did":"{}","stake_amount":5000000000}}]"#, did),
+        )
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .expect("node binary should start");
+
+    // Wait until the API serves the seeded stake and the listener has read the head
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(60);
+    let before = loop {
+        if let Some(trust) = fetch_trust(&api, did).await {
+            if trust["stake_amount"] == 5_000_000_000u64
+                && chain.requests.load(std::sync::atomic::Ordering::SeqCst) > 0
+            {
+                break trust;
+            }
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "node did not come up"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    };
+
+    // Slash 2000 USDC in block 101 and confirm it
+    let mut data = U256::from(2_000_000_000u64).to_be_bytes::<32>().to_vec();
+    data.extend_from_slice(&[0u8; 32]);
+    let slash = alloy::primitives::Log::new_unchecked(
+        TRUST_REGISTRY.parse::<Address>().unwrap(),
+        vec![
+            keccak256("StakeSlashed(bytes32,uint256,bytes32)"),
+            TrustRegistryClient::did_to_hash(did),
+        ],
+        Bytes::from(data),
+    );
+    chain.mine(101, slash, 101 + 12);
+
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(60);
+    let after = loop {
+        if let Some(trust) = fetch_trust(&api, did).await {
+            if trust["stake_amount"] != before["stake_amount"] {
+                break trust;
+            }
+        }
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "stake slash was never applied"
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    };
+
+    assert_eq!(after["stake_amount"], 3_000_000_000u64);
+    assert!(after["score"].as_f64().unwrap() < before["score"].as_f64().unwrap());
+
+    node.kill().await.unwrap();
+}
+
+// ============================================================================
+// Gossip Between Nodes
+// ============================================================================
+
+/// P2P config listening on a free localhost port, and that port's address.
+fn local_p2p_config() -> (NetworkConfig, String) {
+    let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("/ip4/127.0.0.1/tcp/{}", probe.local_addr().unwrap().port());
+    drop(probe);
+
+    let config = NetworkConfig {
+        listen_addresses: vec![address.clone()],
+        bootstrap_peers: vec![],
+        max_connections: 10,
+    };
+    (config, address)
+}
+
+#[tokio::test]
+async fn test_node_announcement_verifies_on_peer_through_did_resolution() {
+    // Node A signs as its own did:agoramesh, which nothing has published
+    let identity = NodeIdentity::generate();
+    let node_did = identity.did(8453);
+    let (config_a, _) = local_p2p_config();
+    let node_a = NetworkManager::with_keypair(config_a, identity.keypair())
+        .unwrap()
+        .with_signer(identity.envelope_signer(&node_did));
+    let discovery_a = DiscoveryService::new().with_publisher(node_a.publisher().unwrap());
+
+    // Node B resolves DIDs as a node without a TrustRegistry does
+    let (config_b, address_b) = local_p2p_config();
+    let mut node_b = NetworkManager::new(config_b).unwrap();
+    let mut events_b = node_b.take_event_receiver().unwrap();
+    let discovery_b = Arc::new(DiscoveryService::new());
+    let resolver = UniversalResolver::with_defaults()
+        .unwrap()
+        .with_method("agoramesh", Arc::new(PeerDidResolver));
+    let handler_b = MessageHandler::new(discovery_b.clone()).with_did_documents(Arc::new(resolver));
+
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    node_a
+        .connect(&format!("{}/p2p/{}", address_b, node_b.local_peer_id()))
+        .await
+        .unwrap();
+
+    let card = CapabilityCard {
+        name: "Node Agent".to_string(),
+        description: "Served by the announcing node".to_string(),
+        url: "https://node-a.example.com/a2a".to_string(),
+        provider: None,
+        skills: vec![],
+        authentication: None,
+        agoramesh: Some(AgoraMeshExtension {
+            did: node_did.clone(),
+            trust_score: None,
+            stake: None,
+            pricing: None,
+            payment_methods: vec![],
+        }),
+    };
+
+    // Publishing only reaches B once the nodes have exchanged subscriptions
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(30);
+    'announce: loop {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "announcement never reached node B"
+        );
+        discovery_a.register(&card).await.unwrap();
+
+        while let Ok(Some(event)) =
+            tokio::time::timeout(std::time::Duration::from_millis(500), events_b.recv()).await
+        {
+            if let NetworkEvent::Message { topic, .. } = &event {
+                assert_eq!(topic, topics::DISCOVERY);
+                handler_b.handle_event(&event).await.unwrap();
+                break 'announce;
+            }
+        }
+    }
+
+    let received = discovery_b.get(&node_did).await.unwrap();
+    assert_eq!(received.unwrap().name, "Node Agent");
+    assert_eq!(handler_b.stats().await.messages_processed, 1);
+}
@@ -157,6 +157,7 @@ async fn test_concurrent_trust_cache_operations() {
                 successful_transactions: 100,
                 failed_transactions: 5,
                 endorsement_count: 3,
+                skill: None,
             };
             cache.insert(&did, info).await;
         });
@@ -234,6 +235,7 @@ async fn test_trust_cache_stress() {
                         successful_transactions: 100,
                         failed_transactions: 5,
                         endorsement_count: 3,
+                        skill: None,
                     };
                     cache.insert(&did, info).await;
                 } else {